@@ -18,1590 +18,9614 @@ mod tests;
 //		Packages																										
 
 use base64::{DecodeError, engine::{Engine as _, general_purpose::STANDARD as BASE64}};
-use bytes::Bytes;
+use bytes::{Buf as _, Bytes};
 use core::{
 	cmp::Ordering,
 	convert::Infallible,
 	error::Error,
 	fmt::{Debug, Display, Write, self},
+	future::Future,
+	mem,
 	ops::{Add, AddAssign},
-	str::FromStr,
+	pin::pin,
+	str::{FromStr, Utf8Error, from_utf8},
+	time::Duration,
 };
 use futures::executor;
-use futures_util::FutureExt as _;
-use http::{Response, StatusCode};
-use http_body_util::{BodyExt as _, Collected, Full};
+use futures_timer::Delay;
+use futures_util::{FutureExt as _, future::{Either, select}};
+use http::{Method, Request, Response, StatusCode, Uri, Version};
+use http_body_util::{BodyExt as _, Collected, Full, LengthLimitError, Limited};
 use hyper::{
-	body::Incoming,
+	body::{Body, Incoming},
 	HeaderMap,
-	header::HeaderValue,
+	header::{CONTENT_LENGTH, CONTENT_TYPE, HeaderName, HeaderValue, SET_COOKIE, TRANSFER_ENCODING},
+};
+use regex::{NoExpand, Regex};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::{DeserializeOwned, Error as DeError}};
+use serde_json::{Error as JsonError, Map as JsonMap, Value as Json, json};
+use serde_urlencoded::{de::Error as FormDeError, ser::Error as FormSerError};
+use std::{
+	borrow::Cow,
+	collections::BTreeMap,
+	env,
+	fs,
+	io::{Error as IoError, ErrorKind as IoErrorKind},
+	path::{Path, PathBuf},
 };
-use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as DeError};
-use serde_json::Value as Json;
-use std::borrow::Cow;
 use thiserror::Error as ThisError;
 
 #[cfg(feature = "axum")]
 use ::{
-	axum::body::{Body as AxumBody, to_bytes},
-	core::mem,
+	axum::{
+		Router,
+		body::{Body as AxumBody, to_bytes},
+		response::{IntoResponse, Response as AxumResponse},
+	},
+	http::{Error as HttpError, request::Builder as RequestBuilder},
+	tower::ServiceExt as _,
+};
+#[cfg(feature = "cassette")]
+use ::{
+	core::future::{Ready, ready},
+	serde_yaml_ng::Error as YamlError,
+	sha2::{Digest as _, Sha256},
+	std::sync::{Mutex, PoisonError},
+};
+#[cfg(any(feature = "cassette", feature = "logging"))]
+use ::{
+	core::{
+		pin::Pin,
+		task::{Context, Poll},
+	},
+	std::sync::Arc,
+	tower_service::Service,
+};
+#[cfg(feature = "logging")]
+use ::{
+	core::task::ready,
+	hyper::body::{Frame, SizeHint},
+	tower_layer::Layer,
+	tracing::info,
+};
+#[cfg(feature = "compression")]
+use ::{
+	brotli::{CompressorWriter as BrotliEncoder, Decompressor as BrotliDecoder},
+	flate2::{
+		Compression,
+		read::{DeflateDecoder, MultiGzDecoder, ZlibDecoder},
+		write::{GzEncoder, ZlibEncoder},
+	},
+	hyper::header::CONTENT_ENCODING,
+	std::io::{Read as _, Write as _},
 };
 
 
 
-//		Enums																											
+//		Macros																											
 
-//		ContentType																
-/// The content type of an HTTP response, for use by [`UnpackedResponseBody`].
+//		assert_cookie_eq!														
+/// Asserts that two cookies are equal, ignoring their expiry.
 /// 
-/// The content type is used to determine how to represent and interpret the
-/// response body when performing serialisation and deserialisation, including
-/// for display.
+/// This works in the same way as the standard [`assert_eq!`] macro, but
+/// compares the cookies using
+/// [`UnpackedCookie::eq_ignoring_expiry()`](crate::http::UnpackedCookie::eq_ignoring_expiry()),
+/// so that the `Expires` and `Max-Age` attributes are not considered. Either
+/// side may be an [`UnpackedCookie`](crate::http::UnpackedCookie) or an
+/// [`Option`] of one, such as is returned by
+/// [`UnpackedResponse::cookie()`](crate::http::UnpackedResponse::cookie()).
+/// Two [`None`] values are considered equal, but a [`None`] is never equal to
+/// a cookie.
 /// 
-/// The default content type is [`Text`](ContentType::Text).
+/// An optional custom message can be supplied, in the same format as for
+/// [`assert_eq!`].
 /// 
-/// This enum is exhaustive and will never have any additional variants added
-/// to it, as all possibilities are already covered.
+/// # Panics
 /// 
-#[expect(clippy::exhaustive_enums, reason = "Exhaustive")]
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
-pub enum ContentType {
-	/// The response body is text. It will be represented as an ordinary
-	/// [`String`] when serialised.
-	#[default]
-	Text,
-	
-	/// The response body is binary. It will be represented as a [`String`]
-	/// in base64 format when serialised.
-	Binary,
+/// This macro will panic if the cookies are not equal.
+/// 
+/// # See also
+/// 
+/// * [`UnpackedCookie`](crate::http::UnpackedCookie)
+/// * [`UnpackedResponse::cookie()`](crate::http::UnpackedResponse::cookie())
+/// 
+#[macro_export]
+macro_rules! assert_cookie_eq {
+	//	Standard comparison
+	($left:expr, $right:expr $(,)?) => {
+		match (::core::option::Option::<$crate::http::UnpackedCookie>::from($left), ::core::option::Option::<$crate::http::UnpackedCookie>::from($right)) {
+			(left, right) => {
+				let equal = match (&left, &right) {
+					(&::core::option::Option::None,           &::core::option::Option::None)           => true,
+					(&::core::option::Option::Some(ref lhs), &::core::option::Option::Some(ref rhs)) => lhs.eq_ignoring_expiry(rhs),
+					_                                                                                  => false,
+				};
+				if !equal {
+					panic!("assertion `left == right` failed: cookies differ\n  left: {left:?}\n right: {right:?}");
+				}
+			}
+		}
+	};
+	//	Comparison with a custom message
+	($left:expr, $right:expr, $($arg:tt)+) => {
+		match (::core::option::Option::<$crate::http::UnpackedCookie>::from($left), ::core::option::Option::<$crate::http::UnpackedCookie>::from($right)) {
+			(left, right) => {
+				let equal = match (&left, &right) {
+					(&::core::option::Option::None,           &::core::option::Option::None)           => true,
+					(&::core::option::Option::Some(ref lhs), &::core::option::Option::Some(ref rhs)) => lhs.eq_ignoring_expiry(rhs),
+					_                                                                                  => false,
+				};
+				if !equal {
+					panic!("assertion `left == right` failed: {}\n  left: {left:?}\n right: {right:?}", format_args!($($arg)+));
+				}
+			}
+		}
+	};
 }
 
-//		ResponseError															
-/// The possible errors that can occur when working with an HTTP response.
-#[derive(Debug, ThisError)]
-#[non_exhaustive]
-pub enum ResponseError {
-	/// An error encountered while converting the response body to bytes.
-	#[error("Error encountered while converting response body to bytes: {0}")]
-	ConversionError(Box<dyn Error>),
+pub use assert_cookie_eq;
+
+//		assert_response_eq!														
+/// Asserts that two unpacked responses are equal.
+/// 
+/// This works in the same way as the standard [`assert_eq!`] macro, but when
+/// the assertion fails, instead of printing the [`Debug`] output of both
+/// responses, it prints the structured differences between them, as provided
+/// by [`UnpackedResponse::diff()`](crate::http::UnpackedResponse::diff()).
+/// This makes it much easier to see why the responses do not match.
+/// 
+/// An optional custom message can be supplied, in the same format as for
+/// [`assert_eq!`].
+/// 
+/// # Panics
+/// 
+/// This macro will panic if the responses are not equal.
+/// 
+/// # See also
+/// 
+/// * [`UnpackedResponse::diff()`](crate::http::UnpackedResponse::diff())
+/// * [`UnpackedResponseDiff`](crate::http::UnpackedResponseDiff)
+/// 
+#[macro_export]
+macro_rules! assert_response_eq {
+	//	Standard comparison
+	($left:expr, $right:expr $(,)?) => {
+		match (&$left, &$right) {
+			(left, right) => {
+				let diff = $crate::http::UnpackedResponse::diff(left, right);
+				if !diff.is_empty() {
+					panic!("assertion `left == right` failed: responses differ\n{diff}");
+				}
+			}
+		}
+	};
+	//	Comparison with a custom message
+	($left:expr, $right:expr, $($arg:tt)+) => {
+		match (&$left, &$right) {
+			(left, right) => {
+				let diff = $crate::http::UnpackedResponse::diff(left, right);
+				if !diff.is_empty() {
+					panic!("assertion `left == right` failed: {}\n{diff}", format_args!($($arg)+));
+				}
+			}
+		}
+	};
+}
+
+pub use assert_response_eq;
+
+//		assert_response_snapshot!												
+/// Asserts that an unpacked response matches a stored snapshot.
+/// 
+/// The response is serialised to JSON and compared against the snapshot file
+/// of the given name, which is stored under `tests/snapshots/` in the crate
+/// being tested, i.e. relative to `CARGO_MANIFEST_DIR`. Any path separators in
+/// the name are replaced with underscores, so that the file is always stored in
+/// the snapshot directory. If the snapshot file does not exist, it is created
+/// and the assertion passes. If it does exist, the response is compared against
+/// it, and when the assertion fails the structured differences are printed, as
+/// provided by
+/// [`UnpackedResponse::diff()`](crate::http::UnpackedResponse::diff()).
+/// 
+/// Setting the `RUBEDO_UPDATE_SNAPSHOTS` environment variable to `1` will
+/// cause any snapshots that do not match to be overwritten instead.
+/// 
+/// An optional [`SnapshotOptions`](crate::http::SnapshotOptions) instance can
+/// be supplied, to configure the directory used and the redaction of headers
+/// and JSON fields that vary between runs, such as `date`.
+/// 
+/// # Panics
+/// 
+/// This macro will panic if the response does not match the snapshot, or if
+/// the snapshot file cannot be read or written.
+/// 
+/// # See also
+/// 
+/// * [`assert_response_eq!`](crate::assert_response_eq)
+/// * [`SnapshotOptions`](crate::http::SnapshotOptions)
+/// * [`SnapshotOptions::check()`](crate::http::SnapshotOptions::check())
+/// 
+#[macro_export]
+macro_rules! assert_response_snapshot {
+	//	Comparison with default options
+	($response:expr, $name:expr $(,)?) => {
+		$crate::assert_response_snapshot!($response, $name, $crate::http::SnapshotOptions::new())
+	};
+	//	Comparison with custom options
+	($response:expr, $name:expr, $options:expr $(,)?) => {
+		match (&$response, &$options) {
+			(response, options) => {
+				let path = $crate::http::SnapshotOptions::path_for(options, env!("CARGO_MANIFEST_DIR"), $name);
+				if let Err(err) = $crate::http::SnapshotOptions::check(options, response, &path) {
+					panic!("assertion failed: {err}");
+				}
+			}
+		}
+	};
 }
 
+pub use assert_response_snapshot;
 
 
-//		Structs																											
 
-//		UnpackedResponse														
-/// An HTTP response in comparison-friendly form for interrogation.
+//		Enums																											
+
+//		BinaryFormat															
+/// The format in which binary bodies are rendered when logged.
 /// 
-/// Data in [`hyper::Response`] (and indeed [`http::Response`] as well) is
-/// stored in a specific form, made up of a header map object and a generic body
-/// type, which can be empty, a [`String`], or a streaming body future. This
-/// struct provides a way to use the data in a more accessible form, to allow it
-/// to be checked and compared. This is useful for testing, as the entire set of
-/// headers plus body can be checked all at once, and also for printing/logging.
+/// # See also
 /// 
-/// If specific headers or body content needs to be checked, it is recommended
-/// to use the standard functions as they will be more efficient and performant.
+/// * [`LoggingLayer::with_binary_format()`]
 /// 
-/// Note that the [`body`](UnpackedResponse::body) property, which is stored as
-/// a vector of bytes, will get converted to a [`String`] if it is run through
-/// the standard [`Debug`] or [`Display`] formatters. This is because
-/// human-readable output is the intuitively-expected outcome in this situation.
-/// The behaviour can be controlled with the [`ContentType`] enum, which is used
-/// to determine whether the data is binary or text. If [`Text`](ContentType::Text),
-/// then the conversion uses [`from_utf8_lossy()`](String::from_utf8_lossy()),
-/// so no errors will occur, but if the body is not valid UTF8 then the
-/// resulting [`String`] will not be exactly the same. If an accurate
-/// representation of the body is required then it should be set to [`Binary`](ContentType::Binary),
-/// or else it should be extracted and converted to a `Vec<u8>` and then run
-/// through the [`Debug`] or [`Display`] formatters directly.
+#[cfg(feature = "logging")]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum BinaryFormat {
+	/// The body is rendered as base64.
+	#[default]
+	Base64,
+	
+	/// The body is rendered as lowercase hexadecimal.
+	Hex,
+}
+
+//		BodyDiff																
+/// A difference between two response bodies, for use by [`UnpackedResponseDiff`].
+/// 
+/// The form of the difference depends upon the nature of the bodies being
+/// compared. If either body is [`Binary`](ContentType::Binary), then the
+/// comparison is byte-based. Otherwise, if both bodies can be parsed as JSON,
+/// the comparison is made between the JSON structures, and the differences are
+/// reported by JSON path. If neither of these apply, the bodies are compared as
+/// text, line by line.
 /// 
 /// # See also
 /// 
-/// * [`axum::response`](https://docs.rs/axum/latest/axum/response/index.html)
-/// * [`axum::response::Response`](https://docs.rs/axum/latest/axum/response/type.Response.html)
-/// * [`http::Response`]
-/// * [`hyper::Response`]
-/// * [`ResponseExt`]
-/// * [`ResponseExt::unpack()`]
-/// * [`UnpackedResponseHeader`]
+/// * [`UnpackedResponseBody::diff()`]
+/// * [`UnpackedResponseDiff`]
 /// 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
-pub struct UnpackedResponse {
-	//		Public properties													
-	/// The response status code. This is an enum, so is not directly comparable
-	/// to a number. The standard [`Display`] formatter will convert it to a
-	/// string in the format `"200 OK"`, but the standard [`FromStr`]
-	/// implementation will error if this is given back to it, as it expects
-	/// only `"200"`. Because this round-trip is basically broken, this struct
-	/// provides custom serialisation and deserialisation functions to convert
-	/// the status code to and from an actual number (a [`u16`]). This allows
-	/// the struct to be serialised and deserialised in a round-trip without
-	/// error, and is also the more intuitive representation of the status code
-	/// in serialised form such as JSON.
-	#[serde(serialize_with = "serialize_status_code", deserialize_with = "deserialize_status_code")]
-	pub status: StatusCode,
+pub enum BodyDiff {
+	/// The bodies differ as binary data. The first byte offset at which they
+	/// differ is given, along with the length of each body.
+	Binary {
+		/// The offset of the first byte that differs. If one body is a prefix
+		/// of the other, this will be the length of the shorter body.
+		offset:    usize,
+		
+		/// The length of the left-hand body, in bytes.
+		left_len:  usize,
+		
+		/// The length of the right-hand body, in bytes.
+		right_len: usize,
+	},
 	
-	/// The response headers. These are in a vector rather than a hashmap
-	/// because there may be multiple headers with the same name. They are
-	/// sorted by name, and then by value, allowing for reliable comparison.
-	/// Sorting does break the original order of the headers, but this should
-	/// only very rarely matter, even when logging, and sorting allows
-	/// duplicates to be spotted by eye more easily in logs.
-	pub headers: Vec<UnpackedResponseHeader>,
+	/// The bodies differ as JSON data. Each difference is reported by the path
+	/// to the value concerned.
+	Json(Vec<JsonPathDiff>),
 	
-	/// The response body. This originates from the response body as a [`Bytes`]
-	/// container, but gets stored here as a vector of bytes for convenience.
-	/// This may not be valid UTF8, so is not converted to a [`String`]. That
-	/// step is left as optional for the caller, if required (and happens when
-	/// running the [`UnpackedResponse`] struct through the [`Debug`] or
-	/// [`Display`] formatters).
-	pub body:    UnpackedResponseBody,
+	/// The bodies differ as text. Each difference is reported by line.
+	Text(Vec<TextLineDiff>),
 }
 
-//󰭅		UnpackedResponse														
-impl UnpackedResponse {
-	//		new																	
-	/// Creates a new unpacked response instance.
-	/// 
-	/// This constructor builds a new [`UnpackedResponse`] instance from the
-	/// response status code, header data, and body data. This is useful when
-	/// the parts 
-	/// 
-	/// # Parameters
-	/// 
-	/// * `status`  - The response status code. See [`status`](UnpackedResponse::status).
-	/// * `headers` - The response headers. See [`headers`](UnpackedResponse::headers).
-	/// * `body`    - The response body. See [`body`](UnpackedResponse::body).
-	/// 
-	#[must_use]
-	pub fn new<T: Into<UnpackedResponseBody>>(
-		status:  StatusCode,
-		headers: Vec<(String, String)>,
-		body:    T
-	) -> Self {
-		Self::new_from_parts(
-			status,
-			headers.into_iter().map(|(name, value)| UnpackedResponseHeader::new(name, value)).collect(),
-			body.into(),
-		)
-	}
-	
-	//		new_from_parts														
-	/// Creates a new unpacked response instance from existing parts.
-	/// 
-	/// This constructor builds a new [`UnpackedResponse`] instance from
-	/// constituent part instances that are already in the correct form. This is
-	/// useful when the parts are already available.
-	/// 
-	/// # Parameters
+//󰭅		Display																	
+impl Display for BodyDiff {
+	//		fmt																	
+	/// Formats the body difference for display.
 	/// 
-	/// * `status`  - The response status code. See [`status`](UnpackedResponse::status).
-	/// * `headers` - The response headers. See [`headers`](UnpackedResponse::headers).
-	/// * `body`    - The response body. See [`body`](UnpackedResponse::body).
+	/// Binary differences are shown on a single line. JSON and text differences
+	/// are shown with a heading line, followed by each difference on its own
+	/// indented line.
 	/// 
-	#[must_use]
-	pub const fn new_from_parts(
-		status:  StatusCode,
-		headers: Vec<UnpackedResponseHeader>,
-		body:    UnpackedResponseBody
-	) -> Self {
-		Self {
-			status,
-			headers,
-			body,
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Binary { offset, left_len, right_len } => {
+				write!(f, "Body (binary): first difference at byte offset {offset} (left length {left_len}, right length {right_len})")
+			},
+			Self::Json(ref diffs)                        => {
+				write!(f, "Body (JSON):")?;
+				diffs.iter().try_for_each(|diff| write!(f, "\n  {diff}"))
+			},
+			Self::Text(ref diffs)                        => {
+				write!(f, "Body (text):")?;
+				diffs.iter().try_for_each(|diff| write!(f, "\n  {diff}"))
+			},
 		}
 	}
 }
 
-//󰭅		PartialEq																
-impl PartialEq for UnpackedResponse {
-	//		eq																	
-    fn eq(&self, other: &Self) -> bool {
-        self.status == other.status && self.headers == other.headers && self.body == other.body
-    }
+//		CassetteMode															
+/// The modes in which a [`Cassette`] can operate.
+/// 
+/// The mode determines whether requests made through a [`CassetteService`]
+/// are replayed from the cassette, forwarded to the inner service and
+/// recorded, or both.
+/// 
+/// The default mode is [`Replay`](CassetteMode::Replay), so that tests run
+/// fully offline unless recording is explicitly requested.
+/// 
+/// # See also
+/// 
+/// * [`Cassette`]
+/// * [`CassetteService`]
+/// 
+#[cfg(feature = "cassette")]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CassetteMode {
+	/// Every request is forwarded to the inner service, and the response is
+	/// recorded, replacing any existing recording for the same request.
+	Record,
+	
+	/// Requests are only ever replayed from the cassette. Any request that has
+	/// not been recorded results in an error, and the inner service is never
+	/// called.
+	#[default]
+	Replay,
+	
+	/// Requests that have been recorded are replayed from the cassette, and
+	/// any that have not are forwarded to the inner service and recorded.
+	RecordMissing,
 }
 
-//		UnpackedResponseHeader													
-/// An HTTP response header.
-/// 
-/// A simple representation of an HTTP response header as a key-value pair. The
-/// purpose of this struct is to formalise the data structure used by
-/// [`UnpackedResponse`] for storing headers.
+//		ContentEncoding															
+/// The content encodings that can be used to compress an HTTP message body.
 /// 
-/// No other properties are planned or logically considerable at present, and so
-/// this struct is seen as being exhaustive.
+/// These correspond to the values of the `content-encoding` header, and are
+/// used when decompressing a response body during unpacking, and when
+/// compressing or decompressing an [`UnpackedResponseBody`].
 /// 
 /// # See also
 /// 
-/// * [`UnpackedResponse`]
+/// * [`UnpackOptions::with_decompression()`]
+/// * [`UnpackedResponseBody::compress()`]
+/// * [`UnpackedResponseBody::decompress()`]
 /// 
-#[expect(clippy::exhaustive_structs, reason = "Exhaustive")]
-#[derive(Debug, Deserialize, Serialize)]
-pub struct UnpackedResponseHeader {
-	//		Public properties													
-	/// The response header name.
-	pub name:  String,
+#[cfg(feature = "compression")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ContentEncoding {
+	/// Brotli compression, identified as `br`.
+	Brotli,
 	
-	/// The response header value.
-	pub value: String,
+	/// DEFLATE compression in the zlib format, identified as `deflate`.
+	Deflate,
+	
+	/// Gzip compression, identified as `gzip`, or `x-gzip` for older clients.
+	Gzip,
 }
 
-//󰭅		UnpackedResponseHeader													
-impl UnpackedResponseHeader {
-	//		new																	
-	/// Creates a new response header instance.
+//󰭅		ContentEncoding															
+#[cfg(feature = "compression")]
+impl ContentEncoding {
+	//		as_str																
+	/// Returns the name of the content encoding, as used in the
+	/// `content-encoding` header.
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Brotli  => "br",
+			Self::Deflate => "deflate",
+			Self::Gzip    => "gzip",
+		}
+	}
+	
+	//		from_name															
+	/// Returns the content encoding with the specified name, if supported.
+	/// 
+	/// The name is matched case-insensitively, and surrounding whitespace is
+	/// ignored.
 	/// 
 	/// # Parameters
 	/// 
-	/// * `name`  - The response header name.
-	/// * `value` - The response header value.
+	/// * `name` - The name of the content encoding, as used in the
+	///            `content-encoding` header.
 	/// 
 	#[must_use]
-	pub const fn new(name: String, value: String) -> Self {
-		Self {
-			name,
-			value,
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name.trim().to_ascii_lowercase().as_str() {
+			"br"               => Some(Self::Brotli),
+			"deflate"          => Some(Self::Deflate),
+			"gzip" | "x-gzip"  => Some(Self::Gzip),
+			_                  => None,
 		}
 	}
 }
 
-//󰭅		PartialEq																
-impl PartialEq for UnpackedResponseHeader {
-	//		eq																	
-	fn eq(&self, other: &Self) -> bool {
-		self.name == other.name && self.value == other.value
+//󰭅		Display																	
+#[cfg(feature = "compression")]
+impl Display for ContentEncoding {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
 	}
 }
 
-//		UnpackedResponseBody													
-/// An HTTP response body.
+//		ContentType																
+/// The content type of an HTTP response, for use by [`UnpackedResponseBody`].
 /// 
-/// A simple representation of an HTTP response body as a vector of bytes. The
-/// purpose of this struct is to formalise the data structure used by
-/// [`UnpackedResponse`] for storing the body.
-///
-/// The data originates from the response body as a [`Bytes`] container, but
-/// gets stored here as a vector of bytes for convenience. This may not be valid
-/// UTF8, so is not converted to a [`String`]. That step is left as optional for
-/// the caller, if required (and happens when running through the [`Debug`] or
-/// [`Display`] formatters).
+/// The content type is used to determine how to represent and interpret the
+/// response body when performing serialisation and deserialisation, including
+/// for display.
 /// 
-/// The conversion to a [`String`] when run through the [`Debug`] and
-/// [`Display`] formatters is because human-readable output is the
-/// intuitively-expected outcome in this situation. The behaviour can be
-/// controlled with the [`ContentType`] enum, which is used to determine whether
-/// the data is binary or text. If [`Text`](ContentType::Text), then the
-/// conversion uses [`from_utf8_lossy()`](String::from_utf8_lossy()), so no
-/// errors will occur, but if the body is not valid UTF8 then the resulting
-/// [`String`] will not be exactly the same. If an accurate representation of
-/// the body is required then it should be set to [`Binary`](ContentType::Binary),
-/// or else it should be extracted and converted to a `Vec<u8>` and then run
-/// through the [`Debug`] or [`Display`] formatters directly.
-///
-/// This struct is very similar in nature to the standard Rust [`String`]
-/// struct, in that it is a wrapper around a vector of bytes, and so its design
-/// and function names are modelled after it. The main difference is that it
-/// does not require its contents to be valid UTF8, and also that it is a tuple
-/// struct rather than a regular struct.
-///
-/// Note that serialisation/deserialisation of this struct directly will produce
-/// and expect a [`String`], not a vector of bytes. This is because this is the
-/// most useful and fitting behaviour for the intended purpose, as with the
-/// implementations of [`Display`] and [`FromStr`]. As noted above, this is
-/// lossy if the [`ContentType`] is [`Text`](ContentType::Text) and the data is
-/// not valid UTF8, but not if set to [`Binary`](ContentType::Binary).
+/// The default content type is [`Text`](ContentType::Text). When serialised
+/// directly, such as in the tagged form of a body produced by
+/// [`serialize_tagged_body()`], the content type is represented in lowercase,
+/// e.g. `"binary"`.
+/// 
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ContentType {
+	/// The response body is text. It will be represented as an ordinary
+	/// [`String`] when serialised.
+	#[default]
+	Text,
+	
+	/// The response body is binary. It will be represented as a [`String`]
+	/// in base64 format when serialised.
+	Binary,
+	
+	/// The response body is JSON. It will be represented as an ordinary
+	/// [`String`] when serialised, but will be pretty-printed for display, and
+	/// compared semantically, so that differences in key order and whitespace
+	/// are ignored.
+	Json,
+}
+
+//		HeaderDiff																
+/// A difference between two sets of headers, for use by [`UnpackedResponseDiff`].
+/// 
+/// Headers are compared by name. If a header name is only present on one side
+/// then each of its headers is reported as having been added or removed. If a
+/// header name is present on both sides but the values differ, the full set of
+/// values for that name from each side is reported as a change, as there may
+/// be multiple headers with the same name.
 /// 
 /// # See also
 /// 
-/// * [`UnpackedResponse`]
+/// * [`UnpackedResponse::diff()`]
+/// * [`UnpackedResponseDiff`]
 /// 
-#[derive(Default)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
-pub struct UnpackedResponseBody {
-	//		Private properties													
-	/// The response body as a vector of bytes. The data originates from the
-	/// response body as a [`Bytes`] container, but gets stored here as a vector
-	/// of bytes for convenience. This may not be valid UTF8, so is not
-	/// converted to a [`String`]. That step is left as optional for the caller,
-	/// if required (and happens when running through the [`Debug`] or
-	/// [`Display`] formatters).
-	body:         Vec<u8>,
+pub enum HeaderDiff {
+	/// The header is present on the right-hand side only.
+	Added(UnpackedResponseHeader),
 	
-	/// The content type of the response body. This is used to determine how to
-	/// represent and interpret the response body when performing serialisation
-	/// and deserialisation, including for display. The default content type is
-	/// [`Text`](ContentType::Text).
-	content_type: ContentType,
+	/// The header is present on the left-hand side only.
+	Removed(UnpackedResponseHeader),
+	
+	/// Headers with this name are present on both sides, but the values are
+	/// different.
+	Changed {
+		/// The header name.
+		name:  String,
+		
+		/// The header values on the left-hand side, in order.
+		left:  Vec<String>,
+		
+		/// The header values on the right-hand side, in order.
+		right: Vec<String>,
+	},
 }
 
-//󰭅		UnpackedResponseBody													
-impl UnpackedResponseBody {
-	//		new																	
-	/// Creates a new response body instance.
-	/// 
-	/// # Parameters
+//󰭅		Display																	
+impl Display for HeaderDiff {
+	//		fmt																	
+	/// Formats the header difference for display.
 	/// 
-	/// * `data` - The response body as any type for which there is a [`From`]
-	///            implementation.
+	/// The difference is prefixed with `-` for a header present only on the
+	/// left-hand side, `+` for a header present only on the right-hand side,
+	/// and `~` for a header that has changed.
 	/// 
-	pub fn new<T: Into<Self>>(data: T) -> Self {
-		data.into()
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Added(ref added)                          => write!(f, "+ {}: {}", added.name, added.value),
+			Self::Removed(ref removed)                      => write!(f, "- {}: {}", removed.name, removed.value),
+			Self::Changed { ref name, ref left, ref right } => write!(f, "~ {name}: [{}] != [{}]", left.join(", "), right.join(", ")),
+		}
 	}
+}
+
+//		HeaderMatcher															
+/// A requirement for a header value, for use by [`ResponseMatcher`].
+/// 
+/// # See also
+/// 
+/// * [`ResponseMatcher::header_matches()`]
+/// * [`ResponseMatcher::header_with()`]
+/// 
+enum HeaderMatcher {
+	/// The header value must match the regular expression.
+	Pattern(Regex),
 	
-	//		content_type														
-	/// Returns the content type of the response body.
+	/// The header value must satisfy the predicate.
+	Predicate(Box<dyn Fn(&str) -> bool + Send + Sync>),
+}
+
+//󰭅		HeaderMatcher															
+impl HeaderMatcher {
+	//		matches																
+	/// Returns whether the header value meets the requirement.
 	/// 
-	/// # See also
-	///
-	/// * [`ContentType`]
-	/// * [`UnpackedResponseBody::is_binary()`]
-	/// * [`UnpackedResponseBody::is_text()`]
-	/// * [`UnpackedResponseBody::set_content_type()`]
+	/// # Parameters
 	/// 
-	#[must_use]
-	pub const fn content_type(&self) -> ContentType {
-		self.content_type
-	}
-	
-	//		set_content_type													
-	/// Sets the content type of the response body.
+	/// * `value` - The header value to check.
 	/// 
-	/// This method is chainable, as it returns a mutable reference to the
-	/// response body instance.
+	fn matches(&self, value: &str) -> bool {
+		match *self {
+			Self::Pattern(ref regex)       => regex.is_match(value),
+			Self::Predicate(ref predicate) => predicate(value),
+		}
+	}
+}
+
+//󰭅		Debug																	
+impl Debug for HeaderMatcher {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Pattern(ref regex) => f.debug_tuple("Pattern").field(regex).finish(),
+			Self::Predicate(_)       => f.debug_tuple("Predicate").finish_non_exhaustive(),
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for HeaderMatcher {
+	//		fmt																	
+	/// Formats the requirement for display.
 	/// 
-	/// # See also
-	///
-	/// * [`ContentType`]
-	/// * [`UnpackedResponseBody::content_type()`]
+	/// This is phrased so that it can follow the word "must" or "does not".
 	/// 
-	pub const fn set_content_type(&mut self, content_type: ContentType) -> &mut Self {
-		self.content_type = content_type;
-		self
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Pattern(ref regex) => write!(f, "match /{}/", regex.as_str()),
+			Self::Predicate(_)       => write!(f, "satisfy predicate"),
+		}
 	}
+}
+
+//		JsonPathDiff															
+/// A difference between two JSON values, for use by [`BodyDiff`].
+/// 
+/// Paths are expressed in the form `$.foo.bar[2]`, where `$` represents the
+/// root value.
+/// 
+/// # See also
+/// 
+/// * [`BodyDiff::Json`]
+/// 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum JsonPathDiff {
+	/// The value is present on the right-hand side only.
+	Added {
+		/// The path to the value.
+		path:  String,
+		
+		/// The value on the right-hand side.
+		value: Json,
+	},
 	
-	//		is_binary															
-	/// Returns whether the response body is binary.
-	/// 
-	/// # See also
-	/// 
-	/// * [`ContentType`]
-	/// * [`UnpackedResponseBody::content_type()`]
-	/// * [`UnpackedResponseBody::is_text()`]
-	/// 
+	/// The value is present on the left-hand side only.
+	Removed {
+		/// The path to the value.
+		path:  String,
+		
+		/// The value on the left-hand side.
+		value: Json,
+	},
+	
+	/// The value is present on both sides, but is different.
+	Changed {
+		/// The path to the value.
+		path:  String,
+		
+		/// The value on the left-hand side.
+		left:  Json,
+		
+		/// The value on the right-hand side.
+		right: Json,
+	},
+}
+
+//󰭅		JsonPathDiff															
+impl JsonPathDiff {
+	//		path																
+	/// Returns the path to the value concerned.
 	#[must_use]
-	pub fn is_binary(&self) -> bool {
-		self.content_type == ContentType::Binary
+	pub fn path(&self) -> &str {
+		match *self {
+			Self::Added   { ref path, .. } |
+			Self::Removed { ref path, .. } |
+			Self::Changed { ref path, .. } => path,
+		}
 	}
-	
-	//		is_text																
-	/// Returns whether the response body is text.
-	/// 
-	/// # See also
+}
+
+//󰭅		Display																	
+impl Display for JsonPathDiff {
+	//		fmt																	
+	/// Formats the JSON difference for display.
 	/// 
-	/// * [`ContentType`]
-	/// * [`UnpackedResponseBody::content_type()`]
-	/// * [`UnpackedResponseBody::is_binary()`]
+	/// The difference is prefixed with `-` for a value present only on the
+	/// left-hand side, `+` for a value present only on the right-hand side,
+	/// and `~` for a value that has changed.
 	/// 
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Added   { ref path, ref value }           => write!(f, "+ {path}: {value}"),
+			Self::Removed { ref path, ref value }           => write!(f, "- {path}: {value}"),
+			Self::Changed { ref path, ref left, ref right } => write!(f, "~ {path}: {left} != {right}"),
+		}
+	}
+}
+
+//		LogEvent																
+/// An event logged by a [`LoggingService`].
+/// 
+/// Each event contains the unpacked request or response, with any redactions
+/// and truncation already applied, so that it is ready to be written out. The
+/// [`Display`] implementation produces a single line, using the serialised JSON
+/// form of the unpacked request or response.
+/// 
+/// # See also
+/// 
+/// * [`LoggingLayer`]
+/// 
+#[cfg(feature = "logging")]
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum LogEvent {
+	/// A request, logged before it is passed to the inner service.
+	Request(UnpackedRequest),
+	
+	/// A response, logged after it has been received from the inner service.
+	Response(UnpackedResponse),
+}
+
+//󰭅		Display																	
+#[cfg(feature = "logging")]
+impl Display for LogEvent {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (kind, json) = match *self {
+			Self::Request(ref request)   => ("Request",  serde_json::to_string(request)),
+			Self::Response(ref response) => ("Response", serde_json::to_string(response)),
+		};
+		write!(f, "{kind}: {}", json.map_err(|_err| fmt::Error)?)
+	}
+}
+
+//		MatchFailure															
+/// A reason why a response did not match, for use by [`MatchReport`].
+/// 
+/// The expected response is considered to be the left-hand side, and the
+/// actual response the right-hand side, for the purposes of the header and
+/// body differences.
+/// 
+/// # See also
+/// 
+/// * [`MatchReport`]
+/// * [`ResponseMatcher`]
+/// 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum MatchFailure {
+	/// The status code does not match.
+	Status {
+		/// The expected status code.
+		expected: StatusCode,
+		
+		/// The actual status code.
+		actual:   StatusCode,
+	},
+	
+	/// A header does not match the expected header.
+	Header(HeaderDiff),
+	
+	/// A header value does not meet the requirement specified for it, or the
+	/// header is missing.
+	HeaderValue {
+		/// The header name.
+		name:        String,
+		
+		/// The actual header value, or [`None`] if the header is missing.
+		actual:      Option<String>,
+		
+		/// A description of the requirement that was not met.
+		requirement: String,
+	},
+	
+	/// The body does not match.
+	Body(BodyDiff),
+}
+
+//󰭅		Display																	
+impl Display for MatchFailure {
+	//		fmt																	
+	/// Formats the match failure for display.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Status { expected, actual }                           => write!(f, "Status: {expected} != {actual}"),
+			Self::Header(ref diff)                                      => write!(f, "Header: {diff}"),
+			Self::HeaderValue { ref name, ref actual, ref requirement } => match *actual {
+				Some(ref value) => write!(f, "Header: {name}: {value} does not {requirement}"),
+				None            => write!(f, "Header: {name} is missing, and must {requirement}"),
+			},
+			Self::Body(ref diff)                                        => write!(f, "{diff}"),
+		}
+	}
+}
+
+//		SameSite																
+/// The values of the `SameSite` attribute of a cookie.
+/// 
+/// # See also
+/// 
+/// * [`UnpackedCookie`]
+/// 
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub enum SameSite {
+	/// The cookie is only sent with same-site requests.
+	Strict,
+	
+	/// The cookie is sent with same-site requests, and with top-level
+	/// cross-site navigations.
+	Lax,
+	
+	/// The cookie is sent with all requests, which requires it to be secure.
+	None,
+}
+
+//󰭅		SameSite																
+impl SameSite {
+	//		as_str																
+	/// Returns the value as used in the `SameSite` cookie attribute.
 	#[must_use]
-	pub fn is_text(&self) -> bool {
-		self.content_type == ContentType::Text
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Strict => "Strict",
+			Self::Lax    => "Lax",
+			Self::None   => "None",
+		}
 	}
 	
-	//		as_bytes															
-	/// Returns a byte slice of the response body's contents.
-	/// 
-	/// Provides a read-only view of the byte data within the response body,
-	/// without consuming the data. The returned slice is a reference to the
-	/// actual data stored in the response body, not a copy. Because of this, it
-	/// is not possible to mutate the contents of the response body through the
-	/// returned slice. It does not allocate new memory or change the ownership
-	/// of the byte data. This method is useful when you need to work with the
-	/// bytes of the response body in a read-only fashion, or when you want to
-	/// avoid copying the data.
+	//		from_name															
+	/// Returns the `SameSite` value with the specified name, if valid.
 	/// 
-	///   - This method returns a slice (`&[u8]`) referencing the bytes of the
-	///     response body contents.
-	///   - The original response body value remains intact, and can still be
-	///     used afterward.
-	///   - No reallocation or copying of data occurs since it's just providing
-	///     a view into the original memory.
+	/// The name is matched case-insensitively, and surrounding whitespace is
+	/// ignored.
 	/// 
-	/// Use this method when you need to work with the byte data in a
-	/// non-destructive, read-only manner while keeping the original response
-	/// body intact.
-	///
-	/// # See also
+	/// # Parameters
 	/// 
-	/// * [`UnpackedResponseBody::as_mut_bytes()`]
-	/// * [`UnpackedResponseBody::into_bytes()`]
-	/// * [`UnpackedResponseBody::to_bytes()`]
+	/// * `name` - The value of the `SameSite` cookie attribute.
 	/// 
 	#[must_use]
-	pub fn as_bytes(&self) -> &[u8] {
-		&self.body
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name.trim().to_ascii_lowercase().as_str() {
+			"strict" => Some(Self::Strict),
+			"lax"    => Some(Self::Lax),
+			"none"   => Some(Self::None),
+			_        => None,
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for SameSite {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
 	}
+}
+
+//		SnapshotStatus															
+/// The outcome of a successful snapshot check.
+/// 
+/// # See also
+/// 
+/// * [`SnapshotOptions::check()`]
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum SnapshotStatus {
+	/// The snapshot did not exist, and has been created.
+	Created,
 	
-	//		as_mut_bytes														
-	/// Returns a mutable referenced to the response body's contents.
-	/// 
-	/// Provides a mutable view of the byte data within the response body,
-	/// without consuming the data. The returned vector is a reference to the
-	/// actual data stored in the response body, not a copy. This method is
-	/// useful when you need to work with, and modify, the bytes of the response
-	/// body directly, without copying the data.
-	/// 
-	///   - This method returns a mutable vector (`&mut Vec<u8>`) referencing
-	///     the bytes of the response body contents.
-	///   - The original response body value remains intact, and can still be
-	///     used afterward.
-	///   - No reallocation or copying of data occurs since it's just providing
-	///     a reference to the original memory.
-	/// 
-	/// Use this method when you need to work directly with the byte data in a
-	/// mutable manner.
+	/// The response matched the existing snapshot.
+	Matched,
+	
+	/// The response did not match the existing snapshot, and the snapshot has
+	/// been updated.
+	Updated,
+}
+
+//		TaggedFrame																
+/// The tagged serialised form of an [`UnpackedFrame`].
+/// 
+/// # See also
+/// 
+/// * [`TaggedResponse`]
+/// 
+#[derive(Deserialize, Serialize)]
+enum TaggedFrame {
+	/// A data frame, with the chunk of the body in tagged form.
+	Data(
+		#[serde(serialize_with = "serialize_tagged_body", deserialize_with = "deserialize_tagged_body")]
+		UnpackedResponseBody
+	),
+	
+	/// A trailers frame.
+	Trailers(Vec<UnpackedResponseHeader>),
+}
+
+//󰭅		From<UnpackedFrame>														
+impl From<UnpackedFrame> for TaggedFrame {
+	//		from																
+	fn from(frame: UnpackedFrame) -> Self {
+		match frame {
+			UnpackedFrame::Data(body)        => Self::Data(body),
+			UnpackedFrame::Trailers(headers) => Self::Trailers(headers),
+		}
+	}
+}
+
+//󰭅		From<TaggedFrame>														
+impl From<TaggedFrame> for UnpackedFrame {
+	//		from																
+	fn from(frame: TaggedFrame) -> Self {
+		match frame {
+			TaggedFrame::Data(body)        => Self::Data(body),
+			TaggedFrame::Trailers(headers) => Self::Trailers(headers),
+		}
+	}
+}
+
+//		TextLineDiff															
+/// A difference between two text bodies, for use by [`BodyDiff`].
+/// 
+/// Line numbers start from 1, and refer to the side that the line belongs to.
+/// A changed line is reported as a removal followed by an addition.
+/// 
+/// # See also
+/// 
+/// * [`BodyDiff::Text`]
+/// 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TextLineDiff {
+	/// The line is present on the right-hand side only.
+	Added {
+		/// The line number on the right-hand side.
+		line: usize,
+		
+		/// The line content.
+		text: String,
+	},
+	
+	/// The line is present on the left-hand side only.
+	Removed {
+		/// The line number on the left-hand side.
+		line: usize,
+		
+		/// The line content.
+		text: String,
+	},
+}
+
+//󰭅		Display																	
+impl Display for TextLineDiff {
+	//		fmt																	
+	/// Formats the line difference for display.
 	/// 
-	/// Note that unlike the function's [`String::as_mut_vec()`] counterpart,
-	/// this method is not unsafe. This is because the response body is not
-	/// required to be valid UTF8, so there is no risk of invalid UTF8 being
-	/// created.
+	/// The difference is prefixed with `-` for a line present only on the
+	/// left-hand side, and `+` for a line present only on the right-hand side.
 	/// 
-	/// Note also that a better name for this method could be `as_mut_vec()`,
-	/// which would be consistent with the standard library's
-	/// [`String::as_mut_vec()`] method, which this method is modelled after,
-	/// but that would break consistency with the other methods on this struct.
-	/// In addition, there is another method called [`str::as_bytes_mut()`],
-	/// which appears to be named quite inconsistently with other comparable
-	/// methods, and so calling this method `as_mut_bytes()` might cause
-	/// confusion, but is at least self-consistent.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Added   { line, ref text } => write!(f, "+ {line}: {text}"),
+			Self::Removed { line, ref text } => write!(f, "- {line}: {text}"),
+		}
+	}
+}
+
+//		UnpackedFrame															
+/// A frame of an HTTP response body.
+/// 
+/// Response bodies are made up of a sequence of frames, which are either data
+/// or trailers. When a body is unpacked normally, the data frames are combined
+/// into a single [`UnpackedResponseBody`] and any trailers are discarded. When
+/// unpacking with [`UnpackOptions::with_frame_recording()`], each frame is also
+/// recorded in [`UnpackedResponse::frames`], which preserves the boundaries
+/// between chunks, e.g. for streamed or chunked responses such as Server-Sent
+/// Events.
+/// 
+/// Frames are recorded as received, i.e. before any decompression.
+/// 
+/// # See also
+/// 
+/// * [`UnpackOptions::with_frame_recording()`]
+/// * [`UnpackedResponse::frames`]
+/// * [`UnpackedResponse::trailers()`]
+/// 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[non_exhaustive]
+pub enum UnpackedFrame {
+	/// A data frame, containing a chunk of the body. The content type is
+	/// [`Binary`](ContentType::Binary) if the body as a whole is binary, and
+	/// [`Text`](ContentType::Text) otherwise.
+	Data(UnpackedResponseBody),
+	
+	/// A trailers frame, containing headers sent after the body.
+	Trailers(Vec<UnpackedResponseHeader>),
+}
+
+//		CassetteError															
+/// The possible errors that can occur when working with a [`Cassette`].
+#[cfg(feature = "cassette")]
+#[derive(Debug, ThisError)]
+#[non_exhaustive]
+pub enum CassetteError {
+	/// An error encountered while reading a request or response body.
+	#[error("Error encountered while reading message body")]
+	Body(#[source] Box<dyn Error + Send + Sync>),
+	
+	/// An error encountered while reading or writing the cassette file.
+	#[error("Error encountered while reading or writing cassette file")]
+	Io(#[source] IoError),
+	
+	/// An error encountered while parsing or serialising the cassette as JSON.
+	#[error("Error encountered while parsing or serialising cassette as JSON")]
+	Json(#[source] JsonError),
+	
+	/// No response has been recorded for the request, and the cassette is in
+	/// [`Replay`](CassetteMode::Replay) mode.
+	#[error("No recorded response found for {method} {path}")]
+	NotRecorded {
+		/// The method of the request.
+		method: Method,
+		
+		/// The path and query of the request.
+		path:   String,
+	},
+	
+	/// The request needed to be forwarded in order to be recorded, but there is
+	/// no inner service to forward it to.
+	#[error("Cannot record {method} {path} as there is no service to forward it to")]
+	Offline {
+		/// The method of the request.
+		method: Method,
+		
+		/// The path and query of the request.
+		path:   String,
+	},
+	
+	/// An error returned by the inner service.
+	#[error("Error encountered while calling inner service")]
+	Service(#[source] Box<dyn Error + Send + Sync>),
+	
+	/// An error encountered while parsing or serialising the cassette as YAML.
+	#[error("Error encountered while parsing or serialising cassette as YAML")]
+	Yaml(#[source] YamlError),
+}
+
+//		CharsetError															
+/// The possible errors that can occur when decoding text using a charset.
+#[derive(Debug, ThisError)]
+#[non_exhaustive]
+pub enum CharsetError {
+	/// The body data is not valid in the specified charset.
+	#[error("Body is not valid {0}")]
+	Invalid(String),
+	
+	/// The specified charset is not supported.
+	#[error("Unsupported charset: {0}")]
+	Unsupported(String),
+}
+
+//		HarError																
+/// The possible errors that can occur when working with HAR data.
+#[derive(Debug, ThisError)]
+#[non_exhaustive]
+pub enum HarError {
+	/// A body is marked as base64-encoded, but could not be decoded.
+	#[error("Error encountered while decoding base64 body")]
+	Base64(#[source] DecodeError),
+	
+	/// A required field is missing, or has an invalid value.
+	#[error("Missing or invalid HAR field: {0}")]
+	InvalidField(String),
+	
+	/// An error encountered while reading or writing the HAR file.
+	#[error("Error encountered while reading or writing HAR file")]
+	Io(#[source] IoError),
+	
+	/// An error encountered while parsing or serialising the HAR as JSON.
+	#[error("Error encountered while parsing or serialising HAR as JSON")]
+	Json(#[source] JsonError),
+}
+
+//		Http1Error																
+/// The possible errors that can occur when parsing an HTTP/1.1 response.
+#[derive(Debug, ThisError)]
+#[non_exhaustive]
+pub enum Http1Error {
+	/// A header line is not a valid header.
+	#[error("Invalid header: {0}")]
+	InvalidHeader(String),
+	
+	/// The status line is missing or invalid.
+	#[error("Invalid status line: {0}")]
+	InvalidStatusLine(String),
+	
+	/// An error encountered while unpacking the response body.
+	#[error("Error encountered while unpacking response")]
+	Response(#[source] ResponseError),
+}
+
+//		LoggingError															
+/// The possible errors that can occur when using a [`LoggingService`].
+#[cfg(feature = "logging")]
+#[derive(Debug, ThisError)]
+#[non_exhaustive]
+pub enum LoggingError {
+	/// An error encountered while reading a request or response body.
+	#[error("Error encountered while reading message body")]
+	Body(#[source] Box<dyn Error + Send + Sync>),
+	
+	/// An error returned by the inner service.
+	#[error("Error encountered while calling inner service")]
+	Service(#[source] Box<dyn Error + Send + Sync>),
+}
+
+//		MultipartError															
+/// The possible errors that can occur when parsing a multipart body.
+#[derive(Debug, ThisError)]
+#[non_exhaustive]
+pub enum MultipartError {
+	/// A part contains a header line that is not a valid header.
+	#[error("Invalid header in multipart part: {0}")]
+	InvalidHeader(String),
+	
+	/// The content type does not specify a multipart boundary.
+	#[error("No multipart boundary specified in content type")]
+	MissingBoundary,
+	
+	/// The body does not contain the boundary delimiter.
+	#[error("Multipart body does not contain the boundary delimiter")]
+	MissingDelimiter,
+	
+	/// The body ends before the closing boundary delimiter.
+	#[error("Multipart body is not terminated by a closing delimiter")]
+	Unterminated,
+}
+
+//		RequestError															
+/// The possible errors that can occur when working with an HTTP request.
+#[derive(Debug, ThisError)]
+#[non_exhaustive]
+pub enum RequestError {
+	/// An error encountered while reading the request body.
+	#[error("Error encountered while reading request body")]
+	BodyReadError(#[source] Box<dyn Error + Send + Sync>),
+}
+
+//		ResponseError															
+/// The possible errors that can occur when working with an HTTP response.
+/// 
+/// All variants are [`Send`] and [`Sync`], so the errors can be passed between
+/// tasks and threads, and converted into other error types. Where an error has
+/// an underlying cause, it is available through [`Error::source()`].
+/// 
+#[derive(Debug, ThisError)]
+#[non_exhaustive]
+pub enum ResponseError {
+	/// An error encountered while reading the response body, such as a failure
+	/// of the underlying stream.
+	#[error("Error encountered while reading response body")]
+	BodyReadError(#[source] Box<dyn Error + Send + Sync>),
+	
+	/// An error encountered while decompressing the response body.
+	#[cfg(feature = "compression")]
+	#[error("Error encountered while decompressing response body")]
+	DecompressionError(#[source] IoError),
+	
+	/// The response body was declared as text or JSON, but is not valid UTF8.
+	/// This is only returned when [`UnpackOptions::with_strict_utf8()`] is
+	/// enabled.
+	#[error("Response body is not valid UTF8")]
+	InvalidUtf8(#[source] Utf8Error),
+	
+	/// The response body exceeded the specified size limit, in bytes.
+	#[error("Response body exceeded the size limit of {0} bytes")]
+	LimitExceeded(usize),
+	
+	/// The response body was not read in full within the specified time. The
+	/// response is unpacked from whatever was received before the timeout, and
+	/// is available as the partial response. Decompression and strict UTF8
+	/// checking are not applied to the partial response, as the body will
+	/// usually be incomplete.
+	#[error("Timed out after {duration:?} while reading response body")]
+	Timeout {
+		/// The timeout that was exceeded.
+		duration: Duration,
+		
+		/// The response unpacked from the data received before the timeout.
+		partial:  Box<UnpackedResponse>,
+	},
+	
+	/// The response body uses a content encoding that is not supported.
+	#[cfg(feature = "compression")]
+	#[error("Unsupported content encoding: {0}")]
+	UnsupportedEncoding(String),
+}
+
+//		SnapshotError															
+/// The possible errors that can occur when checking a response snapshot.
+#[derive(Debug, ThisError)]
+#[non_exhaustive]
+pub enum SnapshotError {
+	/// An error encountered while reading or writing the snapshot file.
+	#[error("Error encountered while reading or writing snapshot file")]
+	Io(#[source] IoError),
+	
+	/// An error encountered while parsing or serialising the snapshot.
+	#[error("Error encountered while parsing or serialising snapshot")]
+	Json(#[source] JsonError),
+	
+	/// The response did not match the snapshot.
+	#[error("Response does not match snapshot {}\n{diff}\nSet RUBEDO_UPDATE_SNAPSHOTS=1 to update", path.display())]
+	Mismatch {
+		/// The path of the snapshot file.
+		path: PathBuf,
+		
+		/// The differences between the snapshot and the response.
+		diff: UnpackedResponseDiff,
+	},
+}
+
+//		TestClientError															
+/// The possible errors that can occur when sending a request using a
+/// [`TestClient`].
+#[cfg(feature = "axum")]
+#[derive(Debug, ThisError)]
+#[non_exhaustive]
+pub enum TestClientError {
+	/// An error encountered while serialising the request body as form data.
+	#[error("Error encountered while serialising request body as form data")]
+	Form(#[source] FormSerError),
+	
+	/// An error encountered while serialising the request body as JSON.
+	#[error("Error encountered while serialising request body as JSON")]
+	Json(#[source] JsonError),
+	
+	/// The request could not be built, e.g. due to an invalid path or header.
+	#[error("Error encountered while building request")]
+	Request(#[source] HttpError),
+	
+	/// An error encountered while unpacking the response.
+	#[error("Error encountered while unpacking response")]
+	Response(#[source] ResponseError),
+}
+
+
+
+//		Structs																											
+
+//		Cassette																
+/// A store of recorded request/response pairs, for replaying offline.
+/// 
+/// A cassette is backed by a file, in which each request is stored along with
+/// the response that was received for it, in the same form as used by the
+/// [`Serialize`] implementations of [`UnpackedRequest`] and
+/// [`UnpackedResponse`]. If the file has a `.yaml` or `.yml` extension then it
+/// is stored as YAML, and otherwise as JSON.
+/// 
+/// Requests are matched against recordings by their method, path and query,
+/// and a SHA-256 hash of their body. The host, headers, and HTTP version are
+/// not considered.
+/// 
+/// Cassettes are normally used via a [`CassetteService`], which sits in front
+/// of a real service, such as an HTTP client, and records and replays the
+/// requests made through it according to the cassette's [`CassetteMode`].
+/// This allows HTTP client code to be tested fully offline, using responses
+/// that were originally recorded from a real server.
+/// 
+/// # See also
+/// 
+/// * [`CassetteEntry`]
+/// * [`CassetteMode`]
+/// * [`CassetteService`]
+/// 
+#[cfg(feature = "cassette")]
+#[derive(Clone, Debug)]
+pub struct Cassette {
+	//		Private properties													
+	/// The recorded request/response pairs.
+	entries: Vec<CassetteEntry>,
+	
+	/// The mode in which the cassette operates.
+	mode:    CassetteMode,
+	
+	/// The path to the cassette file.
+	path:    PathBuf,
+}
+
+//󰭅		Cassette																
+#[cfg(feature = "cassette")]
+impl Cassette {
+	//		new																	
+	/// Creates a new, empty cassette.
 	/// 
-	/// # See also
+	/// No attempt is made to read the file at the specified path, and any
+	/// existing file will be overwritten when the cassette is saved. To use
+	/// existing recordings, use [`load()`](Cassette::load()) instead.
 	/// 
-	/// * [`UnpackedResponseBody::as_bytes()`]
-	/// * [`UnpackedResponseBody::into_bytes()`]
-	/// * [`UnpackedResponseBody::to_bytes()`]
+	/// # Parameters
 	/// 
-	pub const fn as_mut_bytes(&mut self) -> &mut Vec<u8> {
-		&mut self.body
+	/// * `path` - The path to the cassette file.
+	/// * `mode` - The mode in which the cassette operates.
+	/// 
+	#[must_use]
+	pub fn new<P: AsRef<Path>>(path: P, mode: CassetteMode) -> Self {
+		Self {
+			entries: vec![],
+			mode,
+			path:    path.as_ref().to_path_buf(),
+		}
 	}
 	
-	//		into_bytes															
-	/// Returns the response body as a vector of bytes.
+	//		load																
+	/// Loads a cassette from a file.
 	/// 
-	/// This consumes the response body, without cloning or copying, and returns
-	/// a new vector containing the bytes of the response body. It transfers
-	/// ownership of the byte data from the response body to the new vector.
-	/// This method is useful when you need to move the byte data out of the
-	/// response body, for example to pass it to a function that expects a
-	/// `Vec<u8>`, or when you want to modify the byte data in-place without
-	/// affecting the original response body.
+	/// If the file does not exist, an empty cassette is created, unless the
+	/// mode is [`Replay`](CassetteMode::Replay), in which case there would be
+	/// nothing to replay, and so an error is returned.
 	/// 
-	///   - This method consumes the response body contents and returns a
-	///     `Vec<u8>` containing its bytes.
-	///   - After calling this method, the original response body value is no
-	///     longer available for use, because it has been moved.
-	///   - Transforms the response body into a vector of bytes without any
-	///     copying.
+	/// # Parameters
 	/// 
-	/// Use this method when you want to consume the response body and obtain
-	/// ownership of its byte data in the form of a `Vec<u8>`. This is useful
-	/// when you need to modify or move the byte data, or when you want to pass
-	/// it to functions that expect a `Vec<u8>`.
+	/// * `path` - The path to the cassette file.
+	/// * `mode` - The mode in which the cassette operates.
 	/// 
-	/// Note that a better name for this method might be `into_vec()`, but that
-	/// would be inconsistent with the standard library's
-	/// [`String::into_bytes()`] method, which this method is modelled after.
-	///
-	/// # See also
+	/// # Errors
 	/// 
-	/// * [`UnpackedResponseBody::as_bytes()`]
-	/// * [`UnpackedResponseBody::as_mut_bytes()`]
-	/// * [`UnpackedResponseBody::to_bytes()`]
+	/// This function will return an error if the file cannot be read, or if
+	/// its contents cannot be parsed.
 	/// 
+	pub fn load<P: AsRef<Path>>(path: P, mode: CassetteMode) -> Result<Self, CassetteError> {
+		let mut cassette = Self::new(path, mode);
+		match fs::read_to_string(&cassette.path) {
+			Ok(contents)                                                             => {
+				cassette.entries = if is_yaml_path(&cassette.path) {
+					serde_yaml_ng::from_str(&contents).map_err(CassetteError::Yaml)?
+				} else {
+					serde_json::from_str(&contents).map_err(CassetteError::Json)?
+				};
+			},
+			Err(err) if err.kind() == IoErrorKind::NotFound && mode != CassetteMode::Replay => {},
+			Err(err)                                                                 => return Err(CassetteError::Io(err)),
+		}
+		Ok(cassette)
+	}
+	
+	//		entries																
+	/// Returns the recorded request/response pairs.
 	#[must_use]
-	pub fn into_bytes(self) -> Vec<u8> {
-		self.body
+	pub fn entries(&self) -> &[CassetteEntry] {
+		&self.entries
 	}
 	
-	//		to_bytes															
-	/// Returns a copy of the response body data converted to a vector of bytes.
-	/// 
-	/// This does not consume the response body, but clones it. Following Rust's
-	/// naming conventions and idioms, this method "converts" the data content
-	/// of the response body into a byte representation, in a `Vec<u8>`. (No
-	/// actual conversion takes place because the data is already stored
-	/// internally as a vector of bytes, but this is academic and could change
-	/// in future, so "conversion" is implied and expected as a theoretical
-	/// behaviour.) Ownership of the cloned and converted byte data is
-	/// transferred to the caller, and there are no side effects on the internal
-	/// state of the [`UnpackedResponseBody`] instance.
-	/// 
-	///   - This method returns a `Vec<u8>` vector of bytes without consuming
-	///     the response body contents.
-	///   - The original response body value remains intact, and can still be
-	///     used afterward.
-	///   - The response body data is copied, and converted/transformed into
-	///     the output value returned.
+	//		mode																
+	/// Returns the mode in which the cassette operates.
+	#[must_use]
+	pub const fn mode(&self) -> CassetteMode {
+		self.mode
+	}
+	
+	//		path																
+	/// Returns the path to the cassette file.
+	#[must_use]
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+	
+	//		find																
+	/// Finds the recorded response for a request.
 	/// 
-	/// Use this method when you need to obtain a copy of the response body's
-	/// byte data in the form of a `Vec<u8>`, without consuming the response
-	/// body itself. This is useful when you need to pass the byte data to a
-	/// function that expects a `Vec<u8>`, or when you want to modify the byte
-	/// data without affecting the original response body.
+	/// # Parameters
 	/// 
-	/// Note that a better name for this method might be `to_vec()`, but that
-	/// would be inconsistent with the standard library's
-	/// [`String::into_bytes()`] method.
+	/// * `request` - The request to find the recorded response for.
 	/// 
 	/// # See also
 	/// 
-	/// * [`UnpackedResponseBody::as_bytes()`]
-	/// * [`UnpackedResponseBody::as_mut_bytes()`]
-	/// * [`UnpackedResponseBody::into_bytes()`]
+	/// * [`CassetteEntry::key_for()`]
 	/// 
 	#[must_use]
-	pub fn to_bytes(&self) -> Vec<u8> {
-		self.body.clone()
+	pub fn find(&self, request: &UnpackedRequest) -> Option<&UnpackedResponse> {
+		self.find_key(&CassetteEntry::key_for(request))
 	}
 	
-	//		to_base64															
-	/// Returns the response body data converted to a base64-encoded [`String`].
+	//		record																
+	/// Records the response for a request.
 	/// 
-	/// This does not consume the response body, but clones it, as is necessary
-	/// to perform the conversion to base64. It converts straight from bytes to
-	/// base64, without converting to a [`String`] first, because the response
-	/// body is binary data.
+	/// Any existing recording for the same request is replaced. The cassette
+	/// is not saved automatically.
 	/// 
-	/// # See also
+	/// # Parameters
 	/// 
-	/// * [`UnpackedResponseBody::from_base64()`]
+	/// * `request`  - The request that was made.
+	/// * `response` - The response that was received.
 	/// 
-	#[must_use]
-	pub fn to_base64(&self) -> String {
-		BASE64.encode(&self.body)
+	pub fn record(&mut self, request: UnpackedRequest, response: UnpackedResponse) {
+		self.record_entry(CassetteEntry::new(request, response));
 	}
 	
-	//		from_base64															
-	/// Converts a base64-encoded [`String`] to an [`UnpackedResponseBody`].
-	/// 
-	/// This method does not consume the input string, but clones it, as is
-	/// necessary to perform the conversion from [`base64`]. It converts
-	/// straight from base64 to bytes, without converting to a [`String`] first,
-	/// because the response body is binary data. This means that no UTF8
-	/// validation is performed.
+	//		save																
+	/// Saves the cassette to its file.
 	/// 
-	/// Note that unlike the [`From`] type conversion implementations, this
-	/// returns a [`Result`].
+	/// Any missing parent directories are created.
 	/// 
 	/// # Errors
 	/// 
-	/// This method will return an error if the input string is not valid
-	/// base64. Such an error will be returned as a [`DecodeError`], which is
-	/// passed through from the [`base64`] crate.
+	/// This function will return an error if the cassette cannot be serialised,
+	/// or the file cannot be written.
 	/// 
-	/// # See also
+	pub fn save(&self) -> Result<(), CassetteError> {
+		let contents = if is_yaml_path(&self.path) {
+			serde_yaml_ng::to_string(&self.entries).map_err(CassetteError::Yaml)?
+		} else {
+			serde_json::to_string_pretty(&self.entries).map_err(CassetteError::Json)? + "\n"
+		};
+		if let Some(parent) = self.path.parent() {
+			fs::create_dir_all(parent).map_err(CassetteError::Io)?;
+		}
+		fs::write(&self.path, contents).map_err(CassetteError::Io)
+	}
+	
+	//		find_key															
+	/// Finds the recorded response for a request key.
 	/// 
-	/// * [`UnpackedResponseBody::to_base64()`]
+	/// # Parameters
 	/// 
-	pub fn from_base64(encoded: &str) -> Result<Self, DecodeError> {
-		let decoded = BASE64.decode(encoded)?;
-		Ok(Self { body: decoded, content_type: ContentType::Binary })
+	/// * `key` - The key of the request, as returned by
+	///           [`CassetteEntry::key_for()`].
+	/// 
+	fn find_key(&self, key: &str) -> Option<&UnpackedResponse> {
+		self.entries.iter().find(|entry| entry.key == key).map(|entry| &entry.response)
 	}
 	
-	//		clear																
-	/// Removes all contents from the response body.
+	//		record_entry														
+	/// Records an entry, replacing any existing entry with the same key.
 	/// 
-	/// This method removes all data from the response body, resetting it to an
-	/// empty state. This method has no effect on the capacity of the response
-	/// body, and so does not affect any allocation.
+	/// # Parameters
 	/// 
-	pub fn clear(&mut self) {
-		self.body.clear();
+	/// * `entry` - The entry to record.
+	/// 
+	fn record_entry(&mut self, entry: CassetteEntry) {
+		match self.entries.iter_mut().find(|existing| existing.key == entry.key) {
+			Some(existing) => *existing = entry,
+			None           => self.entries.push(entry),
+		}
 	}
+}
+
+//		CassetteEntry															
+/// A recorded request/response pair, as stored in a [`Cassette`].
+/// 
+/// The key is used to match requests against the recording, and is stored so
+/// that matching does not depend upon the serialised form of the request body
+/// being an exact representation of the original.
+/// 
+/// # See also
+/// 
+/// * [`Cassette`]
+/// * [`CassetteEntry::key_for()`]
+/// 
+#[cfg(feature = "cassette")]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct CassetteEntry {
+	//		Public properties													
+	/// The key of the request, made up of the method, path and query, and a
+	/// SHA-256 hash of the body.
+	pub key:      String,
 	
-	//		empty																
-	/// Returns an empty response body.
+	/// The request that was made. The body is stored in tagged form, so that
+	/// it round-trips losslessly.
+	#[serde(serialize_with = "serialize_tagged_request", deserialize_with = "deserialize_tagged_request")]
+	pub request:  UnpackedRequest,
+	
+	/// The response that was received. The body, and the data of any frames,
+	/// are stored in tagged form, so that they round-trip losslessly.
+	#[serde(serialize_with = "serialize_tagged_response", deserialize_with = "deserialize_tagged_response")]
+	pub response: UnpackedResponse,
+}
+
+//󰭅		CassetteEntry															
+#[cfg(feature = "cassette")]
+impl CassetteEntry {
+	//		new																	
+	/// Creates a new cassette entry, with the key generated from the request.
 	/// 
-	/// This method returns an empty response body. This is equivalent to
-	/// creating a new response body with [`UnpackedResponseBody::new()`], but
-	/// without having to supply any parameters.
+	/// # Parameters
+	/// 
+	/// * `request`  - The request that was made.
+	/// * `response` - The response that was received.
 	/// 
 	#[must_use]
-	pub fn empty() -> Self {
-		Self { body: Vec::new(), ..Default::default() }
+	pub fn new(request: UnpackedRequest, response: UnpackedResponse) -> Self {
+		Self {
+			key: Self::key_for(&request),
+			request,
+			response,
+		}
 	}
 	
-	//		is_empty															
-	/// Returns whether the response body is empty.
+	//		key_for																
+	/// Returns the key for a request.
 	/// 
-	/// This method returns whether the response body is empty. This is
-	/// equivalent to checking whether the length of the response body is zero.
+	/// This is made up of the method, the path and query, and a hex-encoded
+	/// SHA-256 hash of the body, separated by spaces, e.g.
+	/// `GET /foo?bar=baz e3b0c442...`. The scheme and host of the URI are not
+	/// included, so that requests recorded against one server can be replayed
+	/// for another.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `request` - The request to generate the key for.
 	/// 
 	#[must_use]
-	pub fn is_empty(&self) -> bool {
-		self.body.is_empty()
+	pub fn key_for(request: &UnpackedRequest) -> String {
+		format!(
+			"{} {} {}",
+			request.method,
+			request_path(&request.uri),
+			hex::encode(Sha256::digest(&request.body.body)),
+		)
 	}
+}
+
+//		CassetteService															
+/// A [`Service`] that records and replays requests using a [`Cassette`].
+/// 
+/// This wraps an inner service, such as an HTTP client, which is called to
+/// obtain real responses when recording. Depending on the [`CassetteMode`] of
+/// the cassette, requests are either replayed from the cassette, or forwarded
+/// to the inner service, in which case the response is recorded and the
+/// cassette is saved. Responses are always returned with a [`Full<Bytes>`](Full)
+/// body, and recorded responses are replayed exactly, including their headers.
+/// 
+/// For tests that should only ever replay, [`offline()`](CassetteService::offline())
+/// can be used to create a service without an inner service.
+/// 
+/// The service can be cloned, and all clones share the same cassette.
+/// 
+/// # See also
+/// 
+/// * [`Cassette`]
+/// * [`CassetteMode`]
+/// * [`OfflineService`]
+/// 
+#[cfg(feature = "cassette")]
+#[derive(Clone, Debug)]
+pub struct CassetteService<S> {
+	//		Private properties													
+	/// The cassette, shared between clones of the service.
+	cassette: Arc<Mutex<Cassette>>,
 	
-	//		len																	
-	/// Returns the length of the response body.
+	/// The inner service, which is called to obtain responses for recording.
+	inner:    S,
+}
+
+//󰭅		CassetteService															
+#[cfg(feature = "cassette")]
+impl<S> CassetteService<S> {
+	//		new																	
+	/// Creates a new cassette service.
 	/// 
-	/// This method returns the length of the response body, in bytes. This is
-	/// equivalent to the length of the vector of bytes that the response body
-	/// contains.
+	/// # Parameters
+	/// 
+	/// * `cassette` - The cassette to record to and replay from.
+	/// * `inner`    - The inner service, which is called to obtain responses
+	///                for recording.
 	/// 
 	#[must_use]
-	pub fn len(&self) -> usize {
-		self.body.len()
+	pub fn new(cassette: Cassette, inner: S) -> Self {
+		Self {
+			cassette: Arc::new(Mutex::new(cassette)),
+			inner,
+		}
 	}
 	
-	//		push																
-	/// Appends a byte to the response body.
-	/// 
-	/// Appends a given byte onto the end of the response body's existing byte
-	/// data. The response body is not required to be valid UTF8, so this method
-	/// does not check the validity of the byte before appending it.
+	//		cassette															
+	/// Returns the cassette used by the service.
 	/// 
-	/// This method accepts a [`u8`] instead of a [`char`] because a [`char`]
-	/// represents a single Unicode scalar value. In Rust, a [`char`] is always
-	/// 4 bytes long because it can represent any Unicode scalar value,
-	/// including those outside the Basic Multilingual Plane. If `push()`
-	/// accepted a [`char`], it would be signaling that [`UnpackedResponseBody`]
-	/// is Unicode-aware and can handle any Unicode character — which is not the
-	/// case. A [`u8`], on the other hand, represents a single byte. By having
-	/// `push()` accept a [`u8`], it's signaling that [`UnpackedResponseBody`]
-	/// is byte-oriented. A specific [`push_char()`](UnpackedResponseBody::push_char())
-	/// method is also available, but `push()` is the most general method for
-	/// appending bytes to the response body.
+	/// This is shared with the service, so any recordings made after this is
+	/// called will be visible through it.
 	/// 
-	/// # Parameters
+	#[must_use]
+	pub fn cassette(&self) -> Arc<Mutex<Cassette>> {
+		Arc::clone(&self.cassette)
+	}
+}
+
+//󰭅		CassetteService<OfflineService>											
+#[cfg(feature = "cassette")]
+impl CassetteService<OfflineService> {
+	//		offline																
+	/// Creates a new cassette service without an inner service.
 	/// 
-	/// * `byte` - The byte to append to the response body.
+	/// This is intended for use with a cassette in [`Replay`](CassetteMode::Replay)
+	/// mode. In any other mode, requests that need to be recorded will fail
+	/// with a [`CassetteError::Offline`] error.
 	/// 
-	/// # See also
+	/// # Parameters
 	/// 
-	/// * [`UnpackedResponseBody::push_bytes()`]
-	/// * [`UnpackedResponseBody::push_char()`]
-	/// * [`UnpackedResponseBody::push_str()`]
+	/// * `cassette` - The cassette to replay from.
 	/// 
-	pub fn push(&mut self, byte: u8) {
-		self.body.push(byte);
+	#[must_use]
+	pub fn offline(cassette: Cassette) -> Self {
+		Self::new(cassette, OfflineService)
+	}
+}
+
+//󰭅		Service																	
+#[cfg(feature = "cassette")]
+impl<S, B, RB> Service<Request<B>> for CassetteService<S>
+where
+	S:         Service<Request<Full<Bytes>>, Response = Response<RB>> + Clone + Send + 'static,
+	S::Error:  Into<Box<dyn Error + Send + Sync>>,
+	S::Future: Send,
+	B:         Body + Send + 'static,
+	B::Data:   Send,
+	B::Error:  Into<Box<dyn Error + Send + Sync>>,
+	RB:        Body + Send,
+	RB::Data:  Send,
+	RB::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+	type Response = Response<Full<Bytes>>;
+	type Error    = CassetteError;
+	type Future   = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+	
+	//		poll_ready															
+	fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+		self.inner.poll_ready(cx).map_err(|err| service_error(err.into()))
 	}
 	
-	//		push_bytes															
-	/// Appends a byte slice to the response body.
-	///
-	/// Appends a given byte slice onto the end of the response body. The byte
-	/// slice is appended to the end of the response body's existing byte data.
-	/// The response body is not required to be valid UTF8, so this method does
-	/// not check the validity of the byte slice before appending it.
-	///
+	//		call																
+	fn call(&mut self, req: Request<B>) -> Self::Future {
+		let cassette  = Arc::clone(&self.cassette);
+		let clone     = self.inner.clone();
+		let mut inner = mem::replace(&mut self.inner, clone);
+		Box::pin(async move {
+			let (parts, body) = req.into_parts();
+			let bytes         = body.collect().await.map_err(|err| CassetteError::Body(err.into()))?.to_bytes();
+			let unpacked      = convert_request(&parts.method, &parts.uri, parts.version, &parts.headers, &bytes);
+			let key           = CassetteEntry::key_for(&unpacked);
+			let mode          = {
+				let guard = cassette.lock().unwrap_or_else(PoisonError::into_inner);
+				if guard.mode != CassetteMode::Record {
+					if let Some(recorded) = guard.find_key(&key) {
+						return Ok(Response::from(recorded.clone()));
+					}
+				}
+				guard.mode
+			};
+			if mode == CassetteMode::Replay {
+				return Err(CassetteError::NotRecorded { path: request_path(&parts.uri), method: parts.method });
+			}
+			let response      = inner.call(Request::from_parts(parts, Full::new(bytes))).await
+				.map_err(|err| service_error(err.into()))?
+			;
+			let (head, data)  = response.into_parts();
+			let content       = data.collect().await.map_err(|err| CassetteError::Body(err.into()))?.to_bytes();
+			let options       = UnpackOptions::default();
+			let recorded      = UnpackedResponse {
+				status:      head.status,
+				headers:     convert_headers(&head.headers, &options),
+				body:        UnpackedResponseBody {
+					body:         content.to_vec(),
+					content_type: detect_content_type(&head.headers, &content, &options),
+				},
+				frames:      Vec::new(),
+				raw_headers: Vec::new(),
+			};
+			let mut guard     = cassette.lock().unwrap_or_else(PoisonError::into_inner);
+			guard.record_entry(CassetteEntry { key, request: unpacked, response: recorded.clone() });
+			guard.save()?;
+			drop(guard);
+			Ok(Response::from(recorded))
+		})
+	}
+}
+
+//		HarArchive																
+/// An HTTP Archive (HAR), containing a list of request/response pairs.
+/// 
+/// HAR is a JSON-based format used by browsers and other tools to capture
+/// HTTP traffic. This struct allows HAR files to be loaded and converted into
+/// [`UnpackedRequest`] and [`UnpackedResponse`] instances, so that captured
+/// sessions can be used as test fixtures, and allows unpacked requests and
+/// responses to be exported as HAR files, so that they can be inspected using
+/// standard tools.
+/// 
+/// Only the data that is represented by the unpacked types is imported, i.e.
+/// the method, URL, version, headers, and body of each request, and the status,
+/// headers, and body of each response. Timings, cache information, and other
+/// metadata are ignored when importing, and are set to placeholder values when
+/// exporting. Bodies that are binary, or are not valid UTF8, are stored as
+/// base64.
+/// 
+/// # See also
+/// 
+/// * [`HarEntry`]
+/// * [`UnpackedRequest::from_har()`]
+/// * [`UnpackedResponse::from_har()`]
+/// 
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct HarArchive {
+	//		Public properties													
+	/// The entries in the archive, in order.
+	pub entries: Vec<HarEntry>,
+}
+
+//󰭅		HarArchive																
+impl HarArchive {
+	//		new																	
+	/// Creates a new, empty archive.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+	
+	//		with_entry															
+	/// Adds an entry.
+	/// 
 	/// # Parameters
-	///
-	/// * `bytes` - The byte slice to append to the response body.
-	///
-	/// # See also
-	///
-	/// * [`UnpackedResponseBody::push()`]
-	/// * [`UnpackedResponseBody::push_char()`]
-	/// * [`UnpackedResponseBody::push_str()`]
-	///
-	pub fn push_bytes(&mut self, bytes: &[u8]) {
-		self.body.extend_from_slice(bytes);
+	/// 
+	/// * `request`  - The request.
+	/// * `response` - The response that was received for the request.
+	/// 
+	#[must_use]
+	pub fn with_entry(mut self, request: UnpackedRequest, response: UnpackedResponse) -> Self {
+		self.entries.push(HarEntry::new(request, response));
+		self
 	}
 	
-	//		push_char															
-	/// Appends a [`char`] to the response body.
-	///
-	/// Appends a given character onto the end of the response body. The
-	/// [`char`] is converted to bytes and then appended to the end of the
-	/// response body's existing byte data.
-	///
+	//		load																
+	/// Loads an archive from a HAR file.
+	/// 
 	/// # Parameters
-	///
-	/// * `char` - The [`char`] to append to the response body.
-	///
-	/// # See also
-	///
-	/// * [`UnpackedResponseBody::push()`]
-	/// * [`UnpackedResponseBody::push_bytes()`]
-	/// * [`UnpackedResponseBody::push_str()`]
-	///
-	pub fn push_char(&mut self, char: &char) {
-		let mut bytes = [0; 4];
-		let used      = char.encode_utf8(&mut bytes).len();
-		#[expect(clippy::indexing_slicing, reason = "Infallible")]
-		self.body.extend(&bytes[..used]);
+	/// 
+	/// * `path` - The path to the HAR file.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the file cannot be read, is not
+	/// valid JSON, or is not a valid HAR.
+	/// 
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, HarError> {
+		let contents = fs::read_to_string(path).map_err(HarError::Io)?;
+		Self::from_json(&serde_json::from_str(&contents).map_err(HarError::Json)?)
 	}
 	
-	//		push_str															
-	/// Appends a string slice to the response body.
+	//		save																
+	/// Saves the archive to a HAR file.
 	/// 
-	/// Appends a given string slice onto the end of the response body. The
-	/// string slice is converted to bytes and then appended to the end of the
-	/// response body's existing byte data.
+	/// Any missing parent directories are created.
 	/// 
 	/// # Parameters
 	/// 
-	/// * `string` - The string slice to append to the response body.
+	/// * `path` - The path to the HAR file.
 	/// 
-	/// # See also
+	/// # Errors
 	/// 
-	/// * [`UnpackedResponseBody::push()`]
-	/// * [`UnpackedResponseBody::push_char()`]
-	/// * [`UnpackedResponseBody::push_bytes()`]
+	/// This method will return an error if the file cannot be written.
 	/// 
-	pub fn push_str(&mut self, string: &str) {
-		self.body.extend_from_slice(string.as_bytes());
+	pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), HarError> {
+		let contents = serde_json::to_string_pretty(&self.to_json()).map_err(HarError::Json)? + "\n";
+		if let Some(parent) = path.as_ref().parent() {
+			fs::create_dir_all(parent).map_err(HarError::Io)?;
+		}
+		fs::write(path, contents).map_err(HarError::Io)
 	}
-}
-
-//󰭅		Add &[u8]																
-impl Add<&[u8]> for UnpackedResponseBody {
-	type Output = Self;
 	
-	//		add																	
-	/// Adds a [`&[u8]`](https://doc.rust-lang.org/std/primitive.slice.html) to
-	/// an [`UnpackedResponseBody`].
-	fn add(mut self, rhs: &[u8]) -> Self {
-		self.push_bytes(rhs);
-		self
+	//		from_json															
+	/// Converts a HAR JSON value into an archive.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `value` - The HAR, i.e. an object with a `log` property.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the value is not a valid HAR.
+	/// 
+	pub fn from_json(value: &Json) -> Result<Self, HarError> {
+		Ok(Self {
+			entries: har_field(har_field(value, "log")?, "entries")?
+				.as_array()
+				.ok_or_else(|| HarError::InvalidField("entries".to_owned()))?
+				.iter()
+				.map(HarEntry::from_json)
+				.collect::<Result<_, _>>()?,
+		})
 	}
-}
-
-//󰭅		Add &[u8; N]															
-impl<const N: usize> Add<&[u8; N]> for UnpackedResponseBody {
-	type Output = Self;
 	
-	//		add																	
-	/// Adds a [`&[u8; N]`](https://doc.rust-lang.org/std/primitive.slice.html)
-	/// to an [`UnpackedResponseBody`].
-	fn add(mut self, rhs: &[u8; N]) -> Self {
-		self.push_bytes(rhs);
-		self
+	//		to_json																
+	/// Converts the archive into a HAR JSON value.
+	/// 
+	/// The HAR version is 1.2, and the creator is set to this crate.
+	/// 
+	#[must_use]
+	pub fn to_json(&self) -> Json {
+		json!({
+			"log": {
+				"version": "1.2",
+				"creator": {
+					"name":    env!("CARGO_PKG_NAME"),
+					"version": env!("CARGO_PKG_VERSION"),
+				},
+				"entries": self.entries.iter().map(HarEntry::to_json).collect::<Vec<_>>(),
+			},
+		})
 	}
 }
 
-//󰭅		Add char																
-impl Add<char> for UnpackedResponseBody {
-	type Output = Self;
+//		HarEntry																
+/// A single entry in an HTTP Archive (HAR).
+/// 
+/// # See also
+/// 
+/// * [`HarArchive`]
+/// 
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct HarEntry {
+	//		Public properties													
+	/// The request.
+	pub request:  UnpackedRequest,
 	
-	//		add																	
-	/// Adds a [`char`] to an [`UnpackedResponseBody`].
-	fn add(mut self, rhs: char) -> Self {
-		self.push_char(&rhs);
-		self
-	}
+	/// The response that was received for the request.
+	pub response: UnpackedResponse,
 }
 
-//󰭅		Add &char																
-impl Add<&char> for UnpackedResponseBody {
-	type Output = Self;
+//󰭅		HarEntry																
+impl HarEntry {
+	//		new																	
+	/// Creates a new entry.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `request`  - The request.
+	/// * `response` - The response that was received for the request.
+	/// 
+	#[must_use]
+	pub const fn new(request: UnpackedRequest, response: UnpackedResponse) -> Self {
+		Self { request, response }
+	}
 	
-	//		add																	
-	/// Adds a [`&char`](char) to an [`UnpackedResponseBody`].
-	fn add(mut self, rhs: &char) -> Self {
-		self.push_char(rhs);
-		self
+	//		from_json															
+	/// Converts a HAR entry JSON value into an entry.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `value` - The HAR entry, i.e. an object with `request` and `response`
+	///             properties.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the request or response is not
+	/// valid.
+	/// 
+	pub fn from_json(value: &Json) -> Result<Self, HarError> {
+		Ok(Self::new(
+			UnpackedRequest::from_har(har_field(value, "request")?)?,
+			UnpackedResponse::from_har(har_field(value, "response")?)?,
+		))
+	}
+	
+	//		to_json																
+	/// Converts the entry into a HAR entry JSON value.
+	/// 
+	/// As timing information is not available, the start time is set to the
+	/// Unix epoch, and all timings are set to zero.
+	/// 
+	#[must_use]
+	pub fn to_json(&self) -> Json {
+		json!({
+			"startedDateTime": "1970-01-01T00:00:00.000Z",
+			"time":            0_i32,
+			"request":         self.request.to_har(),
+			"response":        self.response.to_har(),
+			"cache":           {},
+			"timings":         { "send": 0_i32, "wait": 0_i32, "receive": 0_i32 },
+		})
 	}
 }
 
-//󰭅		Add &str																
-impl Add<&str> for UnpackedResponseBody {
-	type Output = Self;
+//		LoggingBody																
+/// A request or response body that is logged as it is read.
+/// 
+/// This is used by a [`LoggingService`] to pass bodies through to their
+/// destination without buffering them. The data passes through unchanged, and
+/// up to the [maximum body size](LoggingLayer::with_max_body_size()) of it is
+/// kept for logging, along with the full size. The event is logged once the
+/// end of the body is reached, or when the body is dropped, whichever comes
+/// first, and so only the data that has been read by then is included.
+/// 
+/// # See also
+/// 
+/// * [`LoggingLayer`]
+/// * [`LoggingService`]
+/// 
+#[cfg(feature = "logging")]
+pub struct LoggingBody<B> {
+	//		Private properties													
+	/// The body being logged.
+	inner:    Pin<Box<B>>,
 	
-	//		add																	
-	/// Adds a [`&str`](str) to an [`UnpackedResponseBody`].
-	fn add(mut self, rhs: &str) -> Self {
-		self.push_str(rhs);
-		self
-	}
+	/// The data kept for logging, up to the limit.
+	captured: Vec<u8>,
+	
+	/// The maximum number of bytes to keep for logging.
+	limit:    usize,
+	
+	/// The total number of bytes read.
+	size:     usize,
+	
+	/// The function to call with the captured data and total size when the
+	/// body has been read or dropped. This is taken when called.
+	on_end:   Option<Box<dyn FnOnce(Bytes, usize) + Send>>,
 }
 
-//󰭅		Add String																
-impl Add<String> for UnpackedResponseBody {
-	type Output = Self;
+//󰭅		LoggingBody																
+#[cfg(feature = "logging")]
+impl<B> LoggingBody<B> {
+	//		new																	
+	/// Creates a new logging body wrapping the given body.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `inner`  - The body to log.
+	/// * `limit`  - The maximum number of bytes to keep for logging.
+	/// * `on_end` - The function to call with the captured data and total
+	///              size when the body has been read or dropped.
+	/// 
+	fn new<F>(inner: B, limit: usize, on_end: F) -> Self
+	where
+		F: FnOnce(Bytes, usize) + Send + 'static,
+	{
+		Self {
+			inner:    Box::pin(inner),
+			captured: Vec::new(),
+			limit,
+			size:     0,
+			on_end:   Some(Box::new(on_end)),
+		}
+	}
 	
-	//		add																	
-	/// Adds a [`String`] to an [`UnpackedResponseBody`].
-	fn add(mut self, rhs: String) -> Self {
-		self.push_str(&rhs);
-		self
+	//		capture																
+	/// Records a chunk of data read from the body.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `data` - The data read.
+	/// 
+	fn capture(&mut self, data: &[u8]) {
+		let remaining = self.limit.saturating_sub(self.captured.len());
+		self.captured.extend_from_slice(data.get(..remaining).unwrap_or(data));
+		self.size     = self.size.saturating_add(data.len());
+	}
+	
+	//		finish																
+	/// Calls the end function, if it has not already been called.
+	fn finish(&mut self) {
+		if let Some(on_end) = self.on_end.take() {
+			on_end(Bytes::from(mem::take(&mut self.captured)), self.size);
+		}
 	}
 }
 
-//󰭅		Add &String																
-impl Add<&String> for UnpackedResponseBody {
-	type Output = Self;
+//󰭅		Body																	
+#[cfg(feature = "logging")]
+impl<B> Body for LoggingBody<B>
+where
+	B:        Body,
+	B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+	type Data  = Bytes;
+	type Error = LoggingError;
 	
-	//		add																	
-	/// Adds a [`&String`](String) to an [`UnpackedResponseBody`].
-	fn add(mut self, rhs: &String) -> Self {
-		self.push_str(rhs);
-		self
+	//		poll_frame															
+	fn poll_frame(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+		let this = self.get_mut();
+		match ready!(this.inner.as_mut().poll_frame(cx)) {
+			Some(Ok(frame)) => {
+				let mapped = frame.map_data(|mut data| data.copy_to_bytes(data.remaining()));
+				if let Some(data) = mapped.data_ref() {
+					this.capture(data);
+				}
+				Poll::Ready(Some(Ok(mapped)))
+			},
+			Some(Err(err))  => {
+				this.finish();
+				Poll::Ready(Some(Err(LoggingError::Body(err.into()))))
+			},
+			None            => {
+				this.finish();
+				Poll::Ready(None)
+			},
+		}
+	}
+	
+	//		is_end_stream														
+	fn is_end_stream(&self) -> bool {
+		self.inner.is_end_stream()
+	}
+	
+	//		size_hint															
+	fn size_hint(&self) -> SizeHint {
+		self.inner.size_hint()
 	}
 }
 
-//󰭅		Add Box<str>															
-impl Add<Box<str>> for UnpackedResponseBody {
-	type Output = Self;
-	
-	//		add																	
-	/// Adds a [boxed](Box) [string](str) slice to an [`UnpackedResponseBody`].
-	fn add(mut self, rhs: Box<str>) -> Self::Output {
-		self.push_str(&rhs);
-		self
+//󰭅		Debug																	
+#[cfg(feature = "logging")]
+impl<B> Debug for LoggingBody<B> {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("LoggingBody")
+			.field("limit", &self.limit)
+			.field("size",  &self.size)
+			.finish_non_exhaustive()
 	}
 }
 
-//󰭅		Add Cow<str>															
-impl<'a> Add<Cow<'a, str>> for UnpackedResponseBody {
-	type Output = Self;
-	
-	//		add																	
-	/// Adds a [clone-on-write](Cow) [string](str) to an
-	/// [`UnpackedResponseBody`].
-	fn add(mut self, rhs: Cow<'a, str>) -> Self::Output {
-		self.push_str(&rhs);
-		self
+//󰭅		Drop																	
+#[cfg(feature = "logging")]
+impl<B> Drop for LoggingBody<B> {
+	//		drop																
+	fn drop(&mut self) {
+		self.finish();
 	}
 }
 
-//󰭅		Add u8																	
-impl Add<u8> for UnpackedResponseBody {
-	type Output = Self;
+//		LoggingLayer															
+/// A Tower [`Layer`] that logs requests and responses.
+/// 
+/// This wraps a service in a [`LoggingService`], which unpacks each request
+/// and response passing through it, and logs them as [`LogEvent`]s. By
+/// default, events are logged using [`tracing`] at the `INFO` level, but they
+/// can be sent to a custom sink instead, using
+/// [`with_sink()`](LoggingLayer::with_sink()).
+/// 
+/// Before logging, a [`RedactionPolicy`] is applied to each request and
+/// response. By default this replaces the values of sensitive headers with
+/// `[redacted]`, these being `authorization`, `cookie`, `proxy-authorization`,
+/// and `set-cookie`, but a custom policy can be set using
+/// [`with_redaction_policy()`](LoggingLayer::with_redaction_policy()) to
+/// redact JSON fields and text patterns as well. Bodies can be truncated to a
+/// maximum size, and binary bodies are rendered as base64 or hex, according to
+/// the [`BinaryFormat`].
+/// 
+/// Bodies are not buffered, but are passed through as they are read, with only
+/// up to the maximum size kept for logging. Each event is therefore logged once
+/// its body has been read to the end, or dropped, and so a streaming response
+/// is logged when the stream finishes or the client goes away.
+/// 
+/// # See also
+/// 
+/// * [`LogEvent`]
+/// * [`LoggingBody`]
+/// * [`LoggingService`]
+/// 
+#[cfg(feature = "logging")]
+#[derive(Clone)]
+pub struct LoggingLayer {
+	//		Private properties													
+	/// The format in which binary bodies are rendered.
+	binary_format:    BinaryFormat,
 	
-	//		add																	
-	/// Adds a [`u8`] to an [`UnpackedResponseBody`].
-	fn add(mut self, rhs: u8) -> Self {
-		self.push(rhs);
-		self
-	}
+	/// The maximum number of bytes of each body to log.
+	max_body_size:    usize,
+	
+	/// The redactions to apply before logging.
+	redaction:        RedactionPolicy,
+	
+	/// The sink that events are sent to. If not set, events are logged using
+	/// [`tracing`].
+	#[expect(clippy::type_complexity, reason = "Not complex enough to warrant a type alias")]
+	sink:             Option<Arc<dyn Fn(&LogEvent) + Send + Sync>>,
 }
 
-//󰭅		Add Vec<u8>																
-impl Add<Vec<u8>> for UnpackedResponseBody {
-	type Output = Self;
+//󰭅		LoggingLayer															
+#[cfg(feature = "logging")]
+impl LoggingLayer {
+	//		new																	
+	/// Creates a new logging layer with the default settings.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
 	
-	//		add																	
-	/// Adds a [`Vec[u8]`](Vec) to an [`UnpackedResponseBody`].
-	fn add(mut self, rhs: Vec<u8>) -> Self {
-		self.push_bytes(&rhs);
+	//		with_binary_format													
+	/// Sets the format in which binary bodies are rendered.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `format` - The format to use.
+	/// 
+	#[must_use]
+	pub const fn with_binary_format(mut self, format: BinaryFormat) -> Self {
+		self.binary_format = format;
 		self
 	}
-}
-
-//󰭅		Add &Vec<u8>															
-impl Add<&Vec<u8>> for UnpackedResponseBody {
-	type Output = Self;
 	
-	//		add																	
-	/// Adds a [`&Vec[u8]`](Vec) to an [`UnpackedResponseBody`].
-	fn add(mut self, rhs: &Vec<u8>) -> Self {
-		self.push_bytes(rhs);
+	//		with_max_body_size													
+	/// Sets the maximum number of bytes of each body to log.
+	/// 
+	/// Bodies larger than this are truncated, and a note of the full size is
+	/// appended. Text is truncated on a character boundary. By default, bodies
+	/// are not truncated.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `size` - The maximum number of bytes to log.
+	/// 
+	#[must_use]
+	pub const fn with_max_body_size(mut self, size: usize) -> Self {
+		self.max_body_size = size;
 		self
 	}
-}
-
-//󰭅		Add Self																
-impl Add<Self> for UnpackedResponseBody {
-	type Output = Self;
 	
-	//		add																	
-	/// Adds an [`UnpackedResponseBody`] to an [`UnpackedResponseBody`].
-	fn add(mut self, rhs: Self) -> Self {
-		self.push_bytes(&rhs.body);
+	//		with_redacted_header												
+	/// Adds a header to redact.
+	/// 
+	/// The value of any header with this name, compared case-insensitively,
+	/// will be replaced with `[redacted]`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The name of the header to redact.
+	/// 
+	#[must_use]
+	pub fn with_redacted_header(mut self, name: &str) -> Self {
+		self.redaction = self.redaction.with_header(name);
 		self
 	}
-}
-
-//󰭅		Add &Self																
-impl Add<&Self> for UnpackedResponseBody {
-	type Output = Self;
 	
-	//		add																	
-	/// Adds an [`&UnpackedResponseBody`](UnpackedResponseBody) to an
-	/// [`UnpackedResponseBody`].
-	fn add(mut self, rhs: &Self) -> Self {
-		self.push_bytes(rhs.as_bytes());
+	//		with_redacted_headers												
+	/// Sets the headers to redact, replacing the defaults.
+	/// 
+	/// Any other redactions in the current policy are kept.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `names` - The names of the headers to redact. An empty list disables
+	///             header redaction.
+	/// 
+	#[must_use]
+	pub fn with_redacted_headers(mut self, names: &[&str]) -> Self {
+		self.redaction.headers = names.iter().map(|name| name.to_ascii_lowercase()).collect();
 		self
 	}
-}
-
-//󰭅		AddAssign &[u8]															
-impl AddAssign<&[u8]> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds a [`&[u8]`](https://doc.rust-lang.org/std/primitive.slice.html) to
-	/// an [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: &[u8]) {
-		self.push_bytes(rhs);
+	
+	//		with_redaction_policy												
+	/// Sets the redaction policy to apply before logging.
+	/// 
+	/// This replaces the default redactions, and any added using
+	/// [`with_redacted_header()`](LoggingLayer::with_redacted_header()) or
+	/// [`with_redacted_headers()`](LoggingLayer::with_redacted_headers()), and
+	/// allows the full set of redactions supported by [`RedactionPolicy`] to be
+	/// used, such as JSON fields and text patterns.
+	/// 
+	/// Bodies are redacted before they are truncated. However, a JSON body that
+	/// was only partly kept, because it exceeded the
+	/// [maximum body size](LoggingLayer::with_max_body_size()), cannot be
+	/// parsed, and so if the policy redacts any JSON fields then the whole body
+	/// is replaced with the placeholder.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `policy` - The redaction policy to apply.
+	/// 
+	#[must_use]
+	pub fn with_redaction_policy(mut self, policy: RedactionPolicy) -> Self {
+		self.redaction = policy;
+		self
+	}
+	
+	//		with_sink															
+	/// Sets a sink to send events to, instead of logging them using
+	/// [`tracing`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `sink` - The function to call with each event.
+	/// 
+	#[must_use]
+	pub fn with_sink<F>(mut self, sink: F) -> Self
+	where
+		F: Fn(&LogEvent) + Send + Sync + 'static,
+	{
+		self.sink = Some(Arc::new(sink));
+		self
+	}
+	
+	//		format_body															
+	/// Returns a copy of a body prepared for logging.
+	/// 
+	/// The body is truncated if necessary, and binary bodies are rendered as
+	/// text in the configured format.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `body` - The body to prepare. This may be only the start of the
+	///            full body.
+	/// * `size` - The size of the full body, in bytes.
+	/// 
+	fn format_body(&self, body: &UnpackedResponseBody, size: usize) -> UnpackedResponseBody {
+		let truncated = size > self.max_body_size;
+		let data      = body.body.get(..self.max_body_size).unwrap_or(&body.body);
+		let mut text  = match (body.content_type, self.binary_format) {
+			(ContentType::Binary, BinaryFormat::Base64) => BASE64.encode(data),
+			(ContentType::Binary, BinaryFormat::Hex)    => data.iter().fold(String::new(), |mut hex, byte| {
+				_ = write!(hex, "{byte:02x}");
+				hex
+			}),
+			(ContentType::Text | ContentType::Json, _)  => match from_utf8(data) {
+				Ok(valid)                                          => valid.to_owned(),
+				Err(err) if truncated && err.error_len().is_none() => {
+					//	The truncation split a character, so drop the partial bytes
+					String::from_utf8_lossy(data.get(..err.valid_up_to()).unwrap_or_default()).into_owned()
+				},
+				Err(_)                                             => String::from_utf8_lossy(data).into_owned(),
+			},
+		};
+		if truncated {
+			_ = write!(text, "... [truncated from {size} bytes]");
+		}
+		UnpackedResponseBody {
+			body:         text.into_bytes(),
+			content_type: if truncated || body.is_binary() { ContentType::Text } else { body.content_type },
+		}
+	}
+	
+	//		log																	
+	/// Logs an event, after applying redactions and truncation.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `event` - The event to log.
+	/// * `size`  - The size of the full body of the request or response, of
+	///             which the event may only hold the start.
+	/// 
+	fn log(&self, mut event: LogEvent, size: usize) {
+		let partial     = match event {
+			LogEvent::Request(ref request)   => size > request.body.len(),
+			LogEvent::Response(ref response) => size > response.body.len(),
+		};
+		let body        = match event {
+			LogEvent::Request(ref mut request)   => {
+				self.redaction.redact_headers(&mut request.headers);
+				self.redaction.redact_body(&mut request.body);
+				&mut request.body
+			},
+			LogEvent::Response(ref mut response) => {
+				*response = self.redaction.redact(response);
+				&mut response.body
+			},
+		};
+		let logged_size = if partial && body.content_type == ContentType::Json && !self.redaction.json_fields.is_empty() {
+			//	Only part of the body was kept, so it could not be parsed to
+			//	redact the fields
+			*body = UnpackedResponseBody::new(self.redaction.placeholder.clone());
+			body.len()
+		} else {
+			size
+		};
+		*body = self.format_body(body, logged_size);
+		if let Some(ref sink) = self.sink {
+			sink(&event);
+		} else {
+			info!("{event}");
+		}
 	}
 }
 
-//󰭅		AddAssign &[u8; N]														
-impl<const N: usize> AddAssign<&[u8; N]> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds a [`&[u8; N]`](https://doc.rust-lang.org/std/primitive.slice.html)
-	/// to an [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: &[u8; N]) {
-		self.push_bytes(rhs);
+//󰭅		Debug																	
+#[cfg(feature = "logging")]
+impl Debug for LoggingLayer {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("LoggingLayer")
+			.field("binary_format",    &self.binary_format)
+			.field("max_body_size",    &self.max_body_size)
+			.field("redaction",        &self.redaction)
+			.finish_non_exhaustive()
 	}
 }
 
-//󰭅		AddAssign char															
-impl AddAssign<char> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds a [`char`] to an [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: char) {
-		self.push_char(&rhs);
+//󰭅		Default																	
+#[cfg(feature = "logging")]
+impl Default for LoggingLayer {
+	//		default																
+	fn default() -> Self {
+		Self {
+			binary_format:    BinaryFormat::default(),
+			max_body_size:    usize::MAX,
+			redaction:        RedactionPolicy::new()
+				.with_header("authorization")
+				.with_header("cookie")
+				.with_header("proxy-authorization")
+				.with_header("set-cookie")
+			,
+			sink:             None,
+		}
 	}
 }
 
-//󰭅		AddAssign &char															
-impl AddAssign<&char> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds a [`&char`](char) to an [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: &char) {
-		self.push_char(rhs);
+//󰭅		Layer																	
+#[cfg(feature = "logging")]
+impl<S> Layer<S> for LoggingLayer {
+	type Service = LoggingService<S>;
+	
+	//		layer																
+	fn layer(&self, inner: S) -> Self::Service {
+		LoggingService { inner, layer: self.clone() }
 	}
 }
 
-//󰭅		AddAssign &str															
-impl AddAssign<&str> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds a [`&str`](str) to an [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: &str) {
-		self.push_str(rhs);
-	}
+//		LoggingService															
+/// A Tower [`Service`] that logs the requests and responses passing through
+/// it.
+/// 
+/// This is created by applying a [`LoggingLayer`] to an inner service. Request
+/// and response bodies are not buffered, but are wrapped in a [`LoggingBody`],
+/// which passes the data through unchanged as it is read, and so streaming
+/// responses such as Server-Sent Events are not held up. Each event is logged
+/// when the end of its body is reached, or when the body is dropped. The
+/// response status and headers are passed through unchanged.
+/// 
+/// # See also
+/// 
+/// * [`LogEvent`]
+/// * [`LoggingBody`]
+/// * [`LoggingLayer`]
+/// 
+#[cfg(feature = "logging")]
+#[derive(Clone, Debug)]
+pub struct LoggingService<S> {
+	//		Private properties													
+	/// The inner service, which is called to obtain responses.
+	inner: S,
+	
+	/// The layer that created the service, holding the logging settings.
+	layer: LoggingLayer,
 }
 
-//󰭅		AddAssign String														
-impl AddAssign<String> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds a [`String`] to an [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: String) {
-		self.push_str(&rhs);
+//󰭅		Service																	
+#[cfg(feature = "logging")]
+impl<S, B, RB> Service<Request<B>> for LoggingService<S>
+where
+	S:         Service<Request<LoggingBody<B>>, Response = Response<RB>> + Clone + Send + 'static,
+	S::Error:  Into<Box<dyn Error + Send + Sync>>,
+	S::Future: Send,
+	B:         Body + Send + 'static,
+	B::Error:  Into<Box<dyn Error + Send + Sync>>,
+	RB:        Body,
+	RB::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+	type Response = Response<LoggingBody<RB>>;
+	type Error    = LoggingError;
+	type Future   = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+	
+	//		poll_ready															
+	fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+		self.inner.poll_ready(cx).map_err(|err| LoggingError::Service(err.into()))
 	}
-}
-
-//󰭅		AddAssign &String														
-impl AddAssign<&String> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds a [`&String`](String) to an [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: &String) {
-		self.push_str(rhs);
+	
+	//		call																
+	fn call(&mut self, req: Request<B>) -> Self::Future {
+		let layer     = self.layer.clone();
+		let clone     = self.inner.clone();
+		let mut inner = mem::replace(&mut self.inner, clone);
+		let (parts, body)       = req.into_parts();
+		let request_layer       = layer.clone();
+		let (method, uri)       = (parts.method.clone(), parts.uri.clone());
+		let (version, headers)  = (parts.version, parts.headers.clone());
+		let request_body        = LoggingBody::new(body, layer.max_body_size, move |data, size| {
+			request_layer.log(LogEvent::Request(convert_request(&method, &uri, version, &headers, &data)), size);
+		});
+		let future              = inner.call(Request::from_parts(parts, request_body));
+		Box::pin(async move {
+			let response            = future.await.map_err(|err| LoggingError::Service(err.into()))?;
+			let (head, data)        = response.into_parts();
+			let (status, head_map)  = (head.status, head.headers.clone());
+			let response_body       = LoggingBody::new(data, layer.max_body_size, move |content, size| {
+				let options = UnpackOptions::default();
+				layer.log(LogEvent::Response(UnpackedResponse {
+					status,
+					headers:     convert_headers(&head_map, &options),
+					body:        UnpackedResponseBody {
+						body:         content.to_vec(),
+						content_type: detect_content_type(&head_map, &content, &options),
+					},
+					frames:      Vec::new(),
+					raw_headers: Vec::new(),
+				}), size);
+			});
+			Ok(Response::from_parts(head, response_body))
+		})
 	}
 }
 
-//󰭅		AddAssign Box<str>														
-impl AddAssign<Box<str>> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds a [boxed](Box) [string](str) slice to an [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: Box<str>) {
-		self.push_str(&rhs);
-	}
+//		MatchReport																
+/// The result of checking a response against a [`ResponseMatcher`].
+/// 
+/// This provides a structured report of every way in which the response did
+/// not match. If there are no failures, the response matched. The [`Display`]
+/// implementation provides a human-readable form, with each failure shown on
+/// its own line.
+/// 
+/// # See also
+/// 
+/// * [`MatchFailure`]
+/// * [`ResponseMatcher::check()`]
+/// 
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct MatchReport {
+	//		Public properties													
+	/// The reasons why the response did not match, if any.
+	pub failures: Vec<MatchFailure>,
 }
 
-//󰭅		AddAssign Cow<str>														
-impl<'a> AddAssign<Cow<'a, str>> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds a [clone-on-write](Cow) [string](str) to an
-	/// [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: Cow<'a, str>){
-		self.push_str(&rhs);
+//󰭅		MatchReport																
+impl MatchReport {
+	//		is_match															
+	/// Returns whether the response matched, i.e. there are no failures.
+	#[must_use]
+	pub fn is_match(&self) -> bool {
+		self.failures.is_empty()
 	}
 }
 
-//󰭅		AddAssign u8															
-impl AddAssign<u8> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds a [`u8`] to an [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: u8) {
-		self.push(rhs);
+//󰭅		Display																	
+impl Display for MatchReport {
+	//		fmt																	
+	/// Formats the match report for display.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.is_match() {
+			return write!(f, "Response matches");
+		}
+		for failure in &self.failures {
+			writeln!(f, "{failure}")?;
+		}
+		Ok(())
 	}
 }
 
-//󰭅		AddAssign Vec<u8>														
-impl AddAssign<Vec<u8>> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds a [`Vec[u8]`](Vec) to an [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: Vec<u8>) {
-		self.push_bytes(&rhs);
-	}
+//		MultipartBuilder														
+/// A builder for multipart bodies.
+/// 
+/// This constructs a multipart body from a list of parts, for use in fixtures
+/// and mock responses. The subtype defaults to `mixed`, and can be changed,
+/// e.g. to `form-data`. The corresponding `content-type` header value,
+/// including the boundary, is available from
+/// [`content_type()`](MultipartBuilder::content_type()).
+/// 
+/// # See also
+/// 
+/// * [`MultipartPart`]
+/// * [`UnpackedResponseBody::as_multipart()`]
+/// 
+#[derive(Clone, Debug)]
+pub struct MultipartBuilder {
+	//		Private properties													
+	/// The boundary that separates the parts.
+	boundary: String,
+	
+	/// The multipart subtype, e.g. `mixed` or `form-data`.
+	subtype:  String,
+	
+	/// The parts of the body, in order.
+	parts:    Vec<MultipartPart>,
 }
 
-//󰭅		AddAssign &Vec<u8>														
-impl AddAssign<&Vec<u8>> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds a [`&Vec[u8]`](Vec) to an [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: &Vec<u8>) {
-		self.push_bytes(rhs);
+//󰭅		MultipartBuilder														
+impl MultipartBuilder {
+	//		new																	
+	/// Creates a new multipart builder with the specified boundary.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `boundary` - The boundary that separates the parts. This must not
+	///                appear anywhere within the parts.
+	/// 
+	#[must_use]
+	pub fn new(boundary: &str) -> Self {
+		Self {
+			boundary: boundary.to_owned(),
+			subtype:  "mixed".to_owned(),
+			parts:    Vec::new(),
+		}
+	}
+	
+	//		with_subtype														
+	/// Sets the multipart subtype.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `subtype` - The multipart subtype, e.g. `mixed` or `form-data`.
+	/// 
+	#[must_use]
+	pub fn with_subtype(mut self, subtype: &str) -> Self {
+		subtype.clone_into(&mut self.subtype);
+		self
+	}
+	
+	//		with_part															
+	/// Adds a part.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `part` - The part to add.
+	/// 
+	#[must_use]
+	pub fn with_part(mut self, part: MultipartPart) -> Self {
+		self.parts.push(part);
+		self
+	}
+	
+	//		with_field															
+	/// Adds a form field part.
+	/// 
+	/// The part is given a `content-disposition` header of `form-data`, with
+	/// the specified field name.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name`  - The name of the field.
+	/// * `value` - The value of the field.
+	/// 
+	#[must_use]
+	pub fn with_field(self, name: &str, value: &str) -> Self {
+		self.with_part(MultipartPart::new(value)
+			.with_header("content-disposition", &format!("form-data; name={}", quote_parameter(name)))
+		)
+	}
+	
+	//		with_file															
+	/// Adds a file part.
+	/// 
+	/// The part is given a `content-disposition` header of `form-data`, with
+	/// the specified field name and filename, and a `content-type` header. The
+	/// [`ContentType`] of the part body is set according to the MIME type,
+	/// falling back to [`Binary`](ContentType::Binary) if it is not recognised.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name`      - The name of the field.
+	/// * `filename`  - The name of the file.
+	/// * `mime_type` - The MIME type of the file.
+	/// * `data`      - The contents of the file.
+	/// 
+	#[must_use]
+	pub fn with_file<T: Into<UnpackedResponseBody>>(
+		self,
+		name:      &str,
+		filename:  &str,
+		mime_type: &str,
+		data:      T,
+	) -> Self {
+		let mut body = data.into();
+		_ = body.set_content_type(UnpackOptions::default().content_type_for(mime_type).unwrap_or(ContentType::Binary));
+		self.with_part(MultipartPart::new(body)
+			.with_header("content-disposition", &format!(
+				"form-data; name={}; filename={}",
+				quote_parameter(name),
+				quote_parameter(filename),
+			))
+			.with_header(CONTENT_TYPE.as_str(), mime_type)
+		)
+	}
+	
+	//		content_type														
+	/// Returns the `content-type` header value for the body.
+	/// 
+	/// This includes the boundary, which is quoted if necessary.
+	/// 
+	#[must_use]
+	pub fn content_type(&self) -> String {
+		let boundary = if self.boundary.chars().all(is_token_char) {
+			self.boundary.clone()
+		} else {
+			quote_parameter(&self.boundary)
+		};
+		format!("multipart/{}; boundary={boundary}", self.subtype)
+	}
+	
+	//		build																
+	/// Builds the multipart body.
+	/// 
+	/// Each part is preceded by the boundary delimiter and its headers, and
+	/// lines are terminated with CRLF. The [`ContentType`] of the resulting
+	/// body is [`Text`](ContentType::Text) if it is valid UTF8, and
+	/// [`Binary`](ContentType::Binary) otherwise.
+	/// 
+	#[must_use]
+	pub fn build(&self) -> UnpackedResponseBody {
+		let delimiter = format!("--{}", self.boundary);
+		let mut body  = Vec::new();
+		for part in &self.parts {
+			body.extend_from_slice(delimiter.as_bytes());
+			body.extend_from_slice(b"\r\n");
+			for header in &part.headers {
+				body.extend_from_slice(header.name.as_bytes());
+				body.extend_from_slice(b": ");
+				body.extend_from_slice(header.value.as_bytes());
+				body.extend_from_slice(b"\r\n");
+			}
+			body.extend_from_slice(b"\r\n");
+			body.extend_from_slice(part.body.as_bytes());
+			body.extend_from_slice(b"\r\n");
+		}
+		body.extend_from_slice(delimiter.as_bytes());
+		body.extend_from_slice(b"--\r\n");
+		let content_type = if from_utf8(&body).is_ok() { ContentType::Text } else { ContentType::Binary };
+		UnpackedResponseBody { body, content_type }
 	}
 }
 
-//󰭅		AddAssign Self															
-impl AddAssign<Self> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds an [`UnpackedResponseBody`] to an [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: Self) {
-		self.push_bytes(&rhs.body);
-	}
+//		MultipartPart															
+/// A single part of a multipart body.
+/// 
+/// Parts are obtained by parsing a body with
+/// [`UnpackedResponseBody::as_multipart()`] or
+/// [`UnpackedResponse::multipart()`], and can be constructed for use with a
+/// [`MultipartBuilder`]. Header names are stored in lowercase, in the order in
+/// which they appear.
+/// 
+/// # See also
+/// 
+/// * [`MultipartBuilder`]
+/// * [`UnpackedResponse::multipart()`]
+/// * [`UnpackedResponseBody::as_multipart()`]
+/// 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct MultipartPart {
+	//		Public properties													
+	/// The headers of the part.
+	pub headers: Vec<UnpackedResponseHeader>,
+	
+	/// The body of the part. The [`ContentType`] is detected from the part's
+	/// `content-type` header in the same way as for a response.
+	pub body:    UnpackedResponseBody,
 }
 
-//󰭅		AddAssign &Self															
-impl AddAssign<&Self> for UnpackedResponseBody {
-	//		add_assign															
-	/// Adds an [`&UnpackedResponseBody`](UnpackedResponseBody) to an
-	/// [`UnpackedResponseBody`].
-	fn add_assign(&mut self, rhs: &Self) {
-		self.push_bytes(rhs.as_bytes());
+//󰭅		MultipartPart															
+impl MultipartPart {
+	//		new																	
+	/// Creates a new part with the specified body and no headers.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `body` - The body of the part.
+	/// 
+	#[must_use]
+	pub fn new<T: Into<UnpackedResponseBody>>(body: T) -> Self {
+		Self {
+			headers: Vec::new(),
+			body:    body.into(),
+		}
+	}
+	
+	//		with_header															
+	/// Adds a header.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name`  - The header name. This will be converted to lowercase.
+	/// * `value` - The header value.
+	/// 
+	#[must_use]
+	pub fn with_header(mut self, name: &str, value: &str) -> Self {
+		self.headers.push(UnpackedResponseHeader::new(name.to_ascii_lowercase(), value.to_owned()));
+		self
+	}
+	
+	//		header																
+	/// Returns the value of the named header.
+	/// 
+	/// The name is matched case-insensitively. If the header appears more than
+	/// once, the first value is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The name of the header.
+	/// 
+	#[must_use]
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers.iter()
+			.find(|header| header.name.eq_ignore_ascii_case(name))
+			.map(|header| header.value.as_str())
+	}
+	
+	//		name																
+	/// Returns the field name of the part.
+	/// 
+	/// This is taken from the `name` parameter of the `content-disposition`
+	/// header, as used by `multipart/form-data`.
+	/// 
+	#[must_use]
+	pub fn name(&self) -> Option<String> {
+		self.header("content-disposition").and_then(|value| mime_parameter(value, "name"))
+	}
+	
+	//		filename															
+	/// Returns the filename of the part.
+	/// 
+	/// This is taken from the `filename` parameter of the `content-disposition`
+	/// header, as used by `multipart/form-data` for file uploads.
+	/// 
+	#[must_use]
+	pub fn filename(&self) -> Option<String> {
+		self.header("content-disposition").and_then(|value| mime_parameter(value, "filename"))
 	}
 }
 
-//󰭅		AsMut [u8]																
-impl AsMut<[u8]> for UnpackedResponseBody {
-	//		as_mut																
-	fn as_mut(&mut self) -> &mut [u8] {
-		self.as_mut_bytes()
+//		OfflineService															
+/// A [`Service`] that has no network access, for use with a [`Cassette`].
+/// 
+/// This is used as the inner service by [`CassetteService::offline()`], and
+/// fails every request with a [`CassetteError::Offline`] error, so that a
+/// request that has not been recorded can never reach a real server.
+/// 
+/// # See also
+/// 
+/// * [`CassetteService`]
+/// 
+#[cfg(feature = "cassette")]
+#[derive(Copy, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct OfflineService;
+
+//󰭅		Service																	
+#[cfg(feature = "cassette")]
+impl Service<Request<Full<Bytes>>> for OfflineService {
+	type Response = Response<Full<Bytes>>;
+	type Error    = CassetteError;
+	type Future   = Ready<Result<Self::Response, Self::Error>>;
+	
+	//		poll_ready															
+	fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+		Poll::Ready(Ok(()))
+	}
+	
+	//		call																
+	fn call(&mut self, req: Request<Full<Bytes>>) -> Self::Future {
+		ready(Err(CassetteError::Offline { method: req.method().clone(), path: request_path(req.uri()) }))
 	}
 }
 
-//󰭅		AsRef [u8]																
-impl AsRef<[u8]> for UnpackedResponseBody {
-	//		as_ref																
-	fn as_ref(&self) -> &[u8] {
-		self.as_bytes()
-	}
+//		ReadBody																
+/// The data read from a response body.
+/// 
+/// If reading timed out, the data is whatever was received before the
+/// timeout, and the timeout is recorded so that the appropriate error can be
+/// returned once the response has been unpacked.
+/// 
+/// # See also
+/// 
+/// * [`convert_read_body()`]
+/// * [`read_body()`]
+/// 
+#[derive(Default)]
+struct ReadBody {
+	//		Private properties													
+	/// The combined data of the body.
+	bytes:     Bytes,
+	
+	/// The individual frames of the body, if frame recording is enabled.
+	frames:    Vec<UnpackedFrame>,
+	
+	/// The timeout that was exceeded, if reading did not complete in time.
+	timed_out: Option<Duration>,
 }
 
-//󰭅		Clone																	
-impl Clone for UnpackedResponseBody {
-	//		clone																
-	fn clone(&self) -> Self {
-		Self { body: self.body.clone(), ..Default::default() }
-	}
+//		RedactedResponse														
+/// A view of an [`UnpackedResponse`] with a [`RedactionPolicy`] applied.
+/// 
+/// This is obtained from [`UnpackedResponse::redacted()`], and applies the
+/// redactions when it is serialised or displayed, without modifying the
+/// original response. The [`Display`] implementation produces the serialised
+/// JSON form of the redacted response, on a single line, or pretty-printed if
+/// the alternate flag (`{:#}`) is used.
+/// 
+/// # See also
+/// 
+/// * [`RedactionPolicy`]
+/// * [`UnpackedResponse::redacted()`]
+/// 
+#[derive(Clone, Copy, Debug)]
+pub struct RedactedResponse<'a> {
+	//		Private properties													
+	/// The redaction policy to apply.
+	policy:   &'a RedactionPolicy,
 	
-	//		clone_from															
-	fn clone_from(&mut self, source: &Self) {
-		self.body.clone_from(&source.body);
-	}
+	/// The response to redact.
+	response: &'a UnpackedResponse,
 }
 
-//󰭅		Debug																	
-impl Debug for UnpackedResponseBody {
+//󰭅		Display																	
+impl Display for RedactedResponse<'_> {
 	//		fmt																	
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		f.debug_struct("UnpackedResponseBody")
-			.field("body",         &self.to_string())
-			.field("content_type", &self.content_type)
-			.finish()
+		let json = if f.alternate() {
+			serde_json::to_string_pretty(self)
+		} else {
+			serde_json::to_string(self)
+		};
+		write!(f, "{}", json.map_err(|_err| fmt::Error)?)
 	}
 }
 
-//󰭅		Display																	
-impl Display for UnpackedResponseBody {
-	//		fmt																	
-	/// Formats the response body for display.
-	///
-	/// This method serialises the response body based on the content type. If
-	/// the content type is [`ContentType::Text`], then the response body is
-	/// serialised to an ordinary [`String`]. If the content type is
-	/// [`ContentType::Binary`], then the response body is serialised to a
-	/// base64-encoded [`String`].
-	///
-	/// Note that as no validation checks are performed on the response body
-	/// contents, it is not guaranteed to be UTF8, and therefore if not
-	/// specified as binary it is possible that the serialised string will not
-	/// totally match the original response body contents. This is because the
-	/// conversion of the response body bytes to a UTF8 string will be lossy if
-	/// there are invalid characters.
+//󰭅		Serialize																
+impl Serialize for RedactedResponse<'_> {
+	//		serialize															
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		self.policy.redact(self.response).serialize(serializer)
+	}
+}
+
+//		RedactionPolicy															
+/// A policy for masking sensitive data in an [`UnpackedResponse`].
+/// 
+/// This allows tokens and other secrets to be kept out of logs and fixtures.
+/// The values of named headers can be masked, as can fields in JSON bodies,
+/// identified by path, and substrings of text bodies that match regular
+/// expressions. Each redacted value is replaced with a placeholder, which is
+/// `[redacted]` by default.
+/// 
+/// The policy can be applied to obtain a redacted copy of a response, using
+/// [`redact()`](RedactionPolicy::redact()), or a view of a response that is
+/// redacted when serialised or displayed, using
+/// [`UnpackedResponse::redacted()`]. In both cases the original response is
+/// left unchanged.
+/// 
+/// # See also
+/// 
+/// * [`RedactedResponse`]
+/// * [`SnapshotOptions::with_redaction_policy()`]
+/// * [`UnpackedResponse::redacted()`]
+/// 
+#[derive(Clone, Debug)]
+pub struct RedactionPolicy {
+	//		Private properties													
+	/// The names of headers to redact, in lowercase.
+	headers:       Vec<String>,
+	
+	/// The paths of JSON fields to redact in the body.
+	json_fields:   Vec<String>,
+	
+	/// The text to replace redacted values with.
+	placeholder:   String,
+	
+	/// The patterns to redact in text bodies.
+	text_patterns: Vec<Regex>,
+}
+
+//󰭅		RedactionPolicy															
+impl RedactionPolicy {
+	//		new																	
+	/// Creates a new redaction policy, which does not redact anything.
+	/// 
+	/// This is the same as [`RedactionPolicy::default()`].
+	/// 
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+	
+	//		with_header															
+	/// Redacts the named header.
+	/// 
+	/// The values of any headers with this name will be replaced with the
+	/// placeholder, including their raw values, if retained. This also applies
+	/// to trailers recorded in the frames of the response. Header names are
+	/// not case-sensitive.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The name of the header to redact.
+	/// 
+	#[must_use]
+	pub fn with_header(mut self, name: &str) -> Self {
+		self.headers.push(name.to_ascii_lowercase());
+		self
+	}
+	
+	//		with_json_field														
+	/// Redacts a field in a JSON body.
+	/// 
+	/// Paths are expressed in the same form as for [`JsonPathDiff`], i.e.
+	/// `$.foo.bar[2]`, where `$` represents the root value. A wildcard can be
+	/// used in place of an object key (`$.foo.*`) or an array index
+	/// (`$.foo[*].id`). The value at the path will be replaced with the
+	/// placeholder.
+	/// 
+	/// This only has an effect if the body can be parsed as JSON, in which case
+	/// the body will be re-serialised in compact form.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `path` - The path of the field to redact.
+	/// 
+	#[must_use]
+	pub fn with_json_field(mut self, path: &str) -> Self {
+		self.json_fields.push(path.to_owned());
+		self
+	}
+	
+	//		with_placeholder													
+	/// Sets the text to replace redacted values with.
+	/// 
+	/// The default is `[redacted]`. The placeholder is used literally, and so
+	/// any `$` characters will not be treated as references to capture groups
+	/// when replacing text patterns.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `placeholder` - The replacement text.
+	/// 
+	#[must_use]
+	pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+		placeholder.clone_into(&mut self.placeholder);
+		self
+	}
+	
+	//		with_text_pattern													
+	/// Redacts substrings of text bodies that match a pattern.
+	/// 
+	/// Every match of the pattern will be replaced with the placeholder. This
+	/// applies to any body that is not binary, including JSON bodies, after
+	/// any JSON fields have been redacted, and also to the data frames
+	/// recorded for the response.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `pattern` - The pattern to match.
+	/// 
+	#[must_use]
+	pub fn with_text_pattern(mut self, pattern: Regex) -> Self {
+		self.text_patterns.push(pattern);
+		self
+	}
+	
+	//		redact																
+	/// Returns a copy of a response with redactions applied.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `response` - The response to redact.
 	/// 
 	/// # See also
 	/// 
-	/// * [`UnpackedResponseBody::serialize()`]
-	/// * [`UnpackedResponseBody::to_base64()`]
+	/// * [`UnpackedResponse::redacted()`]
 	/// 
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		let body                =  match self.content_type {
-			ContentType::Text   => String::from_utf8_lossy(&self.body),
-			ContentType::Binary => Cow::Owned(self.to_base64()),
+	#[must_use]
+	pub fn redact(&self, response: &UnpackedResponse) -> UnpackedResponse {
+		let mut redacted = response.clone();
+		self.redact_headers(&mut redacted.headers);
+		for header in &mut redacted.raw_headers {
+			if self.headers.iter().any(|name| name.eq_ignore_ascii_case(&header.name)) {
+				header.value = self.placeholder.as_bytes().to_vec();
+			}
+		}
+		self.redact_body(&mut redacted.body);
+		for frame in &mut redacted.frames {
+			match *frame {
+				UnpackedFrame::Data(ref mut body)        => self.redact_text(body),
+				UnpackedFrame::Trailers(ref mut headers) => self.redact_headers(headers),
+			}
+		}
+		redacted
+	}
+	
+	//		redact_body															
+	/// Redacts any JSON fields and text patterns in a body.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `body` - The body to redact.
+	/// 
+	fn redact_body(&self, body: &mut UnpackedResponseBody) {
+		if !self.json_fields.is_empty() && !body.is_binary() {
+			if let Ok(mut json) = serde_json::from_slice::<Json>(&body.body) {
+				redact_json("$", &mut json, &self.json_fields, &self.placeholder);
+				if let Ok(bytes) = serde_json::to_vec(&json) {
+					body.body = bytes;
+				}
+			}
+		}
+		self.redact_text(body);
+	}
+	
+	//		redact_headers														
+	/// Redacts the values of any headers named by the policy.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `headers` - The headers to redact.
+	/// 
+	fn redact_headers(&self, headers: &mut [UnpackedResponseHeader]) {
+		for header in headers {
+			if self.headers.iter().any(|name| name.eq_ignore_ascii_case(&header.name)) {
+				self.placeholder.clone_into(&mut header.value);
+			}
+		}
+	}
+	
+	//		redact_text															
+	/// Redacts any substrings of a text body that match the policy patterns.
+	/// 
+	/// Binary bodies, and bodies that are not valid UTF8, are left unchanged.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `body` - The body to redact.
+	/// 
+	fn redact_text(&self, body: &mut UnpackedResponseBody) {
+		if self.text_patterns.is_empty() || body.is_binary() {
+			return;
+		}
+		let Ok(text) = from_utf8(&body.body) else {
+			return;
 		};
-		write!(f, "{body}")
+		let mut redacted = Cow::Borrowed(text);
+		for pattern in &self.text_patterns {
+			if let Cow::Owned(replaced) = pattern.replace_all(&redacted, NoExpand(&self.placeholder)) {
+				redacted = Cow::Owned(replaced);
+			}
+		}
+		if let Cow::Owned(replaced) = redacted {
+			body.body = replaced.into_bytes();
+		}
 	}
 }
 
-//󰭅		From &[u8]																
-impl From<&[u8]> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`&[u8]`](https://doc.rust-lang.org/std/primitive.slice.html)
-	/// to an [`UnpackedResponseBody`].
-	fn from(b: &[u8]) -> Self {
-		Self { body: b.to_vec(), ..Default::default() }
+//󰭅		Default																	
+impl Default for RedactionPolicy {
+	//		default																
+	fn default() -> Self {
+		Self {
+			headers:       Vec::new(),
+			json_fields:   Vec::new(),
+			placeholder:   "[redacted]".to_owned(),
+			text_patterns: Vec::new(),
+		}
 	}
 }
 
-//󰭅		From &[u8; N]															
-impl<const N: usize> From<&[u8; N]> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`&[u8; N]`](https://doc.rust-lang.org/std/primitive.slice.html)
-	/// to an [`UnpackedResponseBody`].
-	fn from(b: &[u8; N]) -> Self {
-		Self { body: b.to_vec(), ..Default::default() }
-	}
+//		ResponseMatcher															
+/// A configurable matcher for checking an [`UnpackedResponse`] in tests.
+/// 
+/// The [`PartialEq`] implementation for [`UnpackedResponse`] requires every
+/// part of the response to match exactly, which is not always practical. For
+/// instance, headers such as `date`, `content-length`, or request IDs will
+/// often vary between responses, as will fields such as timestamps and IDs in
+/// JSON bodies.
+/// 
+/// This matcher is created from an expected response, and can then be
+/// configured to relax the comparison in various ways. Header names are
+/// compared case-insensitively for the purposes of this configuration. Once
+/// configured, it can be used to check any number of actual responses, and
+/// will return a [`MatchReport`] detailing any failures.
+/// 
+/// # See also
+/// 
+/// * [`MatchReport`]
+/// * [`UnpackedResponse`]
+/// * [`UnpackedResponse::diff()`]
+/// 
+#[derive(Debug)]
+pub struct ResponseMatcher {
+	//		Private properties													
+	/// The expected response.
+	expected:            UnpackedResponse,
+	
+	/// Whether to only require the expected headers to be present, allowing
+	/// additional headers in the actual response.
+	headers_subset:      bool,
+	
+	/// The names of headers to ignore.
+	ignored_headers:     Vec<String>,
+	
+	/// The requirements for specific header values, keyed by header name.
+	header_matchers:     Vec<(String, HeaderMatcher)>,
+	
+	/// The paths of JSON fields to ignore in the body.
+	ignored_json_fields: Vec<String>,
 }
 
-//󰭅		From char																
-impl From<char> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`char`] to an [`UnpackedResponseBody`].
+//󰭅		ResponseMatcher															
+impl ResponseMatcher {
+	//		new																	
+	/// Creates a new response matcher.
 	/// 
-	/// Note that it does this in the way that is most compatible with
-	/// [`String`] conversion. The [`char`] type in Rust represents a Unicode
-	/// scalar value. That means a single [`char`] value corresponds to one
-	/// Unicode character. But Unicode characters can have a wide range of
-	/// values, from `0` to `0x10FFFF` (this range excludes the surrogate
-	/// pairs), and this value range doesn't fit into a single byte. That's why
-	/// [`char`] in Rust is 4 bytes, because it has to accommodate any possible
-	/// Unicode scalar value. The UTF-8 encoded representation of the `ñ`
-	/// character is `[195, 177]`, but in memory, a [`char`] containing `'ñ'`
-	/// does not hold the bytes `[195, 177]`. Instead, it holds the Unicode
-	/// scalar value for `ñ`, which is `U+00F1`, or in integer terms, `241`.
-	/// When we convert a [`char`] to a [`u32`] directly, we're taking this
-	/// scalar value (like `241` for `ñ`) and representing it in memory as a
-	/// 4-byte integer. So using code such as
-	/// `(c as u32).to_le_bytes().to_vec()` would result in [241, 0, 0, 0], and
-	/// not [195, 177]. This behaviour would not match expectation and would not
-	/// match the behaviour of [`String`] conversion. To get the UTF-8 encoded
-	/// bytes of a [`char`], we need to use encoding methods because we're
-	/// effectively translating from the Unicode scalar value to its UTF-8 byte
-	/// sequence. This is what the [`encode_utf8()`](char::encode_utf8()) method
-	/// provides. To put it another way: [`char`] isn't storing bytes, it's
-	/// storing a Unicode scalar value. UTF-8 is one of the ways to represent
-	/// that value (and the most common one in Rust).
+	/// By default, the matcher behaves in the same way as a straightforward
+	/// comparison, requiring the status, all headers, and the body to match.
 	/// 
-	fn from(c: char) -> Self {
-		let mut bytes = [0; 4];
-		let used      = c.encode_utf8(&mut bytes).len();
-		#[expect(clippy::indexing_slicing, reason = "Infallible")]
-		Self { body: bytes[..used].to_vec(), ..Default::default() }
+	/// # Parameters
+	/// 
+	/// * `expected` - The expected response.
+	/// 
+	#[must_use]
+	pub const fn new(expected: UnpackedResponse) -> Self {
+		Self {
+			expected,
+			headers_subset:      false,
+			ignored_headers:     Vec::new(),
+			header_matchers:     Vec::new(),
+			ignored_json_fields: Vec::new(),
+		}
+	}
+	
+	//		ignore_header														
+	/// Ignores the named header.
+	/// 
+	/// Any headers with this name will be ignored in both the expected and
+	/// actual responses.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The name of the header to ignore.
+	/// 
+	#[must_use]
+	pub fn ignore_header(mut self, name: &str) -> Self {
+		self.ignored_headers.push(name.to_owned());
+		self
+	}
+	
+	//		headers_subset														
+	/// Only requires the expected headers to be present.
+	/// 
+	/// Headers that are present in the actual response but not in the expected
+	/// response will be allowed. Headers that are present in the expected
+	/// response must still be present and match.
+	/// 
+	#[must_use]
+	pub const fn headers_subset(mut self) -> Self {
+		self.headers_subset = true;
+		self
+	}
+	
+	//		header_matches														
+	/// Requires the named header to match a regular expression.
+	/// 
+	/// The header must be present in the actual response, and every value for
+	/// it must match the pattern. The header is then excluded from the main
+	/// header comparison, and so any value for it in the expected response is
+	/// ignored.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name`    - The name of the header.
+	/// * `pattern` - The regular expression that the value must match.
+	/// 
+	#[must_use]
+	pub fn header_matches(mut self, name: &str, pattern: Regex) -> Self {
+		self.header_matchers.push((name.to_owned(), HeaderMatcher::Pattern(pattern)));
+		self
+	}
+	
+	//		header_with															
+	/// Requires the named header to satisfy a predicate.
+	/// 
+	/// The header must be present in the actual response, and every value for
+	/// it must satisfy the predicate. The header is then excluded from the main
+	/// header comparison, and so any value for it in the expected response is
+	/// ignored.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name`      - The name of the header.
+	/// * `predicate` - The function that the value must satisfy.
+	/// 
+	#[must_use]
+	pub fn header_with<F>(mut self, name: &str, predicate: F) -> Self
+	where
+		F: Fn(&str) -> bool + Send + Sync + 'static,
+	{
+		self.header_matchers.push((name.to_owned(), HeaderMatcher::Predicate(Box::new(predicate))));
+		self
+	}
+	
+	//		ignore_json_field													
+	/// Ignores a field in a JSON body.
+	/// 
+	/// Paths are expressed in the same form as for [`JsonPathDiff`], i.e.
+	/// `$.foo.bar[2]`, where `$` represents the root value. A wildcard can be
+	/// used in place of an object key (`$.foo.*`) or an array index
+	/// (`$.foo[*].id`). Any differences at or beneath the path will be ignored.
+	/// 
+	/// This only has an effect if both the expected and actual bodies can be
+	/// parsed as JSON.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `path` - The path of the field to ignore.
+	/// 
+	#[must_use]
+	pub fn ignore_json_field(mut self, path: &str) -> Self {
+		self.ignored_json_fields.push(path.to_owned());
+		self
+	}
+	
+	//		check																
+	/// Checks a response against the matcher.
+	/// 
+	/// This compares the actual response against the expected response, in
+	/// accordance with the matcher's configuration, and returns a report of
+	/// any failures.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `actual` - The actual response to check.
+	/// 
+	#[must_use]
+	pub fn check(&self, actual: &UnpackedResponse) -> MatchReport {
+		let mut failures = vec![];
+		if self.expected.status != actual.status {
+			failures.push(MatchFailure::Status { expected: self.expected.status, actual: actual.status });
+		}
+		let expected_headers = self.compared_headers(&self.expected.headers);
+		let actual_headers   = self.compared_headers(&actual.headers);
+		failures.extend(diff_headers(&expected_headers, &actual_headers)
+			.into_iter()
+			.filter(|diff| !self.headers_subset || !matches!(*diff, HeaderDiff::Added(_)))
+			.map(MatchFailure::Header)
+		);
+		for entry in &self.header_matchers {
+			let (name, matcher) = (&entry.0, &entry.1);
+			let values = actual.headers.iter()
+				.filter(|header| header.name.eq_ignore_ascii_case(name))
+				.map(|header| &header.value)
+				.collect::<Vec<_>>()
+			;
+			if values.is_empty() {
+				failures.push(MatchFailure::HeaderValue { name: name.clone(), actual: None, requirement: matcher.to_string() });
+			}
+			for value in values.into_iter().filter(|value| !matcher.matches(value)) {
+				failures.push(MatchFailure::HeaderValue { name: name.clone(), actual: Some(value.clone()), requirement: matcher.to_string() });
+			}
+		}
+		if let Some(diff) = self.diff_body(&actual.body) {
+			failures.push(MatchFailure::Body(diff));
+		}
+		MatchReport { failures }
+	}
+	
+	//		matches																
+	/// Returns whether a response matches.
+	/// 
+	/// This is a convenience method that checks the response and returns
+	/// whether there were no failures, without providing the details.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `actual` - The actual response to check.
+	/// 
+	#[must_use]
+	pub fn matches(&self, actual: &UnpackedResponse) -> bool {
+		self.check(actual).is_match()
+	}
+	
+	//		compared_headers													
+	/// Returns the headers that are subject to direct comparison.
+	/// 
+	/// This excludes any headers that are ignored, or that have a specific
+	/// requirement set for their values.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `headers` - The headers to filter.
+	/// 
+	fn compared_headers(&self, headers: &[UnpackedResponseHeader]) -> Vec<UnpackedResponseHeader> {
+		headers.iter()
+			.filter(|header| !self.ignored_headers.iter()
+				.chain(self.header_matchers.iter().map(|entry| &entry.0))
+				.any(|name| name.eq_ignore_ascii_case(&header.name))
+			)
+			.cloned()
+			.collect()
+	}
+	
+	//		diff_body															
+	/// Returns the difference between the expected and actual bodies, if any.
+	/// 
+	/// If there are JSON fields to ignore and both bodies can be parsed as
+	/// JSON, then differences at or beneath the ignored paths are discarded.
+	/// Otherwise, this is the same as [`UnpackedResponseBody::diff()`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `actual` - The actual response body.
+	/// 
+	fn diff_body(&self, actual: &UnpackedResponseBody) -> Option<BodyDiff> {
+		if !self.ignored_json_fields.is_empty() && !self.expected.body.is_binary() && !actual.is_binary() {
+			if let (Ok(left), Ok(right)) = (serde_json::from_slice::<Json>(&self.expected.body.body), serde_json::from_slice::<Json>(&actual.body)) {
+				let mut diffs = vec![];
+				diff_json("$", &left, &right, &mut diffs);
+				diffs.retain(|diff| !self.ignored_json_fields.iter().any(|path| json_path_matches(path, diff.path())));
+				return (!diffs.is_empty()).then_some(BodyDiff::Json(diffs));
+			}
+		}
+		self.expected.body.diff(actual)
+	}
+}
+
+//		ServerSentEvent															
+/// A Server-Sent Event.
+/// 
+/// This is a single event from a `text/event-stream` response body, as parsed
+/// by [`UnpackedResponseBody::as_sse_events()`].
+/// 
+/// # See also
+/// 
+/// * [`UnpackedResponseBody::as_sse_events()`]
+/// 
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct ServerSentEvent {
+	//		Public properties													
+	/// The event type, from the `event` field, if specified.
+	pub event: Option<String>,
+	
+	/// The event data, from the `data` fields. Multiple `data` fields are
+	/// joined with newlines.
+	pub data:  String,
+	
+	/// The event ID, from the `id` field, if specified for this event.
+	pub id:    Option<String>,
+	
+	/// The reconnection time in milliseconds, from the `retry` field, if
+	/// specified for this event.
+	pub retry: Option<u64>,
+}
+
+//󰭅		ServerSentEvent															
+impl ServerSentEvent {
+	//		new																	
+	/// Creates a new event with the specified data.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `data` - The event data.
+	/// 
+	#[must_use]
+	pub fn new(data: &str) -> Self {
+		Self {
+			data: data.to_owned(),
+			..Default::default()
+		}
+	}
+	
+	//		with_event															
+	/// Sets the event type.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `event` - The event type.
+	/// 
+	#[must_use]
+	pub fn with_event(mut self, event: &str) -> Self {
+		self.event = Some(event.to_owned());
+		self
+	}
+	
+	//		with_id																
+	/// Sets the event ID.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `id` - The event ID.
+	/// 
+	#[must_use]
+	pub fn with_id(mut self, id: &str) -> Self {
+		self.id = Some(id.to_owned());
+		self
+	}
+	
+	//		with_retry															
+	/// Sets the reconnection time.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `retry` - The reconnection time in milliseconds.
+	/// 
+	#[must_use]
+	pub const fn with_retry(mut self, retry: u64) -> Self {
+		self.retry = Some(retry);
+		self
+	}
+}
+
+//		SnapshotOptions															
+/// Options that control how a response snapshot is stored and compared.
+/// 
+/// These are used by the [`assert_response_snapshot!`](crate::assert_response_snapshot)
+/// macro, and by [`SnapshotOptions::check()`].
+/// 
+/// Snapshots are stored as pretty-printed JSON, in the serialised form of
+/// [`UnpackedResponse`], with one file per snapshot. Headers are sorted, so the
+/// files are stable between runs as long as the response is.
+/// 
+/// # Redaction
+/// 
+/// Headers and JSON body fields that vary between runs, such as `date` or
+/// generated IDs, can be redacted, in which case their values are replaced
+/// with `[redacted]` before the response is stored or compared. Header names
+/// are matched case-insensitively. JSON field paths are expressed in the same
+/// form as for [`ResponseMatcher::ignore_json_field()`], including wildcards.
+/// 
+/// # Updating
+/// 
+/// By default, a snapshot that does not match is reported as an error. If the
+/// `RUBEDO_UPDATE_SNAPSHOTS` environment variable is set to anything other
+/// than `0`, `false`, or an empty string, then mismatching snapshots are
+/// overwritten instead. This can also be controlled explicitly using
+/// [`with_update()`](SnapshotOptions::with_update()).
+/// 
+/// # See also
+/// 
+/// * [`assert_response_snapshot!`](crate::assert_response_snapshot)
+/// * [`SnapshotError`]
+/// * [`SnapshotStatus`]
+/// 
+#[derive(Clone, Debug)]
+pub struct SnapshotOptions {
+	//		Private properties													
+	/// The directory in which snapshots are stored. If relative, this is
+	/// resolved against the base directory given to
+	/// [`path_for()`](SnapshotOptions::path_for()).
+	directory: PathBuf,
+	
+	/// The redactions to apply to the response before it is stored.
+	redaction: RedactionPolicy,
+	
+	/// Whether to overwrite snapshots that do not match.
+	update:    bool,
+}
+
+//󰭅		SnapshotOptions															
+impl SnapshotOptions {
+	//		new																	
+	/// Creates a new set of snapshot options, with default settings.
+	/// 
+	/// This is the same as [`SnapshotOptions::default()`].
+	/// 
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+	
+	//		with_directory														
+	/// Sets the directory in which snapshots are stored.
+	/// 
+	/// A relative path is resolved against the base directory, which for the
+	/// [`assert_response_snapshot!`](crate::assert_response_snapshot) macro is
+	/// the root of the crate being tested. The default is `tests/snapshots`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `directory` - The directory in which to store snapshots.
+	/// 
+	#[must_use]
+	pub fn with_directory<P: Into<PathBuf>>(mut self, directory: P) -> Self {
+		self.directory = directory.into();
+		self
+	}
+	
+	//		with_redacted_header												
+	/// Redacts the named header.
+	/// 
+	/// The values of any headers with this name will be replaced with
+	/// `[redacted]`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The name of the header to redact.
+	/// 
+	#[must_use]
+	pub fn with_redacted_header(mut self, name: &str) -> Self {
+		self.redaction = self.redaction.with_header(name);
+		self
+	}
+	
+	//		with_redacted_json_field											
+	/// Redacts a field in a JSON body.
+	/// 
+	/// Paths are expressed in the same form as for [`JsonPathDiff`], i.e.
+	/// `$.foo.bar[2]`, where `$` represents the root value. A wildcard can be
+	/// used in place of an object key (`$.foo.*`) or an array index
+	/// (`$.foo[*].id`). The value at the path will be replaced with
+	/// `[redacted]`.
+	/// 
+	/// This only has an effect if the body can be parsed as JSON.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `path` - The path of the field to redact.
+	/// 
+	#[must_use]
+	pub fn with_redacted_json_field(mut self, path: &str) -> Self {
+		self.redaction = self.redaction.with_json_field(path);
+		self
+	}
+	
+	//		with_redaction_policy												
+	/// Sets the redaction policy to apply.
+	/// 
+	/// This replaces any redactions previously added using
+	/// [`with_redacted_header()`](SnapshotOptions::with_redacted_header()) or
+	/// [`with_redacted_json_field()`](SnapshotOptions::with_redacted_json_field()),
+	/// and allows the full set of redactions supported by [`RedactionPolicy`]
+	/// to be used, such as text patterns.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `policy` - The redaction policy to apply.
+	/// 
+	#[must_use]
+	pub fn with_redaction_policy(mut self, policy: RedactionPolicy) -> Self {
+		self.redaction = policy;
+		self
+	}
+	
+	//		with_update															
+	/// Sets whether to overwrite snapshots that do not match.
+	/// 
+	/// The default is determined by the `RUBEDO_UPDATE_SNAPSHOTS` environment
+	/// variable.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether to overwrite snapshots that do not match.
+	/// 
+	#[must_use]
+	pub const fn with_update(mut self, enabled: bool) -> Self {
+		self.update = enabled;
+		self
+	}
+	
+	//		path_for															
+	/// Returns the path of the snapshot file with the given name.
+	/// 
+	/// The name is used as the file name, so any path separators, and other
+	/// characters that are not safe to use in file names, are replaced with
+	/// underscores. This ensures that the snapshot file is always inside the
+	/// snapshot directory, and that a name such as `../../src/lib` cannot be
+	/// used to write to files elsewhere.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `base` - The base directory, against which a relative snapshot
+	///            directory is resolved.
+	/// * `name` - The name of the snapshot.
+	/// 
+	#[must_use]
+	pub fn path_for<P: AsRef<Path>>(&self, base: P, name: &str) -> PathBuf {
+		let file_name = name
+			.chars()
+			.map(|character| if matches!(character, '/' | '\\' | ':') || character.is_control() { '_' } else { character })
+			.collect::<String>()
+		;
+		base.as_ref().join(&self.directory).join(format!("{file_name}.json"))
+	}
+	
+	//		redact																
+	/// Returns a copy of a response with redactions applied.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `response` - The response to redact.
+	/// 
+	/// # See also
+	/// 
+	/// * [`RedactionPolicy::redact()`]
+	/// 
+	#[must_use]
+	pub fn redact(&self, response: &UnpackedResponse) -> UnpackedResponse {
+		self.redaction.redact(response)
+	}
+	
+	//		check																
+	/// Checks a response against the snapshot stored at the given path.
+	/// 
+	/// Redactions are applied to the response, which is then compared against
+	/// the snapshot. If the snapshot does not exist then it is created. If it
+	/// does not match then it is overwritten if updating is enabled, otherwise
+	/// an error containing the differences is returned.
+	/// 
+	/// Comparison is carried out on the parsed form of the snapshot, and so
+	/// differences in whitespace in the file are not significant.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `response` - The response to check.
+	/// * `path`     - The path of the snapshot file.
+	/// 
+	/// # Errors
+	/// 
+	/// * [`SnapshotError::Io`] if the snapshot file cannot be read or written.
+	/// * [`SnapshotError::Json`] if the snapshot cannot be parsed or the
+	///   response cannot be serialised.
+	/// * [`SnapshotError::Mismatch`] if the response does not match the
+	///   snapshot, and updating is not enabled.
+	/// 
+	pub fn check(&self, response: &UnpackedResponse, path: &Path) -> Result<SnapshotStatus, SnapshotError> {
+		let snapshot = serde_json::to_string_pretty(&self.redact(response)).map_err(SnapshotError::Json)? + "\n";
+		let existing = match fs::read_to_string(path) {
+			Ok(contents)                                    => contents,
+			Err(err) if err.kind() == IoErrorKind::NotFound => {
+				write_snapshot(path, &snapshot)?;
+				return Ok(SnapshotStatus::Created);
+			},
+			Err(err)                                        => return Err(SnapshotError::Io(err)),
+		};
+		//	The actual response is round-tripped through serialisation so that it
+		//	is in exactly the same form as the stored snapshot
+		let expected: UnpackedResponse = serde_json::from_str(&existing).map_err(SnapshotError::Json)?;
+		let actual:   UnpackedResponse = serde_json::from_str(&snapshot).map_err(SnapshotError::Json)?;
+		let diff = expected.diff(&actual);
+		if diff.is_empty() {
+			Ok(SnapshotStatus::Matched)
+		} else if self.update {
+			write_snapshot(path, &snapshot)?;
+			Ok(SnapshotStatus::Updated)
+		} else {
+			Err(SnapshotError::Mismatch { path: path.to_owned(), diff })
+		}
+	}
+}
+
+//󰭅		Default																	
+impl Default for SnapshotOptions {
+	//		default																
+	fn default() -> Self {
+		Self {
+			directory: PathBuf::from("tests/snapshots"),
+			redaction: RedactionPolicy::default(),
+			update:    env::var("RUBEDO_UPDATE_SNAPSHOTS").is_ok_and(|value| !matches!(value.as_str(), "" | "0" | "false")),
+		}
+	}
+}
+
+//		TaggedBody																
+/// The tagged serialised form of an [`UnpackedResponseBody`].
+/// 
+/// # See also
+/// 
+/// * [`deserialize_tagged_body()`]
+/// * [`serialize_tagged_body()`]
+/// 
+#[derive(Deserialize, Serialize)]
+struct TaggedBody {
+	//		Private properties													
+	/// The content type of the body.
+	content_type: ContentType,
+	
+	/// The body data, which is base64-encoded if the content type is
+	/// [`Binary`](ContentType::Binary), or if the encoding says so.
+	data:         String,
+	
+	/// The encoding of the data, if it is base64 despite the content type not
+	/// being binary, due to the body not being valid UTF8.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	encoding:     Option<String>,
+}
+
+//		TaggedRequest															
+/// The tagged serialised form of an [`UnpackedRequest`], as stored in a
+/// [`Cassette`].
+/// 
+/// This is the same as the default serialised form, except that the body is in
+/// tagged form.
+/// 
+/// # See also
+/// 
+/// * [`deserialize_tagged_request()`]
+/// * [`serialize_tagged_request()`]
+/// 
+#[cfg(feature = "cassette")]
+#[derive(Deserialize, Serialize)]
+struct TaggedRequest {
+	//		Private properties													
+	/// The request method.
+	#[serde(serialize_with = "serialize_method", deserialize_with = "deserialize_method")]
+	method:  Method,
+	
+	/// The request URI.
+	#[serde(serialize_with = "serialize_uri", deserialize_with = "deserialize_uri")]
+	uri:     Uri,
+	
+	/// The HTTP version of the request.
+	#[serde(serialize_with = "serialize_version", deserialize_with = "deserialize_version")]
+	version: Version,
+	
+	/// The request headers.
+	headers: Vec<UnpackedResponseHeader>,
+	
+	/// The request body, in tagged form.
+	#[serde(serialize_with = "serialize_tagged_body", deserialize_with = "deserialize_tagged_body")]
+	body:    UnpackedResponseBody,
+}
+
+//󰭅		From<UnpackedRequest>													
+#[cfg(feature = "cassette")]
+impl From<UnpackedRequest> for TaggedRequest {
+	//		from																
+	fn from(request: UnpackedRequest) -> Self {
+		Self {
+			method:  request.method,
+			uri:     request.uri,
+			version: request.version,
+			headers: request.headers,
+			body:    request.body,
+		}
+	}
+}
+
+//󰭅		From<TaggedRequest>														
+#[cfg(feature = "cassette")]
+impl From<TaggedRequest> for UnpackedRequest {
+	//		from																
+	fn from(tagged: TaggedRequest) -> Self {
+		Self {
+			method:  tagged.method,
+			uri:     tagged.uri,
+			version: tagged.version,
+			headers: tagged.headers,
+			body:    tagged.body,
+		}
+	}
+}
+
+//		TaggedResponse															
+/// The tagged serialised form of an [`UnpackedResponse`].
+/// 
+/// This is the same as the default serialised form, except that the body, and
+/// the data of any frames, are in tagged form.
+/// 
+/// # See also
+/// 
+/// * [`deserialize_tagged_response()`]
+/// * [`serialize_tagged_response()`]
+/// 
+#[derive(Deserialize, Serialize)]
+struct TaggedResponse {
+	//		Private properties													
+	/// The response status code.
+	#[serde(serialize_with = "serialize_status_code", deserialize_with = "deserialize_status_code")]
+	status:      StatusCode,
+	
+	/// The response headers.
+	headers:     Vec<UnpackedResponseHeader>,
+	
+	/// The response body, in tagged form.
+	#[serde(serialize_with = "serialize_tagged_body", deserialize_with = "deserialize_tagged_body")]
+	body:        UnpackedResponseBody,
+	
+	/// The frames of the response body, with any data in tagged form.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	frames:      Vec<TaggedFrame>,
+	
+	/// The response headers with their raw values.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	raw_headers: Vec<UnpackedRawHeader>,
+}
+
+//󰭅		From<UnpackedResponse>													
+impl From<UnpackedResponse> for TaggedResponse {
+	//		from																
+	fn from(response: UnpackedResponse) -> Self {
+		Self {
+			status:      response.status,
+			headers:     response.headers,
+			body:        response.body,
+			frames:      response.frames.into_iter().map(TaggedFrame::from).collect(),
+			raw_headers: response.raw_headers,
+		}
+	}
+}
+
+//󰭅		From<TaggedResponse>													
+impl From<TaggedResponse> for UnpackedResponse {
+	//		from																
+	fn from(tagged: TaggedResponse) -> Self {
+		Self {
+			status:      tagged.status,
+			headers:     tagged.headers,
+			body:        tagged.body,
+			frames:      tagged.frames.into_iter().map(UnpackedFrame::from).collect(),
+			raw_headers: tagged.raw_headers,
+		}
+	}
+}
+
+//		TestClient																
+/// An in-process client for testing an Axum [`Router`].
+/// 
+/// This sends requests directly to the router, without binding to a socket or
+/// making any network connections, and unpacks each response into an
+/// [`UnpackedResponse`] so that it can be checked and compared. Requests are
+/// built using [`TestRequest`], which is obtained from
+/// [`request()`](TestClient::request()) or one of the method-specific
+/// functions such as [`get()`](TestClient::get()).
+/// 
+/// The client can be cloned cheaply, as the router is reference-counted.
+/// 
+/// # See also
+/// 
+/// * [`TestClientError`]
+/// * [`TestRequest`]
+/// * [`UnpackOptions`]
+/// 
+#[cfg(feature = "axum")]
+#[derive(Clone, Debug)]
+pub struct TestClient {
+	//		Private properties													
+	/// The options used when unpacking responses.
+	options: UnpackOptions,
+	
+	/// The router that requests are sent to.
+	router:  Router,
+}
+
+//󰭅		TestClient																
+#[cfg(feature = "axum")]
+impl TestClient {
+	//		new																	
+	/// Creates a new test client for the specified router.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `router` - The router to send requests to.
+	/// 
+	#[must_use]
+	pub fn new(router: Router) -> Self {
+		Self {
+			options: UnpackOptions::default(),
+			router,
+		}
+	}
+	
+	//		with_options														
+	/// Sets the options used when unpacking responses.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `options` - The options to use when unpacking responses.
+	/// 
+	#[must_use]
+	pub fn with_options(mut self, options: UnpackOptions) -> Self {
+		self.options = options;
+		self
+	}
+	
+	//		request																
+	/// Starts building a request with the specified method and path.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `method` - The HTTP method of the request.
+	/// * `path`   - The path of the request, including any query string.
+	/// 
+	/// # See also
+	/// 
+	/// * [`TestRequest::send()`]
+	/// 
+	#[must_use]
+	pub fn request(&self, method: Method, path: &str) -> TestRequest {
+		TestRequest {
+			body:         Vec::new(),
+			builder:      Request::builder().method(method).uri(path),
+			client:       self.clone(),
+			content_type: None,
+			error:        None,
+		}
+	}
+	
+	//		get																	
+	/// Starts building a `GET` request for the specified path.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `path` - The path of the request, including any query string.
+	/// 
+	#[must_use]
+	pub fn get(&self, path: &str) -> TestRequest {
+		self.request(Method::GET, path)
+	}
+	
+	//		post																
+	/// Starts building a `POST` request for the specified path.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `path` - The path of the request, including any query string.
+	/// 
+	#[must_use]
+	pub fn post(&self, path: &str) -> TestRequest {
+		self.request(Method::POST, path)
+	}
+	
+	//		put																	
+	/// Starts building a `PUT` request for the specified path.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `path` - The path of the request, including any query string.
+	/// 
+	#[must_use]
+	pub fn put(&self, path: &str) -> TestRequest {
+		self.request(Method::PUT, path)
+	}
+	
+	//		patch																
+	/// Starts building a `PATCH` request for the specified path.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `path` - The path of the request, including any query string.
+	/// 
+	#[must_use]
+	pub fn patch(&self, path: &str) -> TestRequest {
+		self.request(Method::PATCH, path)
+	}
+	
+	//		delete																
+	/// Starts building a `DELETE` request for the specified path.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `path` - The path of the request, including any query string.
+	/// 
+	#[must_use]
+	pub fn delete(&self, path: &str) -> TestRequest {
+		self.request(Method::DELETE, path)
+	}
+	
+	//		send																
+	/// Sends a request to the router, and unpacks the response.
+	/// 
+	/// This can be used to send a request that has been constructed manually,
+	/// rather than by using [`TestRequest`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `request` - The request to send.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will return an error if the response cannot be unpacked.
+	/// 
+	pub async fn send(&self, request: Request<AxumBody>) -> Result<UnpackedResponse, TestClientError> {
+		let mut response = self.router.clone().oneshot(request).await
+			.unwrap_or_else(|never| match never {})
+		;
+		response.unpack_async_with(self.options.clone()).await.map_err(TestClientError::Response)
+	}
+}
+
+//		TestRequest																
+/// A builder for a request sent by a [`TestClient`].
+/// 
+/// Any errors encountered while building the request, such as an invalid
+/// header or a body that cannot be serialised, are deferred until the request
+/// is sent, so that the builder functions can be chained.
+/// 
+/// # See also
+/// 
+/// * [`TestClient`]
+/// * [`TestClientError`]
+/// 
+#[cfg(feature = "axum")]
+#[derive(Debug)]
+pub struct TestRequest {
+	//		Private properties													
+	/// The request body.
+	body:         Vec<u8>,
+	
+	/// The underlying request builder.
+	builder:      RequestBuilder,
+	
+	/// The client that will send the request.
+	client:       TestClient,
+	
+	/// The content type implied by the body, which is used if no
+	/// `content-type` header has been set explicitly.
+	content_type: Option<&'static str>,
+	
+	/// The first error encountered while building the request, if any.
+	error:        Option<TestClientError>,
+}
+
+//󰭅		TestRequest																
+#[cfg(feature = "axum")]
+impl TestRequest {
+	//		with_header															
+	/// Adds a header to the request.
+	/// 
+	/// If the header has already been added, then another value is added,
+	/// rather than replacing the existing one.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name`  - The name of the header.
+	/// * `value` - The value of the header.
+	/// 
+	#[must_use]
+	pub fn with_header(mut self, name: &str, value: &str) -> Self {
+		self.builder = self.builder.header(name, value);
+		self
+	}
+	
+	//		with_body															
+	/// Sets the request body.
+	/// 
+	/// No `content-type` header is added, so one should be specified using
+	/// [`with_header()`](TestRequest::with_header()) if required.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `body` - The request body.
+	/// 
+	#[must_use]
+	pub fn with_body<T: Into<UnpackedResponseBody>>(mut self, body: T) -> Self {
+		self.body         = body.into().into_bytes();
+		self.content_type = None;
+		self
+	}
+	
+	//		with_json															
+	/// Sets the request body to the specified value, serialised as JSON.
+	/// 
+	/// A `content-type` header of `application/json` is added, unless one is
+	/// specified using [`with_header()`](TestRequest::with_header()).
+	/// 
+	/// # Parameters
+	/// 
+	/// * `value` - The value to serialise.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::from_json()`]
+	/// 
+	#[must_use]
+	pub fn with_json<T: Serialize + ?Sized>(mut self, value: &T) -> Self {
+		match UnpackedResponseBody::from_json(value) {
+			Ok(body) => {
+				self.body         = body.into_bytes();
+				self.content_type = Some("application/json");
+			},
+			Err(err) => _ = self.error.get_or_insert(TestClientError::Json(err)),
+		}
+		self
+	}
+	
+	//		with_form															
+	/// Sets the request body to the specified value, serialised as
+	/// form-urlencoded data.
+	/// 
+	/// A `content-type` header of `application/x-www-form-urlencoded` is added,
+	/// unless one is specified using [`with_header()`](TestRequest::with_header()).
+	/// 
+	/// # Parameters
+	/// 
+	/// * `value` - The value to serialise.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::from_form()`]
+	/// 
+	#[must_use]
+	pub fn with_form<T: Serialize + ?Sized>(mut self, value: &T) -> Self {
+		match UnpackedResponseBody::from_form(value) {
+			Ok(body) => {
+				self.body         = body.into_bytes();
+				self.content_type = Some("application/x-www-form-urlencoded");
+			},
+			Err(err) => _ = self.error.get_or_insert(TestClientError::Form(err)),
+		}
+		self
+	}
+	
+	//		build																
+	/// Builds the request, without sending it.
+	/// 
+	/// This is mainly useful for checking the request that would be sent.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will return an error if the request could not be built,
+	/// such as if the path or a header is invalid, or if the body could not be
+	/// serialised.
+	/// 
+	pub fn build(self) -> Result<Request<AxumBody>, TestClientError> {
+		if let Some(err) = self.error {
+			return Err(err);
+		}
+		let mut builder = self.builder;
+		if let Some(content_type) = self.content_type {
+			if !builder.headers_ref().is_some_and(|headers| headers.contains_key(CONTENT_TYPE)) {
+				builder = builder.header(CONTENT_TYPE, content_type);
+			}
+		}
+		builder.body(AxumBody::from(self.body)).map_err(TestClientError::Request)
+	}
+	
+	//		send																
+	/// Sends the request, and unpacks the response.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will return an error if the request could not be built,
+	/// or if the response cannot be unpacked.
+	/// 
+	/// # See also
+	/// 
+	/// * [`TestClient::send()`]
+	/// * [`TestRequest::build()`]
+	/// 
+	pub async fn send(self) -> Result<UnpackedResponse, TestClientError> {
+		let client = self.client.clone();
+		client.send(self.build()?).await
+	}
+}
+
+//		UnpackOptions															
+/// Options that control how a response is unpacked.
+/// 
+/// These are used by [`ResponseExt::unpack_with()`] and
+/// [`AsyncResponseExt::unpack_async_with()`]. The default options are used by
+/// [`ResponseExt::unpack()`] and [`AsyncResponseExt::unpack_async()`].
+/// 
+/// # Content type detection
+/// 
+/// By default, the [`ContentType`] of the unpacked body is determined from
+/// the `content-type` header of the response. JSON types, such as
+/// `application/json` and `application/problem+json`, are mapped to
+/// [`Json`](ContentType::Json). Textual types, such as `text/*`,
+/// `application/xml`, and `application/javascript`, are mapped to
+/// [`Text`](ContentType::Text). Media and other known binary types, such as
+/// `image/*`, `application/octet-stream`, and `application/pdf`, are mapped
+/// to [`Binary`](ContentType::Binary).
+/// 
+/// If the header is missing, or the type is not recognised, the body is
+/// sniffed: if it is valid UTF8 then it is treated as text, otherwise as
+/// binary. Sniffing also applies to bodies declared as text or JSON, so that
+/// invalid UTF8 is never mangled when displayed or serialised.
+/// 
+/// The default mapping can be overridden for specific types using
+/// [`with_content_type()`](UnpackOptions::with_content_type()).
+/// 
+/// # Decompression
+/// 
+/// When the `compression` feature is enabled, the body can be decompressed
+/// according to the `content-encoding` header, using
+/// [`with_decompression()`](UnpackOptions::with_decompression()). In this case
+/// the `content-encoding` header is removed from the unpacked headers, and any
+/// `content-length` header is updated to match the decompressed body. The size
+/// limit applies to both the compressed and the decompressed body. This is not
+/// enabled by default.
+/// 
+/// # See also
+/// 
+/// * [`AsyncResponseExt::unpack_async_with()`]
+/// * [`ContentType`]
+/// * [`ResponseExt::unpack_with()`]
+/// 
+#[expect(clippy::struct_excessive_bools, reason = "Each option is an independent setting")]
+#[derive(Clone, Debug)]
+pub struct UnpackOptions {
+	//		Private properties													
+	/// Mappings from MIME types to content types, which take precedence over
+	/// the default mapping. The MIME types are stored in lowercase.
+	content_types:         Vec<(String, ContentType)>,
+	
+	/// Whether to detect the content type from the `content-type` header.
+	detect_content:        bool,
+	
+	/// Whether to decompress the body according to the `content-encoding`
+	/// header.
+	#[cfg(feature = "compression")]
+	decompress:            bool,
+	
+	/// The maximum size of the response body, in bytes.
+	limit:                 usize,
+	
+	/// Whether to record the individual frames of the response body.
+	record_frames:         bool,
+	
+	/// Whether to preserve the original order of the headers, rather than
+	/// sorting them.
+	preserve_header_order: bool,
+	
+	/// Whether to retain the raw bytes of the header values.
+	raw_header_values:     bool,
+	
+	/// Whether to check the body for invalid UTF8 when determining the content
+	/// type.
+	sniff_content:         bool,
+	
+	/// Whether to reject bodies declared as text or JSON that are not valid
+	/// UTF8.
+	strict_utf8:           bool,
+	
+	/// The maximum time to spend reading the response body.
+	timeout:               Option<Duration>,
+}
+
+//󰭅		UnpackOptions															
+impl UnpackOptions {
+	//		new																	
+	/// Creates a new set of unpacking options, with default settings.
+	/// 
+	/// This is the same as [`UnpackOptions::default()`].
+	/// 
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+	
+	//		with_content_type													
+	/// Maps a MIME type to a content type.
+	/// 
+	/// This overrides the default mapping for the specified MIME type. The
+	/// MIME type is matched case-insensitively against the `content-type`
+	/// header, ignoring any parameters such as `charset`. A wildcard subtype
+	/// can be used to match all types of a given kind, e.g. `image/*`. An
+	/// exact match takes precedence over a wildcard match.
+	/// 
+	/// Bodies with an explicitly-mapped type are not sniffed.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `mime_type`    - The MIME type to map, e.g. `application/x-custom`.
+	/// * `content_type` - The content type to use for the MIME type.
+	/// 
+	#[must_use]
+	pub fn with_content_type(mut self, mime_type: &str, content_type: ContentType) -> Self {
+		self.content_types.push((mime_type.to_ascii_lowercase(), content_type));
+		self
+	}
+	
+	//		with_content_detection												
+	/// Sets whether to detect the content type.
+	/// 
+	/// When disabled, the body will always have the default content type of
+	/// [`Text`](ContentType::Text), regardless of the headers or the body
+	/// content. The default is enabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether to detect the content type.
+	/// 
+	#[must_use]
+	pub const fn with_content_detection(mut self, enabled: bool) -> Self {
+		self.detect_content = enabled;
+		self
+	}
+	
+	//		with_content_sniffing												
+	/// Sets whether to sniff the body when determining the content type.
+	/// 
+	/// When enabled, bodies without a recognised `content-type` header are
+	/// treated as binary if they are not valid UTF8, as are bodies declared as
+	/// text or JSON that are not valid UTF8. When disabled, the `content-type`
+	/// header alone is used, and bodies without a recognised type are treated
+	/// as text. The default is enabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether to sniff the body.
+	/// 
+	#[must_use]
+	pub const fn with_content_sniffing(mut self, enabled: bool) -> Self {
+		self.sniff_content = enabled;
+		self
+	}
+	
+	//		with_decompression													
+	/// Sets whether to decompress the body.
+	/// 
+	/// When enabled, a body with a `content-encoding` header of `gzip`,
+	/// `deflate`, or `br` is decompressed, and multiple encodings are removed
+	/// in reverse order of application. The `content-encoding` header is then
+	/// removed, and any `content-length` header is updated. Unsupported
+	/// encodings result in an error. The default is disabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether to decompress the body.
+	/// 
+	/// # See also
+	/// 
+	/// * [`ContentEncoding`]
+	/// 
+	#[cfg(feature = "compression")]
+	#[must_use]
+	pub const fn with_decompression(mut self, enabled: bool) -> Self {
+		self.decompress = enabled;
+		self
+	}
+	
+	//		with_limit															
+	/// Sets the maximum size of the response body.
+	/// 
+	/// Reading of the response body will stop once the specified number of
+	/// bytes has been exceeded, in which case an error will be returned. The
+	/// default is no limit.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `limit` - The maximum size of the response body, in bytes.
+	/// 
+	#[must_use]
+	pub const fn with_limit(mut self, limit: usize) -> Self {
+		self.limit = limit;
+		self
+	}
+	
+	//		with_frame_recording												
+	/// Sets whether to record the individual frames of the response body.
+	/// 
+	/// When enabled, each frame of the body is recorded in
+	/// [`UnpackedResponse::frames`] as it is received, in addition to the data
+	/// being combined into the body as usual. This preserves the boundaries
+	/// between chunks, and any trailers, which are otherwise discarded. This
+	/// is useful for streamed responses such as Server-Sent Events. The
+	/// default is disabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether to record the frames.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedFrame`]
+	/// 
+	#[must_use]
+	pub const fn with_frame_recording(mut self, enabled: bool) -> Self {
+		self.record_frames = enabled;
+		self
+	}
+	
+	//		with_preserved_header_order											
+	/// Sets whether to preserve the original order of the headers.
+	/// 
+	/// By default, unpacked headers are sorted by name and then by value, which
+	/// allows for reliable comparison. When enabled, the headers are instead
+	/// kept in the order in which they are held in the [`HeaderMap`], which
+	/// for received responses is the order in which each header name first
+	/// appeared, with multiple values for the same name in the order received,
+	/// e.g. for a sequence of `set-cookie` headers. Note that this makes
+	/// comparison of unpacked responses sensitive to header order. The default
+	/// is disabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether to preserve the header order.
+	/// 
+	#[must_use]
+	pub const fn with_preserved_header_order(mut self, enabled: bool) -> Self {
+		self.preserve_header_order = enabled;
+		self
+	}
+	
+	//		with_raw_header_values												
+	/// Sets whether to retain the raw bytes of the header values.
+	/// 
+	/// Header values are not guaranteed to be valid UTF8, and are converted
+	/// lossily to strings. When enabled, the original bytes are also retained
+	/// in [`UnpackedResponse::raw_headers`]. The default is disabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether to retain the raw header values.
+	/// 
+	#[must_use]
+	pub const fn with_raw_header_values(mut self, enabled: bool) -> Self {
+		self.raw_header_values = enabled;
+		self
+	}
+	
+	//		with_strict_utf8													
+	/// Sets whether to reject bodies declared as text or JSON that are not
+	/// valid UTF8.
+	/// 
+	/// By default, a body that is declared as text or JSON by its
+	/// `content-type` header, but is not valid UTF8, is treated as binary when
+	/// sniffing is enabled. When this is enabled, such a body will instead
+	/// cause unpacking to fail with a [`ResponseError::InvalidUtf8`] error. The
+	/// default is disabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether to reject invalid UTF8.
+	/// 
+	#[must_use]
+	pub const fn with_strict_utf8(mut self, enabled: bool) -> Self {
+		self.strict_utf8 = enabled;
+		self
+	}
+	
+	//		with_timeout														
+	/// Sets the maximum time to spend reading the response body.
+	/// 
+	/// If the body has not been read in full once the specified time has
+	/// elapsed, reading will stop and a [`ResponseError::Timeout`] error will
+	/// be returned, containing whatever was received up to that point. This
+	/// is useful for preventing a stalled peer from blocking indefinitely. The
+	/// timer does not depend upon any particular async runtime, and so applies
+	/// to both blocking and asynchronous unpacking. The default is no timeout.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `timeout` - The maximum time to spend reading the response body.
+	/// 
+	#[must_use]
+	pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+	
+	//		content_type_for													
+	/// Returns the content type for a MIME type, if known.
+	/// 
+	/// Any mappings added with [`with_content_type()`](UnpackOptions::with_content_type())
+	/// are checked first, followed by the default mapping. Parameters such as
+	/// `charset` are ignored.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `mime_type` - The MIME type to look up, e.g. from a `content-type`
+	///                 header.
+	/// 
+	#[must_use]
+	pub fn content_type_for(&self, mime_type: &str) -> Option<ContentType> {
+		let essence = mime_essence(mime_type);
+		self.mapped_content_type(&essence).or_else(|| default_content_type(&essence))
+	}
+	
+	//		mapped_content_type													
+	/// Returns the content type explicitly mapped for a MIME type, if any.
+	/// 
+	/// An exact match takes precedence over a wildcard match, and later
+	/// mappings take precedence over earlier ones.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `essence` - The MIME type, in lowercase and without parameters.
+	/// 
+	fn mapped_content_type(&self, essence: &str) -> Option<ContentType> {
+		let wildcard = essence.split('/').next().map(|kind| format!("{kind}/*"));
+		let lookup   = |name: &str| self.content_types.iter()
+			.rev()
+			.find(|entry| entry.0 == name)
+			.map(|entry| entry.1)
+		;
+		lookup(essence).or_else(|| wildcard.as_deref().and_then(lookup))
+	}
+}
+
+//󰭅		Default																	
+impl Default for UnpackOptions {
+	//		default																
+	fn default() -> Self {
+		Self {
+			content_types:         Vec::new(),
+			detect_content:        true,
+			#[cfg(feature = "compression")]
+			decompress:            false,
+			limit:                 usize::MAX,
+			preserve_header_order: false,
+			record_frames:         false,
+			raw_header_values:     false,
+			sniff_content:         true,
+			strict_utf8:           false,
+			timeout:               None,
+		}
+	}
+}
+
+//		UnpackedCookie															
+/// A cookie set by an HTTP response.
+/// 
+/// This is a typed representation of a `set-cookie` header, which allows the
+/// cookies set by a response to be checked without having to parse the header
+/// values by hand. Cookies can be obtained from an [`UnpackedResponse`] using
+/// [`cookies()`](UnpackedResponse::cookies()) and
+/// [`cookie()`](UnpackedResponse::cookie()), or parsed directly using
+/// [`parse()`](UnpackedCookie::parse()).
+/// 
+/// The [`PartialEq`] implementation compares all properties. As expiry times
+/// will often vary between responses, [`eq_ignoring_expiry()`](UnpackedCookie::eq_ignoring_expiry())
+/// is also provided, which ignores the [`expires`](UnpackedCookie::expires)
+/// and [`max_age`](UnpackedCookie::max_age) properties, and is used by the
+/// [`assert_cookie_eq!`](crate::assert_cookie_eq) macro.
+/// 
+/// The [`Display`] implementation produces the cookie in `set-cookie` header
+/// format.
+/// 
+/// # See also
+/// 
+/// * [`SameSite`]
+/// * [`UnpackedResponse::cookie()`]
+/// * [`UnpackedResponse::cookies()`]
+/// 
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct UnpackedCookie {
+	//		Public properties													
+	/// The name of the cookie.
+	pub name:      String,
+	
+	/// The value of the cookie.
+	pub value:     String,
+	
+	/// The path to which the cookie applies, from the `Path` attribute.
+	pub path:      Option<String>,
+	
+	/// The domain to which the cookie applies, from the `Domain` attribute.
+	pub domain:    Option<String>,
+	
+	/// The expiry date of the cookie, from the `Expires` attribute. This is
+	/// kept in the form given, which should be an HTTP date.
+	pub expires:   Option<String>,
+	
+	/// The number of seconds until the cookie expires, from the `Max-Age`
+	/// attribute.
+	pub max_age:   Option<i64>,
+	
+	/// Whether the cookie is only to be sent over secure connections, from the
+	/// `Secure` attribute.
+	pub secure:    bool,
+	
+	/// Whether the cookie is inaccessible to scripts, from the `HttpOnly`
+	/// attribute.
+	pub http_only: bool,
+	
+	/// The cross-site behaviour of the cookie, from the `SameSite` attribute.
+	pub same_site: Option<SameSite>,
+}
+
+//󰭅		UnpackedCookie															
+impl UnpackedCookie {
+	//		new																	
+	/// Creates a new cookie instance, with no attributes.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name`  - The name of the cookie.
+	/// * `value` - The value of the cookie.
+	/// 
+	#[must_use]
+	pub fn new(name: &str, value: &str) -> Self {
+		Self {
+			name:      name.to_owned(),
+			value:     value.to_owned(),
+			path:      None,
+			domain:    None,
+			expires:   None,
+			max_age:   None,
+			secure:    false,
+			http_only: false,
+			same_site: None,
+		}
+	}
+	
+	//		parse																
+	/// Parses a cookie from a `set-cookie` header value.
+	/// 
+	/// Attribute names are matched case-insensitively. Unrecognised attributes,
+	/// and attributes with invalid values, are ignored.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `header` - The value of the `set-cookie` header.
+	/// 
+	/// # Returns
+	/// 
+	/// The cookie, or [`None`] if the header does not start with a valid
+	/// name-value pair.
+	/// 
+	#[must_use]
+	pub fn parse(header: &str) -> Option<Self> {
+		let mut parts     = header.split(';');
+		let (name, value) = parts.next()?.split_once('=')?;
+		if name.trim().is_empty() {
+			return None;
+		}
+		let mut cookie    = Self::new(name.trim(), value.trim());
+		for attribute in parts {
+			let (key, raw_value) = attribute.split_once('=').unwrap_or((attribute, ""));
+			let attribute_value  = raw_value.trim();
+			match key.trim().to_ascii_lowercase().as_str() {
+				"path"     => cookie.path      = Some(attribute_value.to_owned()),
+				"domain"   => cookie.domain    = Some(attribute_value.to_owned()),
+				"expires"  => cookie.expires   = Some(attribute_value.to_owned()),
+				"max-age"  => cookie.max_age   = attribute_value.parse().ok(),
+				"secure"   => cookie.secure    = true,
+				"httponly" => cookie.http_only = true,
+				"samesite" => if let Some(same_site) = SameSite::from_name(attribute_value) {
+					cookie.same_site = Some(same_site);
+				},
+				_          => {},
+			}
+		}
+		Some(cookie)
+	}
+	
+	//		with_path															
+	/// Sets the `Path` attribute.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `path` - The path to which the cookie applies.
+	/// 
+	#[must_use]
+	pub fn with_path(mut self, path: &str) -> Self {
+		self.path = Some(path.to_owned());
+		self
+	}
+	
+	//		with_domain															
+	/// Sets the `Domain` attribute.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `domain` - The domain to which the cookie applies.
+	/// 
+	#[must_use]
+	pub fn with_domain(mut self, domain: &str) -> Self {
+		self.domain = Some(domain.to_owned());
+		self
+	}
+	
+	//		with_expires														
+	/// Sets the `Expires` attribute.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `expires` - The expiry date of the cookie, as an HTTP date.
+	/// 
+	#[must_use]
+	pub fn with_expires(mut self, expires: &str) -> Self {
+		self.expires = Some(expires.to_owned());
+		self
+	}
+	
+	//		with_max_age														
+	/// Sets the `Max-Age` attribute.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `max_age` - The number of seconds until the cookie expires.
+	/// 
+	#[must_use]
+	pub const fn with_max_age(mut self, max_age: i64) -> Self {
+		self.max_age = Some(max_age);
+		self
+	}
+	
+	//		with_secure															
+	/// Sets whether the `Secure` attribute is present.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `secure` - Whether the cookie is only to be sent over secure
+	///              connections.
+	/// 
+	#[must_use]
+	pub const fn with_secure(mut self, secure: bool) -> Self {
+		self.secure = secure;
+		self
+	}
+	
+	//		with_http_only														
+	/// Sets whether the `HttpOnly` attribute is present.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `http_only` - Whether the cookie is inaccessible to scripts.
+	/// 
+	#[must_use]
+	pub const fn with_http_only(mut self, http_only: bool) -> Self {
+		self.http_only = http_only;
+		self
+	}
+	
+	//		with_same_site														
+	/// Sets the `SameSite` attribute.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `same_site` - The cross-site behaviour of the cookie.
+	/// 
+	#[must_use]
+	pub const fn with_same_site(mut self, same_site: SameSite) -> Self {
+		self.same_site = Some(same_site);
+		self
+	}
+	
+	//		eq_ignoring_expiry													
+	/// Compares two cookies for equality, ignoring their expiry.
+	/// 
+	/// This compares all properties apart from [`expires`](UnpackedCookie::expires)
+	/// and [`max_age`](UnpackedCookie::max_age), which will often vary between
+	/// responses.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The cookie to compare against.
+	/// 
+	/// # See also
+	/// 
+	/// * [`assert_cookie_eq!`](crate::assert_cookie_eq)
+	/// 
+	#[must_use]
+	pub fn eq_ignoring_expiry(&self, other: &Self) -> bool {
+		   self.name      == other.name
+		&& self.value     == other.value
+		&& self.path      == other.path
+		&& self.domain    == other.domain
+		&& self.secure    == other.secure
+		&& self.http_only == other.http_only
+		&& self.same_site == other.same_site
+	}
+}
+
+//󰭅		Display																	
+impl Display for UnpackedCookie {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}={}", self.name, self.value)?;
+		if let Some(ref path) = self.path {
+			write!(f, "; Path={path}")?;
+		}
+		if let Some(ref domain) = self.domain {
+			write!(f, "; Domain={domain}")?;
+		}
+		if let Some(ref expires) = self.expires {
+			write!(f, "; Expires={expires}")?;
+		}
+		if let Some(max_age) = self.max_age {
+			write!(f, "; Max-Age={max_age}")?;
+		}
+		if self.secure {
+			f.write_str("; Secure")?;
+		}
+		if self.http_only {
+			f.write_str("; HttpOnly")?;
+		}
+		if let Some(same_site) = self.same_site {
+			write!(f, "; SameSite={same_site}")?;
+		}
+		Ok(())
+	}
+}
+
+//		UnpackedRawHeader														
+/// An HTTP response header with its raw value.
+/// 
+/// Header values are not guaranteed to be valid UTF8, and so the
+/// [`value`](UnpackedResponseHeader::value) of an [`UnpackedResponseHeader`]
+/// is a lossy conversion. This struct retains the exact bytes of the value, and
+/// is used for the [`raw_headers`](UnpackedResponse::raw_headers) of an
+/// [`UnpackedResponse`], which are populated when unpacking with
+/// [`UnpackOptions::with_raw_header_values()`].
+/// 
+/// # See also
+/// 
+/// * [`UnpackedResponse`]
+/// * [`UnpackedResponseHeader`]
+/// 
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct UnpackedRawHeader {
+	//		Public properties													
+	/// The response header name.
+	pub name:  String,
+	
+	/// The raw bytes of the response header value. These are serialised as
+	/// base64.
+	#[serde(serialize_with = "serialize_raw_header_value", deserialize_with = "deserialize_raw_header_value")]
+	pub value: Vec<u8>,
+}
+
+//󰭅		UnpackedRawHeader														
+impl UnpackedRawHeader {
+	//		new																	
+	/// Creates a new raw response header instance.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name`  - The response header name.
+	/// * `value` - The raw bytes of the response header value.
+	/// 
+	#[must_use]
+	pub const fn new(name: String, value: Vec<u8>) -> Self {
+		Self {
+			name,
+			value,
+		}
+	}
+}
+
+//		UnpackedRequest															
+/// An HTTP request in comparison-friendly form for interrogation.
+/// 
+/// This is the request counterpart to [`UnpackedResponse`], and follows the
+/// same principles. Data in [`http::Request`] is stored in a specific form,
+/// made up of a header map object and a generic body type, which can be empty,
+/// a [`String`], or a streaming body future. This struct provides a way to use
+/// the data in a more accessible form, to allow it to be checked and compared.
+/// This is useful for testing middleware and proxies, as the entire set of
+/// request data can be checked all at once, and also for printing/logging.
+/// 
+/// The headers and body are stored using the same [`UnpackedResponseHeader`]
+/// and [`UnpackedResponseBody`] types as used by [`UnpackedResponse`], as they
+/// are identical in nature, and this allows the same functionality to be
+/// applied to both. The notes on [`UnpackedResponse`] regarding the conversion
+/// of the body to a [`String`] for display therefore apply here as well.
+/// 
+/// # See also
+/// 
+/// * [`http::Request`]
+/// * [`RequestExt`]
+/// * [`RequestExt::unpack()`]
+/// * [`UnpackedResponse`]
+/// * [`UnpackedResponseBody`]
+/// * [`UnpackedResponseHeader`]
+/// 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct UnpackedRequest {
+	//		Public properties													
+	/// The request method. This is serialised as a string, such as `"GET"`,
+	/// as the [`Method`] type does not provide serialisation support of its
+	/// own.
+	#[serde(serialize_with = "serialize_method", deserialize_with = "deserialize_method")]
+	pub method:  Method,
+	
+	/// The request URI. This is serialised as a string, in the same form as
+	/// provided by the standard [`Display`] formatter. For requests received
+	/// by a server this will usually only contain the path and query.
+	#[serde(serialize_with = "serialize_uri", deserialize_with = "deserialize_uri")]
+	pub uri:     Uri,
+	
+	/// The HTTP version of the request. This is serialised as a string in the
+	/// format `"HTTP/1.1"`.
+	#[serde(serialize_with = "serialize_version", deserialize_with = "deserialize_version")]
+	pub version: Version,
+	
+	/// The request headers. These are in a vector rather than a hashmap
+	/// because there may be multiple headers with the same name. When unpacked
+	/// using [`RequestExt::unpack()`], they are sorted by name, and then by
+	/// value, allowing for reliable comparison, in the same way as the default
+	/// for [`UnpackedResponse::headers`].
+	pub headers: Vec<UnpackedResponseHeader>,
+	
+	/// The request body. This originates from the request body as a [`Bytes`]
+	/// container, but gets stored here as a vector of bytes for convenience.
+	/// This may not be valid UTF8, so is not converted to a [`String`].
+	pub body:    UnpackedResponseBody,
+}
+
+//󰭅		UnpackedRequest															
+impl UnpackedRequest {
+	//		new																	
+	/// Creates a new unpacked request instance.
+	/// 
+	/// This constructor builds a new [`UnpackedRequest`] instance from the
+	/// request method, URI, header data, and body data. The HTTP version is set
+	/// to HTTP/1.1, which is the default used by [`http::Request`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `method`  - The request method. See [`method`](UnpackedRequest::method).
+	/// * `uri`     - The request URI. See [`uri`](UnpackedRequest::uri).
+	/// * `headers` - The request headers. See [`headers`](UnpackedRequest::headers).
+	/// * `body`    - The request body. See [`body`](UnpackedRequest::body).
+	/// 
+	#[must_use]
+	pub fn new<T: Into<UnpackedResponseBody>>(
+		method:  Method,
+		uri:     Uri,
+		headers: Vec<(String, String)>,
+		body:    T
+	) -> Self {
+		Self::new_from_parts(
+			method,
+			uri,
+			Version::HTTP_11,
+			headers.into_iter().map(|(name, value)| UnpackedResponseHeader::new(name, value)).collect(),
+			body.into(),
+		)
+	}
+	
+	//		new_from_parts														
+	/// Creates a new unpacked request instance from existing parts.
+	/// 
+	/// This constructor builds a new [`UnpackedRequest`] instance from
+	/// constituent part instances that are already in the correct form. This is
+	/// useful when the parts are already available.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `method`  - The request method. See [`method`](UnpackedRequest::method).
+	/// * `uri`     - The request URI. See [`uri`](UnpackedRequest::uri).
+	/// * `version` - The HTTP version. See [`version`](UnpackedRequest::version).
+	/// * `headers` - The request headers. See [`headers`](UnpackedRequest::headers).
+	/// * `body`    - The request body. See [`body`](UnpackedRequest::body).
+	/// 
+	#[must_use]
+	pub const fn new_from_parts(
+		method:  Method,
+		uri:     Uri,
+		version: Version,
+		headers: Vec<UnpackedResponseHeader>,
+		body:    UnpackedResponseBody
+	) -> Self {
+		Self {
+			method,
+			uri,
+			version,
+			headers,
+			body,
+		}
+	}
+	
+	//		query																
+	/// Returns the query string of the request URI as name-value pairs.
+	/// 
+	/// The names and values are percent-decoded, and are returned in the order
+	/// in which they appear, with repeated names retained. If there is no
+	/// query string then the result is empty.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedRequest::to_query()`]
+	/// * [`UnpackedResponseBody::as_form()`]
+	/// 
+	#[must_use]
+	pub fn query(&self) -> Vec<(String, String)> {
+		self.to_query().unwrap_or_default()
+	}
+	
+	//		to_query															
+	/// Returns the query string of the request URI deserialised into a
+	/// specific type.
+	/// 
+	/// The query string is parsed in the same way as a form-urlencoded body.
+	/// If there is no query string then it is treated as empty.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the query string does not match the
+	/// structure of the requested type. Such an error will be returned as a
+	/// [`FormDeError`], which is passed through from the [`serde_urlencoded`]
+	/// crate.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedRequest::query()`]
+	/// * [`UnpackedResponseBody::to_form()`]
+	/// 
+	pub fn to_query<T: DeserializeOwned>(&self) -> Result<T, FormDeError> {
+		serde_urlencoded::from_str(self.uri.query().unwrap_or_default())
+	}
+	
+	//		from_har															
+	/// Converts a HAR request JSON value into an unpacked request.
+	/// 
+	/// HTTP/2 pseudo-headers, such as `:authority`, are ignored. The body is
+	/// taken from the `postData` property, decoding it from base64 if marked as
+	/// such, or encoding the form parameters if there is no text. The content
+	/// type of the body is detected from the `content-type` header, falling
+	/// back to the MIME type of the `postData`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `value` - The HAR request.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if a required field is missing or
+	/// invalid, or if the body cannot be decoded.
+	/// 
+	/// # See also
+	/// 
+	/// * [`HarArchive`]
+	/// * [`UnpackedRequest::to_har()`]
+	/// 
+	pub fn from_har(value: &Json) -> Result<Self, HarError> {
+		let method  = Method::from_bytes(har_str(value, "method")?.as_bytes())
+			.map_err(|_err| HarError::InvalidField("method".to_owned()))?
+		;
+		let uri     = har_str(value, "url")?.parse::<Uri>().map_err(|_err| HarError::InvalidField("url".to_owned()))?;
+		let version = match value.get("httpVersion").and_then(Json::as_str) {
+			Some(name) => parse_http_version(name).ok_or_else(|| HarError::InvalidField("httpVersion".to_owned()))?,
+			None       => Version::HTTP_11,
+		};
+		let headers = parse_har_headers(value)?;
+		let body    = parse_har_body(value.get("postData"), &headers)?;
+		Ok(Self::new_from_parts(method, uri, version, convert_headers(&headers, &UnpackOptions::default()), body))
+	}
+	
+	//		to_har																
+	/// Converts the request into a HAR request JSON value.
+	/// 
+	/// HAR requires an absolute URL, so if the URI is only a path then the
+	/// `host` header is used to complete it, assuming HTTP. Cookies and query
+	/// parameters are taken from the `cookie` header and the URI. The body is
+	/// stored in the `postData` property, if not empty, as text if possible,
+	/// and otherwise as base64.
+	/// 
+	/// # See also
+	/// 
+	/// * [`HarArchive`]
+	/// * [`UnpackedRequest::from_har()`]
+	/// 
+	#[must_use]
+	pub fn to_har(&self) -> Json {
+		let header      = |name: &str| self.headers.iter()
+			.find(|header| header.name.eq_ignore_ascii_case(name))
+			.map(|header| header.value.as_str())
+		;
+		let url         = match header("host") {
+			Some(host) if self.uri.scheme().is_none() => format!("http://{host}{}", self.uri),
+			_                                         => self.uri.to_string(),
+		};
+		let cookies     = header("cookie").unwrap_or_default()
+			.split(';')
+			.filter_map(|cookie| cookie.split_once('='))
+			.map(|(name, value)| json!({ "name": name.trim(), "value": value.trim() }))
+			.collect::<Vec<_>>()
+		;
+		let mut request = json!({
+			"method":      self.method.as_str(),
+			"url":         url,
+			"httpVersion": format!("{:?}", self.version),
+			"cookies":     cookies,
+			"headers":     har_headers(&self.headers),
+			"queryString": self.query().into_iter()
+				.map(|(name, value)| json!({ "name": name, "value": value }))
+				.collect::<Vec<_>>(),
+			"headersSize": -1_i32,
+			"bodySize":    self.body.len(),
+		});
+		if !self.body.is_empty() {
+			if let Some(object) = request.as_object_mut() {
+				drop(object.insert("postData".to_owned(), Json::Object(har_content(&self.body, header(CONTENT_TYPE.as_str())))));
+			}
+		}
+		request
+	}
+}
+
+//󰭅		PartialEq																
+impl PartialEq for UnpackedRequest {
+	//		eq																	
+	fn eq(&self, other: &Self) -> bool {
+		   self.method  == other.method
+		&& self.uri     == other.uri
+		&& self.version == other.version
+		&& self.headers == other.headers
+		&& self.body    == other.body
+	}
+}
+
+//		UnpackedResponse														
+/// An HTTP response in comparison-friendly form for interrogation.
+/// 
+/// Data in [`hyper::Response`] (and indeed [`http::Response`] as well) is
+/// stored in a specific form, made up of a header map object and a generic body
+/// type, which can be empty, a [`String`], or a streaming body future. This
+/// struct provides a way to use the data in a more accessible form, to allow it
+/// to be checked and compared. This is useful for testing, as the entire set of
+/// headers plus body can be checked all at once, and also for printing/logging.
+/// 
+/// If specific headers or body content needs to be checked, it is recommended
+/// to use the standard functions as they will be more efficient and performant.
+/// 
+/// Note that the [`body`](UnpackedResponse::body) property, which is stored as
+/// a vector of bytes, will get converted to a [`String`] if it is run through
+/// the standard [`Debug`] or [`Display`] formatters. This is because
+/// human-readable output is the intuitively-expected outcome in this situation.
+/// The behaviour can be controlled with the [`ContentType`] enum, which is used
+/// to determine whether the data is binary or text. If [`Text`](ContentType::Text),
+/// then the conversion uses [`from_utf8_lossy()`](String::from_utf8_lossy()),
+/// so no errors will occur, but if the body is not valid UTF8 then the
+/// resulting [`String`] will not be exactly the same. If an accurate
+/// representation of the body is required then it should be set to [`Binary`](ContentType::Binary),
+/// or else it should be extracted and converted to a `Vec<u8>` and then run
+/// through the [`Debug`] or [`Display`] formatters directly.
+/// 
+/// An unpacked response can be converted back into a [`Response`], with either
+/// a [`Full<Bytes>`](Full) or a [`String`] body, using [`From`], and when the
+/// `axum` feature is enabled it also implements Axum's
+/// [`IntoResponse`](https://docs.rs/axum/latest/axum/response/trait.IntoResponse.html)
+/// trait.
+/// This allows responses that have been unpacked and serialised, e.g. to
+/// fixture files, to be deserialised and replayed, e.g. from mock servers.
+/// Where the body has to be recovered exactly, the response can be serialised
+/// using [`serialize_tagged_response()`] and
+/// [`deserialize_tagged_response()`].
+/// 
+/// # See also
+/// 
+/// * [`axum::response`](https://docs.rs/axum/latest/axum/response/index.html)
+/// * [`axum::response::Response`](https://docs.rs/axum/latest/axum/response/type.Response.html)
+/// * [`http::Response`]
+/// * [`hyper::Response`]
+/// * [`ResponseExt`]
+/// * [`ResponseExt::unpack()`]
+/// * [`UnpackedResponseHeader`]
+/// 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct UnpackedResponse {
+	//		Public properties													
+	/// The response status code. This is an enum, so is not directly comparable
+	/// to a number. The standard [`Display`] formatter will convert it to a
+	/// string in the format `"200 OK"`, but the standard [`FromStr`]
+	/// implementation will error if this is given back to it, as it expects
+	/// only `"200"`. Because this round-trip is basically broken, this struct
+	/// provides custom serialisation and deserialisation functions to convert
+	/// the status code to and from an actual number (a [`u16`]). This allows
+	/// the struct to be serialised and deserialised in a round-trip without
+	/// error, and is also the more intuitive representation of the status code
+	/// in serialised form such as JSON.
+	#[serde(serialize_with = "serialize_status_code", deserialize_with = "deserialize_status_code")]
+	pub status: StatusCode,
+	
+	/// The response headers. These are in a vector rather than a hashmap
+	/// because there may be multiple headers with the same name. By default
+	/// they are sorted by name, and then by value, allowing for reliable
+	/// comparison. Sorting does break the original order of the headers, but
+	/// this should only very rarely matter, even when logging, and sorting
+	/// allows duplicates to be spotted by eye more easily in logs. When the
+	/// order does matter, it can be preserved by unpacking with
+	/// [`UnpackOptions::with_preserved_header_order()`].
+	pub headers: Vec<UnpackedResponseHeader>,
+	
+	/// The response body. This originates from the response body as a [`Bytes`]
+	/// container, but gets stored here as a vector of bytes for convenience.
+	/// This may not be valid UTF8, so is not converted to a [`String`]. That
+	/// step is left as optional for the caller, if required (and happens when
+	/// running the [`UnpackedResponse`] struct through the [`Debug`] or
+	/// [`Display`] formatters).
+	pub body:    UnpackedResponseBody,
+	
+	/// The frames of the response body, as received. These are only recorded
+	/// when unpacking with [`UnpackOptions::with_frame_recording()`], in which
+	/// case they preserve the chunk boundaries of the body and any trailers.
+	/// They are not considered when comparing responses, and are omitted when
+	/// serialising if empty.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub frames:  Vec<UnpackedFrame>,
+	
+	/// The response headers with their raw values. These are only retained
+	/// when unpacking with [`UnpackOptions::with_raw_header_values()`], in
+	/// which case there is one for each of the [`headers`](UnpackedResponse::headers),
+	/// in the same order. This allows values that are not valid UTF8, and are
+	/// therefore converted lossily in the headers, to be checked exactly. When
+	/// present, they are used in place of the headers when converting back into
+	/// a [`Response`] or to the HTTP/1.1 wire format. They are not considered
+	/// when comparing responses, and are omitted when serialising if empty.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub raw_headers: Vec<UnpackedRawHeader>,
+}
+
+//󰭅		UnpackedResponse														
+impl UnpackedResponse {
+	//		new																	
+	/// Creates a new unpacked response instance.
+	/// 
+	/// This constructor builds a new [`UnpackedResponse`] instance from the
+	/// response status code, header data, and body data. This is useful when
+	/// the parts 
+	/// 
+	/// # Parameters
+	/// 
+	/// * `status`  - The response status code. See [`status`](UnpackedResponse::status).
+	/// * `headers` - The response headers. See [`headers`](UnpackedResponse::headers).
+	/// * `body`    - The response body. See [`body`](UnpackedResponse::body).
+	/// 
+	#[must_use]
+	pub fn new<T: Into<UnpackedResponseBody>>(
+		status:  StatusCode,
+		headers: Vec<(String, String)>,
+		body:    T
+	) -> Self {
+		Self::new_from_parts(
+			status,
+			headers.into_iter().map(|(name, value)| UnpackedResponseHeader::new(name, value)).collect(),
+			body.into(),
+		)
+	}
+	
+	//		new_from_parts														
+	/// Creates a new unpacked response instance from existing parts.
+	/// 
+	/// This constructor builds a new [`UnpackedResponse`] instance from
+	/// constituent part instances that are already in the correct form. This is
+	/// useful when the parts are already available.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `status`  - The response status code. See [`status`](UnpackedResponse::status).
+	/// * `headers` - The response headers. See [`headers`](UnpackedResponse::headers).
+	/// * `body`    - The response body. See [`body`](UnpackedResponse::body).
+	/// 
+	#[must_use]
+	pub const fn new_from_parts(
+		status:  StatusCode,
+		headers: Vec<UnpackedResponseHeader>,
+		body:    UnpackedResponseBody
+	) -> Self {
+		Self {
+			status,
+			headers,
+			body,
+			frames:      Vec::new(),
+			raw_headers: Vec::new(),
+		}
+	}
+	
+	//		diff																
+	/// Returns the differences between this response and another.
+	/// 
+	/// This compares the status, headers, and body of the two responses, and
+	/// reports what is different in a structured form, which is useful when a
+	/// comparison of two responses fails and the reason is not obvious from
+	/// their [`Debug`] output. This instance is treated as the left-hand side,
+	/// and the other as the right-hand side, so headers present only in the
+	/// other response are reported as having been added.
+	/// 
+	/// Headers are compared by name, and then by the values for each name in
+	/// order. The ordering of headers with different names is not considered,
+	/// as unpacked headers are sorted by name.
+	/// 
+	/// The comparison of the body is carried out by
+	/// [`UnpackedResponseBody::diff()`], and the form of the result depends
+	/// upon the body content. See [`BodyDiff`] for details.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The response to compare against.
+	/// 
+	/// # See also
+	/// 
+	/// * [`assert_response_eq!`](crate::assert_response_eq)
+	/// * [`UnpackedResponseBody::diff()`]
+	/// * [`UnpackedResponseDiff`]
+	/// 
+	#[must_use]
+	pub fn diff(&self, other: &Self) -> UnpackedResponseDiff {
+		UnpackedResponseDiff {
+			status:  (self.status != other.status).then_some((self.status, other.status)),
+			headers: diff_headers(&self.headers, &other.headers),
+			body:    self.body.diff(&other.body),
+		}
+	}
+	
+	//		header																
+	/// Returns the value of the named header.
+	/// 
+	/// The name is matched case-insensitively. If there are multiple headers
+	/// with the name, the first is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The name of the header.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponse::headers_all()`]
+	/// 
+	#[must_use]
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers.iter()
+			.find(|header| header.name.eq_ignore_ascii_case(name))
+			.map(|header| header.value.as_str())
+	}
+	
+	//		headers_all															
+	/// Returns all values of the named header.
+	/// 
+	/// The name is matched case-insensitively, and the values are returned in
+	/// the order in which the headers are stored.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The name of the header.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponse::header()`]
+	/// 
+	#[must_use]
+	pub fn headers_all(&self, name: &str) -> Vec<&str> {
+		self.headers.iter()
+			.filter(|header| header.name.eq_ignore_ascii_case(name))
+			.map(|header| header.value.as_str())
+			.collect()
+	}
+	
+	//		cookies																
+	/// Returns the cookies set by the response.
+	/// 
+	/// These are parsed from the `set-cookie` headers, in the order in which
+	/// the headers are stored. Any headers that cannot be parsed are skipped.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedCookie`]
+	/// * [`UnpackedResponse::cookie()`]
+	/// 
+	#[must_use]
+	pub fn cookies(&self) -> Vec<UnpackedCookie> {
+		self.headers_all(SET_COOKIE.as_str()).into_iter().filter_map(UnpackedCookie::parse).collect()
+	}
+	
+	//		cookie																
+	/// Returns the named cookie set by the response.
+	/// 
+	/// The name is matched case-sensitively. If the cookie is set more than
+	/// once, the last one is returned. This is only the one that would take
+	/// effect if the response was unpacked with
+	/// [`UnpackOptions::with_preserved_header_order()`], as otherwise the
+	/// headers are sorted by value.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The name of the cookie.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedCookie`]
+	/// * [`UnpackedResponse::cookies()`]
+	/// 
+	#[must_use]
+	pub fn cookie(&self, name: &str) -> Option<UnpackedCookie> {
+		self.cookies().into_iter().rev().find(|cookie| cookie.name == name)
+	}
+	
+	//		trailers															
+	/// Returns the trailers of the response.
+	/// 
+	/// These are taken from any trailer frames, and so are only available if
+	/// the frames were recorded when unpacking.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackOptions::with_frame_recording()`]
+	/// * [`UnpackedFrame`]
+	/// 
+	#[must_use]
+	pub fn trailers(&self) -> Vec<&UnpackedResponseHeader> {
+		self.frames.iter()
+			.filter_map(|frame| match *frame {
+				UnpackedFrame::Trailers(ref trailers) => Some(trailers),
+				UnpackedFrame::Data(_)                => None,
+			})
+			.flatten()
+			.collect()
+	}
+	
+	//		multipart															
+	/// Returns the response body parsed as a multipart body.
+	/// 
+	/// The boundary is taken from the `content-type` header of the response,
+	/// which would normally be `multipart/mixed` or `multipart/form-data`.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if there is no boundary in the
+	/// `content-type` header, or if the body cannot be parsed.
+	/// 
+	/// # See also
+	/// 
+	/// * [`MultipartPart`]
+	/// * [`UnpackedResponseBody::as_multipart()`]
+	/// 
+	pub fn multipart(&self) -> Result<Vec<MultipartPart>, MultipartError> {
+		let boundary = self.header(CONTENT_TYPE.as_str())
+			.filter(|content_type| mime_essence(content_type).starts_with("multipart/"))
+			.and_then(|content_type| mime_parameter(content_type, "boundary"))
+			.ok_or(MultipartError::MissingBoundary)?
+		;
+		self.body.as_multipart(&boundary)
+	}
+	
+	//		text																
+	/// Returns the response body decoded as text, using the declared charset.
+	/// 
+	/// The charset is taken from the `charset` parameter of the `content-type`
+	/// header of the response, defaulting to UTF8 if there is none. This allows
+	/// bodies in other encodings, such as Latin-1 or UTF-16, to be read
+	/// correctly, rather than being assumed to be UTF8.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the charset is not supported, or if
+	/// the body is not valid in that charset.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::decode_text()`]
+	/// 
+	pub fn text(&self) -> Result<String, CharsetError> {
+		let charset = self.header(CONTENT_TYPE.as_str())
+			.and_then(|content_type| mime_parameter(content_type, "charset"))
+		;
+		self.body.decode_text(charset.as_deref().unwrap_or("utf-8"))
+	}
+	
+	//		from_har															
+	/// Converts a HAR response JSON value into an unpacked response.
+	/// 
+	/// HTTP/2 pseudo-headers are ignored. The body is taken from the `content`
+	/// property, decoding it from base64 if marked as such. The content type of
+	/// the body is detected from the `content-type` header, falling back to
+	/// the MIME type of the `content`. Note that HAR stores the decoded body,
+	/// so any `content-encoding` header will not reflect the body.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `value` - The HAR response.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if a required field is missing or
+	/// invalid, or if the body cannot be decoded.
+	/// 
+	/// # See also
+	/// 
+	/// * [`HarArchive`]
+	/// * [`UnpackedResponse::to_har()`]
+	/// 
+	pub fn from_har(value: &Json) -> Result<Self, HarError> {
+		let status  = har_field(value, "status")?
+			.as_u64()
+			.and_then(|code| u16::try_from(code).ok())
+			.and_then(|code| StatusCode::from_u16(code).ok())
+			.ok_or_else(|| HarError::InvalidField("status".to_owned()))?
+		;
+		let headers = parse_har_headers(value)?;
+		let body    = parse_har_body(value.get("content"), &headers)?;
+		Ok(Self::new_from_parts(status, convert_headers(&headers, &UnpackOptions::default()), body))
+	}
+	
+	//		to_har																
+	/// Converts the response into a HAR response JSON value.
+	/// 
+	/// The HTTP version is given as HTTP/1.1, as the version is not recorded
+	/// when unpacking. Cookies are taken from the `set-cookie` headers, and the
+	/// redirect URL from the `location` header. The body is stored in the
+	/// `content` property as text if possible, and otherwise as base64.
+	/// 
+	/// # See also
+	/// 
+	/// * [`HarArchive`]
+	/// * [`UnpackedResponse::from_har()`]
+	/// 
+	#[must_use]
+	pub fn to_har(&self) -> Json {
+		let mut content = har_content(&self.body, self.header(CONTENT_TYPE.as_str()));
+		drop(content.insert("size".to_owned(), Json::from(self.body.len())));
+		json!({
+			"status":      self.status.as_u16(),
+			"statusText":  self.status.canonical_reason().unwrap_or_default(),
+			"httpVersion": "HTTP/1.1",
+			"cookies":     self.cookies().iter().map(har_cookie).collect::<Vec<_>>(),
+			"headers":     har_headers(&self.headers),
+			"content":     content,
+			"redirectURL": self.header("location").unwrap_or_default(),
+			"headersSize": -1_i32,
+			"bodySize":    self.body.len(),
+		})
+	}
+	
+	//		from_http1															
+	/// Parses a response from the HTTP/1.1 wire format.
+	/// 
+	/// This is the same as [`from_http1_with()`](UnpackedResponse::from_http1_with()),
+	/// but using the default [`UnpackOptions`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `data` - The response message.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the status line or a header is
+	/// invalid, or if the body cannot be unpacked.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponse::from_http1_with()`]
+	/// * [`UnpackedResponse::to_http1()`]
+	/// 
+	pub fn from_http1(data: &[u8]) -> Result<Self, Http1Error> {
+		Self::from_http1_with(data, &UnpackOptions::default())
+	}
+	
+	//		from_http1_with														
+	/// Parses a response from the HTTP/1.1 wire format, with options.
+	/// 
+	/// The data should be a complete response message, consisting of a status
+	/// line, headers, a blank line, and the body, such as is output by
+	/// `curl -i`, or captured from network traffic. Lines in the head may be
+	/// terminated with either CRLF or LF, so that messages pasted into tests
+	/// can be parsed. Any interim informational (`1xx`) responses before the
+	/// final response are skipped, and a chunked body is decoded, with the
+	/// `transfer-encoding` header replaced by a `content-length` header.
+	/// 
+	/// The headers and body are unpacked in the same way as by
+	/// [`ResponseExt::unpack_with()`], so that the result can be compared
+	/// against that of a real response.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `data`    - The response message.
+	/// * `options` - The options to apply when unpacking.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the status line or a header is
+	/// invalid, or if the body cannot be unpacked, e.g. because it exceeds the
+	/// size limit.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponse::from_http1()`]
+	/// * [`UnpackedResponse::to_http1()`]
+	/// 
+	pub fn from_http1_with(data: &[u8], options: &UnpackOptions) -> Result<Self, Http1Error> {
+		parse_http1_response(data, options)
+	}
+	
+	//		to_http1															
+	/// Returns the response in the HTTP/1.1 wire format.
+	/// 
+	/// This produces the literal text of the response, consisting of the
+	/// status line, the headers, a blank line, and the body, with the lines of
+	/// the head terminated with CRLF. The headers are written in the order in
+	/// which they are stored, and are not adjusted to match the body. The
+	/// result can be parsed back using [`from_http1()`](UnpackedResponse::from_http1()).
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponse::from_http1()`]
+	/// 
+	#[must_use]
+	pub fn to_http1(&self) -> Vec<u8> {
+		let mut data = format!(
+			"HTTP/1.1 {} {}\r\n",
+			self.status.as_str(),
+			self.status.canonical_reason().unwrap_or_default(),
+		).into_bytes();
+		for (name, value) in header_bytes(&self.headers, &self.raw_headers) {
+			data.extend_from_slice(name.as_bytes());
+			data.extend_from_slice(b": ");
+			data.extend_from_slice(value);
+			data.extend_from_slice(b"\r\n");
+		}
+		data.extend_from_slice(b"\r\n");
+		data.extend_from_slice(self.body.as_bytes());
+		data
+	}
+	
+	//		redacted															
+	/// Returns a view of the response with a redaction policy applied.
+	/// 
+	/// The redactions are applied when the view is serialised or displayed,
+	/// and the response itself is not modified. This allows responses to be
+	/// written to logs and fixtures without exposing tokens and other secrets.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `policy` - The redaction policy to apply.
+	/// 
+	/// # See also
+	/// 
+	/// * [`RedactedResponse`]
+	/// * [`RedactionPolicy`]
+	/// 
+	#[must_use]
+	pub const fn redacted<'a>(&'a self, policy: &'a RedactionPolicy) -> RedactedResponse<'a> {
+		RedactedResponse { policy, response: self }
+	}
+}
+
+//󰭅		From<UnpackedResponse>													
+impl From<UnpackedResponse> for Response<Full<Bytes>> {
+	//		from																
+	/// Converts an unpacked response into a response with a [`Full<Bytes>`](Full)
+	/// body.
+	/// 
+	/// The body is used exactly as-is. Any headers with names or values that
+	/// are not valid will be omitted.
+	/// 
+	fn from(unpacked: UnpackedResponse) -> Self {
+		rebuild_response(unpacked.status, &unpacked.headers, &unpacked.raw_headers, Full::new(Bytes::from(unpacked.body.into_bytes())))
+	}
+}
+
+//󰭅		From<UnpackedResponse>													
+impl From<UnpackedResponse> for Response<String> {
+	//		from																
+	/// Converts an unpacked response into a response with a [`String`] body.
+	/// 
+	/// If the body is not valid UTF8, it will be converted using
+	/// [`from_utf8_lossy()`](String::from_utf8_lossy()), and so will not be
+	/// exactly the same. Any headers with names or values that are not valid
+	/// will be omitted.
+	/// 
+	fn from(unpacked: UnpackedResponse) -> Self {
+		let body = String::from_utf8(unpacked.body.into_bytes())
+			.unwrap_or_else(|err| String::from_utf8_lossy(err.as_bytes()).into_owned())
+		;
+		rebuild_response(unpacked.status, &unpacked.headers, &unpacked.raw_headers, body)
+	}
+}
+
+//󰭅		IntoResponse															
+#[cfg(feature = "axum")]
+impl IntoResponse for UnpackedResponse {
+	//		into_response														
+	/// Converts the unpacked response into an Axum response.
+	/// 
+	/// This allows an [`UnpackedResponse`] to be returned directly from an Axum
+	/// handler. Any headers with names or values that are not valid will be
+	/// omitted.
+	/// 
+	fn into_response(self) -> AxumResponse {
+		rebuild_response(self.status, &self.headers, &self.raw_headers, AxumBody::from(self.body.into_bytes()))
+	}
+}
+
+//󰭅		PartialEq																
+impl PartialEq for UnpackedResponse {
+	//		eq																	
+    fn eq(&self, other: &Self) -> bool {
+        self.status == other.status && self.headers == other.headers && self.body == other.body
+    }
+}
+
+//		UnpackedResponseHeader													
+/// An HTTP response header.
+/// 
+/// A simple representation of an HTTP response header as a key-value pair. The
+/// purpose of this struct is to formalise the data structure used by
+/// [`UnpackedResponse`] for storing headers.
+/// 
+/// No other properties are planned or logically considerable at present, and so
+/// this struct is seen as being exhaustive.
+/// 
+/// # See also
+/// 
+/// * [`UnpackedResponse`]
+/// 
+#[expect(clippy::exhaustive_structs, reason = "Exhaustive")]
+#[derive(Clone, Debug, Deserialize, Eq, Serialize)]
+pub struct UnpackedResponseHeader {
+	//		Public properties													
+	/// The response header name.
+	pub name:  String,
+	
+	/// The response header value.
+	pub value: String,
+}
+
+//󰭅		UnpackedResponseHeader													
+impl UnpackedResponseHeader {
+	//		new																	
+	/// Creates a new response header instance.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name`  - The response header name.
+	/// * `value` - The response header value.
+	/// 
+	#[must_use]
+	pub const fn new(name: String, value: String) -> Self {
+		Self {
+			name,
+			value,
+		}
+	}
+}
+
+//󰭅		PartialEq																
+impl PartialEq for UnpackedResponseHeader {
+	//		eq																	
+	fn eq(&self, other: &Self) -> bool {
+		self.name == other.name && self.value == other.value
+	}
+}
+
+//		UnpackedResponseBody													
+/// An HTTP response body.
+/// 
+/// A simple representation of an HTTP response body as a vector of bytes. The
+/// purpose of this struct is to formalise the data structure used by
+/// [`UnpackedResponse`] for storing the body.
+///
+/// The data originates from the response body as a [`Bytes`] container, but
+/// gets stored here as a vector of bytes for convenience. This may not be valid
+/// UTF8, so is not converted to a [`String`]. That step is left as optional for
+/// the caller, if required (and happens when running through the [`Debug`] or
+/// [`Display`] formatters).
+/// 
+/// The conversion to a [`String`] when run through the [`Debug`] and
+/// [`Display`] formatters is because human-readable output is the
+/// intuitively-expected outcome in this situation. The behaviour can be
+/// controlled with the [`ContentType`] enum, which is used to determine whether
+/// the data is binary or text. If [`Text`](ContentType::Text), then the
+/// conversion uses [`from_utf8_lossy()`](String::from_utf8_lossy()), so no
+/// errors will occur, but if the body is not valid UTF8 then the resulting
+/// [`String`] will not be exactly the same. If an accurate representation of
+/// the body is required then it should be set to [`Binary`](ContentType::Binary),
+/// or else it should be extracted and converted to a `Vec<u8>` and then run
+/// through the [`Debug`] or [`Display`] formatters directly.
+///
+/// This struct is very similar in nature to the standard Rust [`String`]
+/// struct, in that it is a wrapper around a vector of bytes, and so its design
+/// and function names are modelled after it. The main difference is that it
+/// does not require its contents to be valid UTF8, and also that it is a tuple
+/// struct rather than a regular struct.
+///
+/// Note that serialisation/deserialisation of this struct directly will produce
+/// and expect a [`String`], not a vector of bytes. This is because this is the
+/// most useful and fitting behaviour for the intended purpose, as with the
+/// implementations of [`Display`] and [`FromStr`]. As noted above, this is
+/// lossy if the [`ContentType`] is [`Text`](ContentType::Text) and the data is
+/// not valid UTF8, but not if set to [`Binary`](ContentType::Binary).
+/// 
+/// # See also
+/// 
+/// * [`UnpackedResponse`]
+/// 
+#[derive(Default)]
+#[non_exhaustive]
+pub struct UnpackedResponseBody {
+	//		Private properties													
+	/// The response body as a vector of bytes. The data originates from the
+	/// response body as a [`Bytes`] container, but gets stored here as a vector
+	/// of bytes for convenience. This may not be valid UTF8, so is not
+	/// converted to a [`String`]. That step is left as optional for the caller,
+	/// if required (and happens when running through the [`Debug`] or
+	/// [`Display`] formatters).
+	body:         Vec<u8>,
+	
+	/// The content type of the response body. This is used to determine how to
+	/// represent and interpret the response body when performing serialisation
+	/// and deserialisation, including for display. The default content type is
+	/// [`Text`](ContentType::Text).
+	content_type: ContentType,
+}
+
+//󰭅		UnpackedResponseBody													
+impl UnpackedResponseBody {
+	//		new																	
+	/// Creates a new response body instance.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `data` - The response body as any type for which there is a [`From`]
+	///            implementation.
+	/// 
+	pub fn new<T: Into<Self>>(data: T) -> Self {
+		data.into()
+	}
+	
+	//		content_type														
+	/// Returns the content type of the response body.
+	/// 
+	/// # See also
+	///
+	/// * [`ContentType`]
+	/// * [`UnpackedResponseBody::is_binary()`]
+	/// * [`UnpackedResponseBody::is_json()`]
+	/// * [`UnpackedResponseBody::is_text()`]
+	/// * [`UnpackedResponseBody::set_content_type()`]
+	/// 
+	#[must_use]
+	pub const fn content_type(&self) -> ContentType {
+		self.content_type
+	}
+	
+	//		set_content_type													
+	/// Sets the content type of the response body.
+	/// 
+	/// This method is chainable, as it returns a mutable reference to the
+	/// response body instance.
+	/// 
+	/// # See also
+	///
+	/// * [`ContentType`]
+	/// * [`UnpackedResponseBody::content_type()`]
+	/// 
+	pub const fn set_content_type(&mut self, content_type: ContentType) -> &mut Self {
+		self.content_type = content_type;
+		self
+	}
+	
+	//		is_binary															
+	/// Returns whether the response body is binary.
+	/// 
+	/// # See also
+	/// 
+	/// * [`ContentType`]
+	/// * [`UnpackedResponseBody::content_type()`]
+	/// * [`UnpackedResponseBody::is_json()`]
+	/// * [`UnpackedResponseBody::is_text()`]
+	/// 
+	#[must_use]
+	pub fn is_binary(&self) -> bool {
+		self.content_type == ContentType::Binary
+	}
+	
+	//		is_text																
+	/// Returns whether the response body is text.
+	/// 
+	/// # See also
+	/// 
+	/// * [`ContentType`]
+	/// * [`UnpackedResponseBody::content_type()`]
+	/// * [`UnpackedResponseBody::is_binary()`]
+	/// * [`UnpackedResponseBody::is_json()`]
+	/// 
+	#[must_use]
+	pub fn is_text(&self) -> bool {
+		self.content_type == ContentType::Text
+	}
+	
+	//		is_json																
+	/// Returns whether the response body is JSON.
+	/// 
+	/// # See also
+	/// 
+	/// * [`ContentType`]
+	/// * [`UnpackedResponseBody::content_type()`]
+	/// * [`UnpackedResponseBody::is_text()`]
+	/// 
+	#[must_use]
+	pub fn is_json(&self) -> bool {
+		self.content_type == ContentType::Json
+	}
+	
+	//		sniff_mime_type														
+	/// Returns the MIME type of the response body, detected from its contents.
+	/// 
+	/// This checks the "magic bytes" at the start of the body, to recognise
+	/// PNG, JPEG, PDF, gzip, and zip data. Failing that, leading whitespace
+	/// and any UTF8 byte order mark are skipped, and the body is recognised as
+	/// XML if it starts with an XML declaration, or as JSON if it is a valid
+	/// JSON object or array. This is useful when a response does not have a
+	/// `content-type` header, or when the header is suspected to be wrong.
+	/// 
+	/// The declared [`ContentType`] of the body is not considered. The
+	/// detected MIME type can be mapped to a content type using
+	/// [`UnpackOptions::content_type_for()`].
+	/// 
+	/// # Returns
+	/// 
+	/// The detected MIME type, or [`None`] if the body is not recognised.
+	/// 
+	#[must_use]
+	pub fn sniff_mime_type(&self) -> Option<&'static str> {
+		const SIGNATURES: [(&[u8], &str); 7] = [
+			(b"\x89PNG\r\n\x1A\n", "image/png"),
+			(b"\xFF\xD8\xFF",         "image/jpeg"),
+			(b"%PDF-",                 "application/pdf"),
+			(b"\x1F\x8B",             "application/gzip"),
+			(b"PK\x03\x04",            "application/zip"),
+			(b"PK\x05\x06",            "application/zip"),
+			(b"PK\x07\x08",            "application/zip"),
+		];
+		if let Some(&(_, mime_type)) = SIGNATURES.iter().find(|&&(magic, _)| self.body.starts_with(magic)) {
+			return Some(mime_type);
+		}
+		let text = self.body.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(&self.body).trim_ascii_start();
+		if text.starts_with(b"<?xml") {
+			Some("application/xml")
+		} else if matches!(text.first(), Some(&(b'{' | b'['))) && serde_json::from_slice::<Json>(text).is_ok() {
+			Some("application/json")
+		} else {
+			None
+		}
+	}
+	
+	//		as_bytes															
+	/// Returns a byte slice of the response body's contents.
+	/// 
+	/// Provides a read-only view of the byte data within the response body,
+	/// without consuming the data. The returned slice is a reference to the
+	/// actual data stored in the response body, not a copy. Because of this, it
+	/// is not possible to mutate the contents of the response body through the
+	/// returned slice. It does not allocate new memory or change the ownership
+	/// of the byte data. This method is useful when you need to work with the
+	/// bytes of the response body in a read-only fashion, or when you want to
+	/// avoid copying the data.
+	/// 
+	///   - This method returns a slice (`&[u8]`) referencing the bytes of the
+	///     response body contents.
+	///   - The original response body value remains intact, and can still be
+	///     used afterward.
+	///   - No reallocation or copying of data occurs since it's just providing
+	///     a view into the original memory.
+	/// 
+	/// Use this method when you need to work with the byte data in a
+	/// non-destructive, read-only manner while keeping the original response
+	/// body intact.
+	///
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::as_mut_bytes()`]
+	/// * [`UnpackedResponseBody::into_bytes()`]
+	/// * [`UnpackedResponseBody::to_bytes()`]
+	/// 
+	#[must_use]
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.body
+	}
+	
+	//		as_mut_bytes														
+	/// Returns a mutable referenced to the response body's contents.
+	/// 
+	/// Provides a mutable view of the byte data within the response body,
+	/// without consuming the data. The returned vector is a reference to the
+	/// actual data stored in the response body, not a copy. This method is
+	/// useful when you need to work with, and modify, the bytes of the response
+	/// body directly, without copying the data.
+	/// 
+	///   - This method returns a mutable vector (`&mut Vec<u8>`) referencing
+	///     the bytes of the response body contents.
+	///   - The original response body value remains intact, and can still be
+	///     used afterward.
+	///   - No reallocation or copying of data occurs since it's just providing
+	///     a reference to the original memory.
+	/// 
+	/// Use this method when you need to work directly with the byte data in a
+	/// mutable manner.
+	/// 
+	/// Note that unlike the function's [`String::as_mut_vec()`] counterpart,
+	/// this method is not unsafe. This is because the response body is not
+	/// required to be valid UTF8, so there is no risk of invalid UTF8 being
+	/// created.
+	/// 
+	/// Note also that a better name for this method could be `as_mut_vec()`,
+	/// which would be consistent with the standard library's
+	/// [`String::as_mut_vec()`] method, which this method is modelled after,
+	/// but that would break consistency with the other methods on this struct.
+	/// In addition, there is another method called [`str::as_bytes_mut()`],
+	/// which appears to be named quite inconsistently with other comparable
+	/// methods, and so calling this method `as_mut_bytes()` might cause
+	/// confusion, but is at least self-consistent.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::as_bytes()`]
+	/// * [`UnpackedResponseBody::into_bytes()`]
+	/// * [`UnpackedResponseBody::to_bytes()`]
+	/// 
+	pub const fn as_mut_bytes(&mut self) -> &mut Vec<u8> {
+		&mut self.body
+	}
+	
+	//		into_bytes															
+	/// Returns the response body as a vector of bytes.
+	/// 
+	/// This consumes the response body, without cloning or copying, and returns
+	/// a new vector containing the bytes of the response body. It transfers
+	/// ownership of the byte data from the response body to the new vector.
+	/// This method is useful when you need to move the byte data out of the
+	/// response body, for example to pass it to a function that expects a
+	/// `Vec<u8>`, or when you want to modify the byte data in-place without
+	/// affecting the original response body.
+	/// 
+	///   - This method consumes the response body contents and returns a
+	///     `Vec<u8>` containing its bytes.
+	///   - After calling this method, the original response body value is no
+	///     longer available for use, because it has been moved.
+	///   - Transforms the response body into a vector of bytes without any
+	///     copying.
+	/// 
+	/// Use this method when you want to consume the response body and obtain
+	/// ownership of its byte data in the form of a `Vec<u8>`. This is useful
+	/// when you need to modify or move the byte data, or when you want to pass
+	/// it to functions that expect a `Vec<u8>`.
+	/// 
+	/// Note that a better name for this method might be `into_vec()`, but that
+	/// would be inconsistent with the standard library's
+	/// [`String::into_bytes()`] method, which this method is modelled after.
+	///
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::as_bytes()`]
+	/// * [`UnpackedResponseBody::as_mut_bytes()`]
+	/// * [`UnpackedResponseBody::to_bytes()`]
+	/// 
+	#[must_use]
+	pub fn into_bytes(self) -> Vec<u8> {
+		self.body
+	}
+	
+	//		to_bytes															
+	/// Returns a copy of the response body data converted to a vector of bytes.
+	/// 
+	/// This does not consume the response body, but clones it. Following Rust's
+	/// naming conventions and idioms, this method "converts" the data content
+	/// of the response body into a byte representation, in a `Vec<u8>`. (No
+	/// actual conversion takes place because the data is already stored
+	/// internally as a vector of bytes, but this is academic and could change
+	/// in future, so "conversion" is implied and expected as a theoretical
+	/// behaviour.) Ownership of the cloned and converted byte data is
+	/// transferred to the caller, and there are no side effects on the internal
+	/// state of the [`UnpackedResponseBody`] instance.
+	/// 
+	///   - This method returns a `Vec<u8>` vector of bytes without consuming
+	///     the response body contents.
+	///   - The original response body value remains intact, and can still be
+	///     used afterward.
+	///   - The response body data is copied, and converted/transformed into
+	///     the output value returned.
+	/// 
+	/// Use this method when you need to obtain a copy of the response body's
+	/// byte data in the form of a `Vec<u8>`, without consuming the response
+	/// body itself. This is useful when you need to pass the byte data to a
+	/// function that expects a `Vec<u8>`, or when you want to modify the byte
+	/// data without affecting the original response body.
+	/// 
+	/// Note that a better name for this method might be `to_vec()`, but that
+	/// would be inconsistent with the standard library's
+	/// [`String::into_bytes()`] method.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::as_bytes()`]
+	/// * [`UnpackedResponseBody::as_mut_bytes()`]
+	/// * [`UnpackedResponseBody::into_bytes()`]
+	/// 
+	#[must_use]
+	pub fn to_bytes(&self) -> Vec<u8> {
+		self.body.clone()
+	}
+	
+	//		to_base64															
+	/// Returns the response body data converted to a base64-encoded [`String`].
+	/// 
+	/// This does not consume the response body, but clones it, as is necessary
+	/// to perform the conversion to base64. It converts straight from bytes to
+	/// base64, without converting to a [`String`] first, because the response
+	/// body is binary data.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::from_base64()`]
+	/// 
+	#[must_use]
+	pub fn to_base64(&self) -> String {
+		BASE64.encode(&self.body)
+	}
+	
+	//		from_base64															
+	/// Converts a base64-encoded [`String`] to an [`UnpackedResponseBody`].
+	/// 
+	/// This method does not consume the input string, but clones it, as is
+	/// necessary to perform the conversion from [`base64`]. It converts
+	/// straight from base64 to bytes, without converting to a [`String`] first,
+	/// because the response body is binary data. This means that no UTF8
+	/// validation is performed.
+	/// 
+	/// Note that unlike the [`From`] type conversion implementations, this
+	/// returns a [`Result`].
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the input string is not valid
+	/// base64. Such an error will be returned as a [`DecodeError`], which is
+	/// passed through from the [`base64`] crate.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::to_base64()`]
+	/// 
+	pub fn from_base64(encoded: &str) -> Result<Self, DecodeError> {
+		let decoded = BASE64.decode(encoded)?;
+		Ok(Self { body: decoded, content_type: ContentType::Binary })
+	}
+	
+	//		hexdump																
+	/// Returns the response body data formatted as a hexdump.
+	/// 
+	/// This is far easier to read than base64 when debugging binary protocols.
+	/// The format is the same as that of `hexdump -C`, with each line showing
+	/// the offset, followed by up to 16 bytes in hexadecimal, in two groups of
+	/// 8, and then the same bytes as ASCII, with non-printable characters
+	/// shown as `.`. Unlike `hexdump`, repeated lines are not collapsed, and
+	/// there is no final line giving the length. An empty body produces an
+	/// empty string.
+	/// 
+	/// # Examples
+	/// 
+	/// ```text
+	/// 00000000  89 50 4e 47 0d 0a 1a 0a  00 00 00 0d 49 48 44 52  |.PNG........IHDR|
+	/// 00000010  00 00 00 01                                       |....|
+	/// ```
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::to_base64()`]
+	/// 
+	#[must_use]
+	pub fn hexdump(&self) -> String {
+		let mut dump = String::new();
+		for (index, chunk) in self.body.chunks(16).enumerate() {
+			_ = write!(dump, "{:08x} ", index.saturating_mul(16));
+			for position in 0..16 {
+				if position == 8 {
+					dump.push(' ');
+				}
+				if let Some(byte) = chunk.get(position) {
+					_ = write!(dump, " {byte:02x}");
+				} else {
+					dump.push_str("   ");
+				}
+			}
+			dump.push_str("  |");
+			dump.extend(chunk.iter().map(|&byte| if byte.is_ascii_graphic() || byte == b' ' { char::from(byte) } else { '.' }));
+			dump.push_str("|\n");
+		}
+		dump
+	}
+	
+	//		decode_text															
+	/// Returns the response body data decoded as text using a charset.
+	/// 
+	/// This allows bodies that are not in UTF8 to be read correctly. The
+	/// supported charsets are UTF8, US-ASCII, ISO-8859-1 (Latin-1), and UTF-16,
+	/// in either byte order. Charset names are not case-sensitive, and common
+	/// aliases such as `latin1` are accepted. A leading byte order mark is
+	/// removed. For `utf-16`, the byte order is determined from the byte order
+	/// mark, defaulting to big-endian if there is none.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `charset` - The name of the charset, as used in the `charset`
+	///               parameter of a `content-type` header.
+	/// 
+	/// # Errors
+	/// 
+	/// * [`CharsetError::Invalid`] if the body data is not valid in the
+	///   charset.
+	/// * [`CharsetError::Unsupported`] if the charset is not supported.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponse::text()`]
+	/// 
+	pub fn decode_text(&self, charset: &str) -> Result<String, CharsetError> {
+		let name    = charset.trim().to_ascii_lowercase();
+		let decoded = match name.as_str() {
+			"utf-8" | "utf8"                           => {
+				from_utf8(self.body.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(&self.body)).ok().map(ToOwned::to_owned)
+			},
+			"us-ascii" | "ascii"                       => {
+				self.body.is_ascii().then(|| String::from_utf8_lossy(&self.body).into_owned())
+			},
+			"iso-8859-1" | "latin1" | "latin-1" | "l1" => {
+				Some(self.body.iter().map(|&byte| char::from(byte)).collect())
+			},
+			"utf-16"                                   => decode_utf16(&self.body, None),
+			"utf-16be"                                 => decode_utf16(&self.body, Some(true)),
+			"utf-16le"                                 => decode_utf16(&self.body, Some(false)),
+			_                                          => return Err(CharsetError::Unsupported(charset.to_owned())),
+		};
+		decoded.ok_or_else(|| CharsetError::Invalid(charset.to_owned()))
+	}
+	
+	//		as_json																
+	/// Returns the response body data parsed as a JSON value.
+	/// 
+	/// This does not consume the response body. The data is parsed regardless
+	/// of the content type, so this can be used to interrogate any body that
+	/// contains JSON.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the response body is not valid
+	/// JSON. Such an error will be returned as a [`JsonError`], which is passed
+	/// through from the [`serde_json`] crate.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::from_json()`]
+	/// * [`UnpackedResponseBody::to_json()`]
+	/// 
+	pub fn as_json(&self) -> Result<Json, JsonError> {
+		serde_json::from_slice(&self.body)
+	}
+	
+	//		to_json																
+	/// Returns the response body data deserialised into a specific type.
+	/// 
+	/// This does not consume the response body. The data is parsed as JSON
+	/// and deserialised into the requested type, regardless of the content
+	/// type of the response body.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the response body is not valid
+	/// JSON, or does not match the structure of the requested type. Such an
+	/// error will be returned as a [`JsonError`], which is passed through from
+	/// the [`serde_json`] crate.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::as_json()`]
+	/// * [`UnpackedResponseBody::from_json()`]
+	/// 
+	pub fn to_json<T: DeserializeOwned>(&self) -> Result<T, JsonError> {
+		serde_json::from_slice(&self.body)
+	}
+	
+	//		as_ndjson															
+	/// Returns the response body data parsed as newline-delimited JSON.
+	/// 
+	/// This does not consume the response body. Each non-blank line is parsed
+	/// as a separate JSON value, regardless of the content type, so this can be
+	/// used to interrogate streamed `application/x-ndjson` or JSON Lines
+	/// bodies.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if any line is not valid JSON. Such an
+	/// error will be returned as a [`JsonError`], which is passed through from
+	/// the [`serde_json`] crate.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::as_json()`]
+	/// * [`UnpackedResponseBody::to_ndjson()`]
+	/// 
+	pub fn as_ndjson(&self) -> Result<Vec<Json>, JsonError> {
+		self.to_ndjson()
+	}
+	
+	//		to_ndjson															
+	/// Returns the response body data deserialised from newline-delimited JSON
+	/// into a specific type.
+	/// 
+	/// This does not consume the response body. Each non-blank line is parsed
+	/// as JSON and deserialised into the requested type, regardless of the
+	/// content type of the response body.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if any line is not valid JSON, or does
+	/// not match the structure of the requested type. Such an error will be
+	/// returned as a [`JsonError`], which is passed through from the
+	/// [`serde_json`] crate.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::as_ndjson()`]
+	/// * [`UnpackedResponseBody::to_json()`]
+	/// 
+	pub fn to_ndjson<T: DeserializeOwned>(&self) -> Result<Vec<T>, JsonError> {
+		self.body
+			.split(|&byte| byte == b'\n')
+			.filter(|line| !line.trim_ascii().is_empty())
+			.map(serde_json::from_slice)
+			.collect()
+	}
+	
+	//		as_sse_events														
+	/// Returns the response body data parsed as Server-Sent Events.
+	/// 
+	/// This does not consume the response body. The data is parsed as a
+	/// `text/event-stream`, regardless of the content type, and if it is not
+	/// valid UTF8 then it is converted lossily. Comments are ignored, as are
+	/// events without any data, and any incomplete event at the end of the
+	/// body that has not been terminated by a blank line.
+	/// 
+	/// # See also
+	/// 
+	/// * [`ServerSentEvent`]
+	/// 
+	#[must_use]
+	pub fn as_sse_events(&self) -> Vec<ServerSentEvent> {
+		parse_sse_events(&String::from_utf8_lossy(&self.body))
+	}
+	
+	//		as_multipart														
+	/// Returns the response body parsed as a multipart body.
+	/// 
+	/// This does not consume the response body. Any preamble before the first
+	/// boundary delimiter, and any epilogue after the last, is ignored. Lines
+	/// may be terminated with either CRLF or LF. The [`ContentType`] of each
+	/// part is detected from its `content-type` header using the default
+	/// [`UnpackOptions`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `boundary` - The boundary that separates the parts, as specified in
+	///                the `content-type` header of the response.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the boundary is empty or not found,
+	/// if the body is not terminated by a closing delimiter, or if a part
+	/// contains an invalid header.
+	/// 
+	/// # See also
+	/// 
+	/// * [`MultipartBuilder`]
+	/// * [`MultipartPart`]
+	/// * [`UnpackedResponse::multipart()`]
+	/// 
+	pub fn as_multipart(&self, boundary: &str) -> Result<Vec<MultipartPart>, MultipartError> {
+		parse_multipart(&self.body, boundary, &UnpackOptions::default())
+	}
+	
+	//		from_json															
+	/// Converts any serialisable value to an [`UnpackedResponseBody`].
+	/// 
+	/// The value is serialised to JSON, and the content type of the resulting
+	/// response body is set to [`Json`](ContentType::Json).
+	/// 
+	/// Note that unlike the [`From`] type conversion implementations, this
+	/// returns a [`Result`].
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the value cannot be serialised to
+	/// JSON. Such an error will be returned as a [`JsonError`], which is passed
+	/// through from the [`serde_json`] crate.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::as_json()`]
+	/// * [`UnpackedResponseBody::to_json()`]
+	/// 
+	pub fn from_json<T: Serialize + ?Sized>(value: &T) -> Result<Self, JsonError> {
+		Ok(Self { body: serde_json::to_vec(value)?, content_type: ContentType::Json })
+	}
+	
+	//		as_form																
+	/// Returns the response body data parsed as form-urlencoded pairs.
+	/// 
+	/// This does not consume the response body. The data is parsed as
+	/// `application/x-www-form-urlencoded`, regardless of the content type,
+	/// and the names and values are percent-decoded. The pairs are returned in
+	/// the order in which they appear, and repeated names are retained, so
+	/// that the result can be compared reliably.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the response body cannot be
+	/// decoded. Such an error will be returned as a [`FormDeError`], which is
+	/// passed through from the [`serde_urlencoded`] crate.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::from_form()`]
+	/// * [`UnpackedResponseBody::to_form()`]
+	/// 
+	pub fn as_form(&self) -> Result<Vec<(String, String)>, FormDeError> {
+		serde_urlencoded::from_bytes(&self.body)
+	}
+	
+	//		to_form																
+	/// Returns the response body data deserialised into a specific type.
+	/// 
+	/// This does not consume the response body. The data is parsed as
+	/// `application/x-www-form-urlencoded` and deserialised into the requested
+	/// type, regardless of the content type of the response body. Note that
+	/// only flat structures are supported by this format.
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the response body cannot be
+	/// decoded, or does not match the structure of the requested type. Such an
+	/// error will be returned as a [`FormDeError`], which is passed through
+	/// from the [`serde_urlencoded`] crate.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::as_form()`]
+	/// * [`UnpackedResponseBody::from_form()`]
+	/// 
+	pub fn to_form<T: DeserializeOwned>(&self) -> Result<T, FormDeError> {
+		serde_urlencoded::from_bytes(&self.body)
+	}
+	
+	//		from_form															
+	/// Converts any serialisable value to a form-urlencoded response body.
+	/// 
+	/// The value is serialised as `application/x-www-form-urlencoded`. This
+	/// accepts any flat structure, such as a struct or map, and also a list of
+	/// name-value pairs, e.g. `&[("name", "Alice")]`, which allows the order
+	/// and repetition of names to be controlled. The content type of the
+	/// resulting response body is set to [`Text`](ContentType::Text).
+	/// 
+	/// # Errors
+	/// 
+	/// This method will return an error if the value cannot be serialised, for
+	/// example because it contains nested structures. Such an error will be
+	/// returned as a [`FormSerError`], which is passed through from the
+	/// [`serde_urlencoded`] crate.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::as_form()`]
+	/// * [`UnpackedResponseBody::to_form()`]
+	/// 
+	pub fn from_form<T: Serialize + ?Sized>(value: &T) -> Result<Self, FormSerError> {
+		Ok(Self { body: serde_urlencoded::to_string(value)?.into_bytes(), content_type: ContentType::Text })
+	}
+	
+	//		compress															
+	/// Compresses the body using the specified content encoding.
+	/// 
+	/// This returns a new body containing the compressed data, with a content
+	/// type of [`Binary`](ContentType::Binary). It is the reverse of
+	/// [`decompress()`](UnpackedResponseBody::decompress()), and can be used to
+	/// build the body of a compressed response for comparison or testing.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `encoding` - The content encoding to use.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will return an error if the body could not be compressed.
+	/// 
+	/// # See also
+	/// 
+	/// * [`ContentEncoding`]
+	/// * [`UnpackedResponseBody::decompress()`]
+	/// 
+	#[cfg(feature = "compression")]
+	pub fn compress(&self, encoding: ContentEncoding) -> Result<Self, IoError> {
+		Ok(Self { body: compress_bytes(&self.body, encoding)?, content_type: ContentType::Binary })
+	}
+	
+	//		decompress															
+	/// Decompresses the body using the specified content encoding.
+	/// 
+	/// This returns a new body containing the decompressed data. The content
+	/// type will be [`Text`](ContentType::Text) if the data is valid UTF8, and
+	/// [`Binary`](ContentType::Binary) otherwise.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `encoding` - The content encoding that the body was compressed with.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will return an error if the body could not be
+	/// decompressed, for instance if it is not validly-encoded.
+	/// 
+	/// # See also
+	/// 
+	/// * [`ContentEncoding`]
+	/// * [`UnpackedResponseBody::compress()`]
+	/// 
+	#[cfg(feature = "compression")]
+	pub fn decompress(&self, encoding: ContentEncoding) -> Result<Self, IoError> {
+		let body         = decompress_bytes(&self.body, encoding, usize::MAX)?;
+		let content_type = if from_utf8(&body).is_ok() { ContentType::Text } else { ContentType::Binary };
+		Ok(Self { body, content_type })
+	}
+	
+	//		diff																
+	/// Returns the differences between this response body and another.
+	/// 
+	/// If the bodies are identical, [`None`] is returned. Otherwise, the form
+	/// of the difference depends upon the content. If either body is
+	/// [`Binary`](ContentType::Binary), the first differing byte offset is
+	/// reported. If both bodies can be parsed as JSON, and the parsed values
+	/// differ, the differences are reported by JSON path. Otherwise the bodies
+	/// are compared as text, line by line, using a lossy conversion from UTF8.
+	/// If the bodies differ in a way that none of these comparisons can show,
+	/// such as in whitespace within JSON or in line endings, then the first
+	/// differing byte offset is reported.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The response body to compare against.
+	/// 
+	/// # See also
+	/// 
+	/// * [`BodyDiff`]
+	/// * [`UnpackedResponse::diff()`]
+	/// 
+	#[must_use]
+	pub fn diff(&self, other: &Self) -> Option<BodyDiff> {
+		if self == other {
+			return None;
+		}
+		if !self.is_binary() && !other.is_binary() {
+			if let (Ok(left), Ok(right)) = (serde_json::from_slice::<Json>(&self.body), serde_json::from_slice::<Json>(&other.body)) {
+				let mut diffs = vec![];
+				diff_json("$", &left, &right, &mut diffs);
+				if !diffs.is_empty() {
+					return Some(BodyDiff::Json(diffs));
+				}
+			}
+			let lines = diff_lines(&String::from_utf8_lossy(&self.body), &String::from_utf8_lossy(&other.body));
+			if !lines.is_empty() {
+				return Some(BodyDiff::Text(lines));
+			}
+		}
+		//	Binary content, or differences that are not visible at the JSON or
+		//	line level, such as whitespace or line endings, are reported by byte
+		let offset = self.body.iter().zip(other.body.iter()).take_while(|&(l, r)| l == r).count();
+		Some(BodyDiff::Binary { offset, left_len: self.len(), right_len: other.len() })
+	}
+	
+	//		clear																
+	/// Removes all contents from the response body.
+	/// 
+	/// This method removes all data from the response body, resetting it to an
+	/// empty state. This method has no effect on the capacity of the response
+	/// body, and so does not affect any allocation.
+	/// 
+	pub fn clear(&mut self) {
+		self.body.clear();
+	}
+	
+	//		empty																
+	/// Returns an empty response body.
+	/// 
+	/// This method returns an empty response body. This is equivalent to
+	/// creating a new response body with [`UnpackedResponseBody::new()`], but
+	/// without having to supply any parameters.
+	/// 
+	#[must_use]
+	pub fn empty() -> Self {
+		Self { body: Vec::new(), ..Default::default() }
+	}
+	
+	//		is_empty															
+	/// Returns whether the response body is empty.
+	/// 
+	/// This method returns whether the response body is empty. This is
+	/// equivalent to checking whether the length of the response body is zero.
+	/// 
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.body.is_empty()
+	}
+	
+	//		len																	
+	/// Returns the length of the response body.
+	/// 
+	/// This method returns the length of the response body, in bytes. This is
+	/// equivalent to the length of the vector of bytes that the response body
+	/// contains.
+	/// 
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.body.len()
+	}
+	
+	//		push																
+	/// Appends a byte to the response body.
+	/// 
+	/// Appends a given byte onto the end of the response body's existing byte
+	/// data. The response body is not required to be valid UTF8, so this method
+	/// does not check the validity of the byte before appending it.
+	/// 
+	/// This method accepts a [`u8`] instead of a [`char`] because a [`char`]
+	/// represents a single Unicode scalar value. In Rust, a [`char`] is always
+	/// 4 bytes long because it can represent any Unicode scalar value,
+	/// including those outside the Basic Multilingual Plane. If `push()`
+	/// accepted a [`char`], it would be signaling that [`UnpackedResponseBody`]
+	/// is Unicode-aware and can handle any Unicode character — which is not the
+	/// case. A [`u8`], on the other hand, represents a single byte. By having
+	/// `push()` accept a [`u8`], it's signaling that [`UnpackedResponseBody`]
+	/// is byte-oriented. A specific [`push_char()`](UnpackedResponseBody::push_char())
+	/// method is also available, but `push()` is the most general method for
+	/// appending bytes to the response body.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `byte` - The byte to append to the response body.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::push_bytes()`]
+	/// * [`UnpackedResponseBody::push_char()`]
+	/// * [`UnpackedResponseBody::push_str()`]
+	/// 
+	pub fn push(&mut self, byte: u8) {
+		self.body.push(byte);
+	}
+	
+	//		push_bytes															
+	/// Appends a byte slice to the response body.
+	///
+	/// Appends a given byte slice onto the end of the response body. The byte
+	/// slice is appended to the end of the response body's existing byte data.
+	/// The response body is not required to be valid UTF8, so this method does
+	/// not check the validity of the byte slice before appending it.
+	///
+	/// # Parameters
+	///
+	/// * `bytes` - The byte slice to append to the response body.
+	///
+	/// # See also
+	///
+	/// * [`UnpackedResponseBody::push()`]
+	/// * [`UnpackedResponseBody::push_char()`]
+	/// * [`UnpackedResponseBody::push_str()`]
+	///
+	pub fn push_bytes(&mut self, bytes: &[u8]) {
+		self.body.extend_from_slice(bytes);
+	}
+	
+	//		push_char															
+	/// Appends a [`char`] to the response body.
+	///
+	/// Appends a given character onto the end of the response body. The
+	/// [`char`] is converted to bytes and then appended to the end of the
+	/// response body's existing byte data.
+	///
+	/// # Parameters
+	///
+	/// * `char` - The [`char`] to append to the response body.
+	///
+	/// # See also
+	///
+	/// * [`UnpackedResponseBody::push()`]
+	/// * [`UnpackedResponseBody::push_bytes()`]
+	/// * [`UnpackedResponseBody::push_str()`]
+	///
+	pub fn push_char(&mut self, char: &char) {
+		let mut bytes = [0; 4];
+		let used      = char.encode_utf8(&mut bytes).len();
+		#[expect(clippy::indexing_slicing, reason = "Infallible")]
+		self.body.extend(&bytes[..used]);
+	}
+	
+	//		push_str															
+	/// Appends a string slice to the response body.
+	/// 
+	/// Appends a given string slice onto the end of the response body. The
+	/// string slice is converted to bytes and then appended to the end of the
+	/// response body's existing byte data.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `string` - The string slice to append to the response body.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::push()`]
+	/// * [`UnpackedResponseBody::push_char()`]
+	/// * [`UnpackedResponseBody::push_bytes()`]
+	/// 
+	pub fn push_str(&mut self, string: &str) {
+		self.body.extend_from_slice(string.as_bytes());
+	}
+}
+
+//󰭅		Add &[u8]																
+impl Add<&[u8]> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds a [`&[u8]`](https://doc.rust-lang.org/std/primitive.slice.html) to
+	/// an [`UnpackedResponseBody`].
+	fn add(mut self, rhs: &[u8]) -> Self {
+		self.push_bytes(rhs);
+		self
+	}
+}
+
+//󰭅		Add &[u8; N]															
+impl<const N: usize> Add<&[u8; N]> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds a [`&[u8; N]`](https://doc.rust-lang.org/std/primitive.slice.html)
+	/// to an [`UnpackedResponseBody`].
+	fn add(mut self, rhs: &[u8; N]) -> Self {
+		self.push_bytes(rhs);
+		self
+	}
+}
+
+//󰭅		Add char																
+impl Add<char> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds a [`char`] to an [`UnpackedResponseBody`].
+	fn add(mut self, rhs: char) -> Self {
+		self.push_char(&rhs);
+		self
+	}
+}
+
+//󰭅		Add &char																
+impl Add<&char> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds a [`&char`](char) to an [`UnpackedResponseBody`].
+	fn add(mut self, rhs: &char) -> Self {
+		self.push_char(rhs);
+		self
+	}
+}
+
+//󰭅		Add &str																
+impl Add<&str> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds a [`&str`](str) to an [`UnpackedResponseBody`].
+	fn add(mut self, rhs: &str) -> Self {
+		self.push_str(rhs);
+		self
+	}
+}
+
+//󰭅		Add String																
+impl Add<String> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds a [`String`] to an [`UnpackedResponseBody`].
+	fn add(mut self, rhs: String) -> Self {
+		self.push_str(&rhs);
+		self
+	}
+}
+
+//󰭅		Add &String																
+impl Add<&String> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds a [`&String`](String) to an [`UnpackedResponseBody`].
+	fn add(mut self, rhs: &String) -> Self {
+		self.push_str(rhs);
+		self
+	}
+}
+
+//󰭅		Add Box<str>															
+impl Add<Box<str>> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds a [boxed](Box) [string](str) slice to an [`UnpackedResponseBody`].
+	fn add(mut self, rhs: Box<str>) -> Self::Output {
+		self.push_str(&rhs);
+		self
+	}
+}
+
+//󰭅		Add Cow<str>															
+impl<'a> Add<Cow<'a, str>> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds a [clone-on-write](Cow) [string](str) to an
+	/// [`UnpackedResponseBody`].
+	fn add(mut self, rhs: Cow<'a, str>) -> Self::Output {
+		self.push_str(&rhs);
+		self
+	}
+}
+
+//󰭅		Add u8																	
+impl Add<u8> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds a [`u8`] to an [`UnpackedResponseBody`].
+	fn add(mut self, rhs: u8) -> Self {
+		self.push(rhs);
+		self
+	}
+}
+
+//󰭅		Add Vec<u8>																
+impl Add<Vec<u8>> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds a [`Vec[u8]`](Vec) to an [`UnpackedResponseBody`].
+	fn add(mut self, rhs: Vec<u8>) -> Self {
+		self.push_bytes(&rhs);
+		self
+	}
+}
+
+//󰭅		Add &Vec<u8>															
+impl Add<&Vec<u8>> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds a [`&Vec[u8]`](Vec) to an [`UnpackedResponseBody`].
+	fn add(mut self, rhs: &Vec<u8>) -> Self {
+		self.push_bytes(rhs);
+		self
+	}
+}
+
+//󰭅		Add Self																
+impl Add<Self> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds an [`UnpackedResponseBody`] to an [`UnpackedResponseBody`].
+	fn add(mut self, rhs: Self) -> Self {
+		self.push_bytes(&rhs.body);
+		self
+	}
+}
+
+//󰭅		Add &Self																
+impl Add<&Self> for UnpackedResponseBody {
+	type Output = Self;
+	
+	//		add																	
+	/// Adds an [`&UnpackedResponseBody`](UnpackedResponseBody) to an
+	/// [`UnpackedResponseBody`].
+	fn add(mut self, rhs: &Self) -> Self {
+		self.push_bytes(rhs.as_bytes());
+		self
+	}
+}
+
+//󰭅		AddAssign &[u8]															
+impl AddAssign<&[u8]> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds a [`&[u8]`](https://doc.rust-lang.org/std/primitive.slice.html) to
+	/// an [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: &[u8]) {
+		self.push_bytes(rhs);
+	}
+}
+
+//󰭅		AddAssign &[u8; N]														
+impl<const N: usize> AddAssign<&[u8; N]> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds a [`&[u8; N]`](https://doc.rust-lang.org/std/primitive.slice.html)
+	/// to an [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: &[u8; N]) {
+		self.push_bytes(rhs);
+	}
+}
+
+//󰭅		AddAssign char															
+impl AddAssign<char> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds a [`char`] to an [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: char) {
+		self.push_char(&rhs);
+	}
+}
+
+//󰭅		AddAssign &char															
+impl AddAssign<&char> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds a [`&char`](char) to an [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: &char) {
+		self.push_char(rhs);
+	}
+}
+
+//󰭅		AddAssign &str															
+impl AddAssign<&str> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds a [`&str`](str) to an [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: &str) {
+		self.push_str(rhs);
+	}
+}
+
+//󰭅		AddAssign String														
+impl AddAssign<String> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds a [`String`] to an [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: String) {
+		self.push_str(&rhs);
+	}
+}
+
+//󰭅		AddAssign &String														
+impl AddAssign<&String> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds a [`&String`](String) to an [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: &String) {
+		self.push_str(rhs);
+	}
+}
+
+//󰭅		AddAssign Box<str>														
+impl AddAssign<Box<str>> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds a [boxed](Box) [string](str) slice to an [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: Box<str>) {
+		self.push_str(&rhs);
+	}
+}
+
+//󰭅		AddAssign Cow<str>														
+impl<'a> AddAssign<Cow<'a, str>> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds a [clone-on-write](Cow) [string](str) to an
+	/// [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: Cow<'a, str>){
+		self.push_str(&rhs);
+	}
+}
+
+//󰭅		AddAssign u8															
+impl AddAssign<u8> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds a [`u8`] to an [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: u8) {
+		self.push(rhs);
+	}
+}
+
+//󰭅		AddAssign Vec<u8>														
+impl AddAssign<Vec<u8>> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds a [`Vec[u8]`](Vec) to an [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: Vec<u8>) {
+		self.push_bytes(&rhs);
+	}
+}
+
+//󰭅		AddAssign &Vec<u8>														
+impl AddAssign<&Vec<u8>> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds a [`&Vec[u8]`](Vec) to an [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: &Vec<u8>) {
+		self.push_bytes(rhs);
+	}
+}
+
+//󰭅		AddAssign Self															
+impl AddAssign<Self> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds an [`UnpackedResponseBody`] to an [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: Self) {
+		self.push_bytes(&rhs.body);
+	}
+}
+
+//󰭅		AddAssign &Self															
+impl AddAssign<&Self> for UnpackedResponseBody {
+	//		add_assign															
+	/// Adds an [`&UnpackedResponseBody`](UnpackedResponseBody) to an
+	/// [`UnpackedResponseBody`].
+	fn add_assign(&mut self, rhs: &Self) {
+		self.push_bytes(rhs.as_bytes());
+	}
+}
+
+//󰭅		AsMut [u8]																
+impl AsMut<[u8]> for UnpackedResponseBody {
+	//		as_mut																
+	fn as_mut(&mut self) -> &mut [u8] {
+		self.as_mut_bytes()
+	}
+}
+
+//󰭅		AsRef [u8]																
+impl AsRef<[u8]> for UnpackedResponseBody {
+	//		as_ref																
+	fn as_ref(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
+
+//󰭅		Clone																	
+impl Clone for UnpackedResponseBody {
+	//		clone																
+	fn clone(&self) -> Self {
+		Self { body: self.body.clone(), content_type: self.content_type }
+	}
+	
+	//		clone_from															
+	fn clone_from(&mut self, source: &Self) {
+		self.body.clone_from(&source.body);
+		self.content_type = source.content_type;
+	}
+}
+
+//󰭅		Debug																	
+impl Debug for UnpackedResponseBody {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("UnpackedResponseBody")
+			.field("body",         &self.to_string())
+			.field("content_type", &self.content_type)
+			.finish()
+	}
+}
+
+//󰭅		Display																	
+impl Display for UnpackedResponseBody {
+	//		fmt																	
+	/// Formats the response body for display.
+	///
+	/// This method serialises the response body based on the content type. If
+	/// the content type is [`ContentType::Text`], then the response body is
+	/// serialised to an ordinary [`String`]. If the content type is
+	/// [`ContentType::Binary`], then the response body is serialised to a
+	/// base64-encoded [`String`]. If the content type is [`ContentType::Json`],
+	/// then the response body is pretty-printed, unless it is not valid JSON,
+	/// in which case it is treated as text.
+	///
+	/// Note that as no validation checks are performed on the response body
+	/// contents, it is not guaranteed to be UTF8, and therefore if not
+	/// specified as binary it is possible that the serialised string will not
+	/// totally match the original response body contents. This is because the
+	/// conversion of the response body bytes to a UTF8 string will be lossy if
+	/// there are invalid characters.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::serialize()`]
+	/// * [`UnpackedResponseBody::to_base64()`]
+	/// 
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let body                =  match self.content_type {
+			ContentType::Text   => String::from_utf8_lossy(&self.body),
+			ContentType::Binary => Cow::Owned(self.to_base64()),
+			ContentType::Json   => self.as_json()
+				.and_then(|json| serde_json::to_string_pretty(&json))
+				.map_or_else(|_| String::from_utf8_lossy(&self.body), Cow::Owned)
+			,
+		};
+		write!(f, "{body}")
+	}
+}
+
+//󰭅		From &[u8]																
+impl From<&[u8]> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`&[u8]`](https://doc.rust-lang.org/std/primitive.slice.html)
+	/// to an [`UnpackedResponseBody`].
+	fn from(b: &[u8]) -> Self {
+		Self { body: b.to_vec(), ..Default::default() }
+	}
+}
+
+//󰭅		From &[u8; N]															
+impl<const N: usize> From<&[u8; N]> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`&[u8; N]`](https://doc.rust-lang.org/std/primitive.slice.html)
+	/// to an [`UnpackedResponseBody`].
+	fn from(b: &[u8; N]) -> Self {
+		Self { body: b.to_vec(), ..Default::default() }
+	}
+}
+
+//󰭅		From char																
+impl From<char> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`char`] to an [`UnpackedResponseBody`].
+	/// 
+	/// Note that it does this in the way that is most compatible with
+	/// [`String`] conversion. The [`char`] type in Rust represents a Unicode
+	/// scalar value. That means a single [`char`] value corresponds to one
+	/// Unicode character. But Unicode characters can have a wide range of
+	/// values, from `0` to `0x10FFFF` (this range excludes the surrogate
+	/// pairs), and this value range doesn't fit into a single byte. That's why
+	/// [`char`] in Rust is 4 bytes, because it has to accommodate any possible
+	/// Unicode scalar value. The UTF-8 encoded representation of the `ñ`
+	/// character is `[195, 177]`, but in memory, a [`char`] containing `'ñ'`
+	/// does not hold the bytes `[195, 177]`. Instead, it holds the Unicode
+	/// scalar value for `ñ`, which is `U+00F1`, or in integer terms, `241`.
+	/// When we convert a [`char`] to a [`u32`] directly, we're taking this
+	/// scalar value (like `241` for `ñ`) and representing it in memory as a
+	/// 4-byte integer. So using code such as
+	/// `(c as u32).to_le_bytes().to_vec()` would result in [241, 0, 0, 0], and
+	/// not [195, 177]. This behaviour would not match expectation and would not
+	/// match the behaviour of [`String`] conversion. To get the UTF-8 encoded
+	/// bytes of a [`char`], we need to use encoding methods because we're
+	/// effectively translating from the Unicode scalar value to its UTF-8 byte
+	/// sequence. This is what the [`encode_utf8()`](char::encode_utf8()) method
+	/// provides. To put it another way: [`char`] isn't storing bytes, it's
+	/// storing a Unicode scalar value. UTF-8 is one of the ways to represent
+	/// that value (and the most common one in Rust).
+	/// 
+	fn from(c: char) -> Self {
+		let mut bytes = [0; 4];
+		let used      = c.encode_utf8(&mut bytes).len();
+		#[expect(clippy::indexing_slicing, reason = "Infallible")]
+		Self { body: bytes[..used].to_vec(), ..Default::default() }
+	}
+}
+
+//󰭅		From &char																
+impl From<&char> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`&char`](char) to an [`UnpackedResponseBody`].
+	fn from(c: &char) -> Self {
+		Self::from(c.to_owned())
+	}
+}
+
+//󰭅		From AxumBody															
+#[cfg(feature = "axum")]
+impl From<AxumBody> for UnpackedResponseBody {
+	//		from																
+	fn from(b: AxumBody) -> Self {
+		Self { body: executor::block_on(to_bytes(b, usize::MAX)).unwrap_or_default().to_vec(), ..Default::default() }
+	}
+}
+
+//󰭅		From Full<Bytes>														
+impl From<Full<Bytes>> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`Full<Bytes>`](Full) to an [`UnpackedResponseBody`].
+	fn from(b: Full<Bytes>) -> Self {
+		//	Collect the body into Collected<Bytes>
+		let collected = b.collect().now_or_never()
+			.unwrap_or_else(|| Ok(Collected::default()))
+			.unwrap_or_else(|_| Collected::default())
+		;
+		Self { body: collected.to_bytes().to_vec(), ..Default::default() }
+	}
+}
+
+//󰭅		From Incoming															
+impl From<Incoming> for UnpackedResponseBody {
+	//		from																
+	/// Converts an [`Incoming`] to an [`UnpackedResponseBody`].
+	fn from(b: Incoming) -> Self {
+		//	Collect the body into Collected<Bytes>
+		let collected = executor::block_on(b.collect()).unwrap_or_else(|_| Collected::default());
+		Self { body: collected.to_bytes().to_vec(), ..Default::default() }
+	}
+}
+
+//󰭅		From Json																
+impl From<Json> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`serde_json::Value`] to an [`UnpackedResponseBody`].
+	fn from(j: Json) -> Self {
+		Self { body: j.to_string().into_bytes(), ..Default::default() }
+	}
+}
+
+//󰭅		From &Json																
+impl From<&Json> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`&serde_json::Value`](serde_json::Value) to an
+	/// [`UnpackedResponseBody`].
+	fn from(j: &Json) -> Self {
+		Self { body: j.to_string().into_bytes(), ..Default::default() }
+	}
+}
+
+//󰭅		From &str																
+impl From<&str> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`&str`](str) to an [`UnpackedResponseBody`].
+	fn from(s: &str) -> Self {
+		Self { body: s.to_owned().as_bytes().to_vec(), ..Default::default() }
+	}
+}
+
+//󰭅		From &mut str															
+impl From<&mut str> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`&mut str`](str) to an [`UnpackedResponseBody`].
+	fn from(s: &mut str) -> Self {
+		Self { body: s.to_owned().as_bytes().to_vec(), ..Default::default() }
+	}
+}
+
+//󰭅		From String																
+impl From<String> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`String`] to an [`UnpackedResponseBody`].
+	fn from(s: String) -> Self {
+		Self { body: s.into_bytes(), ..Default::default() }
+	}
+}
+
+//󰭅		From &String															
+impl From<&String> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`&String`](String) to an [`UnpackedResponseBody`].
+	fn from(s: &String) -> Self {
+		Self { body: s.as_str().as_bytes().to_vec(), ..Default::default() }
+	}
+}
+
+//󰭅		From Box<str>															
+impl From<Box<str>> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [boxed](Box) [string](str) slice to an
+	/// [`UnpackedResponseBody`].
+	fn from(s: Box<str>) -> Self {
+		Self { body: s.into_string().into_bytes(), ..Default::default() }
+	}
+}
+
+//󰭅		From Cow<str>															
+impl<'a> From<Cow<'a, str>> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [clone-on-write](Cow) [string](str) to an
+	/// [`UnpackedResponseBody`].
+	fn from(s: Cow<'a, str>) -> Self {
+		Self { body: s.into_owned().into_bytes(), ..Default::default() }
+	}
+}
+
+//󰭅		From u8																	
+impl From<u8> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`u8`] to an [`UnpackedResponseBody`].
+	fn from(c: u8) -> Self {
+		Self { body: Vec::from([c]), ..Default::default() }
+	}
+}
+
+//󰭅		From Vec<u8>															
+impl From<Vec<u8>> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`Vec[u8]`](Vec) to an [`UnpackedResponseBody`].
+	fn from(v: Vec<u8>) -> Self {
+		Self { body: v, ..Default::default() }
+	}
+}
+
+//󰭅		From &Vec<u8>															
+impl From<&Vec<u8>> for UnpackedResponseBody {
+	//		from																
+	/// Converts a [`&Vec[u8]`](Vec) to an [`UnpackedResponseBody`].
+	fn from(v: &Vec<u8>) -> Self {
+		Self { body: v.clone(), ..Default::default() }
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for UnpackedResponseBody {
+	type Err = Infallible;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(Self { body: s.as_bytes().to_vec(), ..Default::default() })
+	}
+}
+
+//󰭅		PartialEq																
+impl PartialEq for UnpackedResponseBody {
+	//		eq																	
+	/// Compares two response bodies for equality.
+	/// 
+	/// The bodies are compared byte for byte, unless either of them has a
+	/// content type of [`ContentType::Json`] and both can be parsed as JSON, in
+	/// which case they are compared semantically. This means that differences
+	/// in key order and whitespace are ignored.
+	/// 
+	fn eq(&self, other: &Self) -> bool {
+		if self.is_json() || other.is_json() {
+			if let (Ok(left), Ok(right)) = (self.as_json(), other.as_json()) {
+				return left == right;
+			}
+		}
+		self.body == other.body
+	}
+}
+
+//󰭅		Serialize																
+impl Serialize for UnpackedResponseBody {
+	//		serialize															
+	/// Serialises the response body to a [`String`].
+	/// 
+	/// This method serialises the response body based on the content type. If
+	/// the content type is [`ContentType::Text`], then the response body is
+	/// serialised to an ordinary [`String`]. If the content type is
+	/// [`ContentType::Binary`], then the response body is serialised to a
+	/// base64-encoded [`String`]. If the content type is [`ContentType::Json`],
+	/// then the response body is serialised to an ordinary [`String`] as-is,
+	/// i.e. without the pretty-printing that is applied for display.
+	/// 
+	/// Note that as no validation checks are performed on the response body
+	/// contents, it is not guaranteed to be UTF8, and therefore if not
+	/// specified as binary it is possible that the serialised string will not
+	/// totally match the original response body contents. This is because the
+	/// conversion of the response body bytes to a UTF8 string will be lossy if
+	/// there are invalid characters.
+	/// 
+	/// For a serialised form that records the content type explicitly, and
+	/// round-trips losslessly, use [`serialize_tagged_body()`] instead, or
+	/// [`serialize_tagged_response()`] for a whole response.
+	/// 
+	/// # See also
+	/// 
+	/// * [`UnpackedResponseBody::deserialize()`]
+	/// * [`UnpackedResponseBody::<Display>fmt()`]
+	/// * [`UnpackedResponseBody::to_base64()`]
+	/// * [`serialize_tagged_body()`]
+	/// 
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		match self.content_type {
+			ContentType::Text | ContentType::Json => serializer.serialize_str(&String::from_utf8_lossy(&self.body)),
+			ContentType::Binary                   => serializer.serialize_str(&self.to_base64()),
+		}
+	}
+}
+
+//󰭅		Deserialize																
+impl <'de> Deserialize<'de> for UnpackedResponseBody {
+	//		deserialize															
+	/// Deserialises the response body from a [`String`].
+	/// 
+	/// This method deserialises the response body based on the content type.
+	/// However, as this method is not an instance method and it is not possible
+	/// to specify the content type in advance, it has to try to detect it. It
+	/// does this by attempting to decode the string as base64. If this succeeds
+	/// then it will set the content type as [`ContentType::Binary`]. If this
+	/// fails then it will assume the content type is [`ContentType::Text`], and
+	/// deserialises the string in standard fashion.
+	/// 
+	/// Note that as the incoming data is from a [`String`], and Rust strings
+	/// are are all valid UTF8, the resulting deserialised response body is
+	/// guaranteed to be UTF8 if the content type is determined to be
+	/// [`ContentType::Text`]. If base64 is detected then the deserialised bytes
+	/// are not guaranteed to be valid UTF8, as no validation checks of that
+	/// nature are performed against the response body.
+	/// 
+	/// Because of this detection, text that happens to be valid base64, such
+	/// as `"test"`, will be deserialised as [`ContentType::Binary`]. To avoid
+	/// this, use [`serialize_tagged_body()`] and [`deserialize_tagged_body()`],
+	/// which record the content type explicitly.
+	///
+	/// # See also
+	///
+	/// * [`UnpackedResponseBody::deserialize()`]
+	/// * [`UnpackedResponseBody::from_base64()`]
+	/// * [`deserialize_tagged_body()`]
+	///
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let string = String::deserialize(deserializer)?;
+		#[expect(clippy::option_if_let_else, reason = "Using map_or_else() here would not be as clear, and no more concise")]
+		match BASE64.decode(&string) {
+			Ok(decoded) => Ok(Self { body: decoded,             content_type: ContentType::Binary }),
+			Err(_)      => Ok(Self { body: string.into_bytes(), content_type: ContentType::Text }),
+		}
+	}
+}
+
+//󰭅		Write																	
+impl Write for UnpackedResponseBody {
+	//		write_str															
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.push_str(s);
+		Ok(())
+	}
+}
+
+//		UnpackedResponseDiff													
+/// The differences between two unpacked responses.
+/// 
+/// This is returned by [`UnpackedResponse::diff()`], and provides a structured
+/// report of what differs between two responses. The [`Display`]
+/// implementation provides a human-readable form, which is what is printed by
+/// the [`assert_response_eq!`](crate::assert_response_eq) macro.
+/// 
+/// # See also
+/// 
+/// * [`BodyDiff`]
+/// * [`HeaderDiff`]
+/// * [`UnpackedResponse::diff()`]
+/// 
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct UnpackedResponseDiff {
+	//		Public properties													
+	/// The left-hand and right-hand status codes, if they differ.
+	pub status:  Option<(StatusCode, StatusCode)>,
+	
+	/// The differences in the headers, if any.
+	pub headers: Vec<HeaderDiff>,
+	
+	/// The difference in the body, if any.
+	pub body:    Option<BodyDiff>,
+}
+
+//󰭅		UnpackedResponseDiff													
+impl UnpackedResponseDiff {
+	//		is_empty															
+	/// Returns whether there are no differences.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.status.is_none() && self.headers.is_empty() && self.body.is_none()
+	}
+}
+
+//󰭅		Display																	
+impl Display for UnpackedResponseDiff {
+	//		fmt																	
+	/// Formats the differences for display.
+	/// 
+	/// Each difference is shown on its own line, prefixed with `-` for
+	/// something present only on the left-hand side, `+` for something present
+	/// only on the right-hand side, and `~` for something changed.
+	/// 
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.is_empty() {
+			return write!(f, "No differences");
+		}
+		if let Some((left, right)) = self.status {
+			writeln!(f, "Status: {left} != {right}")?;
+		}
+		if !self.headers.is_empty() {
+			writeln!(f, "Headers:")?;
+			for header in &self.headers {
+				writeln!(f, "  {header}")?;
+			}
+		}
+		if let Some(ref body) = self.body {
+			writeln!(f, "{body}")?;
+		}
+		Ok(())
+	}
+}
+
+
+
+//		Traits																											
+
+//§		RequestExt																
+/// This trait provides additional functionality to [`Request`].
+pub trait RequestExt {
+	//		unpack																
+	/// Returns an [`UnpackedRequest`] containing the unpacked request data.
+	/// 
+	/// This will unpack the request and provide the method, URI, version,
+	/// headers, and body in a more accessible form, to allow it to be checked
+	/// and compared. This is useful for testing middleware and proxies, as the
+	/// entire request can be checked all at once, and also for
+	/// printing/logging.
+	/// 
+	/// The same considerations apply as for [`ResponseExt::unpack()`]. Notably,
+	/// this function will consume the request body, which is necessary because
+	/// the request might be streamed. In order to provide the full request, the
+	/// whole body must be read first.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will potentially return an error if the request body
+	/// cannot be converted to bytes. This should not happen under normal
+	/// circumstances, but it may be possible if the request body is streamed
+	/// and the stream cannot be read. Many implementations of this function are
+	/// in fact infallible.
+	/// 
+	/// At present [`RequestError`] only contains one error variant, but it is
+	/// possible that more will be added.
+	/// 
+	/// # See also
+	/// 
+	/// * [`http::Request`]
+	/// * [`ResponseExt::unpack()`]
+	/// * [`UnpackedRequest`]
+	/// 
+	fn unpack(&mut self) -> Result<UnpackedRequest, RequestError>;
+}
+
+//󰭅		Request<()>																
+impl RequestExt for Request<()> {
+	//		unpack																
+	fn unpack(&mut self) -> Result<UnpackedRequest, RequestError> {
+		Ok(convert_request(self.method(), self.uri(), self.version(), self.headers(), &Bytes::new()))
+	}
+}
+
+//󰭅		Request<AxumBody>														
+#[cfg(feature = "axum")]
+impl RequestExt for Request<AxumBody> {
+	//		unpack																
+	fn unpack(&mut self) -> Result<UnpackedRequest, RequestError> {
+		let bytes = executor::block_on(to_bytes(mem::replace(self.body_mut(), AxumBody::empty()), usize::MAX))
+			.map_err(|e| RequestError::BodyReadError(Box::new(e)))?
+		;
+		Ok(convert_request(self.method(), self.uri(), self.version(), self.headers(), &bytes))
+	}
+}
+
+//󰭅		Request<Full<Bytes>>													
+impl RequestExt for Request<Full<Bytes>> {
+	//		unpack																
+	fn unpack(&mut self) -> Result<UnpackedRequest, RequestError> {
+		//	Collect the body into Collected<Bytes>
+		let collected = self.body().clone().collect().now_or_never()
+			.unwrap_or_else(|| Ok(Collected::default()))
+			.map_err(|e| RequestError::BodyReadError(Box::new(e)))?
+		;
+		Ok(convert_request(self.method(), self.uri(), self.version(), self.headers(), &collected.to_bytes()))
+	}
+}
+
+//󰭅		Request<Incoming>														
+impl RequestExt for Request<Incoming> {
+	//		unpack																
+	fn unpack(&mut self) -> Result<UnpackedRequest, RequestError> {
+		//	Collect the body into Collected<Bytes>
+		let collected = executor::block_on(self.body_mut().collect())
+			.map_err(|e| RequestError::BodyReadError(Box::new(e)))?
+		;
+		Ok(convert_request(self.method(), self.uri(), self.version(), self.headers(), &collected.to_bytes()))
+	}
+}
+
+//󰭅		Request<String>															
+impl RequestExt for Request<String> {
+	//		unpack																
+	fn unpack(&mut self) -> Result<UnpackedRequest, RequestError> {
+		Ok(convert_request(self.method(), self.uri(), self.version(), self.headers(), &Bytes::from(self.body().clone())))
+	}
+}
+
+//§		ResponseExt																
+/// This trait provides additional functionality to [`Response`].
+pub trait ResponseExt {
+	//		unpack																
+	/// Returns an [`UnpackedResponse`] containing the unpacked response data.
+	/// 
+	/// This will unpack the response and provide the headers and body in a
+	/// more accessible form, to allow it to be checked and compared. This is
+	/// useful for testing, as the entire set of headers plus body can be
+	/// checked all at once, and also for printing/logging.
+	/// 
+	/// If specific headers or body content needs to be checked, it is
+	/// recommended to use the standard functions as they will be more
+	/// efficient and performant. Notably, this function will consume the
+	/// response body, which is necessary because the response might be
+	/// streamed. In order to provide the full response, the whole body must be
+	/// read first. This will obviously use more memory than would be used under
+	/// normal circumstances, so it is not recommended to use this function
+	/// without considering purpose and effect. For tests, ensuring a response
+	/// body matches, this is fine, as the data is known and constrained, and
+	/// memory/performance is less of a concern.
+	/// 
+	/// The [`ContentType`] of the body is detected from the `content-type`
+	/// header, falling back to checking whether the body is valid UTF8. To
+	/// change this behaviour, use [`unpack_with()`](ResponseExt::unpack_with())
+	/// instead.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will potentially return an error if the response body
+	/// cannot be converted to bytes. This should not happen under normal
+	/// circumstances, but it may be possible if the response body is streamed
+	/// and the stream cannot be read. Many implementations of this function are
+	/// in fact infallible.
+	/// 
+	/// If the body cannot be read, the
+	/// [`BodyReadError`](ResponseError::BodyReadError) variant of
+	/// [`ResponseError`] will be returned, with the underlying error available
+	/// as its source. Other variants may be returned depending upon the
+	/// [`UnpackOptions`] used, such as if the body is too large.
+	/// 
+	/// Note that the implementations for streamed bodies block the current
+	/// thread while the body is read. When running inside an async runtime,
+	/// [`AsyncResponseExt::unpack_async()`] should be used instead.
+	/// 
+	/// # See also
+	/// 
+	/// * [`axum::response`](https://docs.rs/axum/latest/axum/response/index.html)
+	/// * [`axum::response::Response`](https://docs.rs/axum/latest/axum/response/type.Response.html)
+	/// * [`AsyncResponseExt::unpack_async()`]
+	/// * [`http::Response`]
+	/// * [`hyper::Response`]
+	/// * [`ResponseExt::unpack_with()`]
+	/// * [`UnpackedResponse`]
+	/// 
+	fn unpack(&mut self) -> Result<UnpackedResponse, ResponseError> {
+		self.unpack_with(UnpackOptions::default())
+	}
+	
+	//		unpack_with															
+	/// Returns an [`UnpackedResponse`], using the specified options.
+	/// 
+	/// This is the same as [`unpack()`](ResponseExt::unpack()), except that
+	/// the unpacking behaviour can be configured, such as how the
+	/// [`ContentType`] of the body is determined, and the maximum size of the
+	/// body that will be read.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `options` - The options to use when unpacking the response.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will return an error if the response body cannot be
+	/// read, in which case the [`BodyReadError`](ResponseError::BodyReadError)
+	/// variant of [`ResponseError`] will be returned. If the response body is
+	/// larger than the limit specified in the options, the
+	/// [`LimitExceeded`](ResponseError::LimitExceeded) variant will be
+	/// returned.
+	/// 
+	/// # See also
+	/// 
+	/// * [`ResponseExt::unpack()`]
+	/// * [`UnpackOptions`]
+	/// 
+	fn unpack_with(&mut self, options: UnpackOptions) -> Result<UnpackedResponse, ResponseError>;
+	
+	//		unpack_with_timeout													
+	/// Returns an [`UnpackedResponse`], with a limit on the time taken.
+	/// 
+	/// This is the same as [`unpack()`](ResponseExt::unpack()), except that
+	/// reading of the response body will stop once the specified time has
+	/// elapsed, in which case an error will be returned. This is useful for
+	/// ensuring that a peer that stalls part-way through sending the body
+	/// causes a failure rather than blocking forever.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `timeout` - The maximum time to spend reading the response body.
+	/// 
+	/// # Errors
+	/// 
+	/// If the response body is not read in full within the specified time,
+	/// the [`Timeout`](ResponseError::Timeout) variant of [`ResponseError`]
+	/// will be returned, containing the response unpacked from whatever was
+	/// received before the timeout. Otherwise, the same errors apply as for
+	/// [`unpack()`](ResponseExt::unpack()).
+	/// 
+	/// # See also
+	/// 
+	/// * [`AsyncResponseExt::unpack_async_with_timeout()`]
+	/// * [`ResponseExt::unpack()`]
+	/// * [`UnpackOptions::with_timeout()`]
+	/// 
+	fn unpack_with_timeout(&mut self, timeout: Duration) -> Result<UnpackedResponse, ResponseError> {
+		self.unpack_with(UnpackOptions::default().with_timeout(timeout))
+	}
+}
+
+//󰭅		Response<()>															
+impl ResponseExt for Response<()> {
+	//		unpack_with															
+	fn unpack_with(&mut self, options: UnpackOptions) -> Result<UnpackedResponse, ResponseError> {
+		convert_response(self.status(), self.headers(), &Bytes::new(), Vec::new(), &options)
+	}
+}
+
+//󰭅		Response<AxumBody>														
+#[cfg(feature = "axum")]
+impl ResponseExt for Response<AxumBody> {
+	//		unpack_with															
+	fn unpack_with(&mut self, options: UnpackOptions) -> Result<UnpackedResponse, ResponseError> {
+		let body            = mem::replace(self.body_mut(), AxumBody::empty());
+		let read            = executor::block_on(read_body(body, &options))?;
+		convert_read_body(self.status(), self.headers(), read, &options)
+	}
+}
+
+//󰭅		Response<Full<Bytes>>													
+impl ResponseExt for Response<Full<Bytes>> {
+	//		unpack_with															
+	fn unpack_with(&mut self, options: UnpackOptions) -> Result<UnpackedResponse, ResponseError> {
+		let read = read_body(self.body().clone(), &options).now_or_never()
+			.unwrap_or_else(|| Ok(ReadBody::default()))?
+		;
+		convert_read_body(self.status(), self.headers(), read, &options)
+	}
+}
+
+//󰭅		Response<Incoming>														
+impl ResponseExt for Response<Incoming> {
+	//		unpack_with															
+	fn unpack_with(&mut self, options: UnpackOptions) -> Result<UnpackedResponse, ResponseError> {
+		let read = executor::block_on(read_body(self.body_mut(), &options))?;
+		convert_read_body(self.status(), self.headers(), read, &options)
+	}
+}
+
+//󰭅		Response<String>														
+impl ResponseExt for Response<String> {
+	//		unpack_with															
+	fn unpack_with(&mut self, options: UnpackOptions) -> Result<UnpackedResponse, ResponseError> {
+		if self.body().len() > options.limit {
+			return Err(ResponseError::LimitExceeded(options.limit));
+		}
+		let read = read_body(Full::new(Bytes::from(self.body().clone())), &options).now_or_never()
+			.unwrap_or_else(|| Ok(ReadBody::default()))?
+		;
+		convert_read_body(self.status(), self.headers(), read, &options)
+	}
+}
+
+//§		AsyncResponseExt														
+/// This trait provides additional asynchronous functionality to [`Response`].
+/// 
+/// It is implemented for any [`Response`] whose body implements [`Body`],
+/// which includes [`Full<Bytes>`](Full), [`Incoming`], [`String`], and (with
+/// the `axum` feature enabled) the Axum body type.
+/// 
+pub trait AsyncResponseExt {
+	//		unpack_async														
+	/// Returns an [`UnpackedResponse`] containing the unpacked response data.
+	/// 
+	/// This is the asynchronous equivalent of [`ResponseExt::unpack()`], and
+	/// the same considerations apply. The difference is that the response body
+	/// is collected by awaiting it, rather than by blocking the current thread,
+	/// which means it is safe to use inside an async runtime, including a
+	/// single-threaded one.
+	/// 
+	/// No limit is applied to the size of the response body. If a limit is
+	/// required, use [`unpack_async_with_limit()`](AsyncResponseExt::unpack_async_with_limit())
+	/// instead.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will return an error if the response body cannot be
+	/// read, in which case the [`BodyReadError`](ResponseError::BodyReadError)
+	/// variant of [`ResponseError`] will be returned.
+	/// 
+	/// # See also
+	/// 
+	/// * [`AsyncResponseExt::unpack_async_with()`]
+	/// * [`AsyncResponseExt::unpack_async_with_limit()`]
+	/// * [`ResponseExt::unpack()`]
+	/// * [`UnpackedResponse`]
+	/// 
+	//	Cannot use the async keyword here due to needing to specify Send as a
+	//	constraint.
+	fn unpack_async(&mut self) -> impl Future<Output = Result<UnpackedResponse, ResponseError>> + Send {
+		self.unpack_async_with(UnpackOptions::default())
+	}
+	
+	//		unpack_async_with_limit												
+	/// Returns an [`UnpackedResponse`], with a limit on the body size.
+	/// 
+	/// This is the same as [`unpack_async()`](AsyncResponseExt::unpack_async()),
+	/// except that reading of the response body will stop once the specified
+	/// number of bytes has been exceeded, in which case an error will be
+	/// returned. This is useful for protecting against unexpectedly-large
+	/// responses.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `limit` - The maximum size of the response body, in bytes.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will return an error if the response body cannot be
+	/// read, in which case the [`BodyReadError`](ResponseError::BodyReadError)
+	/// variant of [`ResponseError`] will be returned. If the response body is
+	/// larger than the specified limit, the [`LimitExceeded`](ResponseError::LimitExceeded)
+	/// variant will be returned.
+	/// 
+	/// # See also
+	/// 
+	/// * [`AsyncResponseExt::unpack_async()`]
+	/// * [`AsyncResponseExt::unpack_async_with()`]
+	/// * [`ResponseExt::unpack()`]
+	/// * [`UnpackedResponse`]
+	/// 
+	fn unpack_async_with_limit(&mut self, limit: usize) -> impl Future<Output = Result<UnpackedResponse, ResponseError>> + Send {
+		self.unpack_async_with(UnpackOptions::default().with_limit(limit))
+	}
+	
+	//		unpack_async_with													
+	/// Returns an [`UnpackedResponse`], using the specified options.
+	/// 
+	/// This is the asynchronous equivalent of [`ResponseExt::unpack_with()`],
+	/// and the same considerations apply.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `options` - The options to use when unpacking the response.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will return an error if the response body cannot be
+	/// read, in which case the [`BodyReadError`](ResponseError::BodyReadError)
+	/// variant of [`ResponseError`] will be returned. If the response body is
+	/// larger than the limit specified in the options, the
+	/// [`LimitExceeded`](ResponseError::LimitExceeded) variant will be
+	/// returned.
+	/// 
+	/// # See also
+	/// 
+	/// * [`AsyncResponseExt::unpack_async()`]
+	/// * [`ResponseExt::unpack_with()`]
+	/// * [`UnpackOptions`]
+	/// 
+	fn unpack_async_with(&mut self, options: UnpackOptions) -> impl Future<Output = Result<UnpackedResponse, ResponseError>> + Send;
+	
+	//		unpack_async_with_timeout											
+	/// Returns an [`UnpackedResponse`], with a limit on the time taken.
+	/// 
+	/// This is the asynchronous equivalent of
+	/// [`ResponseExt::unpack_with_timeout()`], and the same considerations
+	/// apply. The timer does not depend upon any particular async runtime.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `timeout` - The maximum time to spend reading the response body.
+	/// 
+	/// # Errors
+	/// 
+	/// If the response body is not read in full within the specified time,
+	/// the [`Timeout`](ResponseError::Timeout) variant of [`ResponseError`]
+	/// will be returned, containing the response unpacked from whatever was
+	/// received before the timeout. Otherwise, the same errors apply as for
+	/// [`unpack_async()`](AsyncResponseExt::unpack_async()).
+	/// 
+	/// # See also
+	/// 
+	/// * [`AsyncResponseExt::unpack_async()`]
+	/// * [`ResponseExt::unpack_with_timeout()`]
+	/// * [`UnpackOptions::with_timeout()`]
+	/// 
+	fn unpack_async_with_timeout(&mut self, timeout: Duration) -> impl Future<Output = Result<UnpackedResponse, ResponseError>> + Send {
+		self.unpack_async_with(UnpackOptions::default().with_timeout(timeout))
+	}
+}
+
+//󰭅		Response<B>																
+impl<B> AsyncResponseExt for Response<B>
+where
+	B:        Body + Send + Unpin,
+	B::Data:  Send,
+	B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+	//		unpack_async_with													
+	async fn unpack_async_with(&mut self, options: UnpackOptions) -> Result<UnpackedResponse, ResponseError> {
+		let read = read_body(self.body_mut(), &options).await?;
+		convert_read_body(self.status(), self.headers(), read, &options)
+	}
+}
+
+//§		BlockingResponseExt														
+/// This trait provides blocking unpacking for any [`Response`] body type.
+/// 
+/// [`ResponseExt`] is implemented for specific body types, some of which are
+/// unpacked without consuming the body. This trait is implemented for any
+/// [`Response`] whose body implements [`Body`], which includes boxed bodies
+/// such as [`BoxBody`](http_body_util::combinators::BoxBody) and
+/// [`UnsyncBoxBody`](http_body_util::combinators::UnsyncBoxBody), wrappers
+/// such as [`Limited`], [`Empty`](http_body_util::Empty), and bodies from
+/// other crates, such as `tower-http`. It is the blocking equivalent of
+/// [`AsyncResponseExt`].
+/// 
+/// The methods are named differently to those of [`ResponseExt`], so that
+/// both traits can be in scope at the same time without ambiguity.
+/// 
+pub trait BlockingResponseExt {
+	//		unpack_blocking														
+	/// Returns an [`UnpackedResponse`] containing the unpacked response data.
+	/// 
+	/// This is the equivalent of [`ResponseExt::unpack()`] for any body type,
+	/// and the same considerations apply. The response body is consumed, and
+	/// the current thread is blocked while it is read. When running inside an
+	/// async runtime, [`AsyncResponseExt::unpack_async()`] should be used
+	/// instead.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will return an error if the response body cannot be
+	/// read, in which case the [`BodyReadError`](ResponseError::BodyReadError)
+	/// variant of [`ResponseError`] will be returned.
+	/// 
+	/// # See also
+	/// 
+	/// * [`AsyncResponseExt::unpack_async()`]
+	/// * [`BlockingResponseExt::unpack_blocking_with()`]
+	/// * [`ResponseExt::unpack()`]
+	/// * [`UnpackedResponse`]
+	/// 
+	fn unpack_blocking(&mut self) -> Result<UnpackedResponse, ResponseError> {
+		self.unpack_blocking_with(UnpackOptions::default())
+	}
+	
+	//		unpack_blocking_with												
+	/// Returns an [`UnpackedResponse`], using the specified options.
+	/// 
+	/// This is the equivalent of [`ResponseExt::unpack_with()`] for any body
+	/// type, and the same considerations apply.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `options` - The options to use when unpacking the response.
+	/// 
+	/// # Errors
+	/// 
+	/// This function will return an error if the response body cannot be
+	/// read, in which case the [`BodyReadError`](ResponseError::BodyReadError)
+	/// variant of [`ResponseError`] will be returned. Other variants may be
+	/// returned depending upon the [`UnpackOptions`] used, such as
+	/// [`LimitExceeded`](ResponseError::LimitExceeded) if the body is too
+	/// large, or [`Timeout`](ResponseError::Timeout) if it is not read in time.
+	/// 
+	/// # See also
+	/// 
+	/// * [`AsyncResponseExt::unpack_async_with()`]
+	/// * [`BlockingResponseExt::unpack_blocking()`]
+	/// * [`ResponseExt::unpack_with()`]
+	/// * [`UnpackOptions`]
+	/// 
+	fn unpack_blocking_with(&mut self, options: UnpackOptions) -> Result<UnpackedResponse, ResponseError>;
+}
+
+//󰭅		Response<B>																
+impl<B> BlockingResponseExt for Response<B>
+where
+	B:        Body + Unpin,
+	B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+	//		unpack_blocking_with												
+	fn unpack_blocking_with(&mut self, options: UnpackOptions) -> Result<UnpackedResponse, ResponseError> {
+		let read = executor::block_on(read_body(self.body_mut(), &options))?;
+		convert_read_body(self.status(), self.headers(), read, &options)
+	}
+}
+
+
+
+//		Functions																										
+
+//		body_error																
+/// Returns the error for a failure to read a response body.
+/// 
+/// # Parameters
+/// 
+/// * `err`   - The error encountered while reading the body.
+/// * `limit` - The size limit that was applied to the body.
+/// 
+fn body_error(err: Box<dyn Error + Send + Sync>, limit: usize) -> ResponseError {
+	if err.is::<LengthLimitError>() {
+		ResponseError::LimitExceeded(limit)
+	} else {
+		ResponseError::BodyReadError(err)
+	}
+}
+
+//		check_utf8																
+/// Checks that a body declared as text or JSON is valid UTF8.
+/// 
+/// This only applies if [`UnpackOptions::with_strict_utf8()`] is enabled. The
+/// declared content type is determined from the `content-type` header, using
+/// any custom mappings in the options.
+/// 
+/// # Parameters
+/// 
+/// * `headers` - The headers of the response.
+/// * `body`    - The body data.
+/// * `options` - The options that control the unpacking.
+/// 
+/// # Errors
+/// 
+/// If the body is declared as text or JSON and is not valid UTF8, a
+/// [`ResponseError::InvalidUtf8`] error will be returned.
+/// 
+fn check_utf8(headers: &HeaderMap<HeaderValue>, body: &[u8], options: &UnpackOptions) -> Result<(), ResponseError> {
+	if !options.strict_utf8 {
+		return Ok(());
+	}
+	let declared = headers.get(CONTENT_TYPE)
+		.and_then(|value| value.to_str().ok())
+		.map(mime_essence)
+		.and_then(|essence| options.mapped_content_type(&essence).or_else(|| default_content_type(&essence)))
+	;
+	match declared {
+		Some(ContentType::Text | ContentType::Json) => from_utf8(body).map(|_| ()).map_err(ResponseError::InvalidUtf8),
+		Some(ContentType::Binary) | None            => Ok(()),
+	}
+}
+
+//		collect_body															
+/// Collects a body into [`Bytes`], subject to a size limit.
+/// 
+/// This function carries out the asynchronous reading of a body for
+/// [`AsyncResponseExt`]. The body is read in full, unless it exceeds the
+/// specified limit, in which case reading stops and an error is returned.
+/// 
+/// # Parameters
+/// 
+/// * `body`  - The body to collect.
+/// * `limit` - The maximum size of the body, in bytes.
+/// 
+/// # Errors
+/// 
+/// If the body exceeds the limit, a [`ResponseError::LimitExceeded`] error will
+/// be returned. Any other error encountered while reading the body will be
+/// returned as a [`ResponseError::BodyReadError`].
+/// 
+/// # See also
+/// 
+/// * [`AsyncResponseExt::unpack_async_with_limit()`]
+/// 
+async fn collect_body<B>(body: B, limit: usize) -> Result<Bytes, ResponseError>
+where
+	B:        Body,
+	B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+	Limited::new(body, limit).collect().await
+		.map(Collected::to_bytes)
+		.map_err(|err| body_error(err, limit))
+}
+
+//		collect_frames															
+/// Collects the frames of a response body.
+/// 
+/// The data frames are combined into a single [`Bytes`] container, as for
+/// [`collect_body()`]. If frame recording is enabled, each frame is also
+/// recorded individually. Data frames are recorded with a content type of
+/// [`Text`](ContentType::Text), which is corrected later by
+/// [`convert_frames()`].
+/// 
+/// If a timeout is specified in the options, reading stops once it has
+/// elapsed, and the data received up to that point is returned along with the
+/// timeout.
+/// 
+/// # Parameters
+/// 
+/// * `body`    - The response body to collect.
+/// * `options` - The options that control the unpacking.
+/// 
+/// # Errors
+/// 
+/// * [`ResponseError::BodyReadError`] if the body cannot be read.
+/// * [`ResponseError::LimitExceeded`] if the body exceeds the size limit.
+/// 
+/// # See also
+/// 
+/// * [`UnpackOptions::with_frame_recording()`]
+/// * [`UnpackOptions::with_timeout()`]
+/// * [`UnpackedFrame`]
+/// 
+async fn collect_frames<B>(body: B, options: &UnpackOptions) -> Result<ReadBody, ResponseError>
+where
+	B:        Body,
+	B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+	let mut limited = pin!(Limited::new(body, options.limit));
+	let mut delay   = options.timeout.map(Delay::new);
+	let mut data    = Vec::new();
+	let mut frames  = vec![];
+	loop {
+		let next   = limited.frame();
+		let result = if let Some(ref mut timer) = delay {
+			match select(next, timer).await {
+				Either::Left((result, _)) => result,
+				Either::Right(_)          => {
+					return Ok(ReadBody { bytes: Bytes::from(data), frames, timed_out: options.timeout });
+				},
+			}
+		} else {
+			next.await
+		};
+		let Some(received) = result else {
+			break;
+		};
+		match received.map_err(|err| body_error(err, options.limit))?.into_data() {
+			Ok(mut chunk) => {
+				let bytes = chunk.copy_to_bytes(chunk.remaining());
+				data.extend_from_slice(&bytes);
+				if options.record_frames {
+					frames.push(UnpackedFrame::Data(UnpackedResponseBody { body: bytes.to_vec(), content_type: ContentType::Text }));
+				}
+			},
+			Err(frame)    => {
+				if let Ok(trailers) = frame.into_trailers() {
+					if options.record_frames {
+						frames.push(UnpackedFrame::Trailers(convert_headers(&trailers, options)));
+					}
+				}
+			},
+		}
+	}
+	Ok(ReadBody { bytes: Bytes::from(data), frames, timed_out: None })
+}
+
+//		compress_bytes															
+/// Compresses data using the specified content encoding.
+/// 
+/// # Parameters
+/// 
+/// * `data`     - The data to compress.
+/// * `encoding` - The content encoding to use.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the data could not be compressed.
+/// 
+/// # See also
+/// 
+/// * [`UnpackedResponseBody::compress()`]
+/// 
+#[cfg(feature = "compression")]
+fn compress_bytes(data: &[u8], encoding: ContentEncoding) -> Result<Vec<u8>, IoError> {
+	match encoding {
+		ContentEncoding::Brotli  => {
+			let mut encoder = BrotliEncoder::new(Vec::new(), 4_096, 6, 22);
+			encoder.write_all(data)?;
+			Ok(encoder.into_inner())
+		},
+		ContentEncoding::Deflate => {
+			let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+			encoder.write_all(data)?;
+			encoder.finish()
+		},
+		ContentEncoding::Gzip    => {
+			let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+			encoder.write_all(data)?;
+			encoder.finish()
+		},
+	}
+}
+
+//		convert_frames															
+/// Returns recorded frames with the content type of the body applied.
+/// 
+/// Data frames are marked as [`Binary`](ContentType::Binary) if the body as a
+/// whole is binary, and as [`Text`](ContentType::Text) otherwise, as
+/// individual chunks of a JSON body are not usually valid JSON.
+/// 
+/// # Parameters
+/// 
+/// * `frames`       - The recorded frames.
+/// * `content_type` - The content type of the body.
+/// 
+fn convert_frames(frames: Vec<UnpackedFrame>, content_type: ContentType) -> Vec<UnpackedFrame> {
+	let frame_type = if content_type == ContentType::Binary { ContentType::Binary } else { ContentType::Text };
+	frames.into_iter().map(|frame| match frame {
+		UnpackedFrame::Data(data)         => UnpackedFrame::Data(UnpackedResponseBody { content_type: frame_type, ..data }),
+		UnpackedFrame::Trailers(trailers) => UnpackedFrame::Trailers(trailers),
+	}).collect()
+}
+
+//		convert_headers															
+/// Returns a vector of unpacked response headers.
+/// 
+/// These are returned in a vector rather than a hashmap because there may be
+/// multiple headers with the same name. By default they are sorted by name,
+/// and then by value, allowing for reliable comparison. Sorting does break the
+/// original order of the headers, but this should only very rarely matter, and
+/// so the order can be preserved using
+/// [`UnpackOptions::with_preserved_header_order()`].
+/// 
+/// # Parameters
+/// 
+/// * `headermap` - The headers to convert.
+/// * `options`   - The options that control the unpacking.
+/// 
+/// # See also
+/// 
+/// * [`ResponseExt::unpack()`]
+/// * [`UnpackedResponse`]
+/// * [`UnpackedResponseHeader`]
+/// 
+fn convert_headers(headermap: &HeaderMap<HeaderValue>, options: &UnpackOptions) -> Vec<UnpackedResponseHeader> {
+	ordered_headers(headermap, options)
+		.into_iter()
+		.map(|(name, value)| UnpackedResponseHeader::new(name.as_str().to_owned(), String::from_utf8_lossy(value.as_bytes()).into_owned()))
+		.collect()
+}
+
+//		convert_raw_headers														
+/// Returns a vector of unpacked response headers with their raw values.
+/// 
+/// These are only returned if enabled using
+/// [`UnpackOptions::with_raw_header_values()`], and are in the same order as
+/// the headers returned by [`convert_headers()`].
+/// 
+/// # Parameters
+/// 
+/// * `headermap` - The headers to convert.
+/// * `options`   - The options that control the unpacking.
+/// 
+/// # See also
+/// 
+/// * [`UnpackedRawHeader`]
+/// * [`UnpackedResponse::raw_headers`]
+/// 
+fn convert_raw_headers(headermap: &HeaderMap<HeaderValue>, options: &UnpackOptions) -> Vec<UnpackedRawHeader> {
+	if !options.raw_header_values {
+		return Vec::new();
+	}
+	ordered_headers(headermap, options)
+		.into_iter()
+		.map(|(name, value)| UnpackedRawHeader::new(name.as_str().to_owned(), value.as_bytes().to_vec()))
+		.collect()
+}
+
+//		convert_read_body														
+/// Returns an [`UnpackedResponse`] built from the data read from a body.
+/// 
+/// This is a wrapper around [`convert_response()`], which handles the case
+/// where reading of the body timed out. In that case the response is unpacked
+/// from the partial data, without decompression or strict UTF8 checking, as
+/// the body is likely to be incomplete, and is returned inside a
+/// [`ResponseError::Timeout`] error.
+/// 
+/// # Parameters
+/// 
+/// * `status`  - The response status code.
+/// * `headers` - The response headers.
+/// * `read`    - The data read from the response body.
+/// * `options` - The options that control the unpacking.
+/// 
+/// # Errors
+/// 
+/// * [`ResponseError::Timeout`] if reading of the body timed out.
+/// * Any error returned by [`convert_response()`].
+/// 
+fn convert_read_body(
+	status:  StatusCode,
+	headers: &HeaderMap<HeaderValue>,
+	read:    ReadBody,
+	options: &UnpackOptions,
+) -> Result<UnpackedResponse, ResponseError> {
+	let Some(duration) = read.timed_out else {
+		return convert_response(status, headers, &read.bytes, read.frames, options);
+	};
+	let partial_options = UnpackOptions {
+		#[cfg(feature = "compression")]
+		decompress:  false,
+		strict_utf8: false,
+		..options.clone()
+	};
+	let partial         = convert_response(status, headers, &read.bytes, read.frames, &partial_options)?;
+	Err(ResponseError::Timeout { duration, partial: Box::new(partial) })
+}
+
+//		convert_request															
+/// Returns an [`UnpackedRequest`] containing the unpacked request data.
+/// 
+/// This function carries out the common part of the conversion process for
+/// [`RequestExt::unpack()`]. As [`unpack()`](RequestExt::unpack()) has a
+/// number of implementations, the common code is abstracted out into this
+/// function.
+/// 
+/// # Parameters
+/// 
+/// * `method`  - The request method.
+/// * `uri`     - The request URI.
+/// * `version` - The HTTP version of the request.
+/// * `headers` - The request headers.
+/// * `body`    - The request body.
+/// 
+/// # See also
+/// 
+/// * [`http::Request`]
+/// * [`RequestExt::unpack()`]
+/// * [`UnpackedRequest`]
+/// * [`UnpackedResponseHeader`]
+/// 
+fn convert_request(
+	method:  &Method,
+	uri:     &Uri,
+	version: Version,
+	headers: &HeaderMap<HeaderValue>,
+	body:    &Bytes,
+) -> UnpackedRequest {
+	let options = UnpackOptions::default();
+	UnpackedRequest {
+		method:  method.clone(),
+		uri:     uri.clone(),
+		version,
+		headers: convert_headers(headers, &options),
+		body:    UnpackedResponseBody {
+			body:         body.to_vec(),
+			content_type: detect_content_type(headers, body, &options),
+		},
+	}
+}
+
+//		convert_response														
+/// Returns an [`UnpackedResponse`] containing the unpacked response data.
+/// 
+/// This function carries out the common part of the conversion process for
+/// [`ResponseExt::unpack()`]. As [`unpack()`](ResponseExt::unpack()) has a
+/// number of implementations, the common code is abstracted out into this
+/// function.
+/// 
+/// # Parameters
+/// 
+/// * `status`  - The response status code.
+/// * `headers` - The response headers.
+/// * `body`    - The response body.
+/// * `frames`  - The frames of the response body, if recorded.
+/// * `options` - The options that control the unpacking.
+/// 
+/// # Errors
+/// 
+/// If decompression is enabled and the body cannot be decompressed, the error
+/// from decompression will be returned. If strict UTF8 checking is enabled and
+/// a body declared as text or JSON is not valid UTF8, a
+/// [`ResponseError::InvalidUtf8`] error will be returned.
+/// 
+/// # See also
+/// 
+/// * [`axum::response`](https://docs.rs/axum/latest/axum/response/index.html)
+/// * [`axum::response::Response`](https://docs.rs/axum/latest/axum/response/type.Response.html)
+/// * [`http::Response`]
+/// * [`hyper::Response`]
+/// * [`ResponseExt::unpack()`]
+/// * [`UnpackedResponse`]
+/// * [`UnpackedResponseHeader`]
+/// 
+fn convert_response(
+	status:  StatusCode,
+	headers: &HeaderMap<HeaderValue>,
+	body:    &Bytes,
+	frames:  Vec<UnpackedFrame>,
+	options: &UnpackOptions,
+) -> Result<UnpackedResponse, ResponseError> {
+	#[cfg(feature = "compression")]
+	if options.decompress && headers.contains_key(CONTENT_ENCODING) {
+		let mut decoded_headers = headers.clone();
+		let mut decoded_body    = body.to_vec();
+		decompress_content(&mut decoded_headers, &mut decoded_body, options.limit)?;
+		check_utf8(&decoded_headers, &decoded_body, options)?;
+		let content_type        = detect_content_type(&decoded_headers, &decoded_body, options);
+		return Ok(UnpackedResponse {
+			status,
+			headers:     convert_headers(&decoded_headers, options),
+			body:        UnpackedResponseBody {
+				body: decoded_body,
+				content_type,
+			},
+			frames:      convert_frames(frames, content_type),
+			raw_headers: convert_raw_headers(&decoded_headers, options),
+		});
+	}
+	check_utf8(headers, body, options)?;
+	let content_type = detect_content_type(headers, body, options);
+	Ok(UnpackedResponse {
+		status,
+		headers:     convert_headers(headers, options),
+		body:        UnpackedResponseBody {
+			body: body.to_vec(),
+			content_type,
+		},
+		frames:      convert_frames(frames, content_type),
+		raw_headers: convert_raw_headers(headers, options),
+	})
+}
+
+//		decode_chunked															
+/// Decodes a body that uses the chunked transfer encoding.
+/// 
+/// Chunk extensions and trailers are ignored.
+/// 
+/// # Parameters
+/// 
+/// * `data` - The chunked body data.
+/// 
+/// # Returns
+/// 
+/// The decoded body, or [`None`] if the data is not validly chunked.
+/// 
+fn decode_chunked(data: &[u8]) -> Option<Vec<u8>> {
+	let mut decoded = Vec::new();
+	let mut rest    = data;
+	loop {
+		let line_end = rest.iter().position(|&byte| byte == b'\n')?;
+		let line     = from_utf8(rest.get(..line_end)?).ok()?;
+		let size     = usize::from_str_radix(line.split(';').next()?.trim(), 16).ok()?;
+		rest         = rest.get(line_end.checked_add(1)?..)?;
+		if size == 0 {
+			return Some(decoded);
+		}
+		decoded.extend_from_slice(rest.get(..size)?);
+		let after    = rest.get(size..)?;
+		rest         = after.strip_prefix(b"\r\n").or_else(|| after.strip_prefix(b"\n"))?;
+	}
+}
+
+//		decode_utf16															
+/// Decodes UTF-16 data into a [`String`].
+/// 
+/// A leading byte order mark is removed. If the byte order is not specified,
+/// it is determined from the byte order mark, defaulting to big-endian if
+/// there is none.
+/// 
+/// # Parameters
+/// 
+/// * `data`       - The UTF-16 data.
+/// * `big_endian` - Whether the data is big-endian, if known.
+/// 
+/// # Returns
+/// 
+/// The decoded text, or [`None`] if the data is not valid UTF-16.
+/// 
+/// # See also
+/// 
+/// * [`UnpackedResponseBody::decode_text()`]
+/// 
+fn decode_utf16(data: &[u8], big_endian: Option<bool>) -> Option<String> {
+	let (bytes, is_big_endian) = match (data.strip_prefix(b"\xFE\xFF"), data.strip_prefix(b"\xFF\xFE"), big_endian) {
+		(Some(rest), _, None | Some(true))  => (rest, true),
+		(_, Some(rest), None | Some(false)) => (rest, false),
+		(_, _, endianness)                  => (data, endianness.unwrap_or(true)),
+	};
+	let pairs = bytes.chunks_exact(2);
+	if !pairs.remainder().is_empty() {
+		return None;
+	}
+	let units = pairs
+		.map(|pair| <[u8; 2]>::try_from(pair).ok().map(|unit| if is_big_endian { u16::from_be_bytes(unit) } else { u16::from_le_bytes(unit) }))
+		.collect::<Option<Vec<u16>>>()?
+	;
+	String::from_utf16(&units).ok()
+}
+
+//		decompress_bytes														
+/// Decompresses data using the specified content encoding.
+/// 
+/// Reading stops once the decompressed data exceeds the specified limit, so
+/// that a small, highly-compressed body cannot exhaust memory. The caller is
+/// responsible for checking whether the returned data is over the limit.
+/// 
+/// Data with a `deflate` encoding is expected to be in the zlib format, as
+/// required by the HTTP specification, but as some servers send raw DEFLATE
+/// data instead, this is also accepted.
+/// 
+/// # Parameters
+/// 
+/// * `data`     - The data to decompress.
+/// * `encoding` - The content encoding that the data was compressed with.
+/// * `limit`    - The maximum size of the decompressed data, in bytes.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the data could not be decompressed.
+/// 
+/// # See also
+/// 
+/// * [`UnpackedResponseBody::decompress()`]
+/// 
+#[cfg(feature = "compression")]
+fn decompress_bytes(data: &[u8], encoding: ContentEncoding, limit: usize) -> Result<Vec<u8>, IoError> {
+	let max     = u64::try_from(limit).unwrap_or(u64::MAX).saturating_add(1);
+	let mut out = vec![];
+	_ = match encoding {
+		ContentEncoding::Brotli  => BrotliDecoder::new(data, 4_096).take(max).read_to_end(&mut out),
+		ContentEncoding::Deflate => ZlibDecoder::new(data).take(max).read_to_end(&mut out).or_else(|_| {
+			out.clear();
+			DeflateDecoder::new(data).take(max).read_to_end(&mut out)
+		}),
+		ContentEncoding::Gzip    => MultiGzDecoder::new(data).take(max).read_to_end(&mut out),
+	}?;
+	Ok(out)
+}
+
+//		decompress_content														
+/// Decompresses a body according to its `content-encoding` header.
+/// 
+/// The encodings listed in the header are removed in reverse order of
+/// application, and the headers and body are updated in place. Once the body
+/// has been decompressed, the `content-encoding` header is removed, and any
+/// `content-length` header is updated. If the only encoding is `identity`, the
+/// body is left unchanged, but the header is still removed.
+/// 
+/// # Parameters
+/// 
+/// * `headers` - The headers of the response, which will be updated.
+/// * `body`    - The body data, which will be replaced with the decompressed
+///               data.
+/// * `limit`   - The maximum size of the decompressed body, in bytes.
+/// 
+/// # Errors
+/// 
+/// If the body could not be decompressed, a
+/// [`ResponseError::DecompressionError`] error will be returned. If an
+/// encoding is not supported, a [`ResponseError::UnsupportedEncoding`] error
+/// will be returned. If the decompressed body exceeds the limit, a
+/// [`ResponseError::LimitExceeded`] error will be returned.
+/// 
+/// # See also
+/// 
+/// * [`UnpackOptions::with_decompression()`]
+/// 
+#[cfg(feature = "compression")]
+fn decompress_content(
+	headers: &mut HeaderMap<HeaderValue>,
+	body:    &mut Vec<u8>,
+	limit:   usize,
+) -> Result<(), ResponseError> {
+	let encodings: Vec<String> = headers.get_all(CONTENT_ENCODING).iter()
+		.flat_map(|value| String::from_utf8_lossy(value.as_bytes()).split(',').map(str::to_owned).collect::<Vec<_>>())
+		.map(|name| name.trim().to_ascii_lowercase())
+		.filter(|name| !name.is_empty() && name != "identity")
+		.collect()
+	;
+	for name in encodings.iter().rev() {
+		let encoding = ContentEncoding::from_name(name)
+			.ok_or_else(|| ResponseError::UnsupportedEncoding(name.clone()))?
+		;
+		*body        = decompress_bytes(body, encoding, limit).map_err(ResponseError::DecompressionError)?;
+		if body.len() > limit {
+			return Err(ResponseError::LimitExceeded(limit));
+		}
+	}
+	drop(headers.remove(CONTENT_ENCODING));
+	if headers.contains_key(CONTENT_LENGTH) {
+		drop(headers.insert(CONTENT_LENGTH, HeaderValue::from(body.len())));
+	}
+	Ok(())
+}
+
+//		default_content_type													
+/// Returns the default content type for a MIME type, if known.
+/// 
+/// # Parameters
+/// 
+/// * `essence` - The MIME type, in lowercase and without parameters.
+/// 
+/// # See also
+/// 
+/// * [`UnpackOptions`]
+/// * [`UnpackOptions::content_type_for()`]
+/// 
+fn default_content_type(essence: &str) -> Option<ContentType> {
+	let (kind, subtype) = essence.split_once('/')?;
+	if subtype == "json" || subtype.ends_with("+json") {
+		return Some(ContentType::Json);
+	}
+	if kind == "text" || subtype.ends_with("+xml") || matches!(subtype,
+		"xml" | "javascript" | "ecmascript" | "x-www-form-urlencoded" | "graphql" | "x-ndjson" | "yaml" | "x-yaml" | "toml" | "csv"
+	) {
+		return Some(ContentType::Text);
+	}
+	if matches!(kind, "image" | "audio" | "video" | "font") || matches!(subtype,
+		"octet-stream" | "pdf" | "zip" | "gzip" | "x-gzip" | "x-tar" | "x-7z-compressed" | "x-bzip2" | "wasm" | "protobuf" | "x-protobuf" | "msgpack" | "x-msgpack" | "cbor"
+	) {
+		return Some(ContentType::Binary);
+	}
+	None
+}
+
+//		detect_content_type														
+/// Determines the content type of a body.
+/// 
+/// This is done according to the supplied options. See [`UnpackOptions`] for
+/// details of the process.
+/// 
+/// # Parameters
+/// 
+/// * `headers` - The headers of the request or response.
+/// * `body`    - The body data.
+/// * `options` - The options that control the detection.
+/// 
+/// # See also
+/// 
+/// * [`ContentType`]
+/// * [`UnpackOptions`]
+/// 
+fn detect_content_type(headers: &HeaderMap<HeaderValue>, body: &[u8], options: &UnpackOptions) -> ContentType {
+	detect_mime_content_type(headers.get(CONTENT_TYPE).and_then(|value| value.to_str().ok()), body, options)
+}
+
+//		detect_mime_content_type												
+/// Detects the content type of a body from its MIME type.
+/// 
+/// This is used by [`detect_content_type()`], and also for the parts of
+/// multipart bodies, which have their own headers.
+/// 
+/// # Parameters
+/// 
+/// * `mime_type` - The MIME type of the body, if known.
+/// * `body`      - The body data.
+/// * `options`   - The options controlling detection.
+/// 
+fn detect_mime_content_type(mime_type: Option<&str>, body: &[u8], options: &UnpackOptions) -> ContentType {
+	if !options.detect_content {
+		return ContentType::Text;
+	}
+	let essence  = mime_type.map(mime_essence);
+	if let Some(content_type) = essence.as_deref().and_then(|name| options.mapped_content_type(name)) {
+		return content_type;
+	}
+	let detected = essence.as_deref().and_then(default_content_type);
+	if !options.sniff_content {
+		return detected.unwrap_or_default();
+	}
+	match detected {
+		Some(ContentType::Binary)     => ContentType::Binary,
+		_ if from_utf8(body).is_err() => ContentType::Binary,
+		Some(content_type)            => content_type,
+		None                          => ContentType::Text,
 	}
 }
 
-//󰭅		From &char																
-impl From<&char> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`&char`](char) to an [`UnpackedResponseBody`].
-	fn from(c: &char) -> Self {
-		Self::from(c.to_owned())
+//		diff_headers															
+/// Returns the differences between two sets of unpacked headers.
+/// 
+/// Headers are grouped by name, and the values for each name are compared in
+/// order. The results are ordered by header name.
+/// 
+/// # Parameters
+/// 
+/// * `left`  - The left-hand headers.
+/// * `right` - The right-hand headers.
+/// 
+/// # See also
+/// 
+/// * [`HeaderDiff`]
+/// * [`UnpackedResponse::diff()`]
+/// 
+fn diff_headers(left: &[UnpackedResponseHeader], right: &[UnpackedResponseHeader]) -> Vec<HeaderDiff> {
+	let mut grouped: BTreeMap<&str, (Vec<&UnpackedResponseHeader>, Vec<&UnpackedResponseHeader>)> = BTreeMap::new();
+	for header in left {
+		grouped.entry(&header.name).or_default().0.push(header);
 	}
+	for header in right {
+		grouped.entry(&header.name).or_default().1.push(header);
+	}
+	let mut diffs = vec![];
+	for (name, (lhs, rhs)) in grouped {
+		if rhs.is_empty() {
+			diffs.extend(lhs.into_iter().cloned().map(HeaderDiff::Removed));
+		} else if lhs.is_empty() {
+			diffs.extend(rhs.into_iter().cloned().map(HeaderDiff::Added));
+		} else if lhs != rhs {
+			diffs.push(HeaderDiff::Changed {
+				name:  name.to_owned(),
+				left:  lhs.into_iter().map(|header| header.value.clone()).collect(),
+				right: rhs.into_iter().map(|header| header.value.clone()).collect(),
+			});
+		}
+	}
+	diffs
 }
 
-//󰭅		From AxumBody															
-#[cfg(feature = "axum")]
-impl From<AxumBody> for UnpackedResponseBody {
-	//		from																
-	fn from(b: AxumBody) -> Self {
-		Self { body: executor::block_on(to_bytes(b, usize::MAX)).unwrap_or_default().to_vec(), ..Default::default() }
+//		diff_json																
+/// Collects the differences between two JSON values.
+/// 
+/// Objects are compared key by key, and arrays element by element, with any
+/// other values being compared directly. The differences are added to the
+/// supplied vector, with paths built from the supplied base path.
+/// 
+/// # Parameters
+/// 
+/// * `path`  - The path to the values being compared.
+/// * `left`  - The left-hand value.
+/// * `right` - The right-hand value.
+/// * `diffs` - The differences found so far, which will be added to.
+/// 
+/// # See also
+/// 
+/// * [`JsonPathDiff`]
+/// * [`UnpackedResponseBody::diff()`]
+/// 
+fn diff_json(path: &str, left: &Json, right: &Json, diffs: &mut Vec<JsonPathDiff>) {
+	if let (Some(lhs), Some(rhs)) = (left.as_object(), right.as_object()) {
+		for (key, value) in lhs {
+			match rhs.get(key) {
+				Some(other) => diff_json(&format!("{path}.{key}"), value, other, diffs),
+				None        => diffs.push(JsonPathDiff::Removed { path: format!("{path}.{key}"), value: value.clone() }),
+			}
+		}
+		for (key, value) in rhs {
+			if !lhs.contains_key(key) {
+				diffs.push(JsonPathDiff::Added { path: format!("{path}.{key}"), value: value.clone() });
+			}
+		}
+	} else if let (Some(lhs), Some(rhs)) = (left.as_array(), right.as_array()) {
+		for (index, value) in lhs.iter().enumerate() {
+			match rhs.get(index) {
+				Some(other) => diff_json(&format!("{path}[{index}]"), value, other, diffs),
+				None        => diffs.push(JsonPathDiff::Removed { path: format!("{path}[{index}]"), value: value.clone() }),
+			}
+		}
+		for (index, value) in rhs.iter().enumerate().skip(lhs.len()) {
+			diffs.push(JsonPathDiff::Added { path: format!("{path}[{index}]"), value: value.clone() });
+		}
+	} else if left != right {
+		diffs.push(JsonPathDiff::Changed { path: path.to_owned(), left: left.clone(), right: right.clone() });
 	}
 }
 
-//󰭅		From Full<Bytes>														
-impl From<Full<Bytes>> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`Full<Bytes>`](Full) to an [`UnpackedResponseBody`].
-	fn from(b: Full<Bytes>) -> Self {
-		//	Collect the body into Collected<Bytes>
-		let collected = b.collect().now_or_never()
-			.unwrap_or_else(|| Ok(Collected::default()))
-			.unwrap_or_else(|_| Collected::default())
-		;
-		Self { body: collected.to_bytes().to_vec(), ..Default::default() }
+//		diff_lines																
+/// Returns the line-level differences between two pieces of text.
+/// 
+/// This uses a longest-common-subsequence comparison, so that lines which are
+/// present on both sides are matched up, and only the lines that have been
+/// added or removed are reported. A changed line is therefore reported as a
+/// removal followed by an addition.
+/// 
+/// Lines that are the same at the start and end of both sides are always
+/// matched, and are excluded from the comparison. The comparison needs memory
+/// proportional to the product of the numbers of remaining lines on each side,
+/// so if that exceeds a fixed limit, the remaining lines are not compared, and
+/// are all reported as removed from the left and added on the right instead.
+/// 
+/// # Parameters
+/// 
+/// * `left`  - The left-hand text.
+/// * `right` - The right-hand text.
+/// 
+/// # See also
+/// 
+/// * [`TextLineDiff`]
+/// * [`UnpackedResponseBody::diff()`]
+/// 
+#[expect(clippy::arithmetic_side_effects, reason = "Range is controlled")]
+#[expect(clippy::indexing_slicing,        reason = "Infallible")]
+fn diff_lines(left: &str, right: &str) -> Vec<TextLineDiff> {
+	//	The maximum number of cells in the comparison table, which is around 8MB
+	const MAX_CELLS: usize = 1_000_000;
+	let all_lhs   = left.lines().collect::<Vec<_>>();
+	let all_rhs   = right.lines().collect::<Vec<_>>();
+	//	Exclude the lines that are the same at the start and end
+	let prefix    = all_lhs.iter().zip(&all_rhs).take_while(|&(lhs, rhs)| lhs == rhs).count();
+	let suffix    = all_lhs[prefix..].iter().rev()
+		.zip(all_rhs[prefix..].iter().rev())
+		.take_while(|&(lhs, rhs)| lhs == rhs)
+		.count()
+	;
+	let lhs       = &all_lhs[prefix..all_lhs.len() - suffix];
+	let rhs       = &all_rhs[prefix..all_rhs.len() - suffix];
+	let mut diffs = vec![];
+	if (lhs.len() + 1).saturating_mul(rhs.len() + 1) > MAX_CELLS {
+		diffs.extend(lhs.iter().enumerate().map(|(i, text)|
+			TextLineDiff::Removed { line: prefix + i + 1, text: (*text).to_owned() }
+		));
+		diffs.extend(rhs.iter().enumerate().map(|(j, text)|
+			TextLineDiff::Added   { line: prefix + j + 1, text: (*text).to_owned() }
+		));
+		return diffs;
+	}
+	//	Build a table of the longest common subsequence lengths of the remaining
+	//	lines from each position, working backwards from the end
+	let mut table = vec![vec![0_usize; rhs.len() + 1]; lhs.len() + 1];
+	for i in (0..lhs.len()).rev() {
+		for j in (0..rhs.len()).rev() {
+			table[i][j] = if lhs[i] == rhs[j] {
+				table[i + 1][j + 1] + 1
+			} else {
+				table[i + 1][j].max(table[i][j + 1])
+			};
+		}
+	}
+	//	Walk the table forwards to find the differences
+	let (mut i, mut j) = (0, 0);
+	while i < lhs.len() || j < rhs.len() {
+		if i < lhs.len() && j < rhs.len() && lhs[i] == rhs[j] {
+			i += 1;
+			j += 1;
+		} else if i < lhs.len() && (j == rhs.len() || table[i + 1][j] >= table[i][j + 1]) {
+			diffs.push(TextLineDiff::Removed { line: prefix + i + 1, text: lhs[i].to_owned() });
+			i += 1;
+		} else {
+			diffs.push(TextLineDiff::Added   { line: prefix + j + 1, text: rhs[j].to_owned() });
+			j += 1;
+		}
 	}
+	diffs
 }
 
-//󰭅		From Incoming															
-impl From<Incoming> for UnpackedResponseBody {
-	//		from																
-	/// Converts an [`Incoming`] to an [`UnpackedResponseBody`].
-	fn from(b: Incoming) -> Self {
-		//	Collect the body into Collected<Bytes>
-		let collected = executor::block_on(b.collect()).unwrap_or_else(|_| Collected::default());
-		Self { body: collected.to_bytes().to_vec(), ..Default::default() }
-	}
+//		find_delimiter															
+/// Finds the next multipart boundary delimiter in a body.
+/// 
+/// A delimiter only counts if it is at the start of a line, and is followed by
+/// the end of the line, whitespace, or the closing `--`, so that boundaries
+/// that are prefixes of other text are not matched.
+/// 
+/// # Parameters
+/// 
+/// * `data`      - The body data.
+/// * `delimiter` - The delimiter, i.e. the boundary prefixed with `--`.
+/// * `from`      - The position from which to search.
+/// 
+fn find_delimiter(data: &[u8], delimiter: &[u8], from: usize) -> Option<usize> {
+	(from..data.len()).find(|&position| {
+		let at_line_start = position == 0 || data.get(position.saturating_sub(1)) == Some(&b'\n');
+		let rest          = data.get(position..).unwrap_or_default();
+		at_line_start && rest.strip_prefix(delimiter).is_some_and(|tail| {
+			tail.is_empty() || tail.starts_with(b"--") || matches!(tail.first(), Some(b' ' | b'\t' | b'\r' | b'\n'))
+		})
+	})
 }
 
-//󰭅		From Json																
-impl From<Json> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`serde_json::Value`] to an [`UnpackedResponseBody`].
-	fn from(j: Json) -> Self {
-		Self { body: j.to_string().into_bytes(), ..Default::default() }
+//		har_content																
+/// Converts a body into a HAR content object.
+/// 
+/// This is used for both the `content` of responses and the `postData` of
+/// requests. Bodies that are marked as binary, or that are not valid UTF8, are
+/// encoded as base64.
+/// 
+/// # Parameters
+/// 
+/// * `body`      - The body.
+/// * `mime_type` - The MIME type of the body, if known.
+/// 
+fn har_content(body: &UnpackedResponseBody, mime_type: Option<&str>) -> JsonMap<String, Json> {
+	let mut content = JsonMap::new();
+	drop(content.insert("mimeType".to_owned(), Json::from(mime_type.unwrap_or_default())));
+	match from_utf8(body.as_bytes()) {
+		Ok(text) if !body.is_binary() => {
+			drop(content.insert("text".to_owned(),     Json::from(text)));
+		},
+		_                             => {
+			drop(content.insert("text".to_owned(),     Json::from(body.to_base64())));
+			drop(content.insert("encoding".to_owned(), Json::from("base64")));
+		},
 	}
+	content
 }
 
-//󰭅		From &Json																
-impl From<&Json> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`&serde_json::Value`](serde_json::Value) to an
-	/// [`UnpackedResponseBody`].
-	fn from(j: &Json) -> Self {
-		Self { body: j.to_string().into_bytes(), ..Default::default() }
+//		har_cookie																
+/// Converts a cookie into a HAR cookie object.
+/// 
+/// # Parameters
+/// 
+/// * `cookie` - The cookie.
+/// 
+fn har_cookie(cookie: &UnpackedCookie) -> Json {
+	let mut object = JsonMap::new();
+	drop(object.insert("name".to_owned(),     Json::from(cookie.name.as_str())));
+	drop(object.insert("value".to_owned(),    Json::from(cookie.value.as_str())));
+	if let Some(ref path) = cookie.path {
+		drop(object.insert("path".to_owned(),    Json::from(path.as_str())));
+	}
+	if let Some(ref domain) = cookie.domain {
+		drop(object.insert("domain".to_owned(),  Json::from(domain.as_str())));
 	}
+	if let Some(ref expires) = cookie.expires {
+		drop(object.insert("expires".to_owned(), Json::from(expires.as_str())));
+	}
+	drop(object.insert("httpOnly".to_owned(), Json::from(cookie.http_only)));
+	drop(object.insert("secure".to_owned(),   Json::from(cookie.secure)));
+	Json::Object(object)
 }
 
-//󰭅		From &str																
-impl From<&str> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`&str`](str) to an [`UnpackedResponseBody`].
-	fn from(s: &str) -> Self {
-		Self { body: s.to_owned().as_bytes().to_vec(), ..Default::default() }
-	}
+//		har_field																
+/// Returns a required field of a HAR object.
+/// 
+/// # Parameters
+/// 
+/// * `value` - The HAR object.
+/// * `name`  - The name of the field.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the field is missing.
+/// 
+fn har_field<'a>(value: &'a Json, name: &str) -> Result<&'a Json, HarError> {
+	value.get(name).ok_or_else(|| HarError::InvalidField(name.to_owned()))
 }
 
-//󰭅		From &mut str															
-impl From<&mut str> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`&mut str`](str) to an [`UnpackedResponseBody`].
-	fn from(s: &mut str) -> Self {
-		Self { body: s.to_owned().as_bytes().to_vec(), ..Default::default() }
-	}
+//		har_headers																
+/// Converts headers into a list of HAR header objects.
+/// 
+/// # Parameters
+/// 
+/// * `headers` - The headers.
+/// 
+fn har_headers(headers: &[UnpackedResponseHeader]) -> Json {
+	headers.iter()
+		.map(|header| json!({ "name": header.name, "value": header.value }))
+		.collect()
 }
 
-//󰭅		From String																
-impl From<String> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`String`] to an [`UnpackedResponseBody`].
-	fn from(s: String) -> Self {
-		Self { body: s.into_bytes(), ..Default::default() }
-	}
+//		har_str																	
+/// Returns a required string field of a HAR object.
+/// 
+/// # Parameters
+/// 
+/// * `value` - The HAR object.
+/// * `name`  - The name of the field.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the field is missing, or is not a
+/// string.
+/// 
+fn har_str<'a>(value: &'a Json, name: &str) -> Result<&'a str, HarError> {
+	har_field(value, name)?.as_str().ok_or_else(|| HarError::InvalidField(name.to_owned()))
 }
 
-//󰭅		From &String															
-impl From<&String> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`&String`](String) to an [`UnpackedResponseBody`].
-	fn from(s: &String) -> Self {
-		Self { body: s.as_str().as_bytes().to_vec(), ..Default::default() }
+//		header_bytes															
+/// Returns the names and values of headers as bytes.
+/// 
+/// If raw headers are present, i.e. if they were retained when unpacking, then
+/// they are used, so that values that are not valid UTF8 are exact. Otherwise
+/// the headers are used.
+/// 
+/// # Parameters
+/// 
+/// * `headers`     - The unpacked response headers.
+/// * `raw_headers` - The unpacked response headers with their raw values.
+/// 
+/// # See also
+/// 
+/// * [`UnpackedResponse::raw_headers`]
+/// 
+fn header_bytes<'a>(
+	headers:     &'a [UnpackedResponseHeader],
+	raw_headers: &'a [UnpackedRawHeader],
+) -> Vec<(&'a str, &'a [u8])> {
+	if raw_headers.is_empty() {
+		headers.iter().map(|header| (header.name.as_str(), header.value.as_bytes())).collect()
+	} else {
+		raw_headers.iter().map(|header| (header.name.as_str(), header.value.as_slice())).collect()
 	}
 }
 
-//󰭅		From Box<str>															
-impl From<Box<str>> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [boxed](Box) [string](str) slice to an
-	/// [`UnpackedResponseBody`].
-	fn from(s: Box<str>) -> Self {
-		Self { body: s.into_string().into_bytes(), ..Default::default() }
-	}
+//		is_token_char															
+/// Determines whether a character can appear in an unquoted MIME parameter.
+/// 
+/// # Parameters
+/// 
+/// * `character` - The character to check.
+/// 
+const fn is_token_char(character: char) -> bool {
+	character.is_ascii_graphic() && !matches!(character,
+		'(' | ')' | '<' | '>' | '@' | ',' | ';' | ':' | '\\' | '"' | '/' | '[' | ']' | '?' | '='
+	)
 }
 
-//󰭅		From Cow<str>															
-impl<'a> From<Cow<'a, str>> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [clone-on-write](Cow) [string](str) to an
-	/// [`UnpackedResponseBody`].
-	fn from(s: Cow<'a, str>) -> Self {
-		Self { body: s.into_owned().into_bytes(), ..Default::default() }
+//		is_yaml_path															
+/// Returns whether a file path has a YAML extension.
+/// 
+/// # Parameters
+/// 
+/// * `path` - The path to check.
+/// 
+#[cfg(feature = "cassette")]
+fn is_yaml_path(path: &Path) -> bool {
+	path.extension()
+		.and_then(|extension| extension.to_str())
+		.is_some_and(|extension| extension.eq_ignore_ascii_case("yaml") || extension.eq_ignore_ascii_case("yml"))
+}
+
+//		json_path_matches														
+/// Returns whether a JSON path is at or beneath a path pattern.
+/// 
+/// The pattern may contain wildcards in place of object keys (`.*`) or array
+/// indexes (`[*]`).
+/// 
+/// # Parameters
+/// 
+/// * `pattern` - The path pattern to match against.
+/// * `path`    - The path to check.
+/// 
+/// # See also
+/// 
+/// * [`ResponseMatcher::ignore_json_field()`]
+/// 
+fn json_path_matches(pattern: &str, path: &str) -> bool {
+	let expected_segments = split_json_path(pattern);
+	let actual_segments   = split_json_path(path);
+	expected_segments.len() <= actual_segments.len() && expected_segments.iter().zip(&actual_segments).all(|(expected, actual)| {
+		   expected == actual
+		|| (expected == ".*"  && actual.starts_with('.'))
+		|| (expected == "[*]" && actual.starts_with('['))
+	})
+}
+
+//		split_json_path															
+/// Splits a JSON path into its segments.
+/// 
+/// Each segment retains its leading delimiter, so `$.foo[2]` is split into
+/// `$`, `.foo`, and `[2]`.
+/// 
+/// # Parameters
+/// 
+/// * `path` - The path to split.
+/// 
+/// # See also
+/// 
+/// * [`json_path_matches()`]
+/// 
+fn split_json_path(path: &str) -> Vec<String> {
+	let mut segments = vec![];
+	let mut current  = String::new();
+	for ch in path.chars() {
+		if (ch == '.' || ch == '[') && !current.is_empty() {
+			segments.push(mem::take(&mut current));
+		}
+		current.push(ch);
 	}
+	if !current.is_empty() {
+		segments.push(current);
+	}
+	segments
 }
 
-//󰭅		From u8																	
-impl From<u8> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`u8`] to an [`UnpackedResponseBody`].
-	fn from(c: u8) -> Self {
-		Self { body: Vec::from([c]), ..Default::default() }
+//		mime_essence															
+/// Returns the essence of a MIME type.
+/// 
+/// This is the type and subtype, in lowercase, without any parameters such as
+/// `charset`.
+/// 
+/// # Parameters
+/// 
+/// * `mime_type` - The MIME type, e.g. from a `content-type` header.
+/// 
+/// # See also
+/// 
+/// * [`UnpackOptions::content_type_for()`]
+/// 
+fn mime_essence(mime_type: &str) -> String {
+	mime_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase()
+}
+
+//		mime_parameter															
+/// Returns the value of a named parameter from a MIME type.
+/// 
+/// This also works for other headers with the same parameter syntax, such as
+/// `content-disposition`. Parameter names are matched case-insensitively, and
+/// quoted values are unquoted and unescaped.
+/// 
+/// # Parameters
+/// 
+/// * `mime_type` - The MIME type, e.g. from a `content-type` header.
+/// * `name`      - The name of the parameter.
+/// 
+fn mime_parameter(mime_type: &str, name: &str) -> Option<String> {
+	let mut segments = vec![String::new()];
+	let mut quoted   = false;
+	let mut escaped  = false;
+	for character in mime_type.chars() {
+		match character {
+			_ if escaped     => escaped = false,
+			'\\' if quoted => escaped = true,
+			'"'              => quoted  = !quoted,
+			';' if !quoted   => {
+				segments.push(String::new());
+				continue;
+			},
+			_                => {},
+		}
+		segments.last_mut()?.push(character);
 	}
+	segments.into_iter().skip(1).find_map(|segment| {
+		let (key, raw_value) = segment.split_once('=')?;
+		if !key.trim().eq_ignore_ascii_case(name) {
+			return None;
+		}
+		let value            = raw_value.trim();
+		let Some(inner)      = value.strip_prefix('"').and_then(|rest| rest.strip_suffix('"')) else {
+			return Some(value.to_owned());
+		};
+		let mut unescaped    = String::with_capacity(inner.len());
+		let mut characters   = inner.chars();
+		while let Some(character) = characters.next() {
+			unescaped.push(if character == '\\' { characters.next().unwrap_or(character) } else { character });
+		}
+		Some(unescaped)
+	})
 }
 
-//󰭅		From Vec<u8>															
-impl From<Vec<u8>> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`Vec[u8]`](Vec) to an [`UnpackedResponseBody`].
-	fn from(v: Vec<u8>) -> Self {
-		Self { body: v, ..Default::default() }
+//		ordered_headers															
+/// Returns the headers from a header map, in the order to be unpacked.
+/// 
+/// By default, the headers are sorted by name, and then by the bytes of the
+/// value, which for valid UTF8 is the same as sorting by the value as a string.
+/// If enabled using [`UnpackOptions::with_preserved_header_order()`], they are
+/// left in the order in which they are held in the header map.
+/// 
+/// # Parameters
+/// 
+/// * `headermap` - The headers to order.
+/// * `options`   - The options that control the unpacking.
+/// 
+/// # See also
+/// 
+/// * [`convert_headers()`]
+/// * [`convert_raw_headers()`]
+/// 
+fn ordered_headers<'a>(
+	headermap: &'a HeaderMap<HeaderValue>,
+	options:   &UnpackOptions,
+) -> Vec<(&'a HeaderName, &'a HeaderValue)> {
+	let mut headers = headermap.iter().collect::<Vec<_>>();
+	if !options.preserve_header_order {
+		headers.sort_by(|&(a_name, a_value), &(b_name, b_value)| {
+			match a_name.as_str().cmp(b_name.as_str()) {
+				Ordering::Equal   => a_value.as_bytes().cmp(b_value.as_bytes()),
+				Ordering::Greater => Ordering::Greater,
+				Ordering::Less    => Ordering::Less,
+			}
+		});
 	}
+	headers
 }
 
-//󰭅		From &Vec<u8>															
-impl From<&Vec<u8>> for UnpackedResponseBody {
-	//		from																
-	/// Converts a [`&Vec[u8]`](Vec) to an [`UnpackedResponseBody`].
-	fn from(v: &Vec<u8>) -> Self {
-		Self { body: v.clone(), ..Default::default() }
+//		parse_har_body															
+/// Parses the body from a HAR content object.
+/// 
+/// This is used for both the `content` of responses and the `postData` of
+/// requests. If there is no text, but there are form parameters, then these
+/// are encoded as the body. The content type is detected from the headers,
+/// falling back to the MIME type of the content object.
+/// 
+/// # Parameters
+/// 
+/// * `content` - The HAR content object, if present.
+/// * `headers` - The headers of the message.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if a field is invalid, or if the body
+/// is marked as base64 and cannot be decoded.
+/// 
+fn parse_har_body(content: Option<&Json>, headers: &HeaderMap<HeaderValue>) -> Result<UnpackedResponseBody, HarError> {
+	let Some(object)  = content else {
+		return Ok(UnpackedResponseBody::default());
+	};
+	let data          = match (object.get("text"), object.get("params").and_then(Json::as_array)) {
+		(Some(raw_text), _)  => {
+			let text = raw_text.as_str().ok_or_else(|| HarError::InvalidField("text".to_owned()))?;
+			if object.get("encoding").and_then(Json::as_str) == Some("base64") {
+				UnpackedResponseBody::from_base64(text).map_err(HarError::Base64)?.into_bytes()
+			} else {
+				text.as_bytes().to_vec()
+			}
+		},
+		(None, Some(params)) => {
+			let pairs = params.iter()
+				.map(|param| Ok((har_str(param, "name")?, param.get("value").and_then(Json::as_str).unwrap_or_default())))
+				.collect::<Result<Vec<_>, HarError>>()?
+			;
+			UnpackedResponseBody::from_form(&pairs).map_err(|_err| HarError::InvalidField("params".to_owned()))?.into_bytes()
+		},
+		(None, None)         => Vec::new(),
+	};
+	let mime_type     = headers.get(CONTENT_TYPE)
+		.and_then(|value| value.to_str().ok())
+		.or_else(|| object.get("mimeType").and_then(Json::as_str))
+	;
+	let content_type  = detect_mime_content_type(mime_type, &data, &UnpackOptions::default());
+	Ok(UnpackedResponseBody { body: data, content_type })
+}
+
+//		parse_har_headers														
+/// Parses the headers from a HAR request or response object.
+/// 
+/// HTTP/2 pseudo-headers, such as `:authority` and `:status`, are ignored. If
+/// there are no headers then the result is empty.
+/// 
+/// # Parameters
+/// 
+/// * `value` - The HAR request or response object.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if a header is invalid.
+/// 
+fn parse_har_headers(value: &Json) -> Result<HeaderMap<HeaderValue>, HarError> {
+	let mut headermap = HeaderMap::new();
+	let headers       = value.get("headers")
+		.map_or(Ok(&[][..]), |headers| headers.as_array().map(Vec::as_slice).ok_or_else(|| HarError::InvalidField("headers".to_owned())))?
+	;
+	for header in headers {
+		let name      = har_str(header, "name")?;
+		if name.starts_with(':') {
+			continue;
+		}
+		let invalid   = || HarError::InvalidField(format!("headers.{name}"));
+		_ = headermap.append(
+			HeaderName::from_bytes(name.as_bytes()).map_err(|_err| invalid())?,
+			HeaderValue::from_str(har_str(header, "value")?).map_err(|_err| invalid())?,
+		);
 	}
+	Ok(headermap)
 }
 
-//󰭅		FromStr																	
-impl FromStr for UnpackedResponseBody {
-	type Err = Infallible;
-	
-	//		from_str															
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		Ok(Self { body: s.as_bytes().to_vec(), ..Default::default() })
+//		parse_header_lines														
+/// Parses header lines into unpacked headers.
+/// 
+/// Lines may be terminated with either CRLF or LF, and blank lines are
+/// ignored. Lines starting with whitespace are treated as continuations of the
+/// previous header, in accordance with the obsolete line folding syntax. Header
+/// names are converted to lowercase, and names and values are trimmed.
+/// 
+/// # Parameters
+/// 
+/// * `head` - The header lines.
+/// 
+/// # Errors
+/// 
+/// This function will return the first invalid line as an error, if any.
+/// 
+fn parse_header_lines(head: &str) -> Result<Vec<UnpackedResponseHeader>, String> {
+	let mut headers = Vec::<UnpackedResponseHeader>::new();
+	for line in head.split('\n').map(|line| line.trim_end_matches('\r')) {
+		if line.is_empty() {
+			continue;
+		}
+		if line.starts_with([' ', '\t']) {
+			let header     = headers.last_mut().ok_or_else(|| line.to_owned())?;
+			header.value.push(' ');
+			header.value.push_str(line.trim());
+			continue;
+		}
+		let (name, value) = line.split_once(':').ok_or_else(|| line.to_owned())?;
+		headers.push(UnpackedResponseHeader::new(name.trim().to_ascii_lowercase(), value.trim().to_owned()));
 	}
+	Ok(headers)
 }
 
-//󰭅		PartialEq																
-impl PartialEq for UnpackedResponseBody {
-	//		eq																	
-	fn eq(&self, other: &Self) -> bool {
-		self.body == other.body
+//		parse_http1_response													
+/// Parses an HTTP/1.1 response message into an unpacked response.
+/// 
+/// Any interim informational (`1xx`) responses preceding the final response,
+/// such as are shown by `curl -i`, are skipped. A chunked body is decoded if
+/// the `transfer-encoding` header says that it is chunked, and it can be
+/// decoded, in which case that header is replaced by a `content-length` header
+/// giving the decoded length. The headers are converted and the body content
+/// type is detected in the same way as when unpacking a response.
+/// 
+/// # Parameters
+/// 
+/// * `data`    - The response message.
+/// * `options` - The options to apply when unpacking.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the status line or a header is
+/// invalid, or if the body cannot be unpacked.
+/// 
+/// # See also
+/// 
+/// * [`UnpackedResponse::from_http1_with()`]
+/// 
+fn parse_http1_response(data: &[u8], options: &UnpackOptions) -> Result<UnpackedResponse, Http1Error> {
+	let mut message        = data.trim_ascii_start();
+	let (status, raw, body) = loop {
+		let (head, body)       = split_head(message);
+		let head_text          = String::from_utf8_lossy(head);
+		let (status_line, raw) = head_text.split_once('\n').unwrap_or((&head_text, ""));
+		let status             = parse_status_line(status_line.trim_end_matches('\r'))
+			.ok_or_else(|| Http1Error::InvalidStatusLine(status_line.trim_end_matches('\r').to_owned()))?
+		;
+		if !status.is_informational() || !body.trim_ascii_start().starts_with(b"HTTP/") {
+			break (status, raw.to_owned(), body);
+		}
+		message                = body.trim_ascii_start();
+	};
+	let mut headermap      = HeaderMap::new();
+	for header in parse_header_lines(&raw).map_err(Http1Error::InvalidHeader)? {
+		let name           = HeaderName::from_bytes(header.name.as_bytes()).map_err(|_err| Http1Error::InvalidHeader(header.name.clone()))?;
+		let value          = HeaderValue::from_str(&header.value).map_err(|_err| Http1Error::InvalidHeader(header.name.clone()))?;
+		_ = headermap.append(name, value);
+	}
+	let chunked            = headermap.get_all(TRANSFER_ENCODING).iter()
+		.filter_map(|value| value.to_str().ok())
+		.any(|value| value.to_ascii_lowercase().contains("chunked"))
+	;
+	let bytes              = chunked.then(|| decode_chunked(body)).flatten().map_or_else(|| Bytes::copy_from_slice(body), |decoded| {
+		drop(headermap.remove(TRANSFER_ENCODING));
+		drop(headermap.insert(CONTENT_LENGTH, HeaderValue::from(decoded.len())));
+		Bytes::from(decoded)
+	});
+	if bytes.len() > options.limit {
+		return Err(Http1Error::Response(ResponseError::LimitExceeded(options.limit)));
 	}
+	convert_response(status, &headermap, &bytes, Vec::new(), options).map_err(Http1Error::Response)
 }
 
-//󰭅		Serialize																
-impl Serialize for UnpackedResponseBody {
-	//		serialize															
-	/// Serialises the response body to a [`String`].
-	/// 
-	/// This method serialises the response body based on the content type. If
-	/// the content type is [`ContentType::Text`], then the response body is
-	/// serialised to an ordinary [`String`]. If the content type is
-	/// [`ContentType::Binary`], then the response body is serialised to a
-	/// base64-encoded [`String`].
-	/// 
-	/// Note that as no validation checks are performed on the response body
-	/// contents, it is not guaranteed to be UTF8, and therefore if not
-	/// specified as binary it is possible that the serialised string will not
-	/// totally match the original response body contents. This is because the
-	/// conversion of the response body bytes to a UTF8 string will be lossy if
-	/// there are invalid characters.
-	/// 
-	/// # See also
-	/// 
-	/// * [`UnpackedResponseBody::deserialize()`]
-	/// * [`UnpackedResponseBody::<Display>fmt()`]
-	/// * [`UnpackedResponseBody::to_base64()`]
-	/// 
-	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-	where
-		S: Serializer,
-	{
-		serializer.serialize_str(&self.to_string())
+//		parse_http_version														
+/// Parses an HTTP version, as used in HAR files.
+/// 
+/// Different tools use different forms, such as `HTTP/2`, `http/2.0`, and
+/// `h2`, so these are all accepted, case-insensitively. An empty version, as
+/// recorded by some tools when the version is not known, is taken as HTTP/1.1.
+/// 
+/// # Parameters
+/// 
+/// * `name` - The HTTP version.
+/// 
+fn parse_http_version(name: &str) -> Option<Version> {
+	match name.to_ascii_lowercase().as_str() {
+		"http/0.9"                   => Some(Version::HTTP_09),
+		"http/1.0"                   => Some(Version::HTTP_10),
+		"http/1.1" | ""              => Some(Version::HTTP_11),
+		"http/2" | "http/2.0" | "h2" => Some(Version::HTTP_2),
+		"http/3" | "http/3.0" | "h3" => Some(Version::HTTP_3),
+		_                            => None,
 	}
 }
 
-//󰭅		Deserialize																
-impl <'de> Deserialize<'de> for UnpackedResponseBody {
-	//		deserialize															
-	/// Deserialises the response body from a [`String`].
-	/// 
-	/// This method deserialises the response body based on the content type.
-	/// However, as this method is not an instance method and it is not possible
-	/// to specify the content type in advance, it has to try to detect it. It
-	/// does this by attempting to decode the string as base64. If this succeeds
-	/// then it will set the content type as [`ContentType::Binary`]. If this
-	/// fails then it will assume the content type is [`ContentType::Text`], and
-	/// deserialises the string in standard fashion.
-	/// 
-	/// Note that as the incoming data is from a [`String`], and Rust strings
-	/// are are all valid UTF8, the resulting deserialised response body is
-	/// guaranteed to be UTF8 if the content type is determined to be
-	/// [`ContentType::Text`]. If base64 is detected then the deserialised bytes
-	/// are not guaranteed to be valid UTF8, as no validation checks of that
-	/// nature are performed against the response body.
-	///
-	/// # See also
-	///
-	/// * [`UnpackedResponseBody::deserialize()`]
-	/// * [`UnpackedResponseBody::from_base64()`]
-	///
-	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-	where
-		D: Deserializer<'de>,
-	{
-		let string = String::deserialize(deserializer)?;
-		#[expect(clippy::option_if_let_else, reason = "Using map_or_else() here would not be as clear, and no more concise")]
-		match BASE64.decode(&string) {
-			Ok(decoded) => Ok(Self { body: decoded,             content_type: ContentType::Binary }),
-			Err(_)      => Ok(Self { body: string.into_bytes(), content_type: ContentType::Text }),
+//		parse_multipart															
+/// Parses a multipart body into its parts.
+/// 
+/// # Parameters
+/// 
+/// * `data`     - The body data.
+/// * `boundary` - The boundary that separates the parts.
+/// * `options`  - The options used to detect the content type of each part.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the boundary is empty or not found,
+/// if the body is not terminated by a closing delimiter, or if a part contains
+/// an invalid header.
+/// 
+/// # See also
+/// 
+/// * [`UnpackedResponseBody::as_multipart()`]
+/// 
+fn parse_multipart(data: &[u8], boundary: &str, options: &UnpackOptions) -> Result<Vec<MultipartPart>, MultipartError> {
+	if boundary.is_empty() {
+		return Err(MultipartError::MissingBoundary);
+	}
+	let delimiter    = format!("--{boundary}").into_bytes();
+	let mut position = find_delimiter(data, &delimiter, 0).ok_or(MultipartError::MissingDelimiter)?;
+	let mut parts    = Vec::new();
+	loop {
+		let after    = position.saturating_add(delimiter.len());
+		let rest     = data.get(after..).unwrap_or_default();
+		if rest.starts_with(b"--") {
+			return Ok(parts);
 		}
+		let start    = rest.iter()
+			.position(|&byte| byte == b'\n')
+			.map(|line_end| after.saturating_add(line_end).saturating_add(1))
+			.ok_or(MultipartError::Unterminated)?
+		;
+		position     = find_delimiter(data, &delimiter, start).ok_or(MultipartError::Unterminated)?;
+		let raw      = data.get(start..position).unwrap_or_default();
+		let content  = raw.strip_suffix(b"\r\n").or_else(|| raw.strip_suffix(b"\n")).unwrap_or(raw);
+		parts.push(parse_multipart_part(content, options)?);
 	}
 }
 
-//󰭅		Write																	
-impl Write for UnpackedResponseBody {
-	//		write_str															
-	fn write_str(&mut self, s: &str) -> fmt::Result {
-		self.push_str(s);
-		Ok(())
-	}
+//		parse_multipart_part													
+/// Parses a single part of a multipart body.
+/// 
+/// # Parameters
+/// 
+/// * `content` - The content of the part, including its headers.
+/// * `options` - The options used to detect the content type of the part.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the part contains an invalid header.
+/// 
+fn parse_multipart_part(content: &[u8], options: &UnpackOptions) -> Result<MultipartPart, MultipartError> {
+	let (head, data)   = content.strip_prefix(b"\r\n")
+		.or_else(|| content.strip_prefix(b"\n"))
+		.map_or_else(|| split_head(content), |rest| (&b""[..], rest))
+	;
+	let headers        = parse_header_lines(&String::from_utf8_lossy(head)).map_err(MultipartError::InvalidHeader)?;
+	let mime_type      = headers.iter()
+		.find(|header| header.name == CONTENT_TYPE.as_str())
+		.map(|header| header.value.as_str())
+	;
+	let content_type   = detect_mime_content_type(mime_type, data, options);
+	Ok(MultipartPart {
+		headers,
+		body: UnpackedResponseBody { body: data.to_vec(), content_type },
+	})
 }
 
-
-
-//		Traits																											
-
-//§		ResponseExt																
-/// This trait provides additional functionality to [`Response`].
-pub trait ResponseExt {
-	//		unpack																
-	/// Returns an [`UnpackedResponse`] containing the unpacked response data.
-	/// 
-	/// This will unpack the response and provide the headers and body in a
-	/// more accessible form, to allow it to be checked and compared. This is
-	/// useful for testing, as the entire set of headers plus body can be
-	/// checked all at once, and also for printing/logging.
-	/// 
-	/// If specific headers or body content needs to be checked, it is
-	/// recommended to use the standard functions as they will be more
-	/// efficient and performant. Notably, this function will consume the
-	/// response body, which is necessary because the response might be
-	/// streamed. In order to provide the full response, the whole body must be
-	/// read first. This will obviously use more memory than would be used under
-	/// normal circumstances, so it is not recommended to use this function
-	/// without considering purpose and effect. For tests, ensuring a response
-	/// body matches, this is fine, as the data is known and constrained, and
-	/// memory/performance is less of a concern.
-	/// 
-	/// # Errors
-	/// 
-	/// This function will potentially return an error if the response body
-	/// cannot be converted to bytes. This should not happen under normal
-	/// circumstances, but it may be possible if the response body is streamed
-	/// and the stream cannot be read. Many implementations of this function are
-	/// in fact infallible.
-	/// 
-	/// At present [`ResponseError`] only contains one error variant, but it is
-	/// possible that more will be added.
-	/// 
-	/// # See also
-	/// 
-	/// * [`axum::response`](https://docs.rs/axum/latest/axum/response/index.html)
-	/// * [`axum::response::Response`](https://docs.rs/axum/latest/axum/response/type.Response.html)
-	/// * [`http::Response`]
-	/// * [`hyper::Response`]
-	/// * [`UnpackedResponse`]
-	/// 
-	fn unpack(&mut self) -> Result<UnpackedResponse, ResponseError>;
+//		parse_status_line														
+/// Parses the status code from an HTTP response status line.
+/// 
+/// The status line is expected to be in the form `HTTP/1.1 200 OK`. Any HTTP
+/// version is accepted, and the reason phrase is optional, so that the output
+/// of tools such as `curl -i` for HTTP/2 responses can also be parsed.
+/// 
+/// # Parameters
+/// 
+/// * `line` - The status line.
+/// 
+fn parse_status_line(line: &str) -> Option<StatusCode> {
+	let mut parts = line.splitn(3, ' ');
+	if !parts.next()?.starts_with("HTTP/") {
+		return None;
+	}
+	StatusCode::from_bytes(parts.next()?.as_bytes()).ok()
 }
 
-//󰭅		Response<()>															
-impl ResponseExt for Response<()> {
-	//		unpack																
-	fn unpack(&mut self) -> Result<UnpackedResponse, ResponseError> {
-		Ok(convert_response(self.status(), self.headers(), &Bytes::new()))
+//		parse_sse_events														
+/// Parses a `text/event-stream` into Server-Sent Events.
+/// 
+/// This follows the parsing rules of the HTML specification, except that the
+/// event ID is not carried over from one event to the next.
+/// 
+/// # Parameters
+/// 
+/// * `stream` - The event stream to parse.
+/// 
+/// # See also
+/// 
+/// * [`ServerSentEvent`]
+/// * [`UnpackedResponseBody::as_sse_events()`]
+/// 
+fn parse_sse_events(stream: &str) -> Vec<ServerSentEvent> {
+	let mut events  = vec![];
+	let mut current = ServerSentEvent::default();
+	let mut data    = None::<String>;
+	let normalised  = stream.strip_prefix('\u{FEFF}').unwrap_or(stream).replace("\r\n", "\n").replace('\r', "\n");
+	for line in normalised.split_inclusive('\n') {
+		if !line.ends_with('\n') {
+			break;
+		}
+		let content = line.trim_end_matches('\n');
+		if content.is_empty() {
+			let event = mem::take(&mut current);
+			if let Some(text) = data.take() {
+				events.push(ServerSentEvent { data: text, ..event });
+			}
+			continue;
+		}
+		if content.starts_with(':') {
+			continue;
+		}
+		let (field, raw_value) = content.split_once(':').unwrap_or((content, ""));
+		let value              = raw_value.strip_prefix(' ').unwrap_or(raw_value);
+		match field {
+			"data"                            => match data {
+				Some(ref mut text) => { text.push('\n'); text.push_str(value); },
+				None               => data = Some(value.to_owned()),
+			},
+			"event"                           => current.event = Some(value.to_owned()),
+			"id" if !value.contains('\0')     => current.id    = Some(value.to_owned()),
+			"retry"                           => if let Ok(retry) = value.parse() { current.retry = Some(retry); },
+			_                                 => {},
+		}
 	}
+	events
 }
 
-//󰭅		Response<AxumBody>														
-#[cfg(feature = "axum")]
-impl ResponseExt for Response<AxumBody> {
-	//		unpack																
-	fn unpack(&mut self) -> Result<UnpackedResponse, ResponseError> {
-		let status  = self.status();
-		let headers = self.headers().clone();
-		let bytes   = executor::block_on(to_bytes(mem::replace(self.body_mut(), AxumBody::empty()), usize::MAX))
-			.map_err(|e| ResponseError::ConversionError(Box::new(e)))?
-		;
-		Ok(convert_response(status, &headers, &bytes))
-	}
+//		quote_parameter															
+/// Quotes a MIME parameter value.
+/// 
+/// Any backslashes and double quotes in the value are escaped.
+/// 
+/// # Parameters
+/// 
+/// * `value` - The parameter value.
+/// 
+fn quote_parameter(value: &str) -> String {
+	format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
 }
 
-//󰭅		Response<Full<Bytes>>													
-impl ResponseExt for Response<Full<Bytes>> {
-	//		unpack																
-	fn unpack(&mut self) -> Result<UnpackedResponse, ResponseError> {
-		//	Collect the body into Collected<Bytes>
-		let collected = self.body().clone().collect().now_or_never()
-			.unwrap_or_else(|| Ok(Collected::default()))
-			.map_err(|e| ResponseError::ConversionError(Box::new(e)))?
-		;
-		Ok(convert_response(self.status(), self.headers(), &collected.to_bytes()))
+//		read_body																
+/// Reads a response body, recording its frames if required.
+/// 
+/// # Parameters
+/// 
+/// * `body`    - The response body to read.
+/// * `options` - The options that control the unpacking.
+/// 
+/// # Errors
+/// 
+/// * [`ResponseError::BodyReadError`] if the body cannot be read.
+/// * [`ResponseError::LimitExceeded`] if the body exceeds the size limit.
+/// 
+/// # See also
+/// 
+/// * [`collect_body()`]
+/// * [`collect_frames()`]
+/// 
+async fn read_body<B>(body: B, options: &UnpackOptions) -> Result<ReadBody, ResponseError>
+where
+	B:        Body,
+	B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+	if options.record_frames || options.timeout.is_some() {
+		collect_frames(body, options).await
+	} else {
+		Ok(ReadBody { bytes: collect_body(body, options.limit).await?, ..Default::default() })
 	}
 }
 
-//󰭅		Response<Incoming>														
-impl ResponseExt for Response<Incoming> {
-	//		unpack																
-	fn unpack(&mut self) -> Result<UnpackedResponse, ResponseError> {
-		//	Collect the body into Collected<Bytes>
-		let collected = executor::block_on(self.body_mut().collect())
-			.map_err(|e| ResponseError::ConversionError(Box::new(e)))?
-		;
-		Ok(convert_response(self.status(), self.headers(), &collected.to_bytes()))
+//		rebuild_response														
+/// Returns a [`Response`] built from unpacked response data.
+/// 
+/// This function carries out the common part of the conversion process for
+/// turning an [`UnpackedResponse`] back into a [`Response`], for the various
+/// body types supported. Headers are added in the order given, and any with
+/// names or values that are not valid are omitted.
+/// 
+/// # Parameters
+/// 
+/// * `status`      - The response status code.
+/// * `headers`     - The unpacked response headers.
+/// * `raw_headers` - The unpacked response headers with their raw values,
+///                   which are used in place of the headers if present.
+/// * `body`        - The response body.
+/// 
+/// # See also
+/// 
+/// * [`UnpackedResponse`]
+/// 
+fn rebuild_response<B>(
+	status:      StatusCode,
+	headers:     &[UnpackedResponseHeader],
+	raw_headers: &[UnpackedRawHeader],
+	body:        B,
+) -> Response<B> {
+	let mut response       = Response::new(body);
+	*response.status_mut() = status;
+	for (header_name, header_value) in header_bytes(headers, raw_headers) {
+		if let (Ok(name), Ok(value)) = (
+			HeaderName::from_bytes(header_name.as_bytes()),
+			HeaderValue::from_bytes(header_value),
+		) {
+			_ = response.headers_mut().append(name, value);
+		}
 	}
+	response
 }
 
-//󰭅		Response<String>														
-impl ResponseExt for Response<String> {
-	//		unpack																
-	fn unpack(&mut self) -> Result<UnpackedResponse, ResponseError> {
-		Ok(convert_response(self.status(), self.headers(), &Bytes::from(self.body().clone())))
+//		redact_json																
+/// Redacts the values at matching paths in a JSON value.
+/// 
+/// Any value whose path matches one of the patterns is replaced with
+/// `placeholder`. Otherwise, objects and arrays are traversed.
+/// 
+/// # Parameters
+/// 
+/// * `path`        - The path to the value.
+/// * `value`       - The value to redact.
+/// * `patterns`    - The path patterns to redact.
+/// * `placeholder` - The text to replace redacted values with.
+/// 
+/// # See also
+/// 
+/// * [`SnapshotOptions::with_redacted_json_field()`]
+/// 
+fn redact_json(path: &str, value: &mut Json, patterns: &[String], placeholder: &str) {
+	if patterns.iter().any(|pattern| json_path_matches(pattern, path)) {
+		*value = Json::String(placeholder.to_owned());
+	} else if let Some(object) = value.as_object_mut() {
+		for (key, child) in object.iter_mut() {
+			redact_json(&format!("{path}.{key}"), child, patterns, placeholder);
+		}
+	} else if let Some(array) = value.as_array_mut() {
+		for (index, child) in array.iter_mut().enumerate() {
+			redact_json(&format!("{path}[{index}]"), child, patterns, placeholder);
+		}
 	}
 }
 
+//		request_path															
+/// Returns the path and query of a request URI.
+/// 
+/// # Parameters
+/// 
+/// * `uri` - The URI of the request.
+/// 
+#[cfg(feature = "cassette")]
+fn request_path(uri: &Uri) -> String {
+	uri.path_and_query().map_or_else(|| "/".to_owned(), ToString::to_string)
+}
 
-
-//		Functions																										
-
-//		convert_headers															
-/// Returns a vector of unpacked response headers.
+//		service_error															
+/// Returns a [`CassetteError`] for an error from an inner service.
 /// 
-/// These are returned in a vector rather than a hashmap because there may be
-/// multiple headers with the same name. They are sorted by name, and then by
-/// value, allowing for reliable comparison. Sorting does break the original
-/// order of the headers, but this should only very rarely matter.
+/// If the error is itself a [`CassetteError`], such as from an
+/// [`OfflineService`], it is returned as-is, so that it can be matched upon.
+/// Otherwise it is wrapped in [`CassetteError::Service`].
 /// 
-/// # See also
+/// # Parameters
 /// 
-/// * [`ResponseExt::unpack()`]
-/// * [`UnpackedResponse`]
-/// * [`UnpackedResponseHeader`]
+/// * `err` - The error returned by the inner service.
 /// 
-fn convert_headers(headermap: &HeaderMap<HeaderValue>) -> Vec<UnpackedResponseHeader> {
-	let mut headers = vec![];
-	#[expect(clippy::shadow_reuse, reason = "Clear purpose")]
-	for (name, value) in headermap {
-		let name    = name.as_str().to_owned();
-		let value   = String::from_utf8_lossy(value.as_bytes()).into_owned();
-		headers.push(UnpackedResponseHeader { name, value });
-	}
-	headers.sort_by(|a, b| {
-		match a.name.cmp(&b.name) {
-			Ordering::Equal   => a.value.cmp(&b.value),
-			Ordering::Greater => Ordering::Greater,
-			Ordering::Less    => Ordering::Less,
-		}
-	});
-	headers
+#[cfg(feature = "cassette")]
+fn service_error(err: Box<dyn Error + Send + Sync>) -> CassetteError {
+	err.downcast::<CassetteError>().map_or_else(CassetteError::Service, |cassette_err| *cassette_err)
 }
 
-//		convert_response														
-/// Returns an [`UnpackedResponse`] containing the unpacked response data.
+//		split_head																
+/// Splits a message into its head and body.
 /// 
-/// This function carries out the common part of the conversion process for
-/// [`ResponseExt::unpack()`]. As [`unpack()`](ResponseExt::unpack()) has a
-/// number of implementations, the common code is abstracted out into this
-/// function.
+/// The head is separated from the body by the first blank line, which may be
+/// terminated with either CRLF or LF. If there is no blank line then the whole
+/// message is treated as the head. The message is only scanned as far as the
+/// first blank line.
 /// 
 /// # Parameters
 /// 
-/// * `status`  - The response status code.
-/// * `headers` - The response headers.
-/// * `body`    - The response body.
+/// * `message` - The message to split.
 /// 
-/// # See also
+fn split_head(message: &[u8]) -> (&[u8], &[u8]) {
+	message.iter().enumerate()
+		.filter(|&(_, &byte)| byte == b'\n')
+		.find_map(|(index, _)| {
+			let rest = message.get(index..).unwrap_or_default();
+			if rest.starts_with(b"\n\n") {
+				Some((index, 2))
+			} else if rest.starts_with(b"\n\r\n") {
+				index.checked_sub(1).filter(|&start| message.get(start) == Some(&b'\r')).map(|start| (start, 4))
+			} else {
+				None
+			}
+		})
+		.map_or((message, &b""[..]), |(index, length)| (
+			message.get(..index).unwrap_or_default(),
+			message.get(index.saturating_add(length)..).unwrap_or_default(),
+		))
+}
+
+//		write_snapshot															
+/// Writes a snapshot file, creating any parent directories.
 /// 
-/// * [`axum::response`](https://docs.rs/axum/latest/axum/response/index.html)
-/// * [`axum::response::Response`](https://docs.rs/axum/latest/axum/response/type.Response.html)
-/// * [`http::Response`]
-/// * [`hyper::Response`]
-/// * [`ResponseExt::unpack()`]
-/// * [`UnpackedResponse`]
-/// * [`UnpackedResponseHeader`]
+/// # Parameters
 /// 
-fn convert_response(
-	status:  StatusCode,
-	headers: &HeaderMap<HeaderValue>,
-	body:    &Bytes,
-) -> UnpackedResponse {
-	UnpackedResponse {
-		status,
-		headers: convert_headers(headers),
-		body:    UnpackedResponseBody { body: body.to_vec(), ..Default::default() },
+/// * `path`     - The path of the snapshot file.
+/// * `contents` - The serialised snapshot.
+/// 
+/// # Errors
+/// 
+/// * [`SnapshotError::Io`] if the file or its directories cannot be written.
+/// 
+fn write_snapshot(path: &Path, contents: &str) -> Result<(), SnapshotError> {
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent).map_err(SnapshotError::Io)?;
 	}
+	fs::write(path, contents).map_err(SnapshotError::Io)
 }
 
 //		serialize_status_code													
@@ -1658,4 +9682,407 @@ where
 	Ok(status_code)
 }
 
+//		serialize_method														
+/// Returns the request method as a string.
+/// 
+/// This function is used by [`serde`] to serialise the request method as a
+/// string, as the [`Method`] type does not provide serialisation support.
+/// 
+/// # Parameters
+/// 
+/// * `method`     - The request method to serialise.
+/// * `serializer` - The serialiser to use.
+/// 
+/// # See also
+/// 
+/// * [`deserialize_method()`]
+/// * [`http::Method`]
+/// * [`UnpackedRequest`]
+/// 
+fn serialize_method<S>(method: &Method, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	serializer.serialize_str(method.as_str())
+}
+
+//		deserialize_method														
+/// Returns the request method from a string.
+/// 
+/// This function is used by [`serde`] to deserialise the request method from a
+/// string, as the [`Method`] type does not provide deserialisation support.
+/// 
+/// # Parameters
+/// 
+/// * `deserializer` - The deserialiser to use.
+/// 
+/// # See also
+/// 
+/// * [`http::Method`]
+/// * [`serialize_method()`]
+/// * [`UnpackedRequest`]
+/// 
+fn deserialize_method<'de, D>(deserializer: D) -> Result<Method, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let method_value: String = Deserialize::deserialize(deserializer)?;
+	let method               = Method::from_str(&method_value).map_err(DeError::custom)?;
+	Ok(method)
+}
+
+//		serialize_uri															
+/// Returns the request URI as a string.
+/// 
+/// This function is used by [`serde`] to serialise the request URI as a
+/// string, as the [`Uri`] type does not provide serialisation support.
+/// 
+/// # Parameters
+/// 
+/// * `uri`        - The request URI to serialise.
+/// * `serializer` - The serialiser to use.
+/// 
+/// # See also
+/// 
+/// * [`deserialize_uri()`]
+/// * [`http::Uri`]
+/// * [`UnpackedRequest`]
+/// 
+fn serialize_uri<S>(uri: &Uri, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	serializer.serialize_str(&uri.to_string())
+}
+
+//		deserialize_uri															
+/// Returns the request URI from a string.
+/// 
+/// This function is used by [`serde`] to deserialise the request URI from a
+/// string, as the [`Uri`] type does not provide deserialisation support.
+/// 
+/// # Parameters
+/// 
+/// * `deserializer` - The deserialiser to use.
+/// 
+/// # See also
+/// 
+/// * [`http::Uri`]
+/// * [`serialize_uri()`]
+/// * [`UnpackedRequest`]
+/// 
+fn deserialize_uri<'de, D>(deserializer: D) -> Result<Uri, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let uri_value: String = Deserialize::deserialize(deserializer)?;
+	let uri               = Uri::from_str(&uri_value).map_err(DeError::custom)?;
+	Ok(uri)
+}
+
+//		serialize_version														
+/// Returns the HTTP version as a string.
+/// 
+/// This function is used by [`serde`] to serialise the HTTP version as a
+/// string in the format `"HTTP/1.1"`, as the [`Version`] type does not provide
+/// serialisation support.
+/// 
+/// # Parameters
+/// 
+/// * `version`    - The HTTP version to serialise.
+/// * `serializer` - The serialiser to use.
+/// 
+/// # See also
+/// 
+/// * [`deserialize_version()`]
+/// * [`http::Version`]
+/// * [`UnpackedRequest`]
+/// 
+#[expect(clippy::trivially_copy_pass_by_ref, reason = "Needs to match trait")]
+fn serialize_version<S>(version: &Version, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	serializer.serialize_str(&format!("{version:?}"))
+}
+
+//		deserialize_version														
+/// Returns the HTTP version from a string.
+/// 
+/// This function is used by [`serde`] to deserialise the HTTP version from a
+/// string in the format `"HTTP/1.1"`, as the [`Version`] type does not provide
+/// deserialisation support.
+/// 
+/// # Parameters
+/// 
+/// * `deserializer` - The deserialiser to use.
+/// 
+/// # See also
+/// 
+/// * [`http::Version`]
+/// * [`serialize_version()`]
+/// * [`UnpackedRequest`]
+/// 
+fn deserialize_version<'de, D>(deserializer: D) -> Result<Version, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let version_value: String = Deserialize::deserialize(deserializer)?;
+	match version_value.as_str() {
+		"HTTP/0.9" => Ok(Version::HTTP_09),
+		"HTTP/1.0" => Ok(Version::HTTP_10),
+		"HTTP/1.1" => Ok(Version::HTTP_11),
+		"HTTP/2.0" => Ok(Version::HTTP_2),
+		"HTTP/3.0" => Ok(Version::HTTP_3),
+		_          => Err(DeError::custom(format!("Invalid HTTP version: {version_value}"))),
+	}
+}
+
+//		serialize_raw_header_value												
+/// Returns the raw header value as a base64-encoded string.
+/// 
+/// This function is used by [`serde`] to serialise the raw bytes of a header
+/// value, which may not be valid UTF8.
+/// 
+/// # Parameters
+/// 
+/// * `raw`        - The raw header value to serialise.
+/// * `serializer` - The serialiser to use.
+/// 
+/// # See also
+/// 
+/// * [`deserialize_raw_header_value()`]
+/// * [`UnpackedRawHeader`]
+/// 
+fn serialize_raw_header_value<S>(raw: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	serializer.serialize_str(&BASE64.encode(raw))
+}
+
+//		deserialize_raw_header_value											
+/// Returns the raw header value from a base64-encoded string.
+/// 
+/// This function is used by [`serde`] to deserialise the raw bytes of a header
+/// value.
+/// 
+/// # Parameters
+/// 
+/// * `deserializer` - The deserialiser to use.
+/// 
+/// # See also
+/// 
+/// * [`serialize_raw_header_value()`]
+/// * [`UnpackedRawHeader`]
+/// 
+fn deserialize_raw_header_value<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let encoded = String::deserialize(deserializer)?;
+	BASE64.decode(encoded).map_err(DeError::custom)
+}
+
+//		serialize_tagged_body													
+/// Returns the response body in tagged form, with an explicit content type.
+/// 
+/// The default [`Serialize`] implementation for [`UnpackedResponseBody`]
+/// produces a compact string, which relies upon detection to recover the
+/// content type when deserialising, and so does not always round-trip
+/// accurately. This function instead produces an object with `content_type`
+/// and `data` properties, e.g. `{"content_type": "text", "data": "test"}`,
+/// which does. Binary data is base64-encoded, and if a text or JSON body is not
+/// valid UTF8 then it is also base64-encoded, and an `encoding` property is
+/// added with a value of `base64`, so that no data is lost.
+/// 
+/// This function is intended for use by [`serde`] to serialise a body field,
+/// by specifying it with the `serialize_with` attribute, alongside
+/// [`deserialize_tagged_body()`] with the `deserialize_with` attribute. To
+/// serialise a whole response in this way, use
+/// [`serialize_tagged_response()`].
+/// 
+/// # Parameters
+/// 
+/// * `body`       - The response body to serialise.
+/// * `serializer` - The serialiser to use.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the body cannot be serialised. The
+/// error will be a [`Serializer::Error`], which is passed through from the
+/// [`serde`] crate.
+/// 
+/// # See also
+/// 
+/// * [`deserialize_tagged_body()`]
+/// * [`UnpackedResponseBody::serialize()`]
+/// 
+pub fn serialize_tagged_body<S>(body: &UnpackedResponseBody, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	let (data, encoding) = match (body.content_type, from_utf8(&body.body)) {
+		(ContentType::Binary, _)                          => (body.to_base64(), None),
+		(ContentType::Text | ContentType::Json, Ok(text)) => (text.to_owned(), None),
+		(ContentType::Text | ContentType::Json, Err(_))   => (body.to_base64(), Some("base64".to_owned())),
+	};
+	TaggedBody { content_type: body.content_type, data, encoding }.serialize(serializer)
+}
+
+//		deserialize_tagged_body													
+/// Returns the response body from tagged form, with an explicit content type.
+/// 
+/// This is the counterpart to [`serialize_tagged_body()`], and expects an
+/// object with `content_type` and `data` properties, plus an optional
+/// `encoding` property. The content type is used as given, rather than being
+/// detected.
+/// 
+/// # Parameters
+/// 
+/// * `deserializer` - The deserialiser to use.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the object is invalid, if the
+/// encoding is not recognised, or if base64 data cannot be decoded.
+/// 
+/// # See also
+/// 
+/// * [`serialize_tagged_body()`]
+/// * [`UnpackedResponseBody::deserialize()`]
+/// 
+pub fn deserialize_tagged_body<'de, D>(deserializer: D) -> Result<UnpackedResponseBody, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let tagged = TaggedBody::deserialize(deserializer)?;
+	let body   = match (tagged.content_type, tagged.encoding.as_deref()) {
+		(ContentType::Binary, None) | (_, Some("base64")) => BASE64.decode(&tagged.data).map_err(DeError::custom)?,
+		(_, None)                                         => tagged.data.into_bytes(),
+		(_, Some(encoding))                               => return Err(DeError::custom(format!("Unsupported body encoding: {encoding}"))),
+	};
+	Ok(UnpackedResponseBody { body, content_type: tagged.content_type })
+}
+
+//		serialize_tagged_response												
+/// Returns the response in tagged form, with an explicit content type.
+/// 
+/// The default [`Serialize`] implementation for [`UnpackedResponse`] uses the
+/// compact form of the body, which does not always round-trip accurately. This
+/// function produces the same form, except that the body, and the data of any
+/// frames, are serialised in the tagged form produced by
+/// [`serialize_tagged_body()`], and so the response round-trips losslessly.
+/// 
+/// This function is intended for use by [`serde`] to serialise a response
+/// field, by specifying it with the `serialize_with` attribute, alongside
+/// [`deserialize_tagged_response()`] with the `deserialize_with` attribute.
+/// 
+/// # Parameters
+/// 
+/// * `response`   - The response to serialise.
+/// * `serializer` - The serialiser to use.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the response cannot be serialised.
+/// The error will be a [`Serializer::Error`], which is passed through from the
+/// [`serde`] crate.
+/// 
+/// # See also
+/// 
+/// * [`deserialize_tagged_response()`]
+/// * [`serialize_tagged_body()`]
+/// 
+pub fn serialize_tagged_response<S>(response: &UnpackedResponse, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	TaggedResponse::from(response.clone()).serialize(serializer)
+}
+
+//		deserialize_tagged_response												
+/// Returns the response from tagged form, with an explicit content type.
+/// 
+/// This is the counterpart to [`serialize_tagged_response()`], and expects the
+/// body, and the data of any frames, to be in the tagged form expected by
+/// [`deserialize_tagged_body()`].
+/// 
+/// # Parameters
+/// 
+/// * `deserializer` - The deserialiser to use.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the response is invalid, or if its
+/// body cannot be deserialised.
+/// 
+/// # See also
+/// 
+/// * [`deserialize_tagged_body()`]
+/// * [`serialize_tagged_response()`]
+/// 
+pub fn deserialize_tagged_response<'de, D>(deserializer: D) -> Result<UnpackedResponse, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	TaggedResponse::deserialize(deserializer).map(UnpackedResponse::from)
+}
+
+//		serialize_tagged_request												
+/// Returns the request in tagged form, with an explicit content type.
+/// 
+/// This is used to store requests in a [`Cassette`], so that the body
+/// round-trips losslessly.
+/// 
+/// # Parameters
+/// 
+/// * `request`    - The request to serialise.
+/// * `serializer` - The serialiser to use.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the request cannot be serialised.
+/// The error will be a [`Serializer::Error`], which is passed through from the
+/// [`serde`] crate.
+/// 
+/// # See also
+/// 
+/// * [`deserialize_tagged_request()`]
+/// * [`serialize_tagged_body()`]
+/// 
+#[cfg(feature = "cassette")]
+fn serialize_tagged_request<S>(request: &UnpackedRequest, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	TaggedRequest::from(request.clone()).serialize(serializer)
+}
+
+//		deserialize_tagged_request												
+/// Returns the request from tagged form, with an explicit content type.
+/// 
+/// This is the counterpart to [`serialize_tagged_request()`].
+/// 
+/// # Parameters
+/// 
+/// * `deserializer` - The deserialiser to use.
+/// 
+/// # Errors
+/// 
+/// This function will return an error if the request is invalid, or if its
+/// body cannot be deserialised.
+/// 
+/// # See also
+/// 
+/// * [`deserialize_tagged_body()`]
+/// * [`serialize_tagged_request()`]
+/// 
+#[cfg(feature = "cassette")]
+fn deserialize_tagged_request<'de, D>(deserializer: D) -> Result<UnpackedRequest, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	TaggedRequest::deserialize(deserializer).map(UnpackedRequest::from)
+}
 
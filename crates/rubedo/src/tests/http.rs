@@ -3,20 +3,27 @@
 use super::*;
 use crate::sugar::s;
 use assert_json_diff::assert_json_eq;
-use axum::response::IntoResponse as _;
+use axum::{
+	Router,
+	response::IntoResponse as _,
+	routing::{get, post},
+};
 use bytes::Bytes;
-use claims::{assert_err, assert_ok, assert_ok_eq};
+use claims::{assert_err, assert_err_eq, assert_ok, assert_ok_eq};
 use core::{
 	convert::Infallible,
 	future::Future,
 	net::SocketAddr,
 	pin::Pin,
 	str::from_utf8,
+	sync::atomic::{AtomicUsize, Ordering as AtomicOrdering},
 };
+use flate2::write::DeflateEncoder;
+use futures::{StreamExt as _, channel::oneshot, stream};
 use http::{Request, Response};
-use http_body_util::Full;
+use http_body_util::{Empty, Full, StreamBody};
 use hyper::{
-	body::Incoming,
+	body::{Frame, Incoming},
 	server::conn::http1,
 	service::Service,
 };
@@ -29,16 +36,60 @@ use serde_assert::{
 	Serializer   as TestSerializer,
 	token::Token,
 };
-use serde_json::json;
+use regex::Regex;
+use serde_json::{json, value::Serializer as JsonSerializer};
+use std::sync::Mutex;
+use tempfile::tempdir;
 use tokio::{
 	net::TcpListener,
 	spawn,
+	task::spawn_blocking,
 };
+use tower_service::Service as TowerService;
 
 
 
 //		Structs																											
 
+//		TestRecordingService													
+#[derive(Clone, Default)]
+struct TestRecordingService(Arc<AtomicUsize>);
+
+//󰭅		TestRecordingService													
+impl TestRecordingService {
+	fn calls(&self) -> usize {
+		self.0.load(AtomicOrdering::SeqCst)
+	}
+}
+
+//󰭅		TowerService															
+impl<B> TowerService<Request<B>> for TestRecordingService
+where
+	B:        Body,
+	B::Error: Debug,
+{
+	type Response = Response<Full<Bytes>>;
+	type Error    = Infallible;
+	type Future   = Ready<Result<Self::Response, Self::Error>>;
+	
+	//		poll_ready															
+	fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+		Poll::Ready(Ok(()))
+	}
+	
+	//		call																
+	fn call(&mut self, req: Request<B>) -> Self::Future {
+		let count = self.0.fetch_add(1, AtomicOrdering::SeqCst) + 1;
+		let body  = req.into_body().collect().now_or_never().unwrap().unwrap().to_bytes();
+		ready(Ok(Response::builder()
+			.status(StatusCode::CREATED)
+			.header("x-count", count)
+			.body(Full::new(Bytes::from(format!("Response {count}: {}", from_utf8(&body).unwrap()))))
+			.unwrap()
+		))
+	}
+}
+
 //		TestService																
 struct TestService(Bytes);
 
@@ -62,6 +113,35 @@ impl Service<Request<Incoming>> for TestService {
 	}
 }
 
+//		TestRequestService														
+struct TestRequestService(Mutex<Option<oneshot::Sender<Result<UnpackedRequest, String>>>>);
+
+//󰭅		TestRequestService														
+impl TestRequestService {
+	const fn new(sender: oneshot::Sender<Result<UnpackedRequest, String>>) -> Self {
+		Self(Mutex::new(Some(sender)))
+	}
+}
+
+//󰭅		Service																	
+impl Service<Request<Incoming>> for TestRequestService {
+	type Response = Response<Full<Bytes>>;
+	type Error    = Infallible;
+	type Future   = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+	
+	//		call																
+	fn call(&self, mut req: Request<Incoming>) -> Self::Future {
+		let sender = self.0.lock().unwrap().take().unwrap();
+		Box::pin(async move {
+			//	The unpacking blocks, so it needs to happen away from the task
+			//	driving the connection, otherwise the body will never arrive.
+			let unpacked = spawn_blocking(move || req.unpack().map_err(|e| e.to_string())).await.unwrap();
+			sender.send(unpacked).unwrap();
+			Ok(Response::new(Full::new(Bytes::new())))
+		})
+	}
+}
+
 
 
 //		Functions																										
@@ -95,1502 +175,6704 @@ async fn create_incoming_for_testing(data: Bytes) -> Incoming {
 	incoming
 }
 
+//		create_stream_body_for_testing											
+fn create_stream_body_for_testing() -> impl Body<Data = Bytes, Error = Infallible> + Send + Unpin {
+	let mut trailers = HeaderMap::new();
+	drop(trailers.insert("x-checksum", HeaderValue::from_static("abc123")));
+	StreamBody::new(stream::iter(vec![
+		Ok(Frame::data(Bytes::from("data: one\n\n"))),
+		Ok(Frame::data(Bytes::from("data: two\n\n"))),
+		Ok(Frame::trailers(trailers)),
+	]))
+}
+
+//		create_stalled_body_for_testing											
+fn create_stalled_body_for_testing() -> impl Body<Data = Bytes, Error = Infallible> + Send + Unpin + 'static {
+	//	Sends a single frame and then never completes, as a peer that stalls
+	//	part-way through sending the body would.
+	StreamBody::new(stream::iter(vec![
+		Ok(Frame::data(Bytes::from("data: one\n\n"))),
+	]).chain(stream::pending()))
+}
+
+//		unpack_incoming_request_for_testing										
+async fn unpack_incoming_request_for_testing(
+	method: Method,
+	path:   &str,
+	data:   Bytes,
+) -> (String, Result<UnpackedRequest, String>) {
+	//	Bind to a local address on a random port, for the same reasons as noted
+	//	in create_incoming_for_testing(), as this is the only way to obtain a
+	//	Request<Incoming> instance.
+	let listener           = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0))).await.unwrap();
+	let address            = listener.local_addr().unwrap();
+	let (sender, receiver) = oneshot::channel();
+	
+	//	Spawn the server task to receive a single request and unpack it
+	let handle             = spawn(async move {
+		let (stream, _) = listener.accept().await.unwrap();
+		let io          = TokioIo::new(stream);
+		let service     = TestRequestService::new(sender);
+		
+		http1::Builder::new().serve_connection(io, service).await.expect("Error serving connection");
+	});
+	
+	//	Create a client and send a request
+	let request            = Request::builder()
+		.method(method)
+		.uri(format!("http://{address}{path}"))
+		.body(Full::new(data))
+		.unwrap()
+	;
+	drop(Client::builder(TokioExecutor::new())
+		.build_http::<Full<Bytes>>()
+		.request(request).await.unwrap()
+	);
+	
+	handle.await.unwrap();
+	(address.to_string(), receiver.await.unwrap())
+}
+
 
 
 //		Tests																											
 
-//		ResponseError															
+//		SameSite																
 #[cfg(test)]
-mod response_error {
+mod same_site__enum {
 	use super::*;
 	
-	#[derive(Debug)]
-	struct TestError;
-	
-	impl Display for TestError {
-		fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-			write!(f, "Test error")
-		}
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(SameSite::Strict.as_str(), "Strict");
+		assert_eq!(SameSite::Lax.as_str(),    "Lax");
+		assert_eq!(SameSite::None.as_str(),   "None");
 	}
 	
-	impl Error for TestError {}
-	
-	//		debug																
+	//		from_name															
 	#[test]
-	fn debug() {
-		let err = Err::<ResponseError, _>(ResponseError::ConversionError(Box::new(TestError)));
-		assert_err!(&err);
-		assert_eq!(format!("{err:?}"), "Err(ConversionError(TestError))");
+	fn from_name() {
+		assert_eq!(SameSite::from_name("Strict"), Some(SameSite::Strict));
+		assert_eq!(SameSite::from_name(" lax "),  Some(SameSite::Lax));
+		assert_eq!(SameSite::from_name("NONE"),   Some(SameSite::None));
+		assert_eq!(SameSite::from_name("foo"),    None);
 	}
+}
+
+#[cfg(test)]
+mod same_site__traits {
+	use super::*;
 	
 	//		display																
 	#[test]
 	fn display() {
-		let err = ResponseError::ConversionError(Box::new(TestError));
-		assert_eq!(err.to_string(), "Error encountered while converting response body to bytes: Test error");
+		assert_eq!(SameSite::Strict.to_string(), "Strict");
+		assert_eq!(SameSite::Lax.to_string(),    "Lax");
+		assert_eq!(SameSite::None.to_string(),   "None");
 	}
 }
 
-//		UnpackedResponse														
+//		CassetteError															
 #[cfg(test)]
-mod unpacked_response__struct {
+mod cassette_error {
 	use super::*;
 	
-	//		new																	
+	//		display																
 	#[test]
-	fn new() {
-		let headers  = vec![
-			(s!("Foo"), s!("Bar")),
-			(s!("Bar"), s!("Baz")),
-		];
-		let body     = "This is a test";
-		let unpacked = UnpackedResponse::new(StatusCode::OK, headers, body);
-		assert_eq!(unpacked, UnpackedResponse {
-			status:  StatusCode::OK,
-			headers: vec![
-				UnpackedResponseHeader { name: s!("Foo"), value: s!("Bar") },
-				UnpackedResponseHeader { name: s!("Bar"), value: s!("Baz") },
-			],
-			body:    UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		});
+	fn display() {
+		assert_eq!(
+			CassetteError::NotRecorded { method: Method::GET, path: s!("/foo?bar=baz") }.to_string(),
+			"No recorded response found for GET /foo?bar=baz",
+		);
+		assert_eq!(
+			CassetteError::Offline { method: Method::POST, path: s!("/foo") }.to_string(),
+			"Cannot record POST /foo as there is no service to forward it to",
+		);
+		assert_eq!(
+			CassetteError::Io(IoError::other("Test error")).to_string(),
+			"Error encountered while reading or writing cassette file",
+		);
 	}
 	
-	//		new_from_parts														
+	//		source																
 	#[test]
-	fn new_from_parts() {
-		let headers  = vec![
-			UnpackedResponseHeader::new(s!("Foo"), s!("Bar")),
-			UnpackedResponseHeader::new(s!("Bar"), s!("Baz")),
-		];
-		let body     = UnpackedResponseBody::new("This is a test");
-		let unpacked = UnpackedResponse::new_from_parts(StatusCode::OK, headers, body);
-		assert_eq!(unpacked, UnpackedResponse {
-			status:  StatusCode::OK,
-			headers: vec![
-				UnpackedResponseHeader { name: s!("Foo"), value: s!("Bar") },
-				UnpackedResponseHeader { name: s!("Bar"), value: s!("Baz") },
-			],
-			body:    UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		});
+	fn source() {
+		let err1 = CassetteError::Io(IoError::other("Test error"));
+		assert_eq!(err1.source().unwrap().to_string(), "Test error");
+		let err2 = CassetteError::Service(Box::new(IoError::other("Test error")));
+		assert_eq!(err2.source().unwrap().to_string(), "Test error");
+		let err3 = CassetteError::Json(serde_json::from_str::<Vec<CassetteEntry>>("Not JSON").unwrap_err());
+		assert_eq!(err3.source().unwrap().to_string(), "expected value at line 1 column 1");
+		assert!(CassetteError::NotRecorded { method: Method::GET, path: s!("/foo") }.source().is_none());
 	}
 }
 
+//		CharsetError															
 #[cfg(test)]
-mod unpacked_response__traits {
+mod charset_error {
 	use super::*;
 	
-	//		debug																
+	//		display																
 	#[test]
-	fn debug() {
-		let response     = UnpackedResponse {
-			status:        StatusCode::OK,
-			headers:       vec![
-				UnpackedResponseHeader {
-					name:  s!("foo"),
-					value: s!("bar"),
-				},
-			],
-			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		};
-		assert_eq!(format!("{response:?}"), r#"UnpackedResponse { status: 200, headers: [UnpackedResponseHeader { name: "foo", value: "bar" }], body: UnpackedResponseBody { body: "This is a test", content_type: Text } }"#);
+	fn display() {
+		assert_eq!(CharsetError::Invalid(s!("utf-16")).to_string(),     "Body is not valid utf-16");
+		assert_eq!(CharsetError::Unsupported(s!("koi8-r")).to_string(), "Unsupported charset: koi8-r");
 	}
+}
+
+//		CassetteMode															
+#[cfg(test)]
+mod cassette_mode__enum {
+	use super::*;
 	
-	//		partial_eq															
+	//		default																
 	#[test]
-	fn partial_eq() {
-		let response     = UnpackedResponse {
-			status:        StatusCode::OK,
-			headers:       vec![
-				UnpackedResponseHeader {
-					name:  s!("foo"),
-					value: s!("bar"),
-				},
-			],
-			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		};
-		assert_ne!(response, UnpackedResponse {
-			status:        StatusCode::NOT_FOUND,
-			headers:       vec![
-				UnpackedResponseHeader {
-					name:  s!("foo"),
-					value: s!("bar"),
-				},
-			],
-			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		});
-		assert_eq!(response, UnpackedResponse {
-			status:        StatusCode::OK,
-			headers:       vec![
-				UnpackedResponseHeader {
-					name:  s!("foo"),
-					value: s!("bar"),
-				},
-			],
-			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		});
-		assert_ne!(response, UnpackedResponse {
-			status:        StatusCode::OK,
-			headers:       vec![
-				UnpackedResponseHeader {
-					name:  s!("foo"),
-					value: s!("baz"),
-				},
-			],
-			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		});
-		assert_ne!(response, UnpackedResponse {
-			status:        StatusCode::OK,
-			headers:       vec![
-				UnpackedResponseHeader {
-					name:  s!("baz"),
-					value: s!("bar"),
-				},
-			],
-			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		});
-		assert_ne!(response, UnpackedResponse {
-			status:        StatusCode::OK,
-			headers:       vec![
-				UnpackedResponseHeader {
-					name:  s!("foo"),
-					value: s!("bar"),
-				},
-			],
-			body:          UnpackedResponseBody { body: b"This is different".to_vec(), ..Default::default() },
-		});
+	fn default() {
+		assert_eq!(CassetteMode::default(), CassetteMode::Replay);
 	}
+}
+
+//		ContentEncoding															
+#[cfg(test)]
+mod content_encoding__enum {
+	use super::*;
 	
-	//		serialize															
+	//		as_str																
 	#[test]
-	fn serialize() {
-		let response       = UnpackedResponse {
-			status:          StatusCode::OK,
-			headers:         vec![
-				UnpackedResponseHeader {
-					name:    s!("foo"),
-					value:   s!("bar"),
-				},
-			],
-			body:            UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		};
-		let json           = json!({
-			"status":        200,
-			"headers":       [
-				{
-					"name":  "foo",
-					"value": "bar",
-				},
-			],
-			"body":          "This is a test",
-		});
-		assert_json_eq!(json!(response), json);
+	fn as_str() {
+		assert_eq!(ContentEncoding::Brotli.as_str(),  "br");
+		assert_eq!(ContentEncoding::Deflate.as_str(), "deflate");
+		assert_eq!(ContentEncoding::Gzip.as_str(),    "gzip");
 	}
 	
-	//		deserialize															
+	//		from_name															
 	#[test]
-	fn deserialize() {
-		let response       = UnpackedResponse {
-			status:          StatusCode::OK,
-			headers:         vec![
-				UnpackedResponseHeader {
-					name:    s!("foo"),
-					value:   s!("bar"),
-				},
-			],
-			body:            UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		};
-		let json           = json!({
-			"status":        200,
-			"headers":       [
-				{
-					"name":  "foo",
-					"value": "bar",
-				},
-			],
-			"body":          "This is a test",
-		}).to_string();
-		assert_ok_eq!(serde_json::from_str::<UnpackedResponse>(&json), response);
+	fn from_name() {
+		assert_eq!(ContentEncoding::from_name("br"),       Some(ContentEncoding::Brotli));
+		assert_eq!(ContentEncoding::from_name("deflate"),  Some(ContentEncoding::Deflate));
+		assert_eq!(ContentEncoding::from_name("gzip"),     Some(ContentEncoding::Gzip));
+		assert_eq!(ContentEncoding::from_name(" GZIP "),   Some(ContentEncoding::Gzip));
+		assert_eq!(ContentEncoding::from_name("x-gzip"),   Some(ContentEncoding::Gzip));
+		assert_eq!(ContentEncoding::from_name("zstd"),     None);
+		assert_eq!(ContentEncoding::from_name("identity"), None);
 	}
 }
 
-//		UnpackedResponseHeader													
 #[cfg(test)]
-mod unpacked_response_header__struct {
+mod content_encoding__traits {
 	use super::*;
 	
-	//		new																	
+	//		display																
 	#[test]
-	fn new() {
-		let header = UnpackedResponseHeader::new(s!("Foo"), s!("Bar"));
-		assert_eq!(header, UnpackedResponseHeader { name: s!("Foo"), value: s!("Bar") });
+	fn display() {
+		assert_eq!(ContentEncoding::Brotli.to_string(),  "br");
+		assert_eq!(ContentEncoding::Deflate.to_string(), "deflate");
+		assert_eq!(ContentEncoding::Gzip.to_string(),    "gzip");
 	}
 }
 
+//		LogEvent																
 #[cfg(test)]
-mod unpacked_response_header__traits {
+mod log_event__traits {
 	use super::*;
 	
-	//		partial_eq															
+	//		display																
 	#[test]
-	fn partial_eq() {
-		let header = UnpackedResponseHeader {
-			name:    s!("foo"),
-			value:   s!("bar"),
-		};
-		assert_eq!(header, UnpackedResponseHeader {
-			name:    s!("foo"),
-			value:   s!("bar"),
-		});
-		assert_ne!(header, UnpackedResponseHeader {
-			name:    s!("foo"),
-			value:   s!("baz"),
-		});
-		assert_ne!(header, UnpackedResponseHeader {
-			name:    s!("baz"),
-			value:   s!("bar"),
-		});
-	}
-	
-	//		serialize															
-	#[test]
-	fn serialize() {
-		let headers    = vec![
-			UnpackedResponseHeader {
-				name:    s!("foo"),
-				value:   s!("bar"),
-			},
-		];
-		let json       = json!([
-			{
-				"name":  "foo",
-				"value": "bar",
-			},
-		]);
-		assert_json_eq!(json!(headers), json);
+	fn display() {
+		let request  = UnpackedRequest::new(Method::GET, Uri::from_static("/foo"), vec![], "");
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("bar"))], "Hello");
+		assert_eq!(
+			LogEvent::Request(request).to_string(),
+			r#"Request: {"method":"GET","uri":"/foo","version":"HTTP/1.1","headers":[],"body":""}"#,
+		);
+		assert_eq!(
+			LogEvent::Response(response).to_string(),
+			r#"Response: {"status":200,"headers":[{"name":"foo","value":"bar"}],"body":"Hello"}"#,
+		);
 	}
+}
+
+//		MatchFailure															
+#[cfg(test)]
+mod match_failure__traits {
+	use super::*;
 	
-	//		deserialize															
+	//		display																
 	#[test]
-	fn deserialize() {
-		let headers    = vec![
-			UnpackedResponseHeader {
-				name:    s!("foo"),
-				value:   s!("bar"),
-			},
-		];
-		let json       = json!([
-			{
-				"name":  "foo",
-				"value": "bar",
-			},
-		]).to_string();
-		assert_ok_eq!(serde_json::from_str::<Vec<UnpackedResponseHeader>>(&json), headers);
+	fn display() {
+		assert_eq!(
+			MatchFailure::Status { expected: StatusCode::OK, actual: StatusCode::NOT_FOUND }.to_string(),
+			"Status: 200 OK != 404 Not Found",
+		);
+		assert_eq!(
+			MatchFailure::Header(HeaderDiff::Removed(UnpackedResponseHeader::new(s!("foo"), s!("bar")))).to_string(),
+			"Header: - foo: bar",
+		);
+		assert_eq!(
+			MatchFailure::HeaderValue { name: s!("foo"), actual: Some(s!("bar")), requirement: s!("match /^\\d+$/") }.to_string(),
+			"Header: foo: bar does not match /^\\d+$/",
+		);
+		assert_eq!(
+			MatchFailure::HeaderValue { name: s!("foo"), actual: None, requirement: s!("satisfy predicate") }.to_string(),
+			"Header: foo is missing, and must satisfy predicate",
+		);
+		assert_eq!(
+			MatchFailure::Body(BodyDiff::Binary { offset: 1, left_len: 2, right_len: 3 }).to_string(),
+			"Body (binary): first difference at byte offset 1 (left length 2, right length 3)",
+		);
 	}
 }
 
-//		UnpackedResponseBody													
+//		HarError																
 #[cfg(test)]
-mod unpacked_response_body__struct {
+mod har_error {
 	use super::*;
 	
-	//		new																	
+	//		display																
 	#[test]
-	fn new() {
-		let body1 = UnpackedResponseBody::new(b"This is a test".to_vec());
-		assert_eq!(body1, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		
-		let body2 = UnpackedResponseBody::new("This is a test");
-		assert_eq!(body2, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn display() {
+		assert_eq!(HarError::InvalidField(s!("url")).to_string(),            "Missing or invalid HAR field: url");
+		assert_eq!(HarError::Io(IoError::other("Test error")).to_string(),     "Error encountered while reading or writing HAR file");
+		assert_eq!(HarError::Base64(DecodeError::InvalidLength(1)).to_string(), "Error encountered while decoding base64 body");
 	}
 	
-	//		content_type														
+	//		source																
 	#[test]
-	fn content_type() {
-		let body1       = UnpackedResponseBody {
-			body:         b"This is a test".to_vec(),
-			content_type: ContentType::Text
-		};
-		assert_eq!(body1.content_type(), ContentType::Text);
-		
-		let body2       = UnpackedResponseBody {
-			body:         b"This is a test".to_vec(),
-			content_type: ContentType::Binary
-		};
-		assert_eq!(body2.content_type(), ContentType::Binary);
+	fn source() {
+		let err1 = HarError::Base64(DecodeError::InvalidLength(1));
+		assert_eq!(err1.source().unwrap().to_string(), "Invalid input length: 1");
+		let err2 = HarError::Io(IoError::other("Test error"));
+		assert_eq!(err2.source().unwrap().to_string(), "Test error");
+		assert!(HarError::InvalidField(s!("url")).source().is_none());
 	}
+}
+
+//		Http1Error																
+#[cfg(test)]
+mod http1_error {
+	use super::*;
 	
-	//		set_content_type													
+	//		display																
 	#[test]
-	fn set_content_type() {
-		let mut body = UnpackedResponseBody { body: b"".to_vec(), ..Default::default() };
-		assert_eq!(body.content_type(), ContentType::Text);
-		
-		_ = body.set_content_type(ContentType::Binary);
-		assert_eq!(body.content_type(), ContentType::Binary);
-		
-		_ = body.set_content_type(ContentType::Text);
-		assert_eq!(body.content_type(), ContentType::Text);
-		
-		let mut clone = body.clone();
-		assert_eq!(clone.set_content_type(ContentType::Text), &body);
-		
-		assert_eq!(body.set_content_type(ContentType::Binary).content_type(), ContentType::Binary);
+	fn display() {
+		assert_eq!(Http1Error::InvalidHeader(s!("foo")).to_string(),     "Invalid header: foo");
+		assert_eq!(Http1Error::InvalidStatusLine(s!("foo")).to_string(), "Invalid status line: foo");
+		assert_eq!(
+			Http1Error::Response(ResponseError::LimitExceeded(10)).to_string(),
+			"Error encountered while unpacking response",
+		);
 	}
+}
+
+//		LoggingError															
+#[cfg(test)]
+mod logging_error {
+	use super::*;
 	
-	//		is_binary															
+	//		display																
 	#[test]
-	fn is_binary() {
-		let mut body = UnpackedResponseBody { body: b"".to_vec(), ..Default::default() };
-		assert!(!body.is_binary());
-		
-		_ = body.set_content_type(ContentType::Binary);
-		assert!( body.is_binary());
-		
-		_ = body.set_content_type(ContentType::Text);
-		assert!(!body.is_binary());
+	fn display() {
+		assert_eq!(
+			LoggingError::Body(Box::new(IoError::other("Test error"))).to_string(),
+			"Error encountered while reading message body",
+		);
+		assert_eq!(
+			LoggingError::Service(Box::new(IoError::other("Test error"))).to_string(),
+			"Error encountered while calling inner service",
+		);
 	}
 	
-	//		is_text																
+	//		source																
 	#[test]
-	fn is_text() {
-		let mut body = UnpackedResponseBody { body: b"".to_vec(), ..Default::default() };
-		assert!( body.is_text());
-		
-		_ = body.set_content_type(ContentType::Binary);
-		assert!(!body.is_text());
-		
-		_ = body.set_content_type(ContentType::Text);
-		assert!( body.is_text());
+	fn source() {
+		let err1 = LoggingError::Body(Box::new(IoError::other("Test error")));
+		assert_eq!(err1.source().unwrap().to_string(), "Test error");
+		let err2 = LoggingError::Service(Box::new(IoError::other("Test error")));
+		assert_eq!(err2.source().unwrap().to_string(), "Test error");
 	}
+}
+
+//		MultipartError															
+#[cfg(test)]
+mod multipart_error {
+	use super::*;
 	
-	//		as_bytes															
+	//		display																
 	#[test]
-	fn as_bytes() {
-		let body       = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		let byte_slice = body.as_bytes();
-		
-		//	Ensure the byte slice matches the original response body's bytes.
-		assert_eq!(byte_slice, b"This is a test".to_vec());
-		
-		//	We can't modify the byte slice due to immutability.
-		//	Uncommenting the line below would cause a compilation error:
-		//byte_slice[10] = 84;
-		
-		//	as_bytes() doesn't consume the original response body.
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn display() {
+		assert_eq!(MultipartError::InvalidHeader(s!("foo")).to_string(), "Invalid header in multipart part: foo");
+		assert_eq!(MultipartError::MissingBoundary.to_string(),          "No multipart boundary specified in content type");
+		assert_eq!(MultipartError::MissingDelimiter.to_string(),         "Multipart body does not contain the boundary delimiter");
+		assert_eq!(MultipartError::Unterminated.to_string(),             "Multipart body is not terminated by a closing delimiter");
 	}
+}
+
+//		RequestError															
+#[cfg(test)]
+mod request_error {
+	use super::*;
 	
-	//		as_mut_bytes														
-	#[test]
-	fn as_mut_bytes() {
-		let mut body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		let byte_vec = body.as_mut_bytes();
-		
-		//	Ensure the byte vector matches the original response body's bytes.
-		assert_eq!(*byte_vec, b"This is a test".to_vec());
-		
-		// We can modify the byte vector.
-		byte_vec[10] = 84;
-		assert_eq!(*byte_vec, b"This is a Test".to_vec());
-		
-		//	as_mut_bytes() doesn't consume the original response body, but modifying
-		//	the returned vector will have affected the response body's contents.
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a Test".to_vec(), ..Default::default() });
+	#[derive(Debug)]
+	struct TestError;
+	
+	impl Display for TestError {
+		fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+			write!(f, "Test error")
+		}
 	}
 	
-	//		into_bytes															
+	impl Error for TestError {}
+	
+	//		debug																
 	#[test]
-	fn into_bytes() {
-		let body         = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		let mut byte_vec = body.into_bytes();
-		
-		//	Ensure the byte vector matches the original response body's bytes.
-		assert_eq!(byte_vec, b"This is a test".to_vec());
-		
-		// We can modify the byte vector.
-		byte_vec[10]     = 84;
-		assert_eq!(byte_vec, b"This is a Test".to_vec());
-		
-		//	We can't use the original response body after calling into_bytes(),
-		//	because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		// assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn debug() {
+		let err = Err::<RequestError, _>(RequestError::BodyReadError(Box::new(TestError)));
+		assert_err!(&err);
+		assert_eq!(format!("{err:?}"), "Err(BodyReadError(TestError))");
 	}
 	
-	//		to_bytes															
+	//		display																
 	#[test]
-	fn to_bytes() {
-		let body           = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		let mut byte_clone = body.to_bytes();
-		
-		//	Ensure the clone matches the original response body's bytes.
-		assert_eq!(byte_clone, b"This is a test".to_vec());
-		
-		//	We can modify the cloned byte vector.
-		byte_clone[10]     = 84;
-		assert_eq!(byte_clone, b"This is a Test".to_vec());
-		
-		//	to_bytes() doesn't consume or affect the original response body.
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn display() {
+		let err = RequestError::BodyReadError(Box::new(TestError));
+		assert_eq!(err.to_string(), "Error encountered while reading request body");
 	}
 	
-	//		to_string															
+	//		source																
 	#[test]
-	fn to_string() {
-		let body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		assert_eq!(body.to_string(), "This is a test");
+	fn source() {
+		let err = RequestError::BodyReadError(Box::new(TestError));
+		assert_eq!(err.source().unwrap().to_string(), "Test error");
 	}
+}
+
+//		ResponseError															
+#[cfg(test)]
+mod response_error {
+	use super::*;
 	
-	//		to_base64															
-	#[expect(invalid_from_utf8, reason = "Needed for the test")]
-	#[test]
-	fn to_base64() {
-		let mut body    = UnpackedResponseBody {
-			body:         b"This is a test".to_vec(),
-			content_type: ContentType::Binary,
-		};
-		assert_eq!(body.to_base64(), "VGhpcyBpcyBhIHRlc3Q=");
-		
-		body.clear();
-		assert_eq!(body.to_base64(), "");
-		
-		body.body       = vec![0x80];
-		assert_err!(from_utf8(&[0x80]));
-		assert_eq!(body.to_base64(), "gA==");
+	#[derive(Debug)]
+	struct TestError;
+	
+	impl Display for TestError {
+		fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+			write!(f, "Test error")
+		}
 	}
 	
-	//		from_base64															
-	#[expect(invalid_from_utf8, reason = "Needed for the test")]
+	impl Error for TestError {}
+	
+	//		debug																
 	#[test]
-	fn from_base64__valid() {
-		let body1 = UnpackedResponseBody::from_base64("VGhpcyBpcyBhIHRlc3Q=").unwrap();
-		assert_eq!(body1.body,         b"This is a test");
-		assert_eq!(body1.content_type, ContentType::Binary);
-		
-		let body2 = UnpackedResponseBody::from_base64("").unwrap();
-		assert!(body2.body.is_empty());
-		assert_eq!(body2.content_type, ContentType::Binary);
-		
-		let body3 = UnpackedResponseBody::from_base64("gA==").unwrap();
-		assert_eq!(body3.body,         vec![0x80]);
-		assert_eq!(body3.content_type, ContentType::Binary);
-		assert_err!(from_utf8(&[0x80]));
+	fn debug() {
+		let err = Err::<ResponseError, _>(ResponseError::BodyReadError(Box::new(TestError)));
+		assert_err!(&err);
+		assert_eq!(format!("{err:?}"), "Err(BodyReadError(TestError))");
 	}
 	
+	//		display																
 	#[test]
-	fn from_base64__invalid() {
-		assert_err!(UnpackedResponseBody::from_base64("invalid@@base64"));
+	fn display() {
+		let err = ResponseError::BodyReadError(Box::new(TestError));
+		assert_eq!(err.to_string(), "Error encountered while reading response body");
+	}
+	#[test]
+	fn display__limit_exceeded() {
+		let err = ResponseError::LimitExceeded(10);
+		assert_eq!(err.to_string(), "Response body exceeded the size limit of 10 bytes");
+	}
+	#[test]
+	fn display__decompression_error() {
+		let err = ResponseError::DecompressionError(IoError::other("Test error"));
+		assert_eq!(err.to_string(), "Error encountered while decompressing response body");
+	}
+	#[test]
+	fn display__unsupported_encoding() {
+		let err = ResponseError::UnsupportedEncoding(s!("zstd"));
+		assert_eq!(err.to_string(), "Unsupported content encoding: zstd");
+	}
+	#[test]
+	fn display__invalid_utf8() {
+		let bytes = [b'a', 0xFF];
+		let err   = ResponseError::InvalidUtf8(from_utf8(&bytes[..]).unwrap_err());
+		assert_eq!(err.to_string(), "Response body is not valid UTF8");
+	}
+	#[test]
+	fn display__timeout() {
+		let err = ResponseError::Timeout {
+			duration: Duration::from_millis(1500),
+			partial:  Box::new(UnpackedResponse::new(StatusCode::OK, vec![], "")),
+		};
+		assert_eq!(err.to_string(), "Timed out after 1.5s while reading response body");
 	}
 	
-	//		clear																
+	//		send_sync															
 	#[test]
-	fn clear() {
-		let mut body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		body.clear();
-		assert_eq!(body, UnpackedResponseBody { body: b"".to_vec(), ..Default::default() });
+	fn send_sync() {
+		const fn assert_send_sync<T: Send + Sync + 'static>() {}
+		assert_send_sync::<ResponseError>();
+		assert_send_sync::<RequestError>();
+		assert_send_sync::<Http1Error>();
+		assert_send_sync::<TestClientError>();
 	}
 	
-	//		empty																
+	//		source																
 	#[test]
-	fn empty() {
-		let body = UnpackedResponseBody::empty();
-		assert_eq!(body, UnpackedResponseBody { body: b"".to_vec(), ..Default::default() });
+	fn source() {
+		let err1 = ResponseError::BodyReadError(Box::new(TestError));
+		assert_eq!(err1.source().unwrap().to_string(), "Test error");
+		let err2 = ResponseError::DecompressionError(IoError::other("Test error"));
+		assert_eq!(err2.source().unwrap().to_string(), "Test error");
+		assert!(ResponseError::LimitExceeded(10).source().is_none());
+		assert!(ResponseError::Timeout {
+			duration: Duration::from_secs(1),
+			partial:  Box::new(UnpackedResponse::new(StatusCode::OK, vec![], "")),
+		}.source().is_none());
 	}
+	#[test]
+	fn source__chain() {
+		let err    = Http1Error::Response(ResponseError::BodyReadError(Box::new(TestError)));
+		let source = err.source().unwrap();
+		assert_eq!(source.to_string(),                   "Error encountered while reading response body");
+		assert_eq!(source.source().unwrap().to_string(), "Test error");
+		let boxed: Box<dyn Error + Send + Sync> = Box::new(err);
+		assert!(boxed.source().is_some());
+	}
+}
+
+//		SnapshotError															
+#[cfg(test)]
+mod snapshot_error {
+	use super::*;
 	
-	//		is_empty															
+	//		display																
 	#[test]
-	fn is_empty() {
-		let body1 = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		assert!(!body1.is_empty());
-		
-		let body2 = UnpackedResponseBody { body: b"".to_vec(), ..Default::default() };
-		assert!( body2.is_empty());
+	fn display__io() {
+		let err = SnapshotError::Io(IoError::other("Test error"));
+		assert_eq!(err.to_string(), "Error encountered while reading or writing snapshot file");
+	}
+	#[test]
+	fn display__mismatch() {
+		let left  = UnpackedResponse::new(StatusCode::OK,        vec![], "");
+		let right = UnpackedResponse::new(StatusCode::NOT_FOUND, vec![], "");
+		let err   = SnapshotError::Mismatch { path: PathBuf::from("tests/snapshots/foo.json"), diff: left.diff(&right) };
+		assert_eq!(err.to_string(), "Response does not match snapshot tests/snapshots/foo.json\nStatus: 200 OK != 404 Not Found\n\nSet RUBEDO_UPDATE_SNAPSHOTS=1 to update");
 	}
 	
-	//		len																	
+	//		source																
 	#[test]
-	fn len() {
-		let body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		assert_eq!(body.len(), 14);
+	fn source() {
+		let err1 = SnapshotError::Io(IoError::other("Test error"));
+		assert_eq!(err1.source().unwrap().to_string(), "Test error");
+		let err2 = SnapshotError::Json(serde_json::from_str::<UnpackedResponse>("Not JSON").unwrap_err());
+		assert_eq!(err2.source().unwrap().to_string(), "expected value at line 1 column 1");
 	}
+}
+
+//		TestClientError															
+#[cfg(test)]
+mod test_client_error {
+	use super::*;
 	
-	//		push																
+	//		display																
 	#[test]
-	fn push() {
-		let mut body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		body.push(33);
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test!".to_vec(), ..Default::default() });
+	fn display() {
+		let err = Request::builder().uri("not a uri").body(()).unwrap_err();
+		assert_eq!(
+			TestClientError::Request(err).to_string(),
+			"Error encountered while building request",
+		);
+		assert_eq!(
+			TestClientError::Response(ResponseError::LimitExceeded(10)).to_string(),
+			"Error encountered while unpacking response",
+		);
 	}
 	
-	//		push_bytes															
+	//		source																
 	#[test]
-	fn push_bytes__byte_array() {
-		let mut body = UnpackedResponseBody { body: b"This".to_vec(), ..Default::default() };
-		body.push_bytes(b" is a test");
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn source() {
+		let err1 = TestClientError::Request(Request::builder().uri("not a uri").body(()).unwrap_err());
+		assert_eq!(err1.source().unwrap().to_string(), "invalid uri character");
+		let err2 = TestClientError::Json(serde_json::from_str::<UnpackedResponse>("Not JSON").unwrap_err());
+		assert_eq!(err2.source().unwrap().to_string(), "expected value at line 1 column 1");
+		let err3 = TestClientError::Response(ResponseError::LimitExceeded(10));
+		assert_eq!(err3.source().unwrap().to_string(), "Response body exceeded the size limit of 10 bytes");
+	}
+}
+
+//		Cassette																
+#[cfg(test)]
+mod cassette__struct {
+	use super::*;
+	
+	fn request(method: Method, uri: &'static str, body: &str) -> UnpackedRequest {
+		UnpackedRequest::new(method, Uri::from_static(uri), vec![], body)
+	}
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let cassette = Cassette::new("fixtures/test.json", CassetteMode::Record);
+		assert!(cassette.entries().is_empty());
+		assert_eq!(cassette.mode(), CassetteMode::Record);
+		assert_eq!(cassette.path(), Path::new("fixtures/test.json"));
 	}
+	
+	//		load																
 	#[test]
-	fn push_bytes__byte_slice() {
-		let mut body = UnpackedResponseBody { body: b"This".to_vec(), ..Default::default() };
-		body.push_bytes(&b" is a test"[..]);
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn load__missing() {
+		let dir      = tempdir().unwrap();
+		let path     = dir.path().join("missing.json");
+		let cassette = Cassette::load(&path, CassetteMode::RecordMissing).unwrap();
+		assert!(cassette.entries().is_empty());
+		let err      = Cassette::load(&path, CassetteMode::Replay).unwrap_err();
+		assert!(matches!(err, CassetteError::Io(ref inner) if inner.kind() == IoErrorKind::NotFound));
+	}
+	#[test]
+	fn load__invalid() {
+		let dir  = tempdir().unwrap();
+		let path = dir.path().join("invalid.json");
+		fs::write(&path, "Not JSON").unwrap();
+		assert!(matches!(Cassette::load(&path, CassetteMode::Replay).unwrap_err(), CassetteError::Json(_)));
 	}
 	
-	//		push_char															
+	//		find																
 	#[test]
-	fn push_char() {
-		let mut body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		body.push_char(&'!');
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test!".to_vec(), ..Default::default() });
+	fn find() {
+		let mut cassette = Cassette::new("test.json", CassetteMode::Replay);
+		cassette.record(request(Method::GET, "/foo", ""), UnpackedResponse::new(StatusCode::OK, vec![], "Foo"));
+		assert_eq!(cassette.find(&request(Method::GET,  "/foo",                 "")).unwrap().body, UnpackedResponseBody::new("Foo"));
+		assert_eq!(cassette.find(&request(Method::GET,  "http://localhost/foo", "")).unwrap().body, UnpackedResponseBody::new("Foo"));
+		assert!(cassette.find(&request(Method::POST, "/foo",         "")).is_none());
+		assert!(cassette.find(&request(Method::GET,  "/foo?bar=baz", "")).is_none());
+		assert!(cassette.find(&request(Method::GET,  "/foo",         "x")).is_none());
 	}
 	
-	//		push_str															
+	//		record																
 	#[test]
-	fn push_str() {
-		let mut body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		body.push_str(" a test");
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn record() {
+		let mut cassette = Cassette::new("test.json", CassetteMode::Record);
+		cassette.record(request(Method::POST, "/foo", "one"), UnpackedResponse::new(StatusCode::OK, vec![], "First"));
+		cassette.record(request(Method::POST, "/foo", "two"), UnpackedResponse::new(StatusCode::OK, vec![], "Second"));
+		cassette.record(request(Method::POST, "/foo", "one"), UnpackedResponse::new(StatusCode::OK, vec![], "Third"));
+		assert_eq!(cassette.entries().len(), 2);
+		assert_eq!(cassette.find(&request(Method::POST, "/foo", "one")).unwrap().body, UnpackedResponseBody::new("Third"));
+		assert_eq!(cassette.find(&request(Method::POST, "/foo", "two")).unwrap().body, UnpackedResponseBody::new("Second"));
+	}
+	
+	//		save																
+	#[test]
+	fn save__json() {
+		let dir          = tempdir().unwrap();
+		let path         = dir.path().join("nested/test.json");
+		let mut cassette = Cassette::new(&path, CassetteMode::Record);
+		cassette.record(
+			request(Method::GET, "/foo", ""),
+			UnpackedResponse::new(StatusCode::OK, vec![(s!("content-type"), s!("text/plain"))], "Foo"),
+		);
+		cassette.save().unwrap();
+		let contents     = fs::read_to_string(&path).unwrap();
+		assert!(contents.starts_with("[\n  {\n    \"key\": \"GET /foo e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855\","));
+		let loaded       = Cassette::load(&path, CassetteMode::Replay).unwrap();
+		assert_eq!(loaded.entries(), cassette.entries());
+	}
+	#[test]
+	fn save__yaml() {
+		let dir          = tempdir().unwrap();
+		let path         = dir.path().join("test.yaml");
+		let mut cassette = Cassette::new(&path, CassetteMode::Record);
+		cassette.record(request(Method::PUT, "/foo?bar=baz", "Hello"), UnpackedResponse::new(StatusCode::NO_CONTENT, vec![], ""));
+		cassette.save().unwrap();
+		let contents     = fs::read_to_string(&path).unwrap();
+		assert!(contents.starts_with("- key: PUT /foo?bar=baz 185f8db32271fe25f561a6fc938b2e264306ec304eda518007d1764826381969\n"));
+		let loaded       = Cassette::load(&path, CassetteMode::Replay).unwrap();
+		assert_eq!(loaded.entries(), cassette.entries());
+	}
+	#[test]
+	fn save__round_trip() {
+		let dir          = tempdir().unwrap();
+		let path         = dir.path().join("test.json");
+		let mut cassette = Cassette::new(&path, CassetteMode::Record);
+		cassette.record(request(Method::POST, "/ping", "ping"), UnpackedResponse::new(StatusCode::OK, vec![], "pong"));
+		cassette.save().unwrap();
+		let contents     = fs::read_to_string(&path).unwrap();
+		assert!(contents.contains(r#""body": {
+        "content_type": "text",
+        "data": "pong"
+      }"#));
+		let loaded       = Cassette::load(&path, CassetteMode::Replay).unwrap();
+		let entry        = &loaded.entries()[0];
+		assert_eq!(entry.request.body,  UnpackedResponseBody { body: b"ping".to_vec(), content_type: ContentType::Text });
+		assert_eq!(entry.response.body, UnpackedResponseBody { body: b"pong".to_vec(), content_type: ContentType::Text });
+		assert_eq!(loaded.entries(), cassette.entries());
 	}
 }
 
+//		CassetteEntry															
 #[cfg(test)]
-mod unpacked_response_body__traits {
+mod cassette_entry__struct {
 	use super::*;
 	
-	//		add																	
+	//		new																	
 	#[test]
-	fn add__byte_array() {
-		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		assert_eq!(body + b" a test", UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn new() {
+		let request  = UnpackedRequest::new(Method::GET, Uri::from_static("/foo"), vec![], "");
+		let response = UnpackedResponse::new(StatusCode::OK, vec![], "Foo");
+		let entry    = CassetteEntry::new(request.clone(), response.clone());
+		assert_eq!(entry.key,      CassetteEntry::key_for(&request));
+		assert_eq!(entry.request,  request);
+		assert_eq!(entry.response, response);
 	}
+	
+	//		key_for																
 	#[test]
-	fn add__byte_slice() {
-		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		assert_eq!(body + &b" a test"[..], UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn key_for() {
+		let request1 = UnpackedRequest::new(Method::GET,  Uri::from_static("https://example.com"), vec![],                        "");
+		let request2 = UnpackedRequest::new(Method::POST, Uri::from_static("/foo?bar=baz"),        vec![(s!("foo"), s!("bar"))], "Hello");
+		assert_eq!(CassetteEntry::key_for(&request1), "GET / e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+		assert_eq!(CassetteEntry::key_for(&request2), "POST /foo?bar=baz 185f8db32271fe25f561a6fc938b2e264306ec304eda518007d1764826381969");
 	}
-	#[expect(clippy::string_lit_as_bytes, reason = "Needed for the test")]
+}
+
+//		CassetteService															
+#[cfg(test)]
+mod cassette_service__struct {
+	use super::*;
+	
+	//		new																	
 	#[test]
-	fn add__char_one_byte() {
-		let body = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
-		assert_eq!(body + 'A', UnpackedResponseBody { body: s!("This is A").into_bytes(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: s!("This is ").into_bytes() });
+	fn new() {
+		let service = CassetteService::new(Cassette::new("test.json", CassetteMode::Record), TestRecordingService::default());
+		assert_eq!(service.cassette().lock().unwrap().mode(), CassetteMode::Record);
+	}
+	
+	//		offline																
+	#[tokio::test]
+	async fn offline() {
+		let mut service = CassetteService::offline(Cassette::new("test.json", CassetteMode::RecordMissing));
+		let err         = service.call(Request::post("/foo").body(s!("Hello")).unwrap()).await.unwrap_err();
+		assert!(matches!(err, CassetteError::Offline { ref method, ref path } if *method == Method::POST && path == "/foo"));
 	}
+}
+
+#[cfg(test)]
+mod cassette_service__traits {
+	use super::*;
+	
+	//		call																
+	#[tokio::test]
+	async fn call__record_missing() {
+		let dir           = tempdir().unwrap();
+		let path          = dir.path().join("test.json");
+		let inner         = TestRecordingService::default();
+		let mut service   = CassetteService::new(Cassette::load(&path, CassetteMode::RecordMissing).unwrap(), inner.clone());
+		let mut response1 = service.call(Request::post("/foo").body(s!("Hello")).unwrap()).await.unwrap();
+		let mut response2 = service.call(Request::post("/foo").body(s!("Hello")).unwrap()).await.unwrap();
+		let mut response3 = service.call(Request::post("/foo").body(s!("World")).unwrap()).await.unwrap();
+		assert_eq!(inner.calls(), 2);
+		let unpacked1     = response1.unpack().unwrap();
+		assert_eq!(unpacked1.status, StatusCode::CREATED);
+		assert_eq!(unpacked1.body,   UnpackedResponseBody::new("Response 1: Hello"));
+		assert_eq!(response2.unpack().unwrap(), unpacked1);
+		assert_eq!(response3.unpack().unwrap().body, UnpackedResponseBody::new("Response 2: World"));
+		
+		let loaded        = Cassette::load(&path, CassetteMode::Replay).unwrap();
+		assert_eq!(loaded.entries().len(), 2);
+		let mut replayer  = CassetteService::offline(loaded);
+		let mut response4 = replayer.call(Request::post("/foo").body(s!("Hello")).unwrap()).await.unwrap();
+		assert_eq!(response4.unpack().unwrap(), unpacked1);
+	}
+	#[tokio::test]
+	async fn call__record() {
+		let dir           = tempdir().unwrap();
+		let path          = dir.path().join("test.yml");
+		let inner         = TestRecordingService::default();
+		let mut service   = CassetteService::new(Cassette::new(&path, CassetteMode::Record), inner.clone());
+		let _response1    = service.call(Request::get("/foo").body(Full::new(Bytes::new())).unwrap()).await.unwrap();
+		let mut response2 = service.call(Request::get("/foo").body(Full::new(Bytes::new())).unwrap()).await.unwrap();
+		assert_eq!(inner.calls(), 2);
+		assert_eq!(response2.unpack().unwrap().body, UnpackedResponseBody::new("Response 2: "));
+		
+		let loaded        = Cassette::load(&path, CassetteMode::Replay).unwrap();
+		assert_eq!(loaded.entries().len(), 1);
+		assert_eq!(loaded.entries()[0].response.body, UnpackedResponseBody::new("Response 2: "));
+	}
+	#[tokio::test]
+	async fn call__replay() {
+		let mut cassette = Cassette::new("test.json", CassetteMode::Replay);
+		cassette.record(
+			UnpackedRequest::new(Method::GET, Uri::from_static("/foo"), vec![], ""),
+			UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("bar"))], "Foo"),
+		);
+		let inner        = TestRecordingService::default();
+		let mut service  = CassetteService::new(cassette, inner.clone());
+		let mut response = service.call(Request::get("http://example.com/foo").body(Full::new(Bytes::new())).unwrap()).await.unwrap();
+		assert_eq!(response.headers()["foo"], "bar");
+		assert_eq!(response.unpack().unwrap().body, UnpackedResponseBody::new("Foo"));
+		let err          = service.call(Request::get("/bar").body(Full::new(Bytes::new())).unwrap()).await.unwrap_err();
+		assert!(matches!(err, CassetteError::NotRecorded { ref method, ref path } if *method == Method::GET && path == "/bar"));
+		assert_eq!(inner.calls(), 0);
+	}
+}
+
+//		HarArchive																
+#[cfg(test)]
+mod har_archive__struct {
+	use super::*;
+	
+	fn archive() -> HarArchive {
+		HarArchive::new()
+			.with_entry(
+				UnpackedRequest::new(Method::GET, Uri::from_static("http://example.com/foo"), vec![], ""),
+				UnpackedResponse::new(StatusCode::OK, vec![(s!("content-type"), s!("text/plain"))], "This is a test"),
+			)
+			.with_entry(
+				UnpackedRequest::new(Method::POST, Uri::from_static("http://example.com/bar"), vec![], "foo=bar"),
+				UnpackedResponse::new(StatusCode::NOT_FOUND, vec![], vec![0xFF, 0xFE]),
+			)
+	}
+	
+	//		new																	
 	#[test]
-	fn add__char_two_byte() {
-		let body = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
-		assert_eq!(body + 'ñ', UnpackedResponseBody { body: s!("This is ñ").into_bytes(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: s!("This is ").into_bytes() });
+	fn new() {
+		assert!(HarArchive::new().entries.is_empty());
 	}
+	
+	//		with_entry															
 	#[test]
-	fn add__char_three_byte() {
-		let body = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
-		assert_eq!(body + 'Ḁ', UnpackedResponseBody { body: s!("This is Ḁ").into_bytes(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: s!("This is ").into_bytes() });
+	fn with_entry() {
+		let archive = archive();
+		assert_eq!(archive.entries.len(),                   2);
+		assert_eq!(archive.entries[1].request.method,       Method::POST);
+		assert_eq!(archive.entries[1].response.status,      StatusCode::NOT_FOUND);
 	}
+	
+	//		to_json																
 	#[test]
-	fn add__char_four_byte() {
-		let body = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
-		assert_eq!(body + '𐍈', UnpackedResponseBody { body: s!("This is 𐍈").into_bytes(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: s!("This is ").into_bytes() });
+	fn to_json() {
+		let json = archive().to_json();
+		assert_eq!(json["log"]["version"],                           json!("1.2"));
+		assert_eq!(json["log"]["creator"]["name"],                   json!("rubedo"));
+		assert_eq!(json["log"]["entries"].as_array().unwrap().len(), 2);
 	}
-	#[expect(clippy::string_lit_as_bytes, reason = "Needed for the test")]
+	
+	//		from_json															
 	#[test]
-	fn add__char_ref() {
-		let body = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
-		let char = 'A';
-		assert_eq!(body + char, UnpackedResponseBody { body: s!("This is A").into_bytes(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: s!("This is ").into_bytes() });
+	fn from_json__round_trip() {
+		let archive = archive();
+		assert_ok_eq!(HarArchive::from_json(&archive.to_json()), archive);
 	}
 	#[test]
-	fn add__str() {
-		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		assert_eq!(body + " a test", UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn from_json__invalid() {
+		assert!(matches!(HarArchive::from_json(&json!({})),                     Err(HarError::InvalidField(ref field)) if field == "log"));
+		assert!(matches!(HarArchive::from_json(&json!({"log": {"entries": 1}})), Err(HarError::InvalidField(ref field)) if field == "entries"));
 	}
+	
+	//		load																
 	#[test]
-	fn add__str_ref() {
-		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let str  = " a test";
-		assert_eq!(body + str, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn load__missing() {
+		let dir = tempdir().unwrap();
+		assert!(matches!(HarArchive::load(dir.path().join("missing.har")), Err(HarError::Io(_))));
+	}
+	#[test]
+	fn load__invalid_json() {
+		let dir  = tempdir().unwrap();
+		let path = dir.path().join("invalid.har");
+		fs::write(&path, "foo").unwrap();
+		assert!(matches!(HarArchive::load(&path), Err(HarError::Json(_))));
+	}
+	
+	//		save																
+	#[test]
+	fn save__round_trip() {
+		let dir     = tempdir().unwrap();
+		let path    = dir.path().join("sub/session.har");
+		let archive = archive();
+		archive.save(&path).unwrap();
+		assert!(fs::read_to_string(&path).unwrap().ends_with("}\n"));
+		assert_ok_eq!(HarArchive::load(&path), archive);
+	}
+}
+
+//		HarEntry																
+#[cfg(test)]
+mod har_entry__struct {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let request  = UnpackedRequest::new(Method::GET, Uri::from_static("/"), vec![], "");
+		let response = UnpackedResponse::new(StatusCode::OK, vec![], "");
+		let entry    = HarEntry::new(request.clone(), response.clone());
+		assert_eq!(entry.request,  request);
+		assert_eq!(entry.response, response);
+	}
+	
+	//		from_json															
+	#[test]
+	fn from_json__valid() {
+		let entry = HarEntry::from_json(&json!({
+			"request":  { "method": "GET", "url": "https://example.com/", "httpVersion": "h2", "headers": [] },
+			"response": { "status": 204, "headers": [], "content": { "size": 0, "mimeType": "" } },
+		})).unwrap();
+		assert_eq!(entry.request.version,  Version::HTTP_2);
+		assert_eq!(entry.response.status,  StatusCode::NO_CONTENT);
+	}
+	#[test]
+	fn from_json__invalid() {
+		assert!(matches!(HarEntry::from_json(&json!({"request": {}})), Err(HarError::InvalidField(ref field)) if field == "method"));
+		assert!(matches!(HarEntry::from_json(&json!({})),              Err(HarError::InvalidField(ref field)) if field == "request"));
+	}
+	
+	//		to_json																
+	#[test]
+	fn to_json() {
+		let entry = HarEntry::new(
+			UnpackedRequest::new(Method::GET, Uri::from_static("/"), vec![], ""),
+			UnpackedResponse::new(StatusCode::OK, vec![], ""),
+		);
+		let json  = entry.to_json();
+		assert_eq!(json["startedDateTime"],    json!("1970-01-01T00:00:00.000Z"));
+		assert_eq!(json["timings"]["wait"],    json!(0));
+		assert_eq!(json["request"]["method"],  json!("GET"));
+		assert_eq!(json["response"]["status"], json!(200));
+	}
+}
+
+//		LoggingBody																
+#[cfg(test)]
+mod logging_body__struct {
+	use super::*;
+	
+	#[expect(clippy::type_complexity, reason = "Not complex enough to warrant a type alias")]
+	fn record<B>(inner: B, limit: usize) -> (LoggingBody<B>, Arc<Mutex<Vec<(Bytes, usize)>>>) {
+		let ends = Arc::new(Mutex::new(Vec::new()));
+		let sink = Arc::clone(&ends);
+		(LoggingBody::new(inner, limit, move |data, size| sink.lock().unwrap().push((data, size))), ends)
+	}
+	
+	//		new																	
+	#[tokio::test]
+	async fn new() {
+		let (body, ends) = record(Full::new(Bytes::from("This is a test")), usize::MAX);
+		assert!(ends.lock().unwrap().is_empty());
+		assert_eq!(body.collect().await.unwrap().to_bytes(), Bytes::from("This is a test"));
+		assert_eq!(*ends.lock().unwrap(), vec![(Bytes::from("This is a test"), 14)]);
+	}
+	
+	//		capture																
+	#[tokio::test]
+	async fn capture() {
+		let (body, ends) = record(create_stream_body_for_testing(), 15);
+		assert_eq!(body.collect().await.unwrap().to_bytes(), Bytes::from("data: one\n\ndata: two\n\n"));
+		assert_eq!(*ends.lock().unwrap(), vec![(Bytes::from("data: one\n\ndata"), 22)]);
+	}
+	
+	//		finish																
+	#[test]
+	fn finish__dropped() {
+		let (body, ends) = record(Full::new(Bytes::from("This is a test")), usize::MAX);
+		drop(body);
+		assert_eq!(*ends.lock().unwrap(), vec![(Bytes::new(), 0)]);
+	}
+}
+
+#[cfg(test)]
+mod logging_body__traits {
+	use super::*;
+	
+	//		poll_frame															
+	#[tokio::test]
+	async fn poll_frame__streaming() {
+		let ends         = Arc::new(Mutex::new(Vec::new()));
+		let sink         = Arc::clone(&ends);
+		let mut body     = LoggingBody::new(create_stalled_body_for_testing(), usize::MAX, move |data, size| sink.lock().unwrap().push((data, size)));
+		let frame        = body.frame().await.unwrap().unwrap();
+		assert_eq!(frame.into_data().unwrap(), Bytes::from("data: one\n\n"));
+		assert!(ends.lock().unwrap().is_empty());
+		drop(body);
+		assert_eq!(*ends.lock().unwrap(), vec![(Bytes::from("data: one\n\n"), 11)]);
+	}
+	#[tokio::test]
+	async fn poll_frame__trailers() {
+		let body         = LoggingBody::new(create_stream_body_for_testing(), usize::MAX, |_, _| {});
+		let collected    = body.collect().await.unwrap();
+		assert_eq!(collected.trailers().unwrap()["x-checksum"], "abc123");
+	}
+	
+	//		debug																
+	#[test]
+	fn debug() {
+		let body = LoggingBody::new(Full::new(Bytes::new()), 5, |_, _| {});
+		assert_eq!(format!("{body:?}"), "LoggingBody { limit: 5, size: 0, .. }");
+	}
+}
+
+//		LoggingLayer															
+#[cfg(test)]
+mod logging_layer__struct {
+	use super::*;
+	
+	fn collect(layer: LoggingLayer) -> (LoggingLayer, Arc<Mutex<Vec<LogEvent>>>) {
+		let events = Arc::new(Mutex::new(Vec::new()));
+		let sink   = Arc::clone(&events);
+		(layer.with_sink(move |event| sink.lock().unwrap().push(event.clone())), events)
+	}
+	
+	fn response_event(layer: &LoggingLayer, response: UnpackedResponse) -> UnpackedResponse {
+		let (collector, events) = collect(layer.clone());
+		let size                = response.body.len();
+		collector.log(LogEvent::Response(response), size);
+		let event               = events.lock().unwrap().pop().unwrap();
+		match event {
+			LogEvent::Response(logged) => logged,
+			LogEvent::Request(_)       => panic!("Expected a response event"),
+		}
+	}
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let layer = LoggingLayer::new();
+		assert_eq!(layer.binary_format,    BinaryFormat::Base64);
+		assert_eq!(layer.max_body_size,    usize::MAX);
+		assert_eq!(layer.redaction.headers, vec![s!("authorization"), s!("cookie"), s!("proxy-authorization"), s!("set-cookie")]);
+		assert!(layer.sink.is_none());
+		assert_eq!(
+			format!("{layer:?}"),
+			concat!(
+				r#"LoggingLayer { binary_format: Base64, max_body_size: 18446744073709551615, redaction: RedactionPolicy { "#,
+				r#"headers: ["authorization", "cookie", "proxy-authorization", "set-cookie"], json_fields: [], "#,
+				r#"placeholder: "[redacted]", text_patterns: [] }, .. }"#,
+			),
+		);
+	}
+	
+	//		with_binary_format													
+	#[test]
+	fn with_binary_format() {
+		let response = || UnpackedResponse {
+			body: UnpackedResponseBody { body: vec![0xDE, 0xAD, 0xBE, 0xEF], content_type: ContentType::Binary },
+			..UnpackedResponse::new(StatusCode::OK, vec![], "")
+		};
+		let base64   = response_event(&LoggingLayer::new(),                                       response());
+		let hex      = response_event(&LoggingLayer::new().with_binary_format(BinaryFormat::Hex), response());
+		assert_eq!(base64.body.as_bytes(),      b"3q2+7w==");
+		assert_eq!(hex.body.as_bytes(),         b"deadbeef");
+		assert_eq!(hex.body.content_type(),     ContentType::Text);
+	}
+	
+	//		with_max_body_size													
+	#[test]
+	fn with_max_body_size() {
+		let layer    = LoggingLayer::new().with_max_body_size(4);
+		let logged1  = response_event(&layer, UnpackedResponse::new(StatusCode::OK, vec![], "This is a test"));
+		assert_eq!(logged1.body.as_bytes(), b"This... [truncated from 14 bytes]");
+		let logged2  = response_event(&layer, UnpackedResponse::new(StatusCode::OK, vec![], "Test"));
+		assert_eq!(logged2.body.as_bytes(), b"Test");
+	}
+	#[test]
+	fn with_max_body_size__char_boundary() {
+		let layer    = LoggingLayer::new().with_max_body_size(2);
+		let logged   = response_event(&layer, UnpackedResponse::new(StatusCode::OK, vec![], "aé"));
+		assert_eq!(logged.body.as_bytes(), b"a... [truncated from 3 bytes]");
+	}
+	#[test]
+	fn with_max_body_size__json() {
+		let response = || UnpackedResponse {
+			body: UnpackedResponseBody { body: br#"{"foo":"bar"}"#.to_vec(), content_type: ContentType::Json },
+			..UnpackedResponse::new(StatusCode::OK, vec![], "")
+		};
+		let logged1  = response_event(&LoggingLayer::new().with_max_body_size(5), response());
+		assert_eq!(logged1.body.content_type(), ContentType::Text);
+		let logged2  = response_event(&LoggingLayer::new(), response());
+		assert_eq!(logged2.body.content_type(), ContentType::Json);
+	}
+	#[test]
+	fn with_max_body_size__binary() {
+		let body     = UnpackedResponseBody { body: vec![0xDE, 0xAD, 0xBE, 0xEF], content_type: ContentType::Binary };
+		let response = UnpackedResponse { body, ..UnpackedResponse::new(StatusCode::OK, vec![], "") };
+		let logged   = response_event(&LoggingLayer::new().with_max_body_size(2).with_binary_format(BinaryFormat::Hex), response);
+		assert_eq!(logged.body.as_bytes(), b"dead... [truncated from 4 bytes]");
+	}
+	
+	//		with_redacted_header												
+	#[test]
+	fn with_redacted_header() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("Authorization"), s!("Bearer abc")),
+			(s!("x-api-key"),     s!("secret")),
+			(s!("x-other"),       s!("visible")),
+		], "");
+		let logged   = response_event(&LoggingLayer::new().with_redacted_header("X-API-Key"), response);
+		assert_eq!(logged.header("authorization"), Some("[redacted]"));
+		assert_eq!(logged.header("x-api-key"),     Some("[redacted]"));
+		assert_eq!(logged.header("x-other"),       Some("visible"));
+	}
+	
+	//		with_redacted_headers												
+	#[test]
+	fn with_redacted_headers() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("authorization"), s!("Bearer abc")),
+			(s!("x-api-key"),     s!("secret")),
+		], "");
+		let logged1  = response_event(&LoggingLayer::new().with_redacted_headers(&["X-API-Key"]), response.clone());
+		assert_eq!(logged1.header("authorization"), Some("Bearer abc"));
+		assert_eq!(logged1.header("x-api-key"),     Some("[redacted]"));
+		let logged2  = response_event(&LoggingLayer::new().with_redacted_headers(&[]), response);
+		assert_eq!(logged2.header("x-api-key"),     Some("secret"));
+	}
+	#[test]
+	fn with_redacted_headers__keeps_policy() {
+		let policy   = RedactionPolicy::new().with_placeholder("***").with_json_field("$.token");
+		let layer    = LoggingLayer::new().with_redaction_policy(policy).with_redacted_headers(&["x-api-key"]);
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("x-api-key"), s!("secret"))], json!({"token": "abc"}));
+		let logged   = response_event(&layer, response);
+		assert_eq!(logged.header("x-api-key"), Some("***"));
+		assert_eq!(logged.body.as_bytes(),     br#"{"token":"***"}"#);
+	}
+	#[test]
+	fn with_redacted_headers__raw() {
+		let mut response = UnpackedResponse::new(StatusCode::OK, vec![(s!("cookie"), s!("secret"))], "");
+		response.raw_headers = vec![UnpackedRawHeader::new(s!("cookie"), b"secret".to_vec())];
+		let logged       = response_event(&LoggingLayer::new(), response);
+		assert_eq!(logged.headers,     vec![UnpackedResponseHeader::new(s!("cookie"), s!("[redacted]"))]);
+		assert_eq!(logged.raw_headers, vec![UnpackedRawHeader::new(s!("cookie"), b"[redacted]".to_vec())]);
+	}
+	
+	//		with_redaction_policy												
+	#[test]
+	fn with_redaction_policy() {
+		let policy              = RedactionPolicy::new()
+			.with_header("x-api-key")
+			.with_json_field("$.token")
+			.with_text_pattern(Regex::new(r"\d{4}").unwrap())
+		;
+		let (layer, events)     = collect(LoggingLayer::new().with_redaction_policy(policy));
+		let request_body        = json!({"token": "abc", "pin": "1234"}).to_string();
+		layer.log(LogEvent::Request(UnpackedRequest::new(Method::POST, Uri::from_static("/"), vec![
+			(s!("authorization"), s!("Bearer abc")),
+			(s!("x-api-key"),     s!("secret")),
+		], request_body.clone())), request_body.len());
+		layer.log(LogEvent::Response(UnpackedResponse::new(StatusCode::OK, vec![(s!("x-api-key"), s!("secret"))], "Card 1234")), 9);
+		let logged              = events.lock().unwrap().clone();
+		let LogEvent::Request(ref request) = logged[0] else { panic!("Expected a request event") };
+		assert_eq!(request.headers,                 vec![
+			UnpackedResponseHeader::new(s!("authorization"), s!("Bearer abc")),
+			UnpackedResponseHeader::new(s!("x-api-key"),     s!("[redacted]")),
+		]);
+		assert_eq!(request.body.as_bytes(),         br#"{"token":"[redacted]","pin":"[redacted]"}"#);
+		let LogEvent::Response(ref response) = logged[1] else { panic!("Expected a response event") };
+		assert_eq!(response.header("x-api-key"),    Some("[redacted]"));
+		assert_eq!(response.body.as_bytes(),        b"Card [redacted]");
+	}
+	#[test]
+	fn with_redaction_policy__partial_json() {
+		let layer               = LoggingLayer::new().with_redaction_policy(RedactionPolicy::new().with_json_field("$.token"));
+		let (collector, events) = collect(layer);
+		let response            = UnpackedResponse::new(StatusCode::OK, vec![], json!({"token": "abc"}));
+		let partial             = UnpackedResponse {
+			body: UnpackedResponseBody { body: br#"{"token":"#.to_vec(), content_type: ContentType::Json },
+			..response
+		};
+		collector.log(LogEvent::Response(partial), 100);
+		let LogEvent::Response(ref logged) = events.lock().unwrap()[0] else { panic!("Expected a response event") };
+		assert_eq!(logged.body.as_bytes(), b"[redacted]");
+	}
+	
+	//		with_sink															
+	#[test]
+	fn with_sink() {
+		let (layer, events) = collect(LoggingLayer::new());
+		let request         = UnpackedRequest::new(Method::GET, Uri::from_static("/foo"), vec![], "");
+		layer.log(LogEvent::Request(request.clone()), 0);
+		assert_eq!(*events.lock().unwrap(), vec![LogEvent::Request(request)]);
+	}
+	
+	//		layer																
+	#[tokio::test]
+	async fn layer() {
+		let (layer, events) = collect(LoggingLayer::new().with_max_body_size(5));
+		let inner           = TestRecordingService::default();
+		let mut service     = layer.layer(inner.clone());
+		let mut response    = service.call(
+			Request::post("/foo").header("authorization", "Bearer abc").body(s!("Hello, world!")).unwrap()
+		).await.unwrap();
+		assert_eq!(inner.calls(), 1);
+		assert_eq!(response.status(),      StatusCode::CREATED);
+		assert_eq!(response.headers()["x-count"], "1");
+		assert_eq!(response.unpack_async().await.unwrap().body, UnpackedResponseBody::new("Response 1: Hello, world!"));
+		let logged          = events.lock().unwrap().clone();
+		assert_eq!(logged.len(), 2);
+		let LogEvent::Request(ref request) = logged[0] else { panic!("Expected a request event") };
+		assert_eq!(request.method,                  Method::POST);
+		assert_eq!(request.headers,                 vec![UnpackedResponseHeader::new(s!("authorization"), s!("[redacted]"))]);
+		assert_eq!(request.body.as_bytes(),         b"Hello... [truncated from 13 bytes]");
+		let LogEvent::Response(ref unpacked) = logged[1] else { panic!("Expected a response event") };
+		assert_eq!(unpacked.status,                 StatusCode::CREATED);
+		assert_eq!(unpacked.body.as_bytes(),        b"Respo... [truncated from 25 bytes]");
+	}
+}
+
+#[cfg(test)]
+mod logging_service__traits {
+	use super::*;
+	
+	//		call																
+	#[tokio::test]
+	async fn call() {
+		let router       = Router::new().route("/", get(|| async { "Hello, world!" }));
+		let mut service  = LoggingLayer::new().layer(router);
+		let mut response = service.call(Request::get("/").body(Full::new(Bytes::new())).unwrap()).await.unwrap();
+		assert_eq!(response.status(), StatusCode::OK);
+		assert_eq!(response.unpack_async().await.unwrap().body, UnpackedResponseBody::new("Hello, world!"));
+	}
+	#[tokio::test]
+	async fn call__streaming() {
+		let events       = Arc::new(Mutex::new(Vec::new()));
+		let sink         = Arc::clone(&events);
+		let router       = Router::new().route("/", get(|| async { AxumBody::new(create_stalled_body_for_testing()) }));
+		let mut service  = LoggingLayer::new().with_sink(move |event| sink.lock().unwrap().push(event.clone())).layer(router);
+		let mut response = service.call(Request::get("/").body(Full::new(Bytes::new())).unwrap()).await.unwrap();
+		let frame        = response.body_mut().frame().await.unwrap().unwrap();
+		assert_eq!(frame.into_data().unwrap(), Bytes::from("data: one\n\n"));
+		assert_eq!(events.lock().unwrap().len(), 1);
+		drop(response);
+		let logged       = events.lock().unwrap().clone();
+		assert_eq!(logged.len(), 2);
+		let LogEvent::Response(ref unpacked) = logged[1] else { panic!("Expected a response event") };
+		assert_eq!(unpacked.body.as_bytes(), b"data: one\n\n");
+	}
+}
+
+//		MatchReport																
+#[cfg(test)]
+mod match_report__struct {
+	use super::*;
+	
+	//		is_match															
+	#[test]
+	fn is_match() {
+		assert!(MatchReport::default().is_match());
+		assert!(!MatchReport {
+			failures: vec![MatchFailure::Status { expected: StatusCode::OK, actual: StatusCode::NOT_FOUND }],
+		}.is_match());
+	}
+}
+
+#[cfg(test)]
+mod match_report__traits {
+	use super::*;
+	
+	//		display																
+	#[test]
+	fn display__match() {
+		assert_eq!(MatchReport::default().to_string(), "Response matches");
+	}
+	#[test]
+	fn display__failures() {
+		let report = MatchReport {
+			failures: vec![
+				MatchFailure::Status { expected: StatusCode::OK, actual: StatusCode::NOT_FOUND },
+				MatchFailure::Header(HeaderDiff::Added(UnpackedResponseHeader::new(s!("foo"), s!("bar")))),
+				MatchFailure::Body(BodyDiff::Text(vec![
+					TextLineDiff::Removed { line: 1, text: s!("Foo") },
+					TextLineDiff::Added   { line: 1, text: s!("Bar") },
+				])),
+			],
+		};
+		assert_eq!(report.to_string(), concat!(
+			"Status: 200 OK != 404 Not Found\n",
+			"Header: + foo: bar\n",
+			"Body (text):\n",
+			"  - 1: Foo\n",
+			"  + 1: Bar\n",
+		));
+	}
+}
+
+//		MultipartBuilder														
+#[cfg(test)]
+mod multipart_builder__struct {
+	use super::*;
+	
+	//		content_type														
+	#[test]
+	fn content_type__default() {
+		assert_eq!(MultipartBuilder::new("abc").content_type(), "multipart/mixed; boundary=abc");
+	}
+	#[test]
+	fn content_type__subtype() {
+		assert_eq!(MultipartBuilder::new("abc").with_subtype("form-data").content_type(), "multipart/form-data; boundary=abc");
+	}
+	#[test]
+	fn content_type__quoted() {
+		assert_eq!(MultipartBuilder::new("a b:c").content_type(), r#"multipart/mixed; boundary="a b:c""#);
+	}
+	
+	//		build																
+	#[test]
+	fn build__empty() {
+		assert_eq!(MultipartBuilder::new("abc").build(), UnpackedResponseBody::new("--abc--\r\n"));
+	}
+	#[test]
+	fn build__parts() {
+		let body = MultipartBuilder::new("abc")
+			.with_part(MultipartPart::new("foo").with_header("X-Foo", "1"))
+			.with_field("name", "Alice")
+			.with_file("data", "data.json", "application/json", "{}")
+			.build()
+		;
+		assert_eq!(body.to_string(), concat!(
+			"--abc\r\nx-foo: 1\r\n\r\nfoo\r\n",
+			"--abc\r\ncontent-disposition: form-data; name=\"name\"\r\n\r\nAlice\r\n",
+			"--abc\r\ncontent-disposition: form-data; name=\"data\"; filename=\"data.json\"\r\ncontent-type: application/json\r\n\r\n{}\r\n",
+			"--abc--\r\n",
+		));
+		assert!(body.is_text());
+	}
+	#[test]
+	fn build__binary() {
+		let body = MultipartBuilder::new("abc").with_file("image", "a.png", "image/png", vec![0x89, 0xFF]).build();
+		assert!(body.is_binary());
+		let parts = body.as_multipart("abc").unwrap();
+		assert_eq!(parts[0].body.as_bytes(), [0x89, 0xFF]);
+		assert!(parts[0].body.is_binary());
+	}
+	#[test]
+	fn build__round_trip() {
+		let builder = MultipartBuilder::new("abc")
+			.with_field("quote\"d", "Alice")
+			.with_file("data", "data.json", "application/json", r#"{"id":1}"#)
+		;
+		let parts   = builder.build().as_multipart("abc").unwrap();
+		assert_eq!(parts.len(),             2);
+		assert_eq!(parts[0].name(),         Some(s!("quote\"d")));
+		assert_eq!(parts[0].body,           UnpackedResponseBody::new("Alice"));
+		assert_eq!(parts[1].filename(),     Some(s!("data.json")));
+		assert!(parts[1].body.is_json());
+		assert_eq!(parts[1].body.as_json().unwrap(), json!({"id": 1}));
+	}
+}
+
+//		MultipartPart															
+#[cfg(test)]
+mod multipart_part__struct {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let part = MultipartPart::new("foo");
+		assert!(part.headers.is_empty());
+		assert_eq!(part.body, UnpackedResponseBody::new("foo"));
+	}
+	
+	//		with_header															
+	#[test]
+	fn with_header() {
+		let part = MultipartPart::new("foo").with_header("X-Foo", "Bar");
+		assert_eq!(part.headers, vec![UnpackedResponseHeader::new(s!("x-foo"), s!("Bar"))]);
+	}
+	
+	//		header																
+	#[test]
+	fn header() {
+		let part = MultipartPart::new("foo").with_header("x-foo", "1").with_header("x-foo", "2");
+		assert_eq!(part.header("X-Foo"), Some("1"));
+		assert_eq!(part.header("x-bar"), None);
+	}
+	
+	//		name																
+	#[test]
+	fn name() {
+		let part = MultipartPart::new("foo").with_header("content-disposition", r#"form-data; name="a;b""#);
+		assert_eq!(part.name(),                      Some(s!("a;b")));
+		assert_eq!(MultipartPart::new("foo").name(), None);
+	}
+	
+	//		filename															
+	#[test]
+	fn filename() {
+		let part = MultipartPart::new("foo").with_header("Content-Disposition", r#"form-data; name=file; filename="a\\b.txt""#);
+		assert_eq!(part.filename(), Some(s!("a\\b.txt")));
+		assert_eq!(part.name(),     Some(s!("file")));
+	}
+}
+
+//		OfflineService															
+#[cfg(test)]
+mod offline_service__traits {
+	use super::*;
+	
+	//		call																
+	#[tokio::test]
+	async fn call() {
+		let err = OfflineService.call(Request::get("/foo?bar=baz").body(Full::new(Bytes::new())).unwrap()).await.unwrap_err();
+		assert!(matches!(err, CassetteError::Offline { ref method, ref path } if *method == Method::GET && path == "/foo?bar=baz"));
+	}
+}
+
+//		RedactedResponse														
+#[cfg(test)]
+mod redacted_response__traits {
+	use super::*;
+	
+	//		fmt																	
+	#[test]
+	fn fmt() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("authorization"), s!("Bearer abc123"))], "token=abc123");
+		let policy   = RedactionPolicy::new().with_header("Authorization");
+		let redacted = response.redacted(&policy);
+		assert_eq!(redacted.to_string(),       serde_json::to_string(&policy.redact(&response)).unwrap());
+		assert_eq!(format!("{redacted:#}"),    serde_json::to_string_pretty(&policy.redact(&response)).unwrap());
+		assert!(!redacted.to_string().contains("Bearer abc123"));
+		assert!( redacted.to_string().contains("token=abc123"));
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("set-cookie"), s!("session=abc123"))], json!({"token": "abc123", "id": 1}));
+		let policy   = RedactionPolicy::new().with_header("set-cookie").with_json_field("$.token");
+		let json     = serde_json::to_value(response.redacted(&policy)).unwrap();
+		assert_eq!(json, serde_json::to_value(policy.redact(&response)).unwrap());
+		assert_eq!(json["headers"][0]["value"], json!("[redacted]"));
+		assert_eq!(response.header("set-cookie"), Some("session=abc123"));
+	}
+}
+
+//		RedactionPolicy															
+#[cfg(test)]
+mod redaction_policy__struct {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("authorization"), s!("Bearer abc123"))], "This is a test");
+		let policy   = RedactionPolicy::new();
+		assert_eq!(policy.placeholder, "[redacted]");
+		assert_eq!(policy.redact(&response), response);
+	}
+	
+	//		with_header															
+	#[test]
+	fn with_header() {
+		let mut response = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("Authorization"), s!("Bearer abc123")),
+			(s!("content-type"),  s!("text/plain")),
+		], "This is a test");
+		response.raw_headers = vec![UnpackedRawHeader::new(s!("Authorization"), b"Bearer abc123".to_vec())];
+		let redacted     = RedactionPolicy::new().with_header("authorization").redact(&response);
+		assert_eq!(redacted.headers[0].value,     "[redacted]");
+		assert_eq!(redacted.raw_headers[0].value, b"[redacted]");
+		assert_eq!(redacted.headers[1].value,     "text/plain");
+		assert_eq!(response.headers[0].value,     "Bearer abc123");
+	}
+	#[test]
+	fn with_header__trailers() {
+		let mut response = UnpackedResponse::new(StatusCode::OK, vec![], "This is a test");
+		response.frames  = vec![UnpackedFrame::Trailers(vec![UnpackedResponseHeader::new(s!("x-token"), s!("abc123"))])];
+		let redacted     = RedactionPolicy::new().with_header("X-Token").redact(&response);
+		assert_eq!(redacted.trailers(), vec![&UnpackedResponseHeader::new(s!("x-token"), s!("[redacted]"))]);
+	}
+	
+	//		with_json_field														
+	#[test]
+	fn with_json_field() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![], json!({
+			"token": "abc123",
+			"items": [{"secret": 1, "name": "foo"}],
+		}));
+		let policy   = RedactionPolicy::new().with_json_field("$.token").with_json_field("$.items[*].secret");
+		assert_eq!(policy.redact(&response).body.as_json().unwrap(), json!({
+			"token": "[redacted]",
+			"items": [{"secret": "[redacted]", "name": "foo"}],
+		}));
+	}
+	#[test]
+	fn with_json_field__binary() {
+		let response = UnpackedResponse {
+			body: UnpackedResponseBody { body: br#"{"token":"abc123"}"#.to_vec(), content_type: ContentType::Binary },
+			..UnpackedResponse::new(StatusCode::OK, vec![], "")
+		};
+		assert_eq!(RedactionPolicy::new().with_json_field("$.token").redact(&response).body, response.body);
+	}
+	
+	//		with_placeholder													
+	#[test]
+	fn with_placeholder() {
+		let response1 = UnpackedResponse::new(StatusCode::OK, vec![(s!("authorization"), s!("Bearer abc123"))], "token=abc123");
+		let response2 = UnpackedResponse::new(StatusCode::OK, vec![], json!({"token": "abc123"}));
+		let policy    = RedactionPolicy::new()
+			.with_placeholder("$0***")
+			.with_header("authorization")
+			.with_json_field("$.token")
+			.with_text_pattern(Regex::new(r"abc\d+").unwrap())
+		;
+		let redacted1 = policy.redact(&response1);
+		assert_eq!(redacted1.header("authorization"),                  Some("$0***"));
+		assert_eq!(redacted1.body.to_string(),                         "token=$0***");
+		assert_eq!(policy.redact(&response2).body.as_json().unwrap(), json!({"token": "$0***"}));
+	}
+	
+	//		with_text_pattern													
+	#[test]
+	fn with_text_pattern() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![], "token=abc123&key=def456&name=foo");
+		let policy   = RedactionPolicy::new()
+			.with_text_pattern(Regex::new(r"token=\w+").unwrap())
+			.with_text_pattern(Regex::new(r"key=\w+").unwrap())
+		;
+		assert_eq!(policy.redact(&response).body.to_string(), "[redacted]&[redacted]&name=foo");
+		assert_eq!(response.body.to_string(),                  "token=abc123&key=def456&name=foo");
+	}
+	#[test]
+	fn with_text_pattern__json() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![], json!({"note": "Bearer abc123"}));
+		let policy   = RedactionPolicy::new().with_text_pattern(Regex::new(r"Bearer \w+").unwrap());
+		assert_eq!(policy.redact(&response).body.as_json().unwrap(), json!({"note": "[redacted]"}));
+	}
+	#[test]
+	fn with_text_pattern__binary() {
+		let response = UnpackedResponse {
+			body: UnpackedResponseBody { body: b"token=abc123".to_vec(), content_type: ContentType::Binary },
+			..UnpackedResponse::new(StatusCode::OK, vec![], "")
+		};
+		let policy   = RedactionPolicy::new().with_text_pattern(Regex::new(r"token=\w+").unwrap());
+		assert_eq!(policy.redact(&response).body, response.body);
+	}
+	#[test]
+	fn with_text_pattern__frames() {
+		let mut response = UnpackedResponse::new(StatusCode::OK, vec![], "data: abc123\n\n");
+		response.frames  = vec![UnpackedFrame::Data(UnpackedResponseBody::new("data: abc123\n\n"))];
+		let redacted     = RedactionPolicy::new().with_text_pattern(Regex::new(r"abc\d+").unwrap()).redact(&response);
+		assert_eq!(redacted.body.to_string(), "data: [redacted]\n\n");
+		assert_eq!(redacted.frames,           vec![UnpackedFrame::Data(UnpackedResponseBody::new("data: [redacted]\n\n"))]);
+	}
+}
+
+//		ResponseMatcher															
+#[cfg(test)]
+mod response_matcher__struct {
+	use super::*;
+	
+	//		check																
+	#[test]
+	fn check__exact() {
+		let expected = UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("bar"))], "This is a test");
+		let actual   = UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("bar"))], "This is a test");
+		let report   = ResponseMatcher::new(expected).check(&actual);
+		assert!(report.is_match());
+		assert_eq!(report, MatchReport::default());
+	}
+	#[test]
+	fn check__status() {
+		let expected = UnpackedResponse::new(StatusCode::OK,        vec![], "");
+		let actual   = UnpackedResponse::new(StatusCode::NOT_FOUND, vec![], "");
+		assert_eq!(ResponseMatcher::new(expected).check(&actual).failures, vec![
+			MatchFailure::Status { expected: StatusCode::OK, actual: StatusCode::NOT_FOUND },
+		]);
+	}
+	#[test]
+	fn check__ignore_header() {
+		let expected = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("date"),           s!("Thu, 01 Jan 1970 00:00:00 GMT")),
+			(s!("content-type"),   s!("text/plain")),
+		], "");
+		let actual   = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("content-type"),   s!("text/plain")),
+			(s!("date"),           s!("Mon, 02 Jun 2025 12:00:00 GMT")),
+			(s!("content-length"), s!("0")),
+		], "");
+		let matcher  = ResponseMatcher::new(expected)
+			.ignore_header("Date")
+			.ignore_header("content-length")
+		;
+		assert!(matcher.check(&actual).is_match());
+	}
+	#[test]
+	fn check__headers_exact() {
+		let expected = UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("bar"))], "");
+		let actual   = UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("bar")), (s!("baz"), s!("qux"))], "");
+		assert_eq!(ResponseMatcher::new(expected).check(&actual).failures, vec![
+			MatchFailure::Header(HeaderDiff::Added(UnpackedResponseHeader::new(s!("baz"), s!("qux")))),
+		]);
+	}
+	#[test]
+	fn check__headers_subset() {
+		let expected = UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("bar")), (s!("abc"), s!("def"))], "");
+		let actual   = UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("baz")), (s!("qux"), s!("quux"))], "");
+		assert_eq!(ResponseMatcher::new(expected).headers_subset().check(&actual).failures, vec![
+			MatchFailure::Header(HeaderDiff::Removed(UnpackedResponseHeader::new(s!("abc"), s!("def")))),
+			MatchFailure::Header(HeaderDiff::Changed { name: s!("foo"), left: vec![s!("bar")], right: vec![s!("baz")] }),
+		]);
+	}
+	#[test]
+	fn check__header_matches() {
+		let expected = UnpackedResponse::new(StatusCode::OK, vec![(s!("x-request-id"), s!("1"))], "");
+		let matcher  = ResponseMatcher::new(expected)
+			.header_matches("X-Request-Id", Regex::new(r"^\d+$").unwrap())
+		;
+		let actual1  = UnpackedResponse::new(StatusCode::OK, vec![(s!("x-request-id"), s!("12345"))], "");
+		assert!(matcher.check(&actual1).is_match());
+		let actual2  = UnpackedResponse::new(StatusCode::OK, vec![(s!("x-request-id"), s!("abc"))], "");
+		assert_eq!(matcher.check(&actual2).failures, vec![
+			MatchFailure::HeaderValue { name: s!("X-Request-Id"), actual: Some(s!("abc")), requirement: s!(r"match /^\d+$/") },
+		]);
+		let actual3  = UnpackedResponse::new(StatusCode::OK, vec![], "");
+		assert_eq!(matcher.check(&actual3).failures, vec![
+			MatchFailure::HeaderValue { name: s!("X-Request-Id"), actual: None, requirement: s!(r"match /^\d+$/") },
+		]);
+	}
+	#[test]
+	fn check__header_with() {
+		let expected = UnpackedResponse::new(StatusCode::OK, vec![], "");
+		let matcher  = ResponseMatcher::new(expected)
+			.headers_subset()
+			.header_with("content-length", |value| value.parse::<usize>().is_ok_and(|len| len < 100))
+		;
+		let actual1  = UnpackedResponse::new(StatusCode::OK, vec![(s!("content-length"), s!("42"))], "");
+		assert!(matcher.matches(&actual1));
+		let actual2  = UnpackedResponse::new(StatusCode::OK, vec![(s!("content-length"), s!("420"))], "");
+		assert!(!matcher.matches(&actual2));
+		assert_eq!(matcher.check(&actual2).failures, vec![
+			MatchFailure::HeaderValue { name: s!("content-length"), actual: Some(s!("420")), requirement: s!("satisfy predicate") },
+		]);
+	}
+	#[test]
+	fn check__ignore_json_field() {
+		let expected = UnpackedResponse::new(StatusCode::OK, vec![], json!({
+			"id":    1,
+			"name":  "Foo",
+			"items": [ { "id": 1, "value": "a" }, { "id": 2, "value": "b" } ],
+		}).to_string());
+		let actual   = UnpackedResponse::new(StatusCode::OK, vec![], json!({
+			"id":    99,
+			"name":  "Foo",
+			"items": [ { "id": 10, "value": "a" }, { "id": 20, "value": "c" } ],
+		}).to_string());
+		let matcher  = ResponseMatcher::new(expected)
+			.ignore_json_field("$.id")
+			.ignore_json_field("$.items[*].id")
+		;
+		assert_eq!(matcher.check(&actual).failures, vec![
+			MatchFailure::Body(BodyDiff::Json(vec![
+				JsonPathDiff::Changed { path: s!("$.items[1].value"), left: json!("b"), right: json!("c") },
+			])),
+		]);
+	}
+	#[test]
+	fn check__ignore_json_field_not_json() {
+		let expected = UnpackedResponse::new(StatusCode::OK, vec![], "Foo");
+		let actual   = UnpackedResponse::new(StatusCode::OK, vec![], "Bar");
+		let matcher  = ResponseMatcher::new(expected).ignore_json_field("$.id");
+		assert_eq!(matcher.check(&actual).failures, vec![
+			MatchFailure::Body(BodyDiff::Text(vec![
+				TextLineDiff::Removed { line: 1, text: s!("Foo") },
+				TextLineDiff::Added   { line: 1, text: s!("Bar") },
+			])),
+		]);
+	}
+	
+	//		matches																
+	#[test]
+	fn matches() {
+		let expected = UnpackedResponse::new(StatusCode::OK, vec![], "This is a test");
+		let matcher  = ResponseMatcher::new(expected);
+		assert!( matcher.matches(&UnpackedResponse::new(StatusCode::OK, vec![], "This is a test")));
+		assert!(!matcher.matches(&UnpackedResponse::new(StatusCode::OK, vec![], "This is different")));
+	}
+}
+
+//		ServerSentEvent															
+#[cfg(test)]
+mod server_sent_event__struct {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		assert_eq!(ServerSentEvent::new("foo"), ServerSentEvent {
+			event: None,
+			data:  s!("foo"),
+			id:    None,
+			retry: None,
+		});
+	}
+	
+	//		with_event															
+	#[test]
+	fn with_event() {
+		assert_eq!(ServerSentEvent::new("foo").with_event("bar").event, Some(s!("bar")));
+	}
+	
+	//		with_id																
+	#[test]
+	fn with_id() {
+		assert_eq!(ServerSentEvent::new("foo").with_id("1").id, Some(s!("1")));
+	}
+	
+	//		with_retry															
+	#[test]
+	fn with_retry() {
+		assert_eq!(ServerSentEvent::new("foo").with_retry(1000).retry, Some(1000));
+	}
+}
+
+//		SnapshotOptions															
+#[cfg(test)]
+mod snapshot_options__struct {
+	use super::*;
+	
+	//		path_for															
+	#[test]
+	fn path_for__relative() {
+		let options = SnapshotOptions::new();
+		assert_eq!(options.path_for("/crate", "foo"), PathBuf::from("/crate/tests/snapshots/foo.json"));
+	}
+	#[test]
+	fn path_for__absolute() {
+		let options = SnapshotOptions::new().with_directory("/snapshots");
+		assert_eq!(options.path_for("/crate", "foo"), PathBuf::from("/snapshots/foo.json"));
+	}
+	#[test]
+	fn path_for__unsafe_name() {
+		let options = SnapshotOptions::new();
+		assert_eq!(options.path_for("/crate", "../../src/lib"),            PathBuf::from("/crate/tests/snapshots/.._.._src_lib.json"));
+		assert_eq!(options.path_for("/crate", "..\\foo:bar"),              PathBuf::from("/crate/tests/snapshots/.._foo_bar.json"));
+		assert_eq!(options.path_for("/crate", "..").file_name().unwrap(), "...json");
+	}
+	
+	//		redact																
+	#[test]
+	fn redact__headers() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("Date"),         s!("Thu, 01 Jan 1970 00:00:00 GMT")),
+			(s!("content-type"), s!("text/plain")),
+		], "This is a test");
+		let options  = SnapshotOptions::new().with_redacted_header("date");
+		assert_eq!(options.redact(&response), UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("Date"),         s!("[redacted]")),
+			(s!("content-type"), s!("text/plain")),
+		], "This is a test"));
+	}
+	#[test]
+	fn redact__json_fields() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![], json!({
+			"id":    123,
+			"items": [{"id": 1, "name": "foo"}, {"id": 2, "name": "bar"}],
+			"name":  "baz",
+		}));
+		let options  = SnapshotOptions::new()
+			.with_redacted_json_field("$.id")
+			.with_redacted_json_field("$.items[*].id")
+		;
+		assert_eq!(options.redact(&response).body.as_json().unwrap(), json!({
+			"id":    "[redacted]",
+			"items": [{"id": "[redacted]", "name": "foo"}, {"id": "[redacted]", "name": "bar"}],
+			"name":  "baz",
+		}));
+	}
+	#[test]
+	fn redact__json_fields_non_json() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![], "This is a test");
+		let options  = SnapshotOptions::new().with_redacted_json_field("$.id");
+		assert_eq!(options.redact(&response), response);
+	}
+	
+	//		with_redaction_policy												
+	#[test]
+	fn with_redaction_policy() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("date"), s!("Thu, 01 Jan 1970 00:00:00 GMT"))], "id=123");
+		let options  = SnapshotOptions::new()
+			.with_redacted_header("date")
+			.with_redaction_policy(RedactionPolicy::new().with_text_pattern(Regex::new(r"\d+").unwrap()))
+		;
+		assert_eq!(options.redact(&response), UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("date"), s!("Thu, 01 Jan 1970 00:00:00 GMT")),
+		], "id=[redacted]"));
+	}
+	
+	//		check																
+	#[test]
+	fn check__created() {
+		let dir      = tempdir().unwrap();
+		let path     = dir.path().join("nested/foo.json");
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("bar"))], "This is a test");
+		let options  = SnapshotOptions::new().with_update(false);
+		assert_ok_eq!(options.check(&response, &path), SnapshotStatus::Created);
+		assert_eq!(fs::read_to_string(&path).unwrap(), serde_json::to_string_pretty(&response).unwrap() + "\n");
+	}
+	#[test]
+	fn check__matched() {
+		let dir      = tempdir().unwrap();
+		let path     = dir.path().join("foo.json");
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("bar"))], json!({"foo": "bar"}));
+		let options  = SnapshotOptions::new().with_update(false);
+		assert_ok_eq!(options.check(&response, &path), SnapshotStatus::Created);
+		assert_ok_eq!(options.check(&response, &path), SnapshotStatus::Matched);
+	}
+	#[test]
+	fn check__matched_ignores_whitespace() {
+		let dir      = tempdir().unwrap();
+		let path     = dir.path().join("foo.json");
+		let response = UnpackedResponse::new(StatusCode::OK, vec![], "This is a test");
+		fs::write(&path, serde_json::to_string(&response).unwrap()).unwrap();
+		assert_ok_eq!(SnapshotOptions::new().with_update(false).check(&response, &path), SnapshotStatus::Matched);
+	}
+	#[test]
+	fn check__redacted() {
+		let dir     = tempdir().unwrap();
+		let path    = dir.path().join("foo.json");
+		let first   = UnpackedResponse::new(StatusCode::OK, vec![(s!("date"), s!("Thu, 01 Jan 1970 00:00:00 GMT"))], json!({"id": 1}));
+		let second  = UnpackedResponse::new(StatusCode::OK, vec![(s!("date"), s!("Mon, 02 Jun 2025 12:00:00 GMT"))], json!({"id": 2}));
+		let options = SnapshotOptions::new()
+			.with_redacted_header("date")
+			.with_redacted_json_field("$.id")
+			.with_update(false)
+		;
+		assert_ok_eq!(options.check(&first,  &path), SnapshotStatus::Created);
+		assert_ok_eq!(options.check(&second, &path), SnapshotStatus::Matched);
+		assert!(!fs::read_to_string(&path).unwrap().contains("1970"));
+	}
+	#[test]
+	fn check__mismatch() {
+		let dir      = tempdir().unwrap();
+		let path     = dir.path().join("foo.json");
+		let expected = UnpackedResponse::new(StatusCode::OK,        vec![], "This is a test");
+		let actual   = UnpackedResponse::new(StatusCode::NOT_FOUND, vec![], "This is a test");
+		let options  = SnapshotOptions::new().with_update(false);
+		assert_ok_eq!(options.check(&expected, &path), SnapshotStatus::Created);
+		let err      = options.check(&actual, &path).unwrap_err();
+		assert!(matches!(err, SnapshotError::Mismatch { ref diff, .. } if diff.status == Some((StatusCode::OK, StatusCode::NOT_FOUND))));
+		assert_eq!(fs::read_to_string(&path).unwrap(), serde_json::to_string_pretty(&expected).unwrap() + "\n");
+	}
+	#[test]
+	fn check__updated() {
+		let dir      = tempdir().unwrap();
+		let path     = dir.path().join("foo.json");
+		let expected = UnpackedResponse::new(StatusCode::OK,        vec![], "This is a test");
+		let actual   = UnpackedResponse::new(StatusCode::NOT_FOUND, vec![], "This is a test");
+		let options  = SnapshotOptions::new().with_update(true);
+		assert_ok_eq!(options.check(&expected, &path), SnapshotStatus::Created);
+		assert_ok_eq!(options.check(&actual,   &path), SnapshotStatus::Updated);
+		assert_ok_eq!(options.check(&actual,   &path), SnapshotStatus::Matched);
+	}
+	#[test]
+	fn check__invalid_file() {
+		let dir      = tempdir().unwrap();
+		let path     = dir.path().join("foo.json");
+		fs::write(&path, "not json").unwrap();
+		let response = UnpackedResponse::new(StatusCode::OK, vec![], "");
+		let err      = SnapshotOptions::new().with_update(false).check(&response, &path).unwrap_err();
+		assert!(matches!(err, SnapshotError::Json(_)));
+	}
+}
+
+//		TestClient																
+#[cfg(test)]
+mod test_client__struct {
+	use super::*;
+	
+	fn router() -> Router {
+		Router::new()
+			.route("/",     get(|| async { "Hello, world!" }))
+			.route("/echo", post(|headers: HeaderMap, body: String| async move {
+				let content_type = headers.get(CONTENT_TYPE).map(|value| value.to_str().unwrap().to_owned()).unwrap_or_default();
+				([(CONTENT_TYPE, content_type)], body)
+			}))
+	}
+	
+	//		new																	
+	#[tokio::test]
+	async fn new() {
+		let response = TestClient::new(router()).get("/").send().await.unwrap();
+		assert_eq!(response.status, StatusCode::OK);
+		assert_eq!(response.body,   UnpackedResponseBody::new("Hello, world!"));
+	}
+	
+	//		with_options														
+	#[tokio::test]
+	async fn with_options() {
+		let client = TestClient::new(router()).with_options(UnpackOptions::new().with_limit(5));
+		let err    = client.get("/").send().await.unwrap_err();
+		assert!(matches!(err, TestClientError::Response(ResponseError::LimitExceeded(5))));
+	}
+	
+	//		request																
+	#[tokio::test]
+	async fn request() {
+		let client = TestClient::new(router());
+		assert_eq!(client.request(Method::GET,    "/").send().await.unwrap().status,     StatusCode::OK);
+		assert_eq!(client.request(Method::DELETE, "/").send().await.unwrap().status,     StatusCode::METHOD_NOT_ALLOWED);
+		assert_eq!(client.request(Method::GET,    "/missing").send().await.unwrap().status, StatusCode::NOT_FOUND);
+	}
+	
+	//		methods																
+	#[tokio::test]
+	async fn methods() {
+		let client = TestClient::new(router());
+		assert_eq!(client.get("/echo").build().unwrap().method(),    Method::GET);
+		assert_eq!(client.post("/echo").build().unwrap().method(),   Method::POST);
+		assert_eq!(client.put("/echo").build().unwrap().method(),    Method::PUT);
+		assert_eq!(client.patch("/echo").build().unwrap().method(),  Method::PATCH);
+		assert_eq!(client.delete("/echo").build().unwrap().method(), Method::DELETE);
+		assert_eq!(client.post("/echo").send().await.unwrap().status, StatusCode::OK);
+		assert_eq!(client.put("/echo").send().await.unwrap().status,  StatusCode::METHOD_NOT_ALLOWED);
+	}
+	
+	//		send																
+	#[tokio::test]
+	async fn send() {
+		let client  = TestClient::new(router());
+		let request = Request::post("/echo").body(AxumBody::from("This is a test")).unwrap();
+		let response = client.send(request).await.unwrap();
+		assert_eq!(response.status, StatusCode::OK);
+		assert_eq!(response.body,   UnpackedResponseBody::new("This is a test"));
+	}
+}
+
+//		TestRequest																
+#[cfg(test)]
+mod test_request__struct {
+	use super::*;
+	
+	fn client() -> TestClient {
+		TestClient::new(Router::new().route("/echo", post(|headers: HeaderMap, body: String| async move {
+			let content_type = headers.get(CONTENT_TYPE).map(|value| value.to_str().unwrap().to_owned()).unwrap_or_default();
+			let custom       = headers.get_all("x-custom").iter().map(|value| value.to_str().unwrap()).collect::<Vec<_>>().join(",");
+			([(CONTENT_TYPE, content_type), (HeaderName::from_static("x-custom"), custom)], body)
+		})))
+	}
+	
+	//		with_header															
+	#[tokio::test]
+	async fn with_header() {
+		let response = client().post("/echo")
+			.with_header("x-custom", "foo")
+			.with_header("X-Custom", "bar")
+			.send().await.unwrap()
+		;
+		assert_eq!(response.header("x-custom"), Some("foo,bar"));
+	}
+	#[tokio::test]
+	async fn with_header__invalid() {
+		let err = client().post("/echo").with_header("x custom", "foo").send().await.unwrap_err();
+		assert!(matches!(err, TestClientError::Request(_)));
+	}
+	
+	//		with_body															
+	#[tokio::test]
+	async fn with_body() {
+		let response = client().post("/echo")
+			.with_header("content-type", "text/plain")
+			.with_body("This is a test")
+			.send().await.unwrap()
+		;
+		assert_eq!(response.header("content-type"), Some("text/plain"));
+		assert_eq!(response.body,                    UnpackedResponseBody::new("This is a test"));
+	}
+	#[tokio::test]
+	async fn with_body__no_content_type() {
+		let response = client().post("/echo").with_json(&json!({})).with_body("foo").send().await.unwrap();
+		assert_eq!(response.header("content-type"), Some(""));
+		assert_eq!(response.body,                    UnpackedResponseBody::new("foo"));
+	}
+	
+	//		with_json															
+	#[tokio::test]
+	async fn with_json() {
+		let response = client().post("/echo").with_json(&json!({ "foo": [1_i32, 2_i32] })).send().await.unwrap();
+		assert_eq!(response.header("content-type"), Some("application/json"));
+		assert_eq!(response.body.content_type(),     ContentType::Json);
+		assert_ok_eq!(response.body.as_json(),       json!({ "foo": [1_i32, 2_i32] }));
+	}
+	#[tokio::test]
+	async fn with_json__explicit_content_type() {
+		let response = client().post("/echo")
+			.with_json(&json!({ "foo": "bar" }))
+			.with_header("content-type", "application/vnd.test+json")
+			.send().await.unwrap()
+		;
+		assert_eq!(response.header("content-type"), Some("application/vnd.test+json"));
+	}
+	#[tokio::test]
+	async fn with_json__invalid() {
+		let mut map = BTreeMap::new();
+		_ = map.insert(vec![1_u8], "foo");
+		let err = client().post("/echo").with_json(&map).send().await.unwrap_err();
+		assert!(matches!(err, TestClientError::Json(_)));
+	}
+	
+	//		with_form															
+	#[tokio::test]
+	async fn with_form() {
+		let response = client().post("/echo").with_form(&[("foo", "bar baz"), ("a", "1")]).send().await.unwrap();
+		assert_eq!(response.header("content-type"), Some("application/x-www-form-urlencoded"));
+		assert_eq!(response.body,                    UnpackedResponseBody::new("foo=bar+baz&a=1"));
+	}
+	#[tokio::test]
+	async fn with_form__invalid() {
+		let err = client().post("/echo").with_form(&json!({ "foo": { "bar": "baz" } })).send().await.unwrap_err();
+		assert!(matches!(err, TestClientError::Form(_)));
+	}
+	
+	//		build																
+	#[test]
+	fn build() {
+		let request = client().put("/echo?foo=bar").with_header("x-custom", "foo").with_json(&json!([])).build().unwrap();
+		assert_eq!(request.method(),                   Method::PUT);
+		assert_eq!(request.uri(),                      "/echo?foo=bar");
+		assert_eq!(request.headers().get("x-custom").unwrap(),  "foo");
+		assert_eq!(request.headers().get(CONTENT_TYPE).unwrap(), "application/json");
+	}
+	#[test]
+	fn build__invalid_uri() {
+		assert!(matches!(client().get("not a uri").build(), Err(TestClientError::Request(_))));
+	}
+	#[test]
+	fn build__first_error() {
+		let mut map = BTreeMap::new();
+		_ = map.insert(vec![1_u8], "foo");
+		let result = client().post("/echo").with_json(&map).with_form(&json!({ "foo": { "bar": "baz" } })).build();
+		assert!(matches!(result, Err(TestClientError::Json(_))));
+	}
+	
+	//		send																
+	#[tokio::test]
+	async fn send() {
+		let response = client().post("/echo").with_body("This is a test").send().await.unwrap();
+		assert_eq!(response.status, StatusCode::OK);
+		assert_eq!(response.body,   UnpackedResponseBody::new("This is a test"));
+	}
+}
+
+//		UnpackOptions															
+#[cfg(test)]
+mod unpack_options__struct {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let options = UnpackOptions::new();
+		assert!(options.content_types.is_empty());
+		assert!(options.detect_content);
+		assert!(!options.decompress);
+		assert_eq!(options.limit, usize::MAX);
+		assert!(options.sniff_content);
+	}
+	
+	//		with_content_type													
+	#[test]
+	fn with_content_type() {
+		let options = UnpackOptions::new()
+			.with_content_type("Application/X-Custom", ContentType::Binary)
+			.with_content_type("image/*",              ContentType::Text)
+		;
+		assert_eq!(options.content_types, vec![
+			(s!("application/x-custom"), ContentType::Binary),
+			(s!("image/*"),              ContentType::Text),
+		]);
+	}
+	
+	//		with_content_detection												
+	#[test]
+	fn with_content_detection() {
+		assert!(!UnpackOptions::new().with_content_detection(false).detect_content);
+		assert!( UnpackOptions::new().with_content_detection(false).with_content_detection(true).detect_content);
+	}
+	
+	//		with_content_sniffing												
+	#[test]
+	fn with_content_sniffing() {
+		assert!(!UnpackOptions::new().with_content_sniffing(false).sniff_content);
+		assert!( UnpackOptions::new().with_content_sniffing(false).with_content_sniffing(true).sniff_content);
+	}
+	
+	//		with_decompression													
+	#[test]
+	fn with_decompression() {
+		assert!( UnpackOptions::new().with_decompression(true).decompress);
+		assert!(!UnpackOptions::new().with_decompression(true).with_decompression(false).decompress);
+	}
+	
+	//		with_frame_recording												
+	#[test]
+	fn with_frame_recording() {
+		assert!( UnpackOptions::new().with_frame_recording(true).record_frames);
+		assert!(!UnpackOptions::new().with_frame_recording(true).with_frame_recording(false).record_frames);
+	}
+	
+	//		with_limit															
+	#[test]
+	fn with_limit() {
+		assert_eq!(UnpackOptions::new().with_limit(10).limit, 10);
+	}
+	
+	//		with_preserved_header_order											
+	#[test]
+	fn with_preserved_header_order() {
+		assert!( UnpackOptions::new().with_preserved_header_order(true).preserve_header_order);
+		assert!(!UnpackOptions::new().with_preserved_header_order(true).with_preserved_header_order(false).preserve_header_order);
+	}
+	
+	//		with_raw_header_values												
+	#[test]
+	fn with_raw_header_values() {
+		assert!( UnpackOptions::new().with_raw_header_values(true).raw_header_values);
+		assert!(!UnpackOptions::new().with_raw_header_values(true).with_raw_header_values(false).raw_header_values);
+	}
+	
+	//		with_strict_utf8													
+	#[test]
+	fn with_strict_utf8() {
+		assert!(!UnpackOptions::new().strict_utf8);
+		assert!( UnpackOptions::new().with_strict_utf8(true).strict_utf8);
+		assert!(!UnpackOptions::new().with_strict_utf8(true).with_strict_utf8(false).strict_utf8);
+	}
+	
+	//		with_timeout														
+	#[test]
+	fn with_timeout() {
+		assert_eq!(UnpackOptions::new().timeout,                                      None);
+		assert_eq!(UnpackOptions::new().with_timeout(Duration::from_secs(5)).timeout, Some(Duration::from_secs(5)));
+	}
+	
+	//		content_type_for													
+	#[test]
+	fn content_type_for__defaults() {
+		let options = UnpackOptions::new();
+		assert_eq!(options.content_type_for("application/json"),                Some(ContentType::Json));
+		assert_eq!(options.content_type_for("application/problem+json"),        Some(ContentType::Json));
+		assert_eq!(options.content_type_for("Application/JSON; charset=utf-8"), Some(ContentType::Json));
+		assert_eq!(options.content_type_for("text/plain; charset=utf-8"),       Some(ContentType::Text));
+		assert_eq!(options.content_type_for("text/html"),                       Some(ContentType::Text));
+		assert_eq!(options.content_type_for("application/xml"),                 Some(ContentType::Text));
+		assert_eq!(options.content_type_for("image/svg+xml"),                   Some(ContentType::Text));
+		assert_eq!(options.content_type_for("image/png"),                       Some(ContentType::Binary));
+		assert_eq!(options.content_type_for("application/octet-stream"),        Some(ContentType::Binary));
+		assert_eq!(options.content_type_for("application/pdf"),                 Some(ContentType::Binary));
+		assert_eq!(options.content_type_for("application/x-unknown"),           None);
+		assert_eq!(options.content_type_for("invalid"),                         None);
+	}
+	#[test]
+	fn content_type_for__overrides() {
+		let options = UnpackOptions::new()
+			.with_content_type("image/*",              ContentType::Text)
+			.with_content_type("image/png",            ContentType::Binary)
+			.with_content_type("application/x-custom", ContentType::Json)
+			.with_content_type("application/json",     ContentType::Text)
+		;
+		assert_eq!(options.content_type_for("image/png"),              Some(ContentType::Binary));
+		assert_eq!(options.content_type_for("image/jpeg"),             Some(ContentType::Text));
+		assert_eq!(options.content_type_for("application/x-custom"),   Some(ContentType::Json));
+		assert_eq!(options.content_type_for("application/json"),       Some(ContentType::Text));
+		assert_eq!(options.content_type_for("application/ld+json"),    Some(ContentType::Json));
+	}
+}
+
+//		UnpackedCookie															
+#[cfg(test)]
+mod unpacked_cookie__struct {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let cookie = UnpackedCookie::new("foo", "bar");
+		assert_eq!(cookie.name,      "foo");
+		assert_eq!(cookie.value,     "bar");
+		assert_eq!(cookie.path,      None);
+		assert_eq!(cookie.domain,    None);
+		assert_eq!(cookie.expires,   None);
+		assert_eq!(cookie.max_age,   None);
+		assert!(!cookie.secure);
+		assert!(!cookie.http_only);
+		assert_eq!(cookie.same_site, None);
+	}
+	
+	//		parse																
+	#[test]
+	fn parse__basic() {
+		assert_eq!(UnpackedCookie::parse("foo=bar"), Some(UnpackedCookie::new("foo", "bar")));
+		assert_eq!(UnpackedCookie::parse(" foo = "), Some(UnpackedCookie::new("foo", "")));
+	}
+	#[test]
+	fn parse__attributes() {
+		let cookie = UnpackedCookie::parse(
+			"session=abc123; Path=/; Domain=example.com; Expires=Thu, 01 Jan 2026 00:00:00 GMT; Max-Age=3600; Secure; HttpOnly; SameSite=Lax"
+		);
+		assert_eq!(cookie, Some(UnpackedCookie::new("session", "abc123")
+			.with_path("/")
+			.with_domain("example.com")
+			.with_expires("Thu, 01 Jan 2026 00:00:00 GMT")
+			.with_max_age(3600)
+			.with_secure(true)
+			.with_http_only(true)
+			.with_same_site(SameSite::Lax)
+		));
+	}
+	#[test]
+	fn parse__case_insensitive_attributes() {
+		let cookie = UnpackedCookie::parse("foo=bar; path=/foo; secure; HTTPONLY; samesite=strict").unwrap();
+		assert_eq!(cookie.path,      Some(s!("/foo")));
+		assert!(cookie.secure);
+		assert!(cookie.http_only);
+		assert_eq!(cookie.same_site, Some(SameSite::Strict));
+	}
+	#[test]
+	fn parse__invalid_attributes() {
+		let cookie = UnpackedCookie::parse("foo=bar; Max-Age=soon; SameSite=Sometimes; Priority=High").unwrap();
+		assert_eq!(cookie, UnpackedCookie::new("foo", "bar"));
+	}
+	#[test]
+	fn parse__invalid_same_site_after_valid() {
+		let cookie = UnpackedCookie::parse("foo=bar; SameSite=Lax; SameSite=Sometimes").unwrap();
+		assert_eq!(cookie.same_site, Some(SameSite::Lax));
+	}
+	#[test]
+	fn parse__invalid() {
+		assert_eq!(UnpackedCookie::parse(""),          None);
+		assert_eq!(UnpackedCookie::parse("foo"),       None);
+		assert_eq!(UnpackedCookie::parse("=bar"),      None);
+		assert_eq!(UnpackedCookie::parse("; foo=bar"), None);
+	}
+	
+	//		eq_ignoring_expiry													
+	#[test]
+	fn eq_ignoring_expiry() {
+		let cookie = UnpackedCookie::new("foo", "bar").with_path("/").with_max_age(60).with_expires("Thu, 01 Jan 2026 00:00:00 GMT");
+		assert!( cookie.eq_ignoring_expiry(&UnpackedCookie::new("foo", "bar").with_path("/")));
+		assert!( cookie.eq_ignoring_expiry(&UnpackedCookie::new("foo", "bar").with_path("/").with_max_age(120)));
+		assert!(!cookie.eq_ignoring_expiry(&UnpackedCookie::new("foo", "baz").with_path("/")));
+		assert!(!cookie.eq_ignoring_expiry(&UnpackedCookie::new("foo", "bar").with_path("/").with_secure(true)));
+		assert_ne!(cookie, UnpackedCookie::new("foo", "bar").with_path("/"));
+	}
+}
+
+#[cfg(test)]
+mod unpacked_cookie__traits {
+	use super::*;
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(UnpackedCookie::new("foo", "bar").to_string(), "foo=bar");
+		let cookie = UnpackedCookie::new("session", "abc123")
+			.with_path("/")
+			.with_domain("example.com")
+			.with_expires("Thu, 01 Jan 2026 00:00:00 GMT")
+			.with_max_age(3600)
+			.with_secure(true)
+			.with_http_only(true)
+			.with_same_site(SameSite::None)
+		;
+		let string = "session=abc123; Path=/; Domain=example.com; Expires=Thu, 01 Jan 2026 00:00:00 GMT; Max-Age=3600; Secure; HttpOnly; SameSite=None";
+		assert_eq!(cookie.to_string(), string);
+		assert_eq!(UnpackedCookie::parse(string), Some(cookie));
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		let cookie = UnpackedCookie::new("foo", "bar").with_path("/").with_http_only(true).with_same_site(SameSite::Strict);
+		assert_json_eq!(json!(cookie), json!({
+			"name":      "foo",
+			"value":     "bar",
+			"path":      "/",
+			"domain":    null,
+			"expires":   null,
+			"max_age":   null,
+			"secure":    false,
+			"http_only": true,
+			"same_site": "Strict",
+		}));
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let cookie = UnpackedCookie::new("foo", "bar").with_max_age(60).with_same_site(SameSite::Lax);
+		assert_ok_eq!(serde_json::from_str::<UnpackedCookie>(&json!(cookie).to_string()), cookie);
+	}
+}
+
+//		UnpackedRawHeader														
+#[cfg(test)]
+mod unpacked_raw_header__struct {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let header = UnpackedRawHeader::new(s!("foo"), vec![b'a', 0xFF]);
+		assert_eq!(header, UnpackedRawHeader { name: s!("foo"), value: vec![b'a', 0xFF] });
+	}
+}
+
+#[cfg(test)]
+mod unpacked_raw_header__traits {
+	use super::*;
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		let header = UnpackedRawHeader::new(s!("foo"), vec![b'a', 0xFF]);
+		assert_json_eq!(json!(header), json!({
+			"name":  "foo",
+			"value": "Yf8=",
+		}));
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let json = json!({
+			"name":  "foo",
+			"value": "Yf8=",
+		}).to_string();
+		assert_ok_eq!(serde_json::from_str::<UnpackedRawHeader>(&json), UnpackedRawHeader::new(s!("foo"), vec![b'a', 0xFF]));
+	}
+}
+
+//		UnpackedRequest															
+#[cfg(test)]
+mod unpacked_request__struct {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let headers  = vec![
+			(s!("Foo"), s!("Bar")),
+			(s!("Bar"), s!("Baz")),
+		];
+		let body     = "This is a test";
+		let unpacked = UnpackedRequest::new(Method::POST, Uri::from_static("/foo?bar=baz"), headers, body);
+		assert_eq!(unpacked, UnpackedRequest {
+			method:  Method::POST,
+			uri:     Uri::from_static("/foo?bar=baz"),
+			version: Version::HTTP_11,
+			headers: vec![
+				UnpackedResponseHeader { name: s!("Foo"), value: s!("Bar") },
+				UnpackedResponseHeader { name: s!("Bar"), value: s!("Baz") },
+			],
+			body:    UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+		});
+	}
+	
+	//		new_from_parts														
+	#[test]
+	fn new_from_parts() {
+		let headers  = vec![
+			UnpackedResponseHeader::new(s!("Foo"), s!("Bar")),
+			UnpackedResponseHeader::new(s!("Bar"), s!("Baz")),
+		];
+		let body     = UnpackedResponseBody::new("This is a test");
+		let unpacked = UnpackedRequest::new_from_parts(Method::PUT, Uri::from_static("/foo"), Version::HTTP_2, headers, body);
+		assert_eq!(unpacked, UnpackedRequest {
+			method:  Method::PUT,
+			uri:     Uri::from_static("/foo"),
+			version: Version::HTTP_2,
+			headers: vec![
+				UnpackedResponseHeader { name: s!("Foo"), value: s!("Bar") },
+				UnpackedResponseHeader { name: s!("Bar"), value: s!("Baz") },
+			],
+			body:    UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+		});
+	}
+	
+	//		query																
+	#[test]
+	fn query() {
+		let request1 = UnpackedRequest::new(Method::GET, Uri::from_static("/foo?b=2&a=1&b=x%20y"), vec![], "");
+		let request2 = UnpackedRequest::new(Method::GET, Uri::from_static("/foo"),                    vec![], "");
+		assert_eq!(request1.query(), vec![(s!("b"), s!("2")), (s!("a"), s!("1")), (s!("b"), s!("x y"))]);
+		assert_eq!(request2.query(), vec![]);
+	}
+	
+	//		to_query															
+	#[test]
+	fn to_query__valid() {
+		#[derive(Debug, Deserialize, PartialEq)]
+		struct Query {
+			page: u32,
+			sort: Option<String>,
+		}
+		let request = UnpackedRequest::new(Method::GET, Uri::from_static("/foo?page=2"), vec![], "");
+		assert_ok_eq!(request.to_query::<Query>(), Query { page: 2, sort: None });
+	}
+	#[test]
+	fn to_query__invalid() {
+		let request = UnpackedRequest::new(Method::GET, Uri::from_static("/foo?page=two"), vec![], "");
+		assert_err!(request.to_query::<BTreeMap<String, u32>>());
+	}
+	
+	//		from_har															
+	#[test]
+	fn from_har__basic() {
+		let request = UnpackedRequest::from_har(&json!({
+			"method":      "POST",
+			"url":         "https://example.com/login?next=%2F",
+			"httpVersion": "HTTP/1.1",
+			"headers":     [
+				{ "name": ":authority",   "value": "example.com" },
+				{ "name": "Content-Type", "value": "application/json" },
+				{ "name": "X-Foo",        "value": "Bar" },
+			],
+			"postData":    { "mimeType": "application/json", "text": r#"{"user":"alice"}"# },
+		})).unwrap();
+		assert_eq!(request, UnpackedRequest::new(
+			Method::POST,
+			Uri::from_static("https://example.com/login?next=%2F"),
+			vec![(s!("content-type"), s!("application/json")), (s!("x-foo"), s!("Bar"))],
+			r#"{"user":"alice"}"#,
+		));
+		assert!(request.body.is_json());
+	}
+	#[test]
+	fn from_har__params() {
+		let request = UnpackedRequest::from_har(&json!({
+			"method":   "POST",
+			"url":      "http://example.com/",
+			"postData": {
+				"mimeType": "application/x-www-form-urlencoded",
+				"params":   [{ "name": "name", "value": "Alice Smith" }, { "name": "empty" }],
+			},
+		})).unwrap();
+		assert_eq!(request.body, UnpackedResponseBody::new("name=Alice+Smith&empty="));
+		assert_eq!(request.version, Version::HTTP_11);
+	}
+	#[test]
+	fn from_har__base64() {
+		let request = UnpackedRequest::from_har(&json!({
+			"method":   "PUT",
+			"url":      "http://example.com/upload",
+			"postData": { "mimeType": "image/png", "text": "iVBORw==", "encoding": "base64" },
+		})).unwrap();
+		assert_eq!(request.body.as_bytes(), [0x89, 0x50, 0x4E, 0x47]);
+		assert!(request.body.is_binary());
+	}
+	#[test]
+	fn from_har__invalid() {
+		let err1 = UnpackedRequest::from_har(&json!({ "method": "GET" })).unwrap_err();
+		let err2 = UnpackedRequest::from_har(&json!({ "method": "GET", "url": "http://example.com/", "httpVersion": "spdy" })).unwrap_err();
+		let err3 = UnpackedRequest::from_har(&json!({ "method": "GET", "url": "http://example.com/", "headers": {} })).unwrap_err();
+		let err4 = UnpackedRequest::from_har(&json!({ "method": "GET", "url": "http://example.com/", "postData": { "text": "!", "encoding": "base64" } })).unwrap_err();
+		assert!(matches!(err1, HarError::InvalidField(ref field) if field == "url"));
+		assert!(matches!(err2, HarError::InvalidField(ref field) if field == "httpVersion"));
+		assert!(matches!(err3, HarError::InvalidField(ref field) if field == "headers"));
+		assert!(matches!(err4, HarError::Base64(_)));
+	}
+	
+	//		to_har																
+	#[test]
+	fn to_har__basic() {
+		let request = UnpackedRequest::new(
+			Method::POST,
+			Uri::from_static("/search?q=a+b&page=2"),
+			vec![(s!("host"), s!("example.com")), (s!("cookie"), s!("a=1; b=2")), (s!("content-type"), s!("text/plain"))],
+			"foo",
+		);
+		assert_json_eq!(request.to_har(), json!({
+			"method":      "POST",
+			"url":         "http://example.com/search?q=a+b&page=2",
+			"httpVersion": "HTTP/1.1",
+			"cookies":     [{ "name": "a", "value": "1" }, { "name": "b", "value": "2" }],
+			"headers":     [
+				{ "name": "host",         "value": "example.com" },
+				{ "name": "cookie",       "value": "a=1; b=2" },
+				{ "name": "content-type", "value": "text/plain" },
+			],
+			"queryString": [{ "name": "q", "value": "a b" }, { "name": "page", "value": "2" }],
+			"headersSize": -1,
+			"bodySize":    3,
+			"postData":    { "mimeType": "text/plain", "text": "foo" },
+		}));
+	}
+	#[test]
+	fn to_har__no_body() {
+		let request = UnpackedRequest::new(Method::GET, Uri::from_static("https://example.com/"), vec![(s!("host"), s!("other.com"))], "");
+		let har     = request.to_har();
+		assert_eq!(har["url"], json!("https://example.com/"));
+		assert!(har.get("postData").is_none());
+	}
+	#[test]
+	fn to_har__round_trip() {
+		let request = UnpackedRequest::new(Method::PATCH, Uri::from_static("http://example.com/"), vec![(s!("x-foo"), s!("1"))], vec![0x00, 0xFF]);
+		assert_ok_eq!(UnpackedRequest::from_har(&request.to_har()), request);
+	}
+}
+
+#[cfg(test)]
+mod unpacked_request__traits {
+	use super::*;
+	
+	//		debug																
+	#[test]
+	fn debug() {
+		let request      = UnpackedRequest {
+			method:        Method::GET,
+			uri:           Uri::from_static("/foo"),
+			version:       Version::HTTP_11,
+			headers:       vec![
+				UnpackedResponseHeader {
+					name:  s!("foo"),
+					value: s!("bar"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+		};
+		assert_eq!(format!("{request:?}"), r#"UnpackedRequest { method: GET, uri: /foo, version: HTTP/1.1, headers: [UnpackedResponseHeader { name: "foo", value: "bar" }], body: UnpackedResponseBody { body: "This is a test", content_type: Text } }"#);
+	}
+	
+	//		partial_eq															
+	#[test]
+	fn partial_eq() {
+		let request = UnpackedRequest::new(Method::GET, Uri::from_static("/foo"), vec![(s!("foo"), s!("bar"))], "This is a test");
+		assert_eq!(request, UnpackedRequest::new(Method::GET,  Uri::from_static("/foo"), vec![(s!("foo"), s!("bar"))], "This is a test"));
+		assert_ne!(request, UnpackedRequest::new(Method::POST, Uri::from_static("/foo"), vec![(s!("foo"), s!("bar"))], "This is a test"));
+		assert_ne!(request, UnpackedRequest::new(Method::GET,  Uri::from_static("/bar"), vec![(s!("foo"), s!("bar"))], "This is a test"));
+		assert_ne!(request, UnpackedRequest::new(Method::GET,  Uri::from_static("/foo"), vec![(s!("foo"), s!("baz"))], "This is a test"));
+		assert_ne!(request, UnpackedRequest::new(Method::GET,  Uri::from_static("/foo"), vec![(s!("foo"), s!("bar"))], "This is different"));
+		assert_ne!(request, UnpackedRequest::new_from_parts(
+			Method::GET,
+			Uri::from_static("/foo"),
+			Version::HTTP_2,
+			vec![UnpackedResponseHeader::new(s!("foo"), s!("bar"))],
+			UnpackedResponseBody::new("This is a test"),
+		));
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		let request        = UnpackedRequest {
+			method:          Method::POST,
+			uri:             Uri::from_static("https://example.com/foo?bar=baz"),
+			version:         Version::HTTP_11,
+			headers:         vec![
+				UnpackedResponseHeader {
+					name:    s!("foo"),
+					value:   s!("bar"),
+				},
+			],
+			body:            UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+		};
+		let json           = json!({
+			"method":        "POST",
+			"uri":           "https://example.com/foo?bar=baz",
+			"version":       "HTTP/1.1",
+			"headers":       [
+				{
+					"name":  "foo",
+					"value": "bar",
+				},
+			],
+			"body":          "This is a test",
+		});
+		assert_json_eq!(json!(request), json);
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let request        = UnpackedRequest {
+			method:          Method::POST,
+			uri:             Uri::from_static("https://example.com/foo?bar=baz"),
+			version:         Version::HTTP_11,
+			headers:         vec![
+				UnpackedResponseHeader {
+					name:    s!("foo"),
+					value:   s!("bar"),
+				},
+			],
+			body:            UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+		};
+		let json           = json!({
+			"method":        "POST",
+			"uri":           "https://example.com/foo?bar=baz",
+			"version":       "HTTP/1.1",
+			"headers":       [
+				{
+					"name":  "foo",
+					"value": "bar",
+				},
+			],
+			"body":          "This is a test",
+		}).to_string();
+		assert_ok_eq!(serde_json::from_str::<UnpackedRequest>(&json), request);
+	}
+}
+
+//		UnpackedResponse														
+#[cfg(test)]
+mod unpacked_response__struct {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let headers  = vec![
+			(s!("Foo"), s!("Bar")),
+			(s!("Bar"), s!("Baz")),
+		];
+		let body     = "This is a test";
+		let unpacked = UnpackedResponse::new(StatusCode::OK, headers, body);
+		assert_eq!(unpacked, UnpackedResponse {
+			status:      StatusCode::OK,
+			headers:     vec![
+				UnpackedResponseHeader { name: s!("Foo"), value: s!("Bar") },
+				UnpackedResponseHeader { name: s!("Bar"), value: s!("Baz") },
+			],
+			body:        UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:      vec![],
+			raw_headers: vec![],
+		});
+	}
+	
+	//		new_from_parts														
+	#[test]
+	fn new_from_parts() {
+		let headers  = vec![
+			UnpackedResponseHeader::new(s!("Foo"), s!("Bar")),
+			UnpackedResponseHeader::new(s!("Bar"), s!("Baz")),
+		];
+		let body     = UnpackedResponseBody::new("This is a test");
+		let unpacked = UnpackedResponse::new_from_parts(StatusCode::OK, headers, body);
+		assert_eq!(unpacked, UnpackedResponse {
+			status:      StatusCode::OK,
+			headers:     vec![
+				UnpackedResponseHeader { name: s!("Foo"), value: s!("Bar") },
+				UnpackedResponseHeader { name: s!("Bar"), value: s!("Baz") },
+			],
+			body:        UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:      vec![],
+			raw_headers: vec![],
+		});
+	}
+	
+	//		diff																
+	#[test]
+	fn diff__identical() {
+		let left  = UnpackedResponse::new(StatusCode::OK, vec![(s!("Foo"), s!("Bar"))], "This is a test");
+		let right = UnpackedResponse::new(StatusCode::OK, vec![(s!("Foo"), s!("Bar"))], "This is a test");
+		let diff  = left.diff(&right);
+		assert!(diff.is_empty());
+		assert_eq!(diff, UnpackedResponseDiff::default());
+	}
+	#[test]
+	fn diff__status() {
+		let left  = UnpackedResponse::new(StatusCode::OK,        vec![], "This is a test");
+		let right = UnpackedResponse::new(StatusCode::NOT_FOUND, vec![], "This is a test");
+		assert_eq!(left.diff(&right), UnpackedResponseDiff {
+			status:  Some((StatusCode::OK, StatusCode::NOT_FOUND)),
+			headers: vec![],
+			body:    None,
+		});
+	}
+	#[test]
+	fn diff__headers() {
+		let left  = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("Foo"), s!("Bar")),
+			(s!("Bar"), s!("Baz")),
+			(s!("Baz"), s!("Qux")),
+		], "");
+		let right = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("Bar"), s!("Baz")),
+			(s!("Baz"), s!("Quux")),
+			(s!("Qux"), s!("Foo")),
+		], "");
+		assert_eq!(left.diff(&right), UnpackedResponseDiff {
+			status:  None,
+			headers: vec![
+				HeaderDiff::Changed {
+					name:  s!("Baz"),
+					left:  vec![s!("Qux")],
+					right: vec![s!("Quux")],
+				},
+				HeaderDiff::Removed(UnpackedResponseHeader::new(s!("Foo"), s!("Bar"))),
+				HeaderDiff::Added(UnpackedResponseHeader::new(s!("Qux"), s!("Foo"))),
+			],
+			body:    None,
+		});
+	}
+	#[test]
+	fn diff__body() {
+		let left  = UnpackedResponse::new(StatusCode::OK, vec![], "Foo");
+		let right = UnpackedResponse::new(StatusCode::OK, vec![], "Bar");
+		assert_eq!(left.diff(&right), UnpackedResponseDiff {
+			status:  None,
+			headers: vec![],
+			body:    Some(BodyDiff::Text(vec![
+				TextLineDiff::Removed { line: 1, text: s!("Foo") },
+				TextLineDiff::Added   { line: 1, text: s!("Bar") },
+			])),
+		});
+	}
+	
+	//		header																
+	#[test]
+	fn header() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("set-cookie"),   s!("foo=1")),
+			(s!("Set-Cookie"),   s!("bar=2")),
+			(s!("content-type"), s!("text/plain")),
+		], "");
+		assert_eq!(response.header("SET-COOKIE"),   Some("foo=1"));
+		assert_eq!(response.header("content-type"), Some("text/plain"));
+		assert_eq!(response.header("x-missing"),    None);
+	}
+	
+	//		headers_all															
+	#[test]
+	fn headers_all() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("set-cookie"),   s!("foo=1")),
+			(s!("content-type"), s!("text/plain")),
+			(s!("Set-Cookie"),   s!("bar=2")),
+		], "");
+		assert_eq!(response.headers_all("set-cookie"), vec!["foo=1", "bar=2"]);
+		assert!(response.headers_all("x-missing").is_empty());
+	}
+	
+	//		cookies																
+	#[test]
+	fn cookies() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("set-cookie"),   s!("foo=1; Path=/")),
+			(s!("content-type"), s!("text/plain")),
+			(s!("Set-Cookie"),   s!("invalid")),
+			(s!("set-cookie"),   s!("bar=2; HttpOnly")),
+		], "");
+		assert_eq!(response.cookies(), vec![
+			UnpackedCookie::new("foo", "1").with_path("/"),
+			UnpackedCookie::new("bar", "2").with_http_only(true),
+		]);
+	}
+	
+	//		cookie																
+	#[test]
+	fn cookie() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("set-cookie"), s!("foo=1")),
+			(s!("set-cookie"), s!("bar=2")),
+			(s!("set-cookie"), s!("foo=3")),
+		], "");
+		assert_eq!(response.cookie("foo"), Some(UnpackedCookie::new("foo", "3")));
+		assert_eq!(response.cookie("bar"), Some(UnpackedCookie::new("bar", "2")));
+		assert_eq!(response.cookie("Foo"), None);
+	}
+	
+	//		trailers															
+	#[test]
+	fn trailers() {
+		let mut response = UnpackedResponse::new(StatusCode::OK, vec![], "foobar");
+		assert!(response.trailers().is_empty());
+		response.frames  = vec![
+			UnpackedFrame::Data(UnpackedResponseBody::new("foo")),
+			UnpackedFrame::Trailers(vec![UnpackedResponseHeader::new(s!("x-foo"), s!("1"))]),
+			UnpackedFrame::Data(UnpackedResponseBody::new("bar")),
+			UnpackedFrame::Trailers(vec![UnpackedResponseHeader::new(s!("x-bar"), s!("2"))]),
+		];
+		assert_eq!(response.trailers(), vec![
+			&UnpackedResponseHeader::new(s!("x-foo"), s!("1")),
+			&UnpackedResponseHeader::new(s!("x-bar"), s!("2")),
+		]);
+	}
+	
+	//		multipart															
+	#[test]
+	fn multipart__form_data() {
+		let response = UnpackedResponse::new(
+			StatusCode::OK,
+			vec![(s!("content-type"), s!("multipart/form-data; boundary=\"abc\""))],
+			"preamble\r\n--abc\r\ncontent-disposition: form-data; name=\"name\"\r\n\r\nAlice\r\n--abc\r\ncontent-disposition: form-data; name=\"data\"; filename=\"data.json\"\r\ncontent-type: application/json\r\n\r\n{\"id\": 1}\r\n--abc--\r\nepilogue",
+		);
+		let parts    = response.multipart().unwrap();
+		assert_eq!(parts.len(),      2);
+		assert_eq!(parts[0].name(),  Some(s!("name")));
+		assert_eq!(parts[1].name(),  Some(s!("data")));
+		assert!(parts[1].body.is_json());
+	}
+	#[test]
+	fn multipart__not_multipart() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("content-type"), s!("text/plain; boundary=abc"))], "");
+		assert!(matches!(response.multipart(), Err(MultipartError::MissingBoundary)));
+	}
+	#[test]
+	fn multipart__no_boundary() {
+		let response1 = UnpackedResponse::new(StatusCode::OK, vec![(s!("content-type"), s!("multipart/mixed"))], "");
+		let response2 = UnpackedResponse::new(StatusCode::OK, vec![], "");
+		assert!(matches!(response1.multipart(), Err(MultipartError::MissingBoundary)));
+		assert!(matches!(response2.multipart(), Err(MultipartError::MissingBoundary)));
+	}
+	
+	//		text																
+	#[test]
+	fn text__default_utf8() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("content-type"), s!("text/plain"))], "Café");
+		assert_ok_eq!(response.text(), s!("Café"));
+		assert_ok_eq!(UnpackedResponse::new(StatusCode::OK, vec![], "Café").text(), s!("Café"));
+	}
+	#[test]
+	fn text__latin1() {
+		let response = UnpackedResponse {
+			body: UnpackedResponseBody { body: b"Caf\xE9".to_vec(), content_type: ContentType::Binary },
+			..UnpackedResponse::new(StatusCode::OK, vec![(s!("content-type"), s!("text/plain; charset=ISO-8859-1"))], "")
+		};
+		assert_ok_eq!(response.text(), s!("Café"));
+	}
+	#[test]
+	fn text__utf16() {
+		let response = UnpackedResponse {
+			body: UnpackedResponseBody { body: b"\xFF\xFEC\0a\0f\0\xE9\0".to_vec(), content_type: ContentType::Binary },
+			..UnpackedResponse::new(StatusCode::OK, vec![(s!("content-type"), s!(r#"text/plain; charset="utf-16""#))], "")
+		};
+		assert_ok_eq!(response.text(), s!("Café"));
+	}
+	#[test]
+	fn text__invalid() {
+		let response = UnpackedResponse {
+			body: UnpackedResponseBody { body: b"Caf\xE9".to_vec(), content_type: ContentType::Binary },
+			..UnpackedResponse::new(StatusCode::OK, vec![(s!("content-type"), s!("text/plain; charset=utf-8"))], "")
+		};
+		assert!(matches!(response.text(), Err(CharsetError::Invalid(ref name)) if name == "utf-8"));
+	}
+	#[test]
+	fn text__unsupported() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("content-type"), s!("text/plain; charset=koi8-r"))], "");
+		assert!(matches!(response.text(), Err(CharsetError::Unsupported(ref name)) if name == "koi8-r"));
+	}
+	
+	//		from_http1															
+	#[test]
+	fn from_http1__basic() {
+		let unpacked = UnpackedResponse::from_http1(b"HTTP/1.1 404 Not Found\r\nX-Foo: 1\r\ncontent-type: application/json\r\n\r\n{\"error\":true}").unwrap();
+		assert_eq!(unpacked.status,  StatusCode::NOT_FOUND);
+		assert_eq!(unpacked.headers, vec![
+			UnpackedResponseHeader::new(s!("content-type"), s!("application/json")),
+			UnpackedResponseHeader::new(s!("x-foo"),        s!("1")),
+		]);
+		assert_eq!(unpacked.body,    UnpackedResponseBody::new(r#"{"error": true}"#));
+		assert!(unpacked.body.is_json());
+	}
+	#[test]
+	fn from_http1__matches_unpack() {
+		let mut response = Response::builder()
+			.status(StatusCode::CREATED)
+			.header("content-type", "text/plain")
+			.header("set-cookie",   "b=2")
+			.header("set-cookie",   "a=1")
+			.body(Full::new(Bytes::from("This is a test")))
+			.unwrap()
+		;
+		let text         = "HTTP/1.1 201 Created\nContent-Type: text/plain\nSet-Cookie: b=2\nSet-Cookie: a=1\n\nThis is a test";
+		assert_eq!(UnpackedResponse::from_http1(text.as_bytes()).unwrap(), response.unpack().unwrap());
+	}
+	#[test]
+	fn from_http1__curl_output() {
+		let text     = "\nHTTP/1.1 100 Continue\r\n\r\nHTTP/2 200 \r\ndate: Thu, 01 Jan 1970 00:00:00 GMT\r\n\r\nfoo";
+		let unpacked = UnpackedResponse::from_http1(text.as_bytes()).unwrap();
+		assert_eq!(unpacked.status, StatusCode::OK);
+		assert_eq!(unpacked.header("date"), Some("Thu, 01 Jan 1970 00:00:00 GMT"));
+		assert_eq!(unpacked.body, UnpackedResponseBody::new("foo"));
+	}
+	#[test]
+	fn from_http1__many_interim_responses() {
+		let text     = format!("{}HTTP/1.1 200 OK\r\n\r\nfoo", "HTTP/1.1 100 Continue\r\n\r\n".repeat(100_000));
+		let unpacked = UnpackedResponse::from_http1(text.as_bytes()).unwrap();
+		assert_eq!(unpacked.status, StatusCode::OK);
+		assert_eq!(unpacked.body,   UnpackedResponseBody::new("foo"));
+	}
+	#[test]
+	fn from_http1__chunked() {
+		let text     = "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nThis\r\na;ext=1\r\n is a test\r\n0\r\n\r\n";
+		let unpacked = UnpackedResponse::from_http1(text.as_bytes()).unwrap();
+		assert_eq!(unpacked.body, UnpackedResponseBody::new("This is a test"));
+		assert_eq!(unpacked.header("transfer-encoding"), None);
+		assert_eq!(unpacked.header("content-length"),    Some("14"));
+	}
+	#[test]
+	fn from_http1__no_body() {
+		let unpacked = UnpackedResponse::from_http1(b"HTTP/1.1 204 No Content\r\nX-Foo: 1").unwrap();
+		assert_eq!(unpacked, UnpackedResponse::new(StatusCode::NO_CONTENT, vec![(s!("x-foo"), s!("1"))], ""));
+	}
+	#[test]
+	fn from_http1__invalid() {
+		let err1 = UnpackedResponse::from_http1(b"HTTP/1.1 OK\r\n\r\n").unwrap_err();
+		let err2 = UnpackedResponse::from_http1(b"200 OK\r\n\r\n").unwrap_err();
+		let err3 = UnpackedResponse::from_http1(b"HTTP/1.1 200 OK\r\nfoo\r\n\r\n").unwrap_err();
+		let err4 = UnpackedResponse::from_http1(b"HTTP/1.1 200 OK\r\nfoo bar: baz\r\n\r\n").unwrap_err();
+		assert!(matches!(err1, Http1Error::InvalidStatusLine(ref line) if line == "HTTP/1.1 OK"));
+		assert!(matches!(err2, Http1Error::InvalidStatusLine(ref line) if line == "200 OK"));
+		assert!(matches!(err3, Http1Error::InvalidHeader(ref line)     if line == "foo"));
+		assert!(matches!(err4, Http1Error::InvalidHeader(ref line)     if line == "foo bar"));
+	}
+	
+	//		from_http1_with														
+	#[test]
+	fn from_http1_with__limit() {
+		let err = UnpackedResponse::from_http1_with(b"HTTP/1.1 200 OK\r\n\r\nThis is a test", &UnpackOptions::new().with_limit(5)).unwrap_err();
+		assert!(matches!(err, Http1Error::Response(ResponseError::LimitExceeded(5))));
+	}
+	#[test]
+	fn from_http1_with__preserved_header_order() {
+		let options  = UnpackOptions::new().with_preserved_header_order(true);
+		let unpacked = UnpackedResponse::from_http1_with(b"HTTP/1.1 200 OK\r\nx-b: 1\r\nx-a: 2\r\n\r\n", &options).unwrap();
+		assert_eq!(unpacked.headers, vec![
+			UnpackedResponseHeader::new(s!("x-b"), s!("1")),
+			UnpackedResponseHeader::new(s!("x-a"), s!("2")),
+		]);
+	}
+	
+	//		to_http1															
+	#[test]
+	fn to_http1__basic() {
+		let response = UnpackedResponse::new(
+			StatusCode::NOT_FOUND,
+			vec![(s!("content-type"), s!("text/plain")), (s!("x-foo"), s!("1"))],
+			"Not here",
+		);
+		assert_eq!(response.to_http1(), b"HTTP/1.1 404 Not Found\r\ncontent-type: text/plain\r\nx-foo: 1\r\n\r\nNot here".to_vec());
+	}
+	#[test]
+	fn to_http1__unknown_status() {
+		let response = UnpackedResponse::new(StatusCode::from_u16(599).unwrap(), vec![], "");
+		assert_eq!(response.to_http1(), b"HTTP/1.1 599 \r\n\r\n".to_vec());
+	}
+	#[test]
+	fn to_http1__round_trip() {
+		let response = UnpackedResponse::new(
+			StatusCode::OK,
+			vec![(s!("content-type"), s!("image/png"))],
+			vec![0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x0A, 0xFF],
+		);
+		let parsed   = UnpackedResponse::from_http1(&response.to_http1()).unwrap();
+		assert_eq!(parsed, response);
+		assert!(parsed.body.is_binary());
+	}
+	#[test]
+	fn to_http1__round_trip_chunked() {
+		let text     = "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nThis\r\na\r\n is a test\r\n0\r\n\r\n";
+		let unpacked = UnpackedResponse::from_http1(text.as_bytes()).unwrap();
+		assert_eq!(unpacked.to_http1(), b"HTTP/1.1 200 OK\r\ncontent-length: 14\r\n\r\nThis is a test".to_vec());
+		let parsed   = UnpackedResponse::from_http1(&unpacked.to_http1()).unwrap();
+		assert_eq!(parsed, unpacked);
+		let response = Response::<Full<Bytes>>::from(unpacked);
+		assert!(response.headers().get("transfer-encoding").is_none());
+	}
+	#[test]
+	fn to_http1__raw_headers() {
+		let mut response     = UnpackedResponse::new(StatusCode::OK, vec![(s!("x-foo"), s!("a\u{FFFD}"))], "");
+		response.raw_headers = vec![UnpackedRawHeader::new(s!("x-foo"), vec![b'a', 0xFF])];
+		assert_eq!(response.to_http1(), b"HTTP/1.1 200 OK\r\nx-foo: a\xFF\r\n\r\n".to_vec());
+	}
+	
+	//		from_har															
+	#[test]
+	fn from_har__basic() {
+		let response = UnpackedResponse::from_har(&json!({
+			"status":     200,
+			"statusText": "OK",
+			"headers":    [{ "name": "Content-Type", "value": "application/json" }, { "name": ":status", "value": "200" }],
+			"content":    { "size": 13, "mimeType": "application/json", "text": r#"{"id": 1}"# },
+		})).unwrap();
+		assert_eq!(response, UnpackedResponse::new(
+			StatusCode::OK,
+			vec![(s!("content-type"), s!("application/json"))],
+			UnpackedResponseBody::from_json(&json!({"id": 1})).unwrap(),
+		));
+		assert!(response.body.is_json());
+	}
+	#[test]
+	fn from_har__mime_type_fallback() {
+		let response = UnpackedResponse::from_har(&json!({
+			"status":  200,
+			"content": { "mimeType": "image/png", "text": "iVBORw==", "encoding": "base64" },
+		})).unwrap();
+		assert!(response.headers.is_empty());
+		assert!(response.body.is_binary());
+		assert_eq!(response.body.as_bytes(), [0x89, 0x50, 0x4E, 0x47]);
+	}
+	#[test]
+	fn from_har__invalid() {
+		let err1 = UnpackedResponse::from_har(&json!({})).unwrap_err();
+		let err2 = UnpackedResponse::from_har(&json!({ "status": 1000 })).unwrap_err();
+		let err3 = UnpackedResponse::from_har(&json!({ "status": 200, "headers": [{ "name": "x foo", "value": "1" }] })).unwrap_err();
+		let err4 = UnpackedResponse::from_har(&json!({ "status": 200, "content": { "text": 1 } })).unwrap_err();
+		assert!(matches!(err1, HarError::InvalidField(ref field) if field == "status"));
+		assert!(matches!(err2, HarError::InvalidField(ref field) if field == "status"));
+		assert!(matches!(err3, HarError::InvalidField(ref field) if field == "headers.x foo"));
+		assert!(matches!(err4, HarError::InvalidField(ref field) if field == "text"));
+	}
+	
+	//		to_har																
+	#[test]
+	fn to_har__basic() {
+		let response = UnpackedResponse::new(
+			StatusCode::FOUND,
+			vec![
+				(s!("location"),   s!("/home")),
+				(s!("set-cookie"), s!("id=1; Path=/; HttpOnly")),
+			],
+			"",
+		);
+		assert_json_eq!(response.to_har(), json!({
+			"status":      302,
+			"statusText":  "Found",
+			"httpVersion": "HTTP/1.1",
+			"cookies":     [{ "name": "id", "value": "1", "path": "/", "httpOnly": true, "secure": false }],
+			"headers":     [
+				{ "name": "location",   "value": "/home" },
+				{ "name": "set-cookie", "value": "id=1; Path=/; HttpOnly" },
+			],
+			"content":     { "size": 0, "mimeType": "", "text": "" },
+			"redirectURL": "/home",
+			"headersSize": -1,
+			"bodySize":    0,
+		}));
+	}
+	#[test]
+	fn to_har__binary() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("content-type"), s!("image/png"))], vec![0x89, 0x50, 0x4E, 0x47]);
+		assert_json_eq!(response.to_har()["content"], json!({
+			"size":     4,
+			"mimeType": "image/png",
+			"text":     "iVBORw==",
+			"encoding": "base64",
+		}));
+	}
+	#[test]
+	fn to_har__round_trip() {
+		let response = UnpackedResponse::new(
+			StatusCode::OK,
+			vec![(s!("content-type"), s!("text/html")), (s!("x-foo"), s!("1"))],
+			"<p>This is a test</p>",
+		);
+		assert_ok_eq!(UnpackedResponse::from_har(&response.to_har()), response);
+	}
+}
+
+#[cfg(test)]
+mod unpacked_response__traits {
+	use super::*;
+	
+	//		debug																
+	#[test]
+	fn debug() {
+		let response     = UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![
+				UnpackedResponseHeader {
+					name:  s!("foo"),
+					value: s!("bar"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		};
+		assert_eq!(format!("{response:?}"), r#"UnpackedResponse { status: 200, headers: [UnpackedResponseHeader { name: "foo", value: "bar" }], body: UnpackedResponseBody { body: "This is a test", content_type: Text }, frames: [], raw_headers: [] }"#);
+	}
+	
+	//		from																
+	#[test]
+	fn from__response_full_bytes() {
+		let unpacked     = UnpackedResponse::new(StatusCode::NOT_FOUND, vec![
+			(s!("foo"),          s!("bar")),
+			(s!("foo"),          s!("baz")),
+			(s!("invalid name"), s!("value")),
+		], vec![0xFF, 0xFE]);
+		let mut response = Response::<Full<Bytes>>::from(unpacked);
+		assert_eq!(response.status(), StatusCode::NOT_FOUND);
+		assert_eq!(response.headers().get_all("foo").iter().collect::<Vec<_>>(), vec!["bar", "baz"]);
+		assert_eq!(response.headers().len(), 2);
+		let repacked     = response.unpack().unwrap();
+		assert_eq!(repacked.body.as_bytes(), &[0xFF, 0xFE]);
+	}
+	#[test]
+	fn from__response_raw_headers() {
+		let mut unpacked     = UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("a\u{FFFD}"))], "");
+		unpacked.raw_headers = vec![UnpackedRawHeader::new(s!("foo"), vec![b'a', 0xFF])];
+		let response         = Response::<Full<Bytes>>::from(unpacked);
+		assert_eq!(response.headers()["foo"].as_bytes(), &[b'a', 0xFF]);
+	}
+	#[test]
+	fn from__response_string() {
+		let unpacked1 = UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("bar"))], "This is a test");
+		let response1 = Response::<String>::from(unpacked1);
+		assert_eq!(response1.status(),         StatusCode::OK);
+		assert_eq!(response1.headers()["foo"], "bar");
+		assert_eq!(response1.body(),           "This is a test");
+		
+		let unpacked2 = UnpackedResponse::new(StatusCode::OK, vec![], vec![b'a', 0xFF]);
+		let response2 = Response::<String>::from(unpacked2);
+		assert_eq!(response2.body(),           "a\u{FFFD}");
+	}
+	#[test]
+	fn from__round_trip() {
+		let json         = r#"{"status":201,"headers":[{"name":"content-type","value":"application/json"}],"body":"{\"foo\":\"bar\"}"}"#;
+		let unpacked     = serde_json::from_str::<UnpackedResponse>(json).unwrap();
+		let mut response = Response::<Full<Bytes>>::from(unpacked);
+		let repacked     = response.unpack().unwrap();
+		assert_eq!(repacked.status,              StatusCode::CREATED);
+		assert_eq!(repacked.body.content_type(), ContentType::Json);
+		assert_eq!(serde_json::to_string(&repacked).unwrap(), json);
+	}
+	
+	//		into_response														
+	#[test]
+	fn into_response() {
+		let unpacked     = UnpackedResponse::new(StatusCode::ACCEPTED, vec![(s!("foo"), s!("bar"))], "This is a test");
+		let mut response = unpacked.into_response();
+		assert_eq!(response.status(),         StatusCode::ACCEPTED);
+		assert_eq!(response.headers()["foo"], "bar");
+		let repacked     = response.unpack();
+		assert_ok_eq!(repacked, UnpackedResponse::new(StatusCode::ACCEPTED, vec![(s!("foo"), s!("bar"))], "This is a test"));
+	}
+	
+	//		partial_eq															
+	#[test]
+	fn partial_eq() {
+		let response     = UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![
+				UnpackedResponseHeader {
+					name:  s!("foo"),
+					value: s!("bar"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		};
+		assert_ne!(response, UnpackedResponse {
+			status:        StatusCode::NOT_FOUND,
+			headers:       vec![
+				UnpackedResponseHeader {
+					name:  s!("foo"),
+					value: s!("bar"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		});
+		assert_eq!(response, UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![
+				UnpackedResponseHeader {
+					name:  s!("foo"),
+					value: s!("bar"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		});
+		assert_ne!(response, UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![
+				UnpackedResponseHeader {
+					name:  s!("foo"),
+					value: s!("baz"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		});
+		assert_ne!(response, UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![
+				UnpackedResponseHeader {
+					name:  s!("baz"),
+					value: s!("bar"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		});
+		assert_ne!(response, UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![
+				UnpackedResponseHeader {
+					name:  s!("foo"),
+					value: s!("bar"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"This is different".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		});
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		let response       = UnpackedResponse {
+			status:          StatusCode::OK,
+			headers:         vec![
+				UnpackedResponseHeader {
+					name:    s!("foo"),
+					value:   s!("bar"),
+				},
+			],
+			body:            UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:          vec![],
+			raw_headers:     vec![],
+		};
+		let json           = json!({
+			"status":        200,
+			"headers":       [
+				{
+					"name":  "foo",
+					"value": "bar",
+				},
+			],
+			"body":          "This is a test",
+		});
+		assert_json_eq!(json!(response), json);
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let response       = UnpackedResponse {
+			status:          StatusCode::OK,
+			headers:         vec![
+				UnpackedResponseHeader {
+					name:    s!("foo"),
+					value:   s!("bar"),
+				},
+			],
+			body:            UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:          vec![],
+			raw_headers:     vec![],
+		};
+		let json           = json!({
+			"status":        200,
+			"headers":       [
+				{
+					"name":  "foo",
+					"value": "bar",
+				},
+			],
+			"body":          "This is a test",
+		}).to_string();
+		assert_ok_eq!(serde_json::from_str::<UnpackedResponse>(&json), response);
+	}
+}
+
+//		UnpackedResponseHeader													
+#[cfg(test)]
+mod unpacked_response_header__struct {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let header = UnpackedResponseHeader::new(s!("Foo"), s!("Bar"));
+		assert_eq!(header, UnpackedResponseHeader { name: s!("Foo"), value: s!("Bar") });
+	}
+}
+
+#[cfg(test)]
+mod unpacked_response_header__traits {
+	use super::*;
+	
+	//		partial_eq															
+	#[test]
+	fn partial_eq() {
+		let header = UnpackedResponseHeader {
+			name:    s!("foo"),
+			value:   s!("bar"),
+		};
+		assert_eq!(header, UnpackedResponseHeader {
+			name:    s!("foo"),
+			value:   s!("bar"),
+		});
+		assert_ne!(header, UnpackedResponseHeader {
+			name:    s!("foo"),
+			value:   s!("baz"),
+		});
+		assert_ne!(header, UnpackedResponseHeader {
+			name:    s!("baz"),
+			value:   s!("bar"),
+		});
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		let headers    = vec![
+			UnpackedResponseHeader {
+				name:    s!("foo"),
+				value:   s!("bar"),
+			},
+		];
+		let json       = json!([
+			{
+				"name":  "foo",
+				"value": "bar",
+			},
+		]);
+		assert_json_eq!(json!(headers), json);
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let headers    = vec![
+			UnpackedResponseHeader {
+				name:    s!("foo"),
+				value:   s!("bar"),
+			},
+		];
+		let json       = json!([
+			{
+				"name":  "foo",
+				"value": "bar",
+			},
+		]).to_string();
+		assert_ok_eq!(serde_json::from_str::<Vec<UnpackedResponseHeader>>(&json), headers);
+	}
+}
+
+//		UnpackedResponseBody													
+#[cfg(test)]
+mod unpacked_response_body__struct {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let body1 = UnpackedResponseBody::new(b"This is a test".to_vec());
+		assert_eq!(body1, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		
+		let body2 = UnpackedResponseBody::new("This is a test");
+		assert_eq!(body2, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	
+	//		content_type														
+	#[test]
+	fn content_type() {
+		let body1       = UnpackedResponseBody {
+			body:         b"This is a test".to_vec(),
+			content_type: ContentType::Text
+		};
+		assert_eq!(body1.content_type(), ContentType::Text);
+		
+		let body2       = UnpackedResponseBody {
+			body:         b"This is a test".to_vec(),
+			content_type: ContentType::Binary
+		};
+		assert_eq!(body2.content_type(), ContentType::Binary);
+	}
+	
+	//		set_content_type													
+	#[test]
+	fn set_content_type() {
+		let mut body = UnpackedResponseBody { body: b"".to_vec(), ..Default::default() };
+		assert_eq!(body.content_type(), ContentType::Text);
+		
+		_ = body.set_content_type(ContentType::Binary);
+		assert_eq!(body.content_type(), ContentType::Binary);
+		
+		_ = body.set_content_type(ContentType::Text);
+		assert_eq!(body.content_type(), ContentType::Text);
+		
+		let mut clone = body.clone();
+		assert_eq!(clone.set_content_type(ContentType::Text), &body);
+		
+		assert_eq!(body.set_content_type(ContentType::Binary).content_type(), ContentType::Binary);
+	}
+	
+	//		is_binary															
+	#[test]
+	fn is_binary() {
+		let mut body = UnpackedResponseBody { body: b"".to_vec(), ..Default::default() };
+		assert!(!body.is_binary());
+		
+		_ = body.set_content_type(ContentType::Binary);
+		assert!( body.is_binary());
+		
+		_ = body.set_content_type(ContentType::Text);
+		assert!(!body.is_binary());
+	}
+	
+	//		is_text																
+	#[test]
+	fn is_text() {
+		let mut body = UnpackedResponseBody { body: b"".to_vec(), ..Default::default() };
+		assert!( body.is_text());
+		
+		_ = body.set_content_type(ContentType::Binary);
+		assert!(!body.is_text());
+		
+		_ = body.set_content_type(ContentType::Text);
+		assert!( body.is_text());
+	}
+	
+	//		is_json																
+	#[test]
+	fn is_json() {
+		let mut body = UnpackedResponseBody { body: b"".to_vec(), ..Default::default() };
+		assert!(!body.is_json());
+		
+		_ = body.set_content_type(ContentType::Json);
+		assert!( body.is_json());
+		assert!(!body.is_text());
+		assert!(!body.is_binary());
+	}
+	
+	//		sniff_mime_type														
+	#[test]
+	fn sniff_mime_type__magic_bytes() {
+		let sniff = |data: &[u8]| UnpackedResponseBody { body: data.to_vec(), content_type: ContentType::Binary }.sniff_mime_type();
+		assert_eq!(sniff(b"\x89PNG\r\n\x1A\n\0\0\0\rIHDR"), Some("image/png"));
+		assert_eq!(sniff(b"\xFF\xD8\xFF\xE0\0\x10JFIF"),    Some("image/jpeg"));
+		assert_eq!(sniff(b"%PDF-1.7\n"),                    Some("application/pdf"));
+		assert_eq!(sniff(b"\x1F\x8B\x08\0"),                Some("application/gzip"));
+		assert_eq!(sniff(b"PK\x03\x04\x14\0"),              Some("application/zip"));
+		assert_eq!(sniff(b"PK\x05\x06"),                    Some("application/zip"));
+		assert_eq!(sniff(b"\x89PN"),                        None);
+		assert_eq!(sniff(b""),                              None);
+	}
+	#[test]
+	fn sniff_mime_type__text() {
+		assert_eq!(UnpackedResponseBody::new(r#"{"foo": "bar"}"#).sniff_mime_type(),              Some("application/json"));
+		assert_eq!(UnpackedResponseBody::new(" \n[1, 2, 3]").sniff_mime_type(),                   Some("application/json"));
+		assert_eq!(UnpackedResponseBody::new("\u{FEFF}{}").sniff_mime_type(),                     Some("application/json"));
+		assert_eq!(UnpackedResponseBody::new(r#"<?xml version="1.0"?><foo/>"#).sniff_mime_type(), Some("application/xml"));
+		assert_eq!(UnpackedResponseBody::new(r#"{"foo": "#).sniff_mime_type(),                    None);
+		assert_eq!(UnpackedResponseBody::new("42").sniff_mime_type(),                             None);
+		assert_eq!(UnpackedResponseBody::new("This is a test").sniff_mime_type(),                 None);
+		assert_eq!(UnpackedResponseBody::new("<html></html>").sniff_mime_type(),                  None);
+	}
+	
+	//		as_bytes															
+	#[test]
+	fn as_bytes() {
+		let body       = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		let byte_slice = body.as_bytes();
+		
+		//	Ensure the byte slice matches the original response body's bytes.
+		assert_eq!(byte_slice, b"This is a test".to_vec());
+		
+		//	We can't modify the byte slice due to immutability.
+		//	Uncommenting the line below would cause a compilation error:
+		//byte_slice[10] = 84;
+		
+		//	as_bytes() doesn't consume the original response body.
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	
+	//		as_mut_bytes														
+	#[test]
+	fn as_mut_bytes() {
+		let mut body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		let byte_vec = body.as_mut_bytes();
+		
+		//	Ensure the byte vector matches the original response body's bytes.
+		assert_eq!(*byte_vec, b"This is a test".to_vec());
+		
+		// We can modify the byte vector.
+		byte_vec[10] = 84;
+		assert_eq!(*byte_vec, b"This is a Test".to_vec());
+		
+		//	as_mut_bytes() doesn't consume the original response body, but modifying
+		//	the returned vector will have affected the response body's contents.
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a Test".to_vec(), ..Default::default() });
+	}
+	
+	//		into_bytes															
+	#[test]
+	fn into_bytes() {
+		let body         = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		let mut byte_vec = body.into_bytes();
+		
+		//	Ensure the byte vector matches the original response body's bytes.
+		assert_eq!(byte_vec, b"This is a test".to_vec());
+		
+		// We can modify the byte vector.
+		byte_vec[10]     = 84;
+		assert_eq!(byte_vec, b"This is a Test".to_vec());
+		
+		//	We can't use the original response body after calling into_bytes(),
+		//	because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		// assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	
+	//		to_bytes															
+	#[test]
+	fn to_bytes() {
+		let body           = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		let mut byte_clone = body.to_bytes();
+		
+		//	Ensure the clone matches the original response body's bytes.
+		assert_eq!(byte_clone, b"This is a test".to_vec());
+		
+		//	We can modify the cloned byte vector.
+		byte_clone[10]     = 84;
+		assert_eq!(byte_clone, b"This is a Test".to_vec());
+		
+		//	to_bytes() doesn't consume or affect the original response body.
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	
+	//		to_string															
+	#[test]
+	fn to_string() {
+		let body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		assert_eq!(body.to_string(), "This is a test");
+	}
+	
+	//		to_base64															
+	#[expect(invalid_from_utf8, reason = "Needed for the test")]
+	#[test]
+	fn to_base64() {
+		let mut body    = UnpackedResponseBody {
+			body:         b"This is a test".to_vec(),
+			content_type: ContentType::Binary,
+		};
+		assert_eq!(body.to_base64(), "VGhpcyBpcyBhIHRlc3Q=");
+		
+		body.clear();
+		assert_eq!(body.to_base64(), "");
+		
+		body.body       = vec![0x80];
+		assert_err!(from_utf8(&[0x80]));
+		assert_eq!(body.to_base64(), "gA==");
+	}
+	
+	//		from_base64															
+	#[expect(invalid_from_utf8, reason = "Needed for the test")]
+	#[test]
+	fn from_base64__valid() {
+		let body1 = UnpackedResponseBody::from_base64("VGhpcyBpcyBhIHRlc3Q=").unwrap();
+		assert_eq!(body1.body,         b"This is a test");
+		assert_eq!(body1.content_type, ContentType::Binary);
+		
+		let body2 = UnpackedResponseBody::from_base64("").unwrap();
+		assert!(body2.body.is_empty());
+		assert_eq!(body2.content_type, ContentType::Binary);
+		
+		let body3 = UnpackedResponseBody::from_base64("gA==").unwrap();
+		assert_eq!(body3.body,         vec![0x80]);
+		assert_eq!(body3.content_type, ContentType::Binary);
+		assert_err!(from_utf8(&[0x80]));
+	}
+	
+	#[test]
+	fn from_base64__invalid() {
+		assert_err!(UnpackedResponseBody::from_base64("invalid@@base64"));
+	}
+	
+	//		hexdump																
+	#[test]
+	fn hexdump() {
+		let body = UnpackedResponseBody {
+			body:         b"\x89PNG\r\n\x1A\n\0\0\0\rIHDR\0\0\0\x01 ~".to_vec(),
+			content_type: ContentType::Binary,
+		};
+		assert_eq!(body.hexdump(), concat!(
+			"00000000  89 50 4e 47 0d 0a 1a 0a  00 00 00 0d 49 48 44 52  |.PNG........IHDR|\n",
+			"00000010  00 00 00 01 20 7e                                 |.... ~|\n",
+		));
+	}
+	#[test]
+	fn hexdump__partial_first_group() {
+		assert_eq!(UnpackedResponseBody::new("abc").hexdump(), "00000000  61 62 63                                          |abc|\n");
+	}
+	#[test]
+	fn hexdump__offsets() {
+		let dump = UnpackedResponseBody::new(vec![0_u8; 40]).hexdump();
+		let lines: Vec<&str> = dump.lines().collect();
+		assert_eq!(lines.len(), 3);
+		assert!(lines[1].starts_with("00000010  "));
+		assert!(lines[2].starts_with("00000020  00 00 00 00 00 00 00 00 "));
+		assert!(lines.iter().all(|line| line.find('|') == Some(60)));
+	}
+	#[test]
+	fn hexdump__empty() {
+		assert_eq!(UnpackedResponseBody::new("").hexdump(), "");
+	}
+	
+	//		decode_text															
+	#[test]
+	fn decode_text__utf8() {
+		let body = UnpackedResponseBody::new("\u{FEFF}Café");
+		assert_ok_eq!(body.decode_text("utf-8"), s!("Café"));
+		assert_ok_eq!(body.decode_text("UTF8"),  s!("Café"));
+	}
+	#[test]
+	fn decode_text__ascii() {
+		assert_ok_eq!(UnpackedResponseBody::new("Cafe").decode_text("US-ASCII"), s!("Cafe"));
+		assert!(matches!(UnpackedResponseBody::new("Café").decode_text("ascii"), Err(CharsetError::Invalid(_))));
+	}
+	#[test]
+	fn decode_text__latin1() {
+		let body = UnpackedResponseBody { body: b"Caf\xE9 \xA3\xFF".to_vec(), content_type: ContentType::Binary };
+		assert_ok_eq!(body.decode_text("iso-8859-1"), s!("Café £ÿ"));
+		assert_ok_eq!(body.decode_text("Latin1"),     s!("Café £ÿ"));
+	}
+	#[test]
+	fn decode_text__utf16() {
+		let be = UnpackedResponseBody { body: b"\0C\0a\0f\0\xE9\xD8\x3D\xDE\x00".to_vec(), content_type: ContentType::Binary };
+		let le = UnpackedResponseBody { body: b"C\0a\0f\0\xE9\0".to_vec(),                 content_type: ContentType::Binary };
+		assert_ok_eq!(be.decode_text("utf-16be"), s!("Café😀"));
+		assert_ok_eq!(be.decode_text("utf-16"),   s!("Café😀"));
+		assert_ok_eq!(le.decode_text("UTF-16LE"), s!("Café"));
+	}
+	#[test]
+	fn decode_text__invalid() {
+		let odd      = UnpackedResponseBody { body: b"\0C\0".to_vec(),    content_type: ContentType::Binary };
+		let unpaired = UnpackedResponseBody { body: b"\xD8\x3D".to_vec(), content_type: ContentType::Binary };
+		assert!(matches!(odd.decode_text("utf-16"),      Err(CharsetError::Invalid(ref name)) if name == "utf-16"));
+		assert!(matches!(unpaired.decode_text("utf-16"), Err(CharsetError::Invalid(_))));
+	}
+	#[test]
+	fn decode_text__unsupported() {
+		assert!(matches!(UnpackedResponseBody::new("foo").decode_text("koi8-r"), Err(CharsetError::Unsupported(ref name)) if name == "koi8-r"));
+	}
+	
+	//		as_json																
+	#[test]
+	fn as_json__valid() {
+		let body = UnpackedResponseBody::new(r#"{"foo": [1, 2], "bar": null}"#);
+		assert_ok_eq!(body.as_json(), json!({ "foo": [ 1, 2 ], "bar": null }));
+	}
+	#[test]
+	fn as_json__invalid() {
+		let body = UnpackedResponseBody::new("This is a test");
+		assert_err!(body.as_json());
+	}
+	
+	//		to_json																
+	#[test]
+	fn to_json__valid() {
+		#[derive(Debug, Deserialize, PartialEq)]
+		struct Data {
+			foo: Vec<u8>,
+			bar: Option<String>,
+		}
+		let body = UnpackedResponseBody::new(r#"{"foo": [1, 2], "bar": null}"#);
+		assert_ok_eq!(body.to_json::<Data>(), Data { foo: vec![1, 2], bar: None });
+		assert_ok_eq!(body.to_json::<Json>(), json!({ "foo": [ 1, 2 ], "bar": null }));
+	}
+	#[test]
+	fn to_json__invalid() {
+		let body = UnpackedResponseBody::new(r#"{"foo": "bar"}"#);
+		assert_err!(body.to_json::<Vec<u8>>());
+		assert_err!(UnpackedResponseBody::new("This is a test").to_json::<Json>());
+	}
+	
+	//		as_ndjson															
+	#[test]
+	fn as_ndjson__valid() {
+		let body = UnpackedResponseBody::new("{\"foo\": 1}\n\n[1, 2]\r\n\"bar\"\n");
+		assert_ok_eq!(body.as_ndjson(), vec![json!({"foo": 1}), json!([1, 2]), json!("bar")]);
+		assert_ok_eq!(UnpackedResponseBody::new("").as_ndjson(), Vec::<Json>::new());
+	}
+	#[test]
+	fn as_ndjson__invalid() {
+		assert_err!(UnpackedResponseBody::new("{\"foo\": 1}\nfoo\n").as_ndjson());
+	}
+	
+	//		to_ndjson															
+	#[test]
+	fn to_ndjson__valid() {
+		#[derive(Debug, Deserialize, PartialEq)]
+		struct Data {
+			id: u8,
+		}
+		let body = UnpackedResponseBody::new("{\"id\": 1}\n{\"id\": 2}");
+		assert_ok_eq!(body.to_ndjson::<Data>(), vec![Data { id: 1 }, Data { id: 2 }]);
+	}
+	#[test]
+	fn to_ndjson__invalid() {
+		assert_err!(UnpackedResponseBody::new("{\"id\": 1}\n[1]").to_ndjson::<BTreeMap<String, u8>>());
+	}
+	
+	//		as_sse_events														
+	#[test]
+	fn as_sse_events__basic() {
+		let body = UnpackedResponseBody::new("data: foo\n\nevent: update\nid: 2\nretry: 1000\ndata: bar\ndata:baz\n\n");
+		assert_eq!(body.as_sse_events(), vec![
+			ServerSentEvent::new("foo"),
+			ServerSentEvent::new("bar\nbaz").with_event("update").with_id("2").with_retry(1000),
+		]);
+	}
+	#[test]
+	fn as_sse_events__line_endings() {
+		let body = UnpackedResponseBody::new("\u{FEFF}data: foo\r\n\r\ndata: bar\r\rdata: baz\n\n");
+		assert_eq!(body.as_sse_events(), vec![
+			ServerSentEvent::new("foo"),
+			ServerSentEvent::new("bar"),
+			ServerSentEvent::new("baz"),
+		]);
+	}
+	#[test]
+	fn as_sse_events__ignored() {
+		let body = UnpackedResponseBody::new(": comment\nevent: empty\n\nfoo: bar\nretry: soon\ndata\n\ndata: incomplete\n");
+		assert_eq!(body.as_sse_events(), vec![ServerSentEvent::new("")]);
+	}
+	
+	//		as_multipart														
+	#[test]
+	fn as_multipart__form_data() {
+		let body  = UnpackedResponseBody::new("preamble\r\n--abc\r\ncontent-disposition: form-data; name=\"name\"\r\n\r\nAlice\r\n--abc\r\ncontent-disposition: form-data; name=\"data\"; filename=\"data.json\"\r\ncontent-type: application/json\r\n\r\n{\"id\": 1}\r\n--abc--\r\nepilogue");
+		let parts = body.as_multipart("abc").unwrap();
+		assert_eq!(parts, vec![
+			MultipartPart::new("Alice").with_header("content-disposition", r#"form-data; name="name""#),
+			MultipartPart::new(r#"{"id": 1}"#)
+				.with_header("content-disposition", r#"form-data; name="data"; filename="data.json""#)
+				.with_header("content-type",        "application/json")
+			,
+		]);
+		assert!(parts[0].body.is_text());
+		assert!(parts[1].body.is_json());
+	}
+	#[test]
+	fn as_multipart__lf_line_endings() {
+		let body  = UnpackedResponseBody::new("--abc\nx-foo: 1\n\nfoo\n--abc\n\nbar\nbaz\n--abc--");
+		assert_eq!(body.as_multipart("abc").unwrap(), vec![
+			MultipartPart::new("foo").with_header("x-foo", "1"),
+			MultipartPart::new("bar\nbaz"),
+		]);
+	}
+	#[test]
+	fn as_multipart__folded_header() {
+		let body  = UnpackedResponseBody::new("--abc\r\nX-Foo: one\r\n two\r\n\r\nfoo\r\n--abc--\r\n");
+		assert_eq!(body.as_multipart("abc").unwrap(), vec![MultipartPart::new("foo").with_header("x-foo", "one two")]);
+	}
+	#[test]
+	fn as_multipart__boundary_prefix() {
+		let body  = UnpackedResponseBody::new("--abc\r\n\r\n--abcd\r\n--abc--");
+		assert_eq!(body.as_multipart("abc").unwrap(), vec![MultipartPart::new("--abcd")]);
+	}
+	#[test]
+	fn as_multipart__empty() {
+		assert_eq!(UnpackedResponseBody::new("--abc--\r\n").as_multipart("abc").unwrap(), vec![]);
+	}
+	#[test]
+	fn as_multipart__errors() {
+		let body1 = UnpackedResponseBody::new("--abc\r\n\r\nfoo\r\n--abc--");
+		let body2 = UnpackedResponseBody::new("--abc\r\n\r\nfoo");
+		let body3 = UnpackedResponseBody::new("--abc\r\nfoo\r\n\r\nbar\r\n--abc--");
+		assert!(matches!(body1.as_multipart(""),    Err(MultipartError::MissingBoundary)));
+		assert!(matches!(body1.as_multipart("xyz"), Err(MultipartError::MissingDelimiter)));
+		assert!(matches!(body2.as_multipart("abc"), Err(MultipartError::Unterminated)));
+		assert!(matches!(body3.as_multipart("abc"), Err(MultipartError::InvalidHeader(ref line)) if line == "foo"));
+	}
+	
+	//		from_json															
+	#[test]
+	fn from_json() {
+		#[derive(Serialize)]
+		struct Data {
+			foo: Vec<u8>,
+			bar: Option<String>,
+		}
+		let body1 = UnpackedResponseBody::from_json(&Data { foo: vec![1, 2], bar: None }).unwrap();
+		assert_eq!(body1.body,         br#"{"foo":[1,2],"bar":null}"#.to_vec());
+		assert_eq!(body1.content_type, ContentType::Json);
+		
+		let map   = BTreeMap::from([("b", 2), ("a", 1)]);
+		let body2 = UnpackedResponseBody::from_json(&map).unwrap();
+		assert_eq!(body2.body,         br#"{"a":1,"b":2}"#.to_vec());
+		assert_eq!(body2.content_type, ContentType::Json);
+	}
+	
+	//		as_form																
+	#[test]
+	fn as_form() {
+		let body = UnpackedResponseBody::new("name=Alice+Smith&tag=a&tag=b%26c&empty=");
+		assert_ok_eq!(body.as_form(), vec![
+			(s!("name"),  s!("Alice Smith")),
+			(s!("tag"),   s!("a")),
+			(s!("tag"),   s!("b&c")),
+			(s!("empty"), s!("")),
+		]);
+		assert_ok_eq!(UnpackedResponseBody::new("").as_form(), vec![]);
+	}
+	
+	//		to_form																
+	#[test]
+	fn to_form__valid() {
+		#[derive(Debug, Deserialize, PartialEq)]
+		struct Login {
+			username: String,
+			remember: bool,
+		}
+		let body = UnpackedResponseBody::new("username=alice%40example.com&remember=true");
+		assert_ok_eq!(body.to_form::<Login>(), Login { username: s!("alice@example.com"), remember: true });
+	}
+	#[test]
+	fn to_form__invalid() {
+		assert_err!(UnpackedResponseBody::new("count=many").to_form::<BTreeMap<String, u8>>());
+	}
+	
+	//		from_form															
+	#[test]
+	fn from_form__struct() {
+		#[derive(Serialize)]
+		struct Login {
+			username: String,
+			remember: bool,
+		}
+		let body = UnpackedResponseBody::from_form(&Login { username: s!("alice@example.com"), remember: true }).unwrap();
+		assert_eq!(body.body,         b"username=alice%40example.com&remember=true".to_vec());
+		assert_eq!(body.content_type, ContentType::Text);
+	}
+	#[test]
+	fn from_form__pairs() {
+		let body = UnpackedResponseBody::from_form(&[("tag", "a b"), ("tag", "c&d")]).unwrap();
+		assert_eq!(body.to_string(), "tag=a+b&tag=c%26d");
+		assert_ok_eq!(body.as_form(), vec![(s!("tag"), s!("a b")), (s!("tag"), s!("c&d"))]);
+	}
+	#[test]
+	fn from_form__invalid() {
+		assert_err!(UnpackedResponseBody::from_form(&json!({"foo": {"bar": 1}})));
+	}
+	
+	//		compress															
+	#[test]
+	fn compress() {
+		let body = UnpackedResponseBody::new("This is a test. This is a test. This is a test.");
+		for encoding in [ContentEncoding::Brotli, ContentEncoding::Deflate, ContentEncoding::Gzip] {
+			let compressed = body.compress(encoding).unwrap();
+			assert_eq!(compressed.content_type, ContentType::Binary);
+			assert_ne!(compressed.body,         body.body);
+			assert_eq!(compressed.decompress(encoding).unwrap(), body);
+		}
+	}
+	
+	//		decompress															
+	#[test]
+	fn decompress() {
+		let compressed1 = UnpackedResponseBody::new(compress_bytes(b"This is a test", ContentEncoding::Gzip).unwrap());
+		let body1       = compressed1.decompress(ContentEncoding::Gzip).unwrap();
+		assert_eq!(body1.body,         b"This is a test".to_vec());
+		assert_eq!(body1.content_type, ContentType::Text);
+		
+		let compressed2 = UnpackedResponseBody::new(compress_bytes(&[0xFF, 0xFE], ContentEncoding::Brotli).unwrap());
+		let body2       = compressed2.decompress(ContentEncoding::Brotli).unwrap();
+		assert_eq!(body2.body,         vec![0xFF, 0xFE]);
+		assert_eq!(body2.content_type, ContentType::Binary);
+	}
+	#[test]
+	fn decompress__invalid() {
+		let body = UnpackedResponseBody::new("This is a test");
+		assert_err!(body.decompress(ContentEncoding::Gzip));
+		assert_err!(body.decompress(ContentEncoding::Deflate));
+	}
+	
+	//		clear																
+	#[test]
+	fn clear() {
+		let mut body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		body.clear();
+		assert_eq!(body, UnpackedResponseBody { body: b"".to_vec(), ..Default::default() });
+	}
+	
+	//		empty																
+	#[test]
+	fn empty() {
+		let body = UnpackedResponseBody::empty();
+		assert_eq!(body, UnpackedResponseBody { body: b"".to_vec(), ..Default::default() });
+	}
+	
+	//		is_empty															
+	#[test]
+	fn is_empty() {
+		let body1 = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		assert!(!body1.is_empty());
+		
+		let body2 = UnpackedResponseBody { body: b"".to_vec(), ..Default::default() };
+		assert!( body2.is_empty());
+	}
+	
+	//		len																	
+	#[test]
+	fn len() {
+		let body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		assert_eq!(body.len(), 14);
+	}
+	
+	//		push																
+	#[test]
+	fn push() {
+		let mut body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		body.push(33);
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test!".to_vec(), ..Default::default() });
+	}
+	
+	//		push_bytes															
+	#[test]
+	fn push_bytes__byte_array() {
+		let mut body = UnpackedResponseBody { body: b"This".to_vec(), ..Default::default() };
+		body.push_bytes(b" is a test");
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn push_bytes__byte_slice() {
+		let mut body = UnpackedResponseBody { body: b"This".to_vec(), ..Default::default() };
+		body.push_bytes(&b" is a test"[..]);
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	
+	//		push_char															
+	#[test]
+	fn push_char() {
+		let mut body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		body.push_char(&'!');
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test!".to_vec(), ..Default::default() });
+	}
+	
+	//		push_str															
+	#[test]
+	fn push_str() {
+		let mut body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		body.push_str(" a test");
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	
+	//		diff																
+	#[test]
+	fn diff__identical() {
+		let left  = UnpackedResponseBody::new("This is a test");
+		let right = UnpackedResponseBody::new("This is a test");
+		assert_eq!(left.diff(&right), None);
+	}
+	#[test]
+	fn diff__json() {
+		let left  = UnpackedResponseBody::new(json!({ "foo": 1, "bar": [ 1, 2 ], "baz": true }).to_string());
+		let right = UnpackedResponseBody::new(json!({ "foo": 2, "bar": [ 1 ], "qux": null }).to_string());
+		assert_eq!(left.diff(&right), Some(BodyDiff::Json(vec![
+			JsonPathDiff::Changed { path: s!("$.foo"),    left: json!(1), right: json!(2) },
+			JsonPathDiff::Removed { path: s!("$.bar[1]"), value: json!(2) },
+			JsonPathDiff::Removed { path: s!("$.baz"),    value: json!(true) },
+			JsonPathDiff::Added   { path: s!("$.qux"),    value: json!(null) },
+		])));
+	}
+	#[test]
+	fn diff__json_semantically_equal() {
+		let mut left  = UnpackedResponseBody::new(r#"{"foo":1,"bar":2}"#);
+		let right     = UnpackedResponseBody::new(r#"{ "bar": 2, "foo": 1 }"#);
+		_ = left.set_content_type(ContentType::Json);
+		assert_eq!(left.diff(&right), None);
+	}
+	#[test]
+	fn diff__json_formatting_only() {
+		let left  = UnpackedResponseBody::new(r#"{"foo":1}"#);
+		let right = UnpackedResponseBody::new(r#"{ "foo": 1 }"#);
+		assert_eq!(left.diff(&right), Some(BodyDiff::Text(vec![
+			TextLineDiff::Removed { line: 1, text: s!(r#"{"foo":1}"#) },
+			TextLineDiff::Added   { line: 1, text: s!(r#"{ "foo": 1 }"#) },
+		])));
+	}
+	#[test]
+	fn diff__text() {
+		let left  = UnpackedResponseBody::new("Foo\nBar\nBaz");
+		let right = UnpackedResponseBody::new("Foo\nQux\nBaz\nQuux");
+		assert_eq!(left.diff(&right), Some(BodyDiff::Text(vec![
+			TextLineDiff::Removed { line: 2, text: s!("Bar") },
+			TextLineDiff::Added   { line: 2, text: s!("Qux") },
+			TextLineDiff::Added   { line: 4, text: s!("Quux") },
+		])));
+	}
+	#[test]
+	fn diff__text_line_endings() {
+		let left  = UnpackedResponseBody::new("Foo\nBar");
+		let right = UnpackedResponseBody::new("Foo\r\nBar");
+		assert_eq!(left.diff(&right), Some(BodyDiff::Binary { offset: 3, left_len: 7, right_len: 8 }));
+	}
+	#[test]
+	fn diff__binary() {
+		let mut left  = UnpackedResponseBody::new(vec![0x01, 0x02, 0x03, 0x04]);
+		let mut right = UnpackedResponseBody::new(vec![0x01, 0x02, 0xFF]);
+		_ = left.set_content_type(ContentType::Binary);
+		_ = right.set_content_type(ContentType::Binary);
+		assert_eq!(left.diff(&right), Some(BodyDiff::Binary { offset: 2, left_len: 4, right_len: 3 }));
+	}
+	#[test]
+	fn diff__binary_prefix() {
+		let mut left  = UnpackedResponseBody::new(vec![0x01, 0x02]);
+		let mut right = UnpackedResponseBody::new(vec![0x01, 0x02, 0x03]);
+		_ = left.set_content_type(ContentType::Binary);
+		_ = right.set_content_type(ContentType::Binary);
+		assert_eq!(left.diff(&right), Some(BodyDiff::Binary { offset: 2, left_len: 2, right_len: 3 }));
+	}
+}
+
+#[cfg(test)]
+mod unpacked_response_body__traits {
+	use super::*;
+	
+	//		add																	
+	#[test]
+	fn add__byte_array() {
+		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		assert_eq!(body + b" a test", UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add__byte_slice() {
+		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		assert_eq!(body + &b" a test"[..], UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	#[expect(clippy::string_lit_as_bytes, reason = "Needed for the test")]
+	#[test]
+	fn add__char_one_byte() {
+		let body = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
+		assert_eq!(body + 'A', UnpackedResponseBody { body: s!("This is A").into_bytes(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: s!("This is ").into_bytes() });
+	}
+	#[test]
+	fn add__char_two_byte() {
+		let body = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
+		assert_eq!(body + 'ñ', UnpackedResponseBody { body: s!("This is ñ").into_bytes(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: s!("This is ").into_bytes() });
+	}
+	#[test]
+	fn add__char_three_byte() {
+		let body = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
+		assert_eq!(body + 'Ḁ', UnpackedResponseBody { body: s!("This is Ḁ").into_bytes(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: s!("This is ").into_bytes() });
+	}
+	#[test]
+	fn add__char_four_byte() {
+		let body = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
+		assert_eq!(body + '𐍈', UnpackedResponseBody { body: s!("This is 𐍈").into_bytes(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: s!("This is ").into_bytes() });
+	}
+	#[expect(clippy::string_lit_as_bytes, reason = "Needed for the test")]
+	#[test]
+	fn add__char_ref() {
+		let body = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
+		let char = 'A';
+		assert_eq!(body + char, UnpackedResponseBody { body: s!("This is A").into_bytes(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: s!("This is ").into_bytes() });
+	}
+	#[test]
+	fn add__str() {
+		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		assert_eq!(body + " a test", UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add__str_ref() {
+		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let str  = " a test";
+		assert_eq!(body + str, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add__string() {
+		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		assert_eq!(body + s!(" a test"), UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add__string_ref() {
+		let body   = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let string = s!(" a test");
+		assert_eq!(body + &string, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add__box_str() {
+		let body   = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		assert_eq!(body + s!(" a test").into_boxed_str(), UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add__cow_borrowed() {
+		let body              = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let cow: Cow<'_, str> = Cow::Borrowed(" a test");
+		assert_eq!(body + cow, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add__cow_owned() {
+		let body              = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let cow: Cow<'_, str> = Cow::Owned(s!(" a test"));
+		assert_eq!(body + cow, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add__u8() {
+		let body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		assert_eq!(body + 33, UnpackedResponseBody { body: b"This is a test!".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add__vec_u8() {
+		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		assert_eq!(body + b" a test".to_vec(), UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add__vec_u8_ref() {
+		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let vec  = b" a test".to_vec();
+		assert_eq!(body + &vec, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add__unpacked_response_body() {
+		let body1 = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let body2 = UnpackedResponseBody { body: b" a test".to_vec(), ..Default::default() };
+		assert_eq!(body1 + body2, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body1, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add__unpacked_response_body_ref() {
+		let body1 = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let body2 = UnpackedResponseBody { body: b" a test".to_vec(), ..Default::default() };
+		assert_eq!(body1 + &body2, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original response body after using the +
+		//	operator, because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(body1, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	}
+	
+	//		add_assign															
+	#[test]
+	fn add_assign__byte_array() {
+		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		body         += b" a test";
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__byte_slice() {
+		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		body         += &b" a test"[..];
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[expect(clippy::string_lit_as_bytes, reason = "Needed for the test")]
+	#[test]
+	fn add_assign__char_one_byte() {
+		let mut body  = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
+		body         += 'A';
+		assert_eq!(body, UnpackedResponseBody { body: s!("This is A").into_bytes(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__char_two_byte() {
+		let mut body  = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
+		body         += 'ñ';
+		assert_eq!(body, UnpackedResponseBody { body: s!("This is ñ").into_bytes(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__char_three_byte() {
+		let mut body  = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
+		body         += 'Ḁ';
+		assert_eq!(body, UnpackedResponseBody { body: s!("This is Ḁ").into_bytes(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__char_four_byte() {
+		let mut body  = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
+		body         += '𐍈';
+		assert_eq!(body, UnpackedResponseBody { body: s!("This is 𐍈").into_bytes(), ..Default::default() });
+	}
+	#[expect(clippy::string_lit_as_bytes, reason = "Needed for the test")]
+	#[test]
+	fn add_assign__char_ref() {
+		let mut body  = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
+		let char      = 'A';
+		body         += &char;
+		assert_eq!(body, UnpackedResponseBody { body: s!("This is A").into_bytes(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__str() {
+		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		body         += " a test";
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__str_ref() {
+		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let str       = " a test";
+		body         += str;
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__string() {
+		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		body         += s!(" a test");
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__string_ref() {
+		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let string    = s!(" a test");
+		body         += &string;
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__box_str() {
+		let mut body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let box_str  = s!(" a test").into_boxed_str();
+		body        += box_str;
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__cow_borrowed() {
+		let mut body           = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let cow: Cow<'_, str>  = Cow::Borrowed(" a test");
+		body                  += cow;
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__cow_owned() {
+		let mut body           = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let cow: Cow<'_, str>  = Cow::Owned(s!(" a test"));
+		body                  += cow;
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__u8() {
+		let mut body  = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		body         += 33;
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test!".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__vec_u8() {
+		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		body         += b" a test".to_vec();
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__vec_u8_ref() {
+		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let vec       = b" a test".to_vec();
+		body         += &vec;
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__unpacked_response_body() {
+		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		body         += UnpackedResponseBody { body: b" a test".to_vec(), ..Default::default() };
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn add_assign__unpacked_response_body_ref() {
+		let mut body1  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		let body2      = UnpackedResponseBody { body: b" a test".to_vec(), ..Default::default() };
+		body1          += &body2;
+		assert_eq!(body1, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	
+	//		as_ref																
+	#[test]
+	fn as_ref() {
+		//	Same tests as for as_bytes().
+		let body       = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		let byte_slice = body.as_ref();
+		assert_eq!(byte_slice, b"This is a test".to_vec());
+		assert_eq!(body,       UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	
+	//		as_mut																
+	#[test]
+	fn as_mut() {
+		//	Same tests as for as_mut_bytes().
+		let mut body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		let byte_vec = body.as_mut();
+		assert_eq!(*byte_vec, b"This is a test".to_vec());
+		
+		byte_vec[10] = 84;
+		assert_eq!(*byte_vec, b"This is a Test".to_vec());
+		assert_eq!(body,      UnpackedResponseBody { body: b"This is a Test".to_vec(), ..Default::default() });
+	}
+	
+	//		clone																
+	#[test]
+	fn clone() {
+		let mut body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		let clone    = body.clone();
+		assert_eq!(clone, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		
+		body.clear();
+		body.push_str("This is a different test");
+		assert_eq!(body,  UnpackedResponseBody { body: b"This is a different test".to_vec(), ..Default::default() });
+		assert_eq!(clone, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn clone__content_type() {
+		let body       = UnpackedResponseBody { body: vec![0xFF], content_type: ContentType::Binary };
+		let clone1     = body.clone();
+		let mut clone2 = UnpackedResponseBody::default();
+		clone2.clone_from(&body);
+		assert_eq!(clone1.content_type(), ContentType::Binary);
+		assert_eq!(clone2.content_type(), ContentType::Binary);
+		assert_eq!(body.content_type(),   ContentType::Binary);
+	}
+	
+	//		clone_from															
+	#[test]
+	fn clone_from() {
+		let mut body  = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		let mut clone = UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() };
+		clone.clone_from(&body);
+		assert_eq!(body,  UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		assert_eq!(clone, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		
+		body.clear();
+		body.push_str("This is a different test");
+		assert_eq!(body,  UnpackedResponseBody { body: b"This is a different test".to_vec(), ..Default::default() });
+		assert_eq!(clone, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	
+	//		debug																
+	#[test]
+	fn debug__binary() {
+		let body        = UnpackedResponseBody {
+			body:         b"This is a test".to_vec(),
+			content_type: ContentType::Binary,
+		};
+		assert_eq!(format!("{body:?}"), r#"UnpackedResponseBody { body: "VGhpcyBpcyBhIHRlc3Q=", content_type: Binary }"#);
+	}
+	#[test]
+	fn debug__text() {
+		let body        = UnpackedResponseBody {
+			body:         b"This is a test".to_vec(),
+			content_type: ContentType::Text,
+		};
+		assert_eq!(format!("{body:?}"), r#"UnpackedResponseBody { body: "This is a test", content_type: Text }"#);
+	}
+	
+	//		default																
+	#[test]
+	fn default() {
+		let body = UnpackedResponseBody::default();
+		assert_eq!(body, UnpackedResponseBody { body: b"".to_vec(), ..Default::default() });
+	}
+	
+	//		display																
+	#[test]
+	fn display__binary() {
+		let body        = UnpackedResponseBody {
+			body:         b"This is a test".to_vec(),
+			content_type: ContentType::Binary,
+		};
+		assert_eq!(format!("{body}"), r"VGhpcyBpcyBhIHRlc3Q=");
+	}
+	#[test]
+	fn display__text() {
+		let body        = UnpackedResponseBody {
+			body:         b"This is a test".to_vec(),
+			content_type: ContentType::Text,
+		};
+		assert_eq!(format!("{body}"), r"This is a test");
+	}
+	#[test]
+	fn display__json() {
+		let body        = UnpackedResponseBody {
+			body:         br#"{"foo":[1,2],"bar":null}"#.to_vec(),
+			content_type: ContentType::Json,
+		};
+		assert_eq!(format!("{body}"), "{\n  \"foo\": [\n    1,\n    2\n  ],\n  \"bar\": null\n}");
+	}
+	#[test]
+	fn display__json_invalid() {
+		let body        = UnpackedResponseBody {
+			body:         b"This is a test".to_vec(),
+			content_type: ContentType::Json,
+		};
+		assert_eq!(format!("{body}"), r"This is a test");
+	}
+	
+	//		from																
+	#[test]
+	fn from__axum_body() {
+		let body1 = UnpackedResponseBody::from(AxumBody::from("This is a test"));
+		assert_eq!(body1, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		
+		let axum  = AxumBody::from("This is another test");
+		let body2 = UnpackedResponseBody::from(axum);
+		assert_eq!(body2, UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original AxumBody after calling from(),
+		//	because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(axum, "This is another test");
+	}
+	#[test]
+	fn from__byte_array() {
+		let body1      = UnpackedResponseBody::from(b"This is a test");
+		assert_eq!(body1,      UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		
+		let byte_array = b"This is another test";
+		let body2      = UnpackedResponseBody::from(byte_array);
+		assert_eq!(body2,      UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() });
+		assert_eq!(byte_array, b"This is another test");
+	}
+	#[test]
+	fn from__byte_slice() {
+		let body1       = UnpackedResponseBody::from(&b"This is a test"[..]);
+		assert_eq!(body1,       UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		
+		let byte_slice = &b"This is another test"[..];
+		let body2      = UnpackedResponseBody::from(byte_slice);
+		assert_eq!(body2,      UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() });
+		assert_eq!(byte_slice, b"This is another test");
+	}
+	#[test]
+	fn from__char() {
+		let body1 = UnpackedResponseBody::from('A');
+		assert_eq!(body1, UnpackedResponseBody { body: b"A".to_vec(), ..Default::default() });
+		
+		let char  = 'B';
+		let body2 = UnpackedResponseBody::from(char);
+		assert_eq!(body2, UnpackedResponseBody { body: b"B".to_vec(), ..Default::default() });
+		assert_eq!(char,  'B');
+	}
+	#[test]
+	fn from__char_ref() {
+		let char = 'A';
+		let body = UnpackedResponseBody::from(&char);
+		assert_eq!(body, UnpackedResponseBody { body: b"A".to_vec(), ..Default::default() });
+		assert_eq!(char, 'A');
+	}
+	#[expect(clippy::string_lit_as_bytes, reason = "Needed for the test")]
+	#[test]
+	fn from__char_one_byte() {
+		let body = UnpackedResponseBody::from('A');
+		assert_eq!(body, UnpackedResponseBody { body: vec![65], ..Default::default() });
+		assert_eq!(body, UnpackedResponseBody::from(s!("A")));
+		assert_eq!(body, UnpackedResponseBody { body: s!("A").into_bytes(), ..Default::default() });
+	}
+	#[test]
+	fn from__char_two_byte() {
+		let body = UnpackedResponseBody::from('ñ');
+		assert_eq!(body, UnpackedResponseBody { body: vec![195, 177], ..Default::default() });
+		assert_eq!(body, UnpackedResponseBody::from(s!("ñ")));
+		assert_eq!(body, UnpackedResponseBody { body: s!("ñ").into_bytes(), ..Default::default() });
+	}
+	#[test]
+	fn from__char_three_byte() {
+		let three_byte_single_width = UnpackedResponseBody::from('Ḁ');
+		assert_eq!(three_byte_single_width, UnpackedResponseBody { body: vec![225, 184, 128], ..Default::default() });
+		assert_eq!(three_byte_single_width, UnpackedResponseBody::from(s!("Ḁ")));
+		assert_eq!(three_byte_single_width, UnpackedResponseBody { body: s!("Ḁ").into_bytes(), ..Default::default() });
+		
+		let three_byte_double_width = UnpackedResponseBody::from('你');
+		assert_eq!(three_byte_double_width, UnpackedResponseBody { body: vec![228, 189, 160], ..Default::default() });
+		assert_eq!(three_byte_double_width, UnpackedResponseBody::from(s!("你")));
+		assert_eq!(three_byte_double_width, UnpackedResponseBody { body: s!("你").into_bytes(), ..Default::default() });
+	}
+	#[test]
+	fn from__char_four_byte() {
+		let body = UnpackedResponseBody::from('𐍈');
+		assert_eq!(body, UnpackedResponseBody { body: vec![240, 144, 141, 136], ..Default::default() });
+		assert_eq!(body, UnpackedResponseBody::from(s!("𐍈")));
+		assert_eq!(body, UnpackedResponseBody { body: s!("𐍈").into_bytes(), ..Default::default() });
+	}
+	#[test]
+	fn from__full() {
+		let body1 = UnpackedResponseBody::from(Full::new(Bytes::from("This is a test")));
+		assert_eq!(body1, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		
+		let full  = Full::new(Bytes::from("This is another test"));
+		let body2 = UnpackedResponseBody::from(full);
+		assert_eq!(body2, UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original Full after calling from(),
+		//	because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(full, "This is another test");
+	}
+	#[tokio::test]
+	async fn from__incoming() {
+		let body1    = UnpackedResponseBody::from(create_incoming_for_testing(Bytes::from("This is a test")).await);
+		assert_eq!(body1, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		
+		let incoming = create_incoming_for_testing(Bytes::from("This is another test")).await;
+		let body2    = UnpackedResponseBody::from(incoming);
+		assert_eq!(body2, UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original incoming after calling from(),
+		//	because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(incoming, "This is another test");
+	}
+	#[test]
+	fn from__json() {
+		let body1 = UnpackedResponseBody::from(json!({
+			"foo": "bar",
+			"baz": 2,
+		}));
+		assert_json_eq!(json!(body1), r#"{"foo":"bar","baz":2}"#);
+		
+		let json = json!({
+			"str":   "foo",
+			"int":   99,
+			"float": 1.234,
+			"bool":  true,
+		});
+		let body2 = UnpackedResponseBody::from(json);
+		assert_json_eq!(json!(body2), r#"{"str":"foo","int":99,"float":1.234,"bool":true}"#);
+		//	We cannot compare to the original JSON after calling from(),
+		//	because it has been consumed.
+		//	Uncommenting the lines below would cause a compilation error:
+		// assert_json_eq!(json, json!({
+		// 	"str":   "foo",
+		// 	"int":   99,
+		// 	"float": 1.234,
+		// 	"bool":  true,
+		// }));
+	}
+	#[test]
+	fn from__json_ref() {
+		let json = json!({
+			"foo": "bar",
+			"baz": 2,
+		});
+		let body = UnpackedResponseBody::from(&json);
+		assert_json_eq!(json!(body), r#"{"foo":"bar","baz":2}"#);
+		assert_json_eq!(json, json!({
+			"foo": "bar",
+			"baz": 2,
+		}));
+	}
+	#[test]
+	fn from__str() {
+		let body = UnpackedResponseBody::from("This is a test");
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn from__str_ref() {
+		let str  = "This is a test";
+		let body = UnpackedResponseBody::from(str);
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		assert_eq!(str,  "This is a test");
+	}
+	#[test]
+	fn from__mut_str_ref() {
+		let mut string = s!("This is a test");
+		let mut_str    = string.as_mut_str();
+		let body       = UnpackedResponseBody::from(mut_str);
+		assert_eq!(body,   UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		assert_eq!(string, "This is a test");
+	}
+	#[test]
+	fn from__string() {
+		let string = s!("This is a test");
+		let body   = UnpackedResponseBody::from(string);
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original string after calling from(),
+		//	because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(string, "This is a test");
+	}
+	#[test]
+	fn from__string_ref() {
+		let string = s!("This is a test");
+		let body   = UnpackedResponseBody::from(&string);
+		assert_eq!(body,   UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		assert_eq!(string, "This is a test");
+	}
+	#[test]
+	fn from__box_str() {
+		let box_str = s!("This is a test").into_boxed_str();
+		let body    = UnpackedResponseBody::from(box_str);
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original box_str after calling from(),
+		//	because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(box_str, s!("This is a test").into_boxed_str());
+	}
+	#[test]
+	fn from__cow_borrowed() {
+		let cow: Cow<'_, str> = Cow::Borrowed("This is a test");
+		let body              = UnpackedResponseBody::from(cow);
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original cow after calling from(),
+		//	because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(cow, "This is a test");
+	}
+	#[test]
+	fn from__cow_owned() {
+		let cow: Cow<'_, str> = Cow::Owned(s!("This is a test"));
+		let body              = UnpackedResponseBody::from(cow);
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original cow after calling from(),
+		//	because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(cow, "This is a test");
+	}
+	#[test]
+	fn from__u8() {
+		let body = UnpackedResponseBody::from(65);
+		assert_eq!(body, UnpackedResponseBody { body: vec![65], ..Default::default() });
+		assert_eq!(body, UnpackedResponseBody { body: b"A".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn from__vec_u8() {
+		let body1 = UnpackedResponseBody::from(b"This is a test".to_vec());
+		assert_eq!(body1, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		
+		let vec   = b"This is another test".to_vec();
+		let body2 = UnpackedResponseBody::from(vec);
+		assert_eq!(body2, UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() });
+		//	We cannot compare to the original vec after calling from(),
+		//	because it has been consumed.
+		//	Uncommenting the line below would cause a compilation error:
+		//assert_eq!(vec,  b"This is another test".to_vec());
+		
+	}
+	#[test]
+	fn from__vec_u8_ref() {
+		let vec  = b"This is a test".to_vec();
+		let body = UnpackedResponseBody::from(&vec);
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		assert_eq!(vec,  b"This is a test".to_vec());
+	}
+	
+	//		from_str															
+	#[test]
+	fn from_str() {
+		//	Basic ASCII string
+		assert_ok_eq!(UnpackedResponseBody::from_str("Test"), UnpackedResponseBody { body: b"Test".to_vec(), ..Default::default() });
+		//	Strings containing different sizes of UTF8 characters
+		assert_ok_eq!(UnpackedResponseBody::from_str("ñ"),    UnpackedResponseBody { body: s!("ñ").into_bytes(), ..Default::default() });
+		assert_ok_eq!(UnpackedResponseBody::from_str("Ḁ"),    UnpackedResponseBody { body: s!("Ḁ").into_bytes(), ..Default::default() });
+		assert_ok_eq!(UnpackedResponseBody::from_str("𐍈"),    UnpackedResponseBody { body: s!("𐍈").into_bytes(), ..Default::default() });
+	}
+	
+	//		partial_eq															
+	#[test]
+	fn partial_eq() {
+		let body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+		assert_ne!(body, UnpackedResponseBody { body: b"This is different".to_vec(), ..Default::default() });
+	}
+	#[test]
+	fn partial_eq__json() {
+		let body1 = UnpackedResponseBody { body: br#"{"foo":1,"bar":[1,2]}"#.to_vec(),          content_type: ContentType::Json };
+		let body2 = UnpackedResponseBody { body: br#"{ "bar": [ 1, 2 ], "foo": 1 }"#.to_vec(), content_type: ContentType::Text };
+		let body3 = UnpackedResponseBody { body: br#"{"foo":1,"bar":[2,1]}"#.to_vec(),          content_type: ContentType::Json };
+		assert_eq!(body1, body2);
+		assert_eq!(body2, body1);
+		assert_ne!(body1, body3);
+		
+		let body4 = UnpackedResponseBody { body: br#"{"foo":1,"bar":[1,2]}"#.to_vec(),          content_type: ContentType::Text };
+		assert_ne!(body2, body4);
+	}
+	#[test]
+	fn partial_eq__json_invalid() {
+		let body1 = UnpackedResponseBody { body: b"This is a test".to_vec(),     content_type: ContentType::Json };
+		let body2 = UnpackedResponseBody { body: b"This is a test".to_vec(),     content_type: ContentType::Text };
+		let body3 = UnpackedResponseBody { body: b" This is a test ".to_vec(),   content_type: ContentType::Json };
+		assert_eq!(body1, body2);
+		assert_ne!(body1, body3);
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize__binary() {
+		let body        = UnpackedResponseBody {
+			body:         b"This is a test".to_vec(),
+			content_type: ContentType::Binary,
+		};
+		let json        = json!("VGhpcyBpcyBhIHRlc3Q=");
+		assert_json_eq!(json!(body), json);
+	}
+	#[test]
+	fn serialize__text() {
+		let body        = UnpackedResponseBody {
+			body:         b"This is a test".to_vec(),
+			content_type: ContentType::Text,
+		};
+		let json        = json!("This is a test");
+		assert_json_eq!(json!(body), json);
+	}
+	#[test]
+	fn serialize__json() {
+		let body        = UnpackedResponseBody {
+			body:         br#"{"foo":[1,2]}"#.to_vec(),
+			content_type: ContentType::Json,
+		};
+		let json        = json!(r#"{"foo":[1,2]}"#);
+		assert_json_eq!(json!(body), json);
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize__binary() {
+		let json        = r#""VGhpcyBpcyBhIHRlc3Q=""#;
+		let body        = UnpackedResponseBody {
+			body:         b"This is a test".to_vec(),
+			content_type: ContentType::Text,
+		};
+		assert_ok_eq!(serde_json::from_str::<UnpackedResponseBody>(json), body);
+	}
+	#[test]
+	fn deserialize__text() {
+		let json        = r#""This is a test""#;
+		let body        = UnpackedResponseBody {
+			body:         b"This is a test".to_vec(),
+			content_type: ContentType::Text,
+		};
+		assert_ok_eq!(serde_json::from_str::<UnpackedResponseBody>(json), body);
+	}
+	
+	//		write_str															
+	#[test]
+	fn write_str() {
+		let mut body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
+		assert_ok!(body.write_str(" a test"));
+		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	}
+}
+
+//		UnpackedResponseDiff													
+#[cfg(test)]
+mod unpacked_response_diff__struct {
+	use super::*;
+	
+	//		is_empty															
+	#[test]
+	fn is_empty() {
+		assert!(UnpackedResponseDiff::default().is_empty());
+		assert!(!UnpackedResponseDiff {
+			status:  Some((StatusCode::OK, StatusCode::NOT_FOUND)),
+			..Default::default()
+		}.is_empty());
+		assert!(!UnpackedResponseDiff {
+			headers: vec![HeaderDiff::Added(UnpackedResponseHeader::new(s!("Foo"), s!("Bar")))],
+			..Default::default()
+		}.is_empty());
+		assert!(!UnpackedResponseDiff {
+			body:    Some(BodyDiff::Binary { offset: 0, left_len: 0, right_len: 1 }),
+			..Default::default()
+		}.is_empty());
+	}
+}
+
+#[cfg(test)]
+mod unpacked_response_diff__traits {
+	use super::*;
+	
+	//		display																
+	#[test]
+	fn display__empty() {
+		assert_eq!(UnpackedResponseDiff::default().to_string(), "No differences");
+	}
+	#[test]
+	fn display__headers_and_text() {
+		let diff = UnpackedResponseDiff {
+			status:  Some((StatusCode::OK, StatusCode::NOT_FOUND)),
+			headers: vec![
+				HeaderDiff::Changed {
+					name:  s!("Baz"),
+					left:  vec![s!("Foo"), s!("Bar")],
+					right: vec![s!("Qux")],
+				},
+				HeaderDiff::Removed(UnpackedResponseHeader::new(s!("Foo"), s!("Bar"))),
+				HeaderDiff::Added(UnpackedResponseHeader::new(s!("Qux"), s!("Foo"))),
+			],
+			body:    Some(BodyDiff::Text(vec![
+				TextLineDiff::Removed { line: 2, text: s!("Bar") },
+				TextLineDiff::Added   { line: 2, text: s!("Qux") },
+			])),
+		};
+		assert_eq!(diff.to_string(), concat!(
+			"Status: 200 OK != 404 Not Found\n",
+			"Headers:\n",
+			"  ~ Baz: [Foo, Bar] != [Qux]\n",
+			"  - Foo: Bar\n",
+			"  + Qux: Foo\n",
+			"Body (text):\n",
+			"  - 2: Bar\n",
+			"  + 2: Qux\n",
+		));
+	}
+	#[test]
+	fn display__json() {
+		let diff = UnpackedResponseDiff {
+			body: Some(BodyDiff::Json(vec![
+				JsonPathDiff::Removed { path: s!("$.bar[1]"), value: json!(2) },
+				JsonPathDiff::Changed { path: s!("$.foo"),    left: json!(1), right: json!("1") },
+				JsonPathDiff::Added   { path: s!("$.qux"),    value: json!({ "a": null }) },
+			])),
+			..Default::default()
+		};
+		assert_eq!(diff.to_string(), concat!(
+			"Body (JSON):\n",
+			"  - $.bar[1]: 2\n",
+			"  ~ $.foo: 1 != \"1\"\n",
+			"  + $.qux: {\"a\":null}\n",
+		));
+	}
+	#[test]
+	fn display__binary() {
+		let diff = UnpackedResponseDiff {
+			body: Some(BodyDiff::Binary { offset: 2, left_len: 4, right_len: 3 }),
+			..Default::default()
+		};
+		assert_eq!(diff.to_string(), "Body (binary): first difference at byte offset 2 (left length 4, right length 3)\n");
+	}
+}
+
+//§		RequestExt																
+#[cfg(test)]
+mod request_ext {
+	use super::*;
+	
+	//		unpack																
+	#[test]
+	fn unpack__basic() {
+		let mut request = Request::builder()
+			.method(Method::GET)
+			.uri("/foo")
+			.header("foo", "bar")
+			.body(())
+			.unwrap()
+		;
+		let unpacked    = request.unpack();
+		let crafted     = UnpackedRequest {
+			method:       Method::GET,
+			uri:          Uri::from_static("/foo"),
+			version:      Version::HTTP_11,
+			headers:      vec![
+				UnpackedResponseHeader {
+					name:  s!("foo"),
+					value: s!("bar"),
+				},
+			],
+			body:         UnpackedResponseBody { body: b"".to_vec(), ..Default::default() },
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	#[test]
+	fn unpack__string() {
+		let mut request = Request::builder()
+			.method(Method::POST)
+			.uri("/foo")
+			.body(s!("This is a test"))
+			.unwrap()
+		;
+		let unpacked    = request.unpack();
+		let crafted     = UnpackedRequest {
+			method:       Method::POST,
+			uri:          Uri::from_static("/foo"),
+			version:      Version::HTTP_11,
+			headers:      vec![],
+			body:         UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	#[test]
+	fn unpack__hyper_body() {
+		let mut request = Request::builder()
+			.method(Method::PUT)
+			.uri("/foo")
+			.body(Full::new(Bytes::from("This is a test")))
+			.unwrap()
+		;
+		let unpacked    = request.unpack();
+		let crafted     = UnpackedRequest {
+			method:       Method::PUT,
+			uri:          Uri::from_static("/foo"),
+			version:      Version::HTTP_11,
+			headers:      vec![],
+			body:         UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	#[tokio::test]
+	async fn unpack__incoming() {
+		let (address, unpacked) = unpack_incoming_request_for_testing(
+			Method::POST,
+			"/foo?bar=baz",
+			Bytes::from("This is a test"),
+		).await;
+		let crafted             = UnpackedRequest {
+			method:               Method::POST,
+			uri:                  Uri::from_static("/foo?bar=baz"),
+			version:              Version::HTTP_11,
+			headers:              vec![
+				//	Hyper automatically adds these headers.
+				UnpackedResponseHeader {
+					name:         s!("content-length"),
+					value:        s!("14"),
+				},
+				UnpackedResponseHeader {
+					name:         s!("host"),
+					value:        address,
+				},
+			],
+			body:                 UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	#[test]
+	fn unpack__axum_body() {
+		let mut request = Request::builder()
+			.method(Method::POST)
+			.uri("/foo")
+			.header("content-type", "text/plain")
+			.body(AxumBody::from("This is a test"))
+			.unwrap()
+		;
+		let unpacked    = request.unpack();
+		let crafted     = UnpackedRequest {
+			method:       Method::POST,
+			uri:          Uri::from_static("/foo"),
+			version:      Version::HTTP_11,
+			headers:      vec![
+				UnpackedResponseHeader {
+					name:  s!("content-type"),
+					value: s!("text/plain"),
+				},
+			],
+			body:         UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+}
+
+//§		ResponseExt																
+#[cfg(test)]
+mod response_ext {
+	use super::*;
+	
+	//		unpack																
+	#[test]
+	fn unpack__basic() {
+		let mut response = Response::builder()
+			.status(StatusCode::OK)
+			.header("foo", "bar")
+			.body(())
+			.unwrap()
+		;
+		let unpacked     = response.unpack();
+		let crafted      = UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![
+				UnpackedResponseHeader {
+					name:  s!("foo"),
+					value: s!("bar"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	#[test]
+	fn unpack__string() {
+		let mut response = Response::builder()
+			.status(StatusCode::OK)
+			.body(s!("This is a test"))
+			.unwrap()
+		;
+		let unpacked     = response.unpack();
+		let crafted      = UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	#[test]
+	fn unpack__hyper_body() {
+		let mut response = Response::builder()
+			.status(StatusCode::OK)
+			.body(Full::new(Bytes::from("This is a test")))
+			.unwrap()
+		;
+		let unpacked     = response.unpack();
+		let crafted      = UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	#[test]
+	fn unpack__axum_body() {
+		let mut response = (
+			StatusCode::OK,
+			"This is a test",
+		).into_response();
+		let unpacked     = response.unpack();
+		let crafted      = UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![
+				//  Axum automatically adds a content-type header.
+				UnpackedResponseHeader {
+					name:  s!("content-type"),
+					value: s!("text/plain; charset=utf-8"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	#[test]
+	fn unpack__content_type_detection() {
+		let mut response1 = Response::builder()
+			.header("content-type", "image/png")
+			.body(Full::new(Bytes::from_static(b"\x89PNG")))
+			.unwrap()
+		;
+		assert_eq!(response1.unpack().unwrap().body.content_type(), ContentType::Binary);
+		
+		let mut response2 = Response::builder()
+			.header("content-type", "application/json")
+			.body(s!(r#"{"foo":"bar"}"#))
+			.unwrap()
+		;
+		assert_eq!(response2.unpack().unwrap().body.content_type(), ContentType::Json);
+		
+		let mut response3 = Response::builder()
+			.body(Full::new(Bytes::from_static(b"\xFF\xFE")))
+			.unwrap()
+		;
+		assert_eq!(response3.unpack().unwrap().body.content_type(), ContentType::Binary);
+		
+		let mut response4 = (StatusCode::OK, "This is a test").into_response();
+		assert_eq!(response4.unpack().unwrap().body.content_type(), ContentType::Text);
+	}
+	
+	//		unpack_with															
+	#[test]
+	fn unpack_with__content_type() {
+		let mut response1 = Response::builder()
+			.header("content-type", "application/x-custom")
+			.body(s!("This is a test"))
+			.unwrap()
+		;
+		let unpacked1     = response1.unpack_with(UnpackOptions::new().with_content_type("application/x-custom", ContentType::Binary)).unwrap();
+		assert_eq!(unpacked1.body.content_type(), ContentType::Binary);
+		
+		let mut response2 = Response::builder()
+			.header("content-type", "image/png")
+			.body(s!("This is a test"))
+			.unwrap()
+		;
+		let unpacked2     = response2.unpack_with(UnpackOptions::new().with_content_detection(false)).unwrap();
+		assert_eq!(unpacked2.body.content_type(), ContentType::Text);
+	}
+	#[test]
+	fn unpack_with__limit() {
+		let mut response1 = Response::builder()
+			.body(s!("This is a test"))
+			.unwrap()
+		;
+		let err1          = response1.unpack_with(UnpackOptions::new().with_limit(13)).unwrap_err();
+		assert!(matches!(err1, ResponseError::LimitExceeded(13)));
+		
+		let mut response2 = Response::builder()
+			.body(Full::new(Bytes::from("This is a test")))
+			.unwrap()
+		;
+		let err2          = response2.unpack_with(UnpackOptions::new().with_limit(13)).unwrap_err();
+		assert!(matches!(err2, ResponseError::LimitExceeded(13)));
+		
+		let mut response3 = (StatusCode::OK, "This is a test").into_response();
+		let err3          = response3.unpack_with(UnpackOptions::new().with_limit(13)).unwrap_err();
+		assert!(matches!(err3, ResponseError::LimitExceeded(13)));
+		
+		let mut response4 = Response::builder()
+			.body(Full::new(Bytes::from("This is a test")))
+			.unwrap()
+		;
+		let unpacked4     = response4.unpack_with(UnpackOptions::new().with_limit(14));
+		assert_ok_eq!(unpacked4, UnpackedResponse::new(StatusCode::OK, vec![], "This is a test"));
+	}
+	#[test]
+	fn unpack_with__raw_header_values() {
+		let response = || Response::builder()
+			.header("x-foo", HeaderValue::from_bytes(&[b'a', 0xFF]).unwrap())
+			.body(s!(""))
+			.unwrap()
+		;
+		let unpacked = response().unpack_with(UnpackOptions::new().with_raw_header_values(true)).unwrap();
+		assert_eq!(unpacked.headers,     vec![UnpackedResponseHeader::new(s!("x-foo"), s!("a\u{FFFD}"))]);
+		assert_eq!(unpacked.raw_headers, vec![UnpackedRawHeader::new(s!("x-foo"), vec![b'a', 0xFF])]);
+		assert_eq!(unpacked, response().unpack().unwrap());
+		assert!(response().unpack().unwrap().raw_headers.is_empty());
+	}
+	#[test]
+	fn unpack_with__strict_utf8() {
+		let response = |content_type: &str| Response::builder()
+			.header(CONTENT_TYPE, content_type)
+			.body(Full::new(Bytes::from_static(&[b'a', 0xFF])))
+			.unwrap()
+		;
+		let strict   = UnpackOptions::new().with_strict_utf8(true);
+		let err      = response("text/plain; charset=utf-8").unpack_with(strict.clone()).unwrap_err();
+		assert!(matches!(err, ResponseError::InvalidUtf8(ref inner) if inner.valid_up_to() == 1));
+		assert!(matches!(response("application/json").unpack_with(strict.clone()), Err(ResponseError::InvalidUtf8(_))));
+		assert_ok!(response("application/octet-stream").unpack_with(strict.clone()));
+		assert_eq!(response("text/plain").unpack_with(UnpackOptions::new()).unwrap().body.content_type(), ContentType::Binary);
+		let custom   = strict.with_content_type("text/plain", ContentType::Binary);
+		assert_ok!(response("text/plain").unpack_with(custom));
+	}
+	#[test]
+	fn unpack_with__decompression() {
+		let mut response1 = Response::builder()
+			.header("content-encoding", "gzip")
+			.header("content-length",   "34")
+			.header("content-type",     "application/json")
+			.body(Full::new(Bytes::from(compress_bytes(br#"{"foo":"bar"}"#, ContentEncoding::Gzip).unwrap())))
+			.unwrap()
+		;
+		let unpacked1     = response1.unpack_with(UnpackOptions::new().with_decompression(true)).unwrap();
+		let crafted1      = UnpackedResponse::new(StatusCode::OK, vec![
+			(s!("content-length"), s!("13")),
+			(s!("content-type"),   s!("application/json")),
+		], r#"{"foo":"bar"}"#);
+		assert_eq!(unpacked1,                     crafted1);
+		assert_eq!(unpacked1.body.content_type(), ContentType::Json);
+		
+		let compressed    = compress_bytes(&compress_bytes(b"This is a test", ContentEncoding::Deflate).unwrap(), ContentEncoding::Brotli).unwrap();
+		let mut response2 = Response::builder()
+			.header("content-encoding", "deflate, BR")
+			.body(Full::new(Bytes::from(compressed.clone())))
+			.unwrap()
+		;
+		let unpacked2     = response2.unpack_with(UnpackOptions::new().with_decompression(true));
+		assert_ok_eq!(unpacked2, UnpackedResponse::new(StatusCode::OK, vec![], "This is a test"));
+		
+		let mut response3 = Response::builder()
+			.header("content-encoding", "deflate, br")
+			.body(Full::new(Bytes::from(compressed.clone())))
+			.unwrap()
+		;
+		let unpacked3     = response3.unpack().unwrap();
+		assert_eq!(unpacked3.headers,    vec![UnpackedResponseHeader::new(s!("content-encoding"), s!("deflate, br"))]);
+		assert_eq!(unpacked3.body.body,  compressed);
+		
+		let mut response4 = Response::builder()
+			.header("content-encoding", "identity")
+			.body(s!("This is a test"))
+			.unwrap()
+		;
+		let unpacked4     = response4.unpack_with(UnpackOptions::new().with_decompression(true));
+		assert_ok_eq!(unpacked4, UnpackedResponse::new(StatusCode::OK, vec![], "This is a test"));
+	}
+	#[test]
+	fn unpack_with__decompression_errors() {
+		let mut response1 = Response::builder()
+			.header("content-encoding", "zstd")
+			.body(s!("This is a test"))
+			.unwrap()
+		;
+		let err1          = response1.unpack_with(UnpackOptions::new().with_decompression(true)).unwrap_err();
+		assert!(matches!(err1, ResponseError::UnsupportedEncoding(ref name) if name == "zstd"));
+		
+		let mut response2 = Response::builder()
+			.header("content-encoding", "gzip")
+			.body(s!("This is a test"))
+			.unwrap()
+		;
+		let err2          = response2.unpack_with(UnpackOptions::new().with_decompression(true)).unwrap_err();
+		assert!(matches!(err2, ResponseError::DecompressionError(_)));
+		
+		let mut response3 = Response::builder()
+			.header("content-encoding", "gzip")
+			.body(Full::new(Bytes::from(compress_bytes(&[b'a'; 1_000], ContentEncoding::Gzip).unwrap())))
+			.unwrap()
+		;
+		let err3          = response3.unpack_with(UnpackOptions::new().with_decompression(true).with_limit(100)).unwrap_err();
+		assert!(matches!(err3, ResponseError::LimitExceeded(100)));
+	}
+	#[test]
+	fn unpack_with__frames_hyper_body() {
+		let mut response = Response::new(Full::new(Bytes::from("This is a test")));
+		let unpacked     = response.unpack_with(UnpackOptions::new().with_frame_recording(true)).unwrap();
+		assert_eq!(unpacked.frames, vec![UnpackedFrame::Data(UnpackedResponseBody::new("This is a test"))]);
+	}
+	#[test]
+	fn unpack_with__frames_string() {
+		let mut response = Response::new(s!("This is a test"));
+		let unpacked     = response.unpack_with(UnpackOptions::new().with_frame_recording(true)).unwrap();
+		assert_eq!(unpacked.frames, vec![UnpackedFrame::Data(UnpackedResponseBody::new("This is a test"))]);
+		assert!(Response::new(s!("This is a test")).unpack().unwrap().frames.is_empty());
+	}
+	#[test]
+	fn unpack_with__timeout_frames() {
+		let mut response = Response::new(AxumBody::new(create_stalled_body_for_testing()));
+		let options      = UnpackOptions::new().with_frame_recording(true).with_timeout(Duration::from_millis(50));
+		let err          = response.unpack_with(options).unwrap_err();
+		let ResponseError::Timeout { partial, .. } = err else {
+			panic!("Expected a timeout error, got {err:?}");
+		};
+		assert_eq!(partial.frames, vec![UnpackedFrame::Data(UnpackedResponseBody::new("data: one\n\n"))]);
+	}
+	
+	//		unpack_with_timeout													
+	#[test]
+	fn unpack_with_timeout__complete() {
+		let mut response = Response::new(Full::new(Bytes::from("This is a test")));
+		let unpacked     = response.unpack_with_timeout(Duration::from_secs(5));
+		assert_ok_eq!(unpacked, UnpackedResponse::new(StatusCode::OK, vec![], "This is a test"));
+	}
+	#[test]
+	fn unpack_with_timeout__stalled() {
+		let mut response = Response::builder()
+			.status(StatusCode::ACCEPTED)
+			.header("content-type", "text/event-stream")
+			.body(AxumBody::new(create_stalled_body_for_testing()))
+			.unwrap()
+		;
+		let err          = response.unpack_with_timeout(Duration::from_millis(50)).unwrap_err();
+		assert_eq!(err.to_string(), "Timed out after 50ms while reading response body");
+		let ResponseError::Timeout { duration, partial } = err else {
+			panic!("Expected a timeout error, got {err:?}");
+		};
+		assert_eq!(duration,        Duration::from_millis(50));
+		assert_eq!(partial.status,  StatusCode::ACCEPTED);
+		assert_eq!(partial.headers, vec![UnpackedResponseHeader::new(s!("content-type"), s!("text/event-stream"))]);
+		assert_eq!(partial.body,    UnpackedResponseBody::new("data: one\n\n"));
+		assert!(partial.frames.is_empty());
+	}
+}
+
+//§		AsyncResponseExt														
+#[cfg(test)]
+mod async_response_ext {
+	use super::*;
+	
+	//		unpack_async														
+	#[tokio::test]
+	async fn unpack_async__string() {
+		let mut response = Response::builder()
+			.status(StatusCode::OK)
+			.body(s!("This is a test"))
+			.unwrap()
+		;
+		let unpacked     = response.unpack_async().await;
+		let crafted      = UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	#[tokio::test]
+	async fn unpack_async__hyper_body() {
+		let mut response = Response::builder()
+			.status(StatusCode::OK)
+			.body(Full::new(Bytes::from("This is a test")))
+			.unwrap()
+		;
+		let unpacked     = response.unpack_async().await;
+		let crafted      = UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	#[tokio::test]
+	async fn unpack_async__incoming() {
+		let mut response = Response::new(create_incoming_for_testing(Bytes::from("This is a test")).await);
+		let unpacked     = response.unpack_async().await;
+		let crafted      = UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	#[tokio::test]
+	async fn unpack_async__axum_body() {
+		let mut response = (
+			StatusCode::OK,
+			"This is a test",
+		).into_response();
+		let unpacked     = response.unpack_async().await;
+		let crafted      = UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![
+				//  Axum automatically adds a content-type header.
+				UnpackedResponseHeader {
+					name:  s!("content-type"),
+					value: s!("text/plain; charset=utf-8"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	
+	//		unpack_async_with_limit												
+	#[tokio::test]
+	async fn unpack_async_with_limit__within() {
+		let mut response = Response::builder()
+			.status(StatusCode::OK)
+			.body(Full::new(Bytes::from("This is a test")))
+			.unwrap()
+		;
+		let unpacked     = response.unpack_async_with_limit(14).await;
+		let crafted      = UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		};
+		assert_ok_eq!(unpacked, crafted);
+	}
+	#[tokio::test]
+	async fn unpack_async_with_limit__exceeded() {
+		let mut response = Response::builder()
+			.status(StatusCode::OK)
+			.body(Full::new(Bytes::from("This is a test")))
+			.unwrap()
+		;
+		let err          = response.unpack_async_with_limit(13).await.unwrap_err();
+		assert!(matches!(err, ResponseError::LimitExceeded(13)));
+	}
+	#[tokio::test]
+	async fn unpack_async_with_limit__exceeded_incoming() {
+		let mut response = Response::new(create_incoming_for_testing(Bytes::from("This is a test")).await);
+		let err          = response.unpack_async_with_limit(4).await.unwrap_err();
+		assert!(matches!(err, ResponseError::LimitExceeded(4)));
+	}
+	
+	//		unpack_async_with													
+	#[tokio::test]
+	async fn unpack_async_with__content_type() {
+		let mut response = Response::builder()
+			.header("content-type", "application/vnd.api+json")
+			.body(Full::new(Bytes::from(r#"{"foo":"bar"}"#)))
+			.unwrap()
+		;
+		let unpacked     = response.unpack_async_with(UnpackOptions::new()).await.unwrap();
+		assert_eq!(unpacked.body.content_type(), ContentType::Json);
+		assert_eq!(unpacked.body.as_json().unwrap(), json!({ "foo": "bar" }));
+	}
+	#[tokio::test]
+	async fn unpack_async_with__limit() {
+		let mut response = Response::builder()
+			.body(Full::new(Bytes::from("This is a test")))
+			.unwrap()
+		;
+		let err          = response.unpack_async_with(UnpackOptions::new().with_limit(5)).await.unwrap_err();
+		assert!(matches!(err, ResponseError::LimitExceeded(5)));
+	}
+	#[tokio::test]
+	async fn unpack_async_with__decompression() {
+		let mut response = Response::builder()
+			.header("content-encoding", "br")
+			.body(Full::new(Bytes::from(compress_bytes(b"This is a test", ContentEncoding::Brotli).unwrap())))
+			.unwrap()
+		;
+		let unpacked     = response.unpack_async_with(UnpackOptions::new().with_decompression(true)).await;
+		assert_ok_eq!(unpacked, UnpackedResponse::new(StatusCode::OK, vec![], "This is a test"));
+	}
+	#[tokio::test]
+	async fn unpack_async_with__frames() {
+		let mut response = Response::builder()
+			.header("content-type", "text/event-stream")
+			.body(create_stream_body_for_testing())
+			.unwrap()
+		;
+		let unpacked     = response.unpack_async_with(UnpackOptions::new().with_frame_recording(true)).await.unwrap();
+		assert_eq!(unpacked.body,   UnpackedResponseBody::new("data: one\n\ndata: two\n\n"));
+		assert_eq!(unpacked.frames, vec![
+			UnpackedFrame::Data(UnpackedResponseBody::new("data: one\n\n")),
+			UnpackedFrame::Data(UnpackedResponseBody::new("data: two\n\n")),
+			UnpackedFrame::Trailers(vec![UnpackedResponseHeader::new(s!("x-checksum"), s!("abc123"))]),
+		]);
+		assert_eq!(unpacked.trailers(),             vec![&UnpackedResponseHeader::new(s!("x-checksum"), s!("abc123"))]);
+		assert_eq!(unpacked.body.as_sse_events(),   vec![ServerSentEvent::new("one"), ServerSentEvent::new("two")]);
+	}
+	#[tokio::test]
+	async fn unpack_async_with__frames_not_recorded() {
+		let mut response = Response::new(create_stream_body_for_testing());
+		let unpacked     = response.unpack_async().await.unwrap();
+		assert_eq!(unpacked.body, UnpackedResponseBody::new("data: one\n\ndata: two\n\n"));
+		assert!(unpacked.frames.is_empty());
+	}
+	#[tokio::test]
+	async fn unpack_async_with__frames_limit_exceeded() {
+		let mut response = Response::new(create_stream_body_for_testing());
+		let err          = response.unpack_async_with(UnpackOptions::new().with_frame_recording(true).with_limit(15)).await.unwrap_err();
+		assert!(matches!(err, ResponseError::LimitExceeded(15)));
+	}
+	
+	//		unpack_async_with_timeout											
+	#[tokio::test]
+	async fn unpack_async_with_timeout__complete() {
+		let mut response = Response::new(create_stream_body_for_testing());
+		let unpacked     = response.unpack_async_with_timeout(Duration::from_secs(5)).await.unwrap();
+		assert_eq!(unpacked.body, UnpackedResponseBody::new("data: one\n\ndata: two\n\n"));
+		assert!(unpacked.frames.is_empty());
+	}
+	#[tokio::test]
+	async fn unpack_async_with_timeout__stalled() {
+		let mut response = Response::new(create_stalled_body_for_testing());
+		let err          = response.unpack_async_with_timeout(Duration::from_millis(50)).await.unwrap_err();
+		let ResponseError::Timeout { duration, partial } = err else {
+			panic!("Expected a timeout error, got {err:?}");
+		};
+		assert_eq!(duration,     Duration::from_millis(50));
+		assert_eq!(partial.body, UnpackedResponseBody::new("data: one\n\n"));
+	}
+	#[tokio::test]
+	async fn unpack_async_with_timeout__incoming() {
+		let mut response = Response::new(create_incoming_for_testing(Bytes::from("This is a test")).await);
+		let unpacked     = response.unpack_async_with_timeout(Duration::from_secs(5)).await;
+		assert_ok_eq!(unpacked, UnpackedResponse::new(StatusCode::OK, vec![], "This is a test"));
+	}
+}
+
+//§		BlockingResponseExt														
+#[cfg(test)]
+mod blocking_response_ext {
+	use super::*;
+	
+	//		unpack_blocking														
+	#[test]
+	fn unpack_blocking__box_body() {
+		let mut response = Response::builder()
+			.status(StatusCode::CREATED)
+			.header("content-type", "application/json")
+			.body(Full::new(Bytes::from(r#"{"foo":"bar"}"#)).boxed())
+			.unwrap()
+		;
+		let unpacked     = response.unpack_blocking().unwrap();
+		assert_eq!(unpacked.status,                   StatusCode::CREATED);
+		assert_eq!(unpacked.body.content_type(),      ContentType::Json);
+		assert_eq!(unpacked.body.as_json().unwrap(),  json!({"foo": "bar"}));
+	}
+	#[test]
+	fn unpack_blocking__unsync_box_body() {
+		let mut response = Response::new(Full::new(Bytes::from("This is a test")).boxed_unsync());
+		assert_ok_eq!(response.unpack_blocking(), UnpackedResponse::new(StatusCode::OK, vec![], "This is a test"));
+	}
+	#[test]
+	fn unpack_blocking__empty() {
+		let mut response = Response::new(Empty::<Bytes>::new());
+		assert_ok_eq!(response.unpack_blocking(), UnpackedResponse::new(StatusCode::OK, vec![], ""));
+	}
+	#[test]
+	fn unpack_blocking__limited() {
+		let mut response = Response::new(Limited::new(Full::new(Bytes::from("This is a test")), 100));
+		assert_ok_eq!(response.unpack_blocking(), UnpackedResponse::new(StatusCode::OK, vec![], "This is a test"));
+	}
+	#[test]
+	fn unpack_blocking__stream() {
+		let mut response = Response::new(create_stream_body_for_testing());
+		let unpacked     = response.unpack_blocking().unwrap();
+		assert_eq!(unpacked.body, UnpackedResponseBody::new("data: one\n\ndata: two\n\n"));
+		assert!(unpacked.frames.is_empty());
+	}
+	#[test]
+	fn unpack_blocking__consumes_body() {
+		let mut response = Response::new(Full::new(Bytes::from("This is a test")).boxed());
+		assert_eq!(response.unpack_blocking().unwrap().body, UnpackedResponseBody::new("This is a test"));
+		assert_eq!(response.unpack_blocking().unwrap().body, UnpackedResponseBody::new(""));
+	}
+	#[test]
+	fn unpack_blocking__matches_specialisation() {
+		let mut response1 = Response::builder()
+			.header("content-type", "text/plain")
+			.header("x-foo",        "bar")
+			.body(Full::new(Bytes::from("This is a test")))
+			.unwrap()
+		;
+		let mut response2 = Response::builder()
+			.header("content-type", "text/plain")
+			.header("x-foo",        "bar")
+			.body(Full::new(Bytes::from("This is a test")).boxed())
+			.unwrap()
+		;
+		assert_eq!(response1.unpack().unwrap(), response2.unpack_blocking().unwrap());
+	}
+	
+	//		unpack_blocking_with												
+	#[test]
+	fn unpack_blocking_with__frames() {
+		let mut response = Response::new(create_stream_body_for_testing().boxed_unsync());
+		let unpacked     = response.unpack_blocking_with(UnpackOptions::new().with_frame_recording(true)).unwrap();
+		assert_eq!(unpacked.frames.len(), 3);
+		assert_eq!(unpacked.trailers(),   vec![&UnpackedResponseHeader::new(s!("x-checksum"), s!("abc123"))]);
+	}
+	#[test]
+	fn unpack_blocking_with__limit() {
+		let mut response = Response::new(Full::new(Bytes::from("This is a test")).boxed());
+		let err          = response.unpack_blocking_with(UnpackOptions::new().with_limit(5)).unwrap_err();
+		assert!(matches!(err, ResponseError::LimitExceeded(5)));
+	}
+	#[test]
+	fn unpack_blocking_with__timeout() {
+		let mut response = Response::new(create_stalled_body_for_testing());
+		let err          = response.unpack_blocking_with(UnpackOptions::new().with_timeout(Duration::from_millis(50))).unwrap_err();
+		let ResponseError::Timeout { partial, .. } = err else {
+			panic!("Expected a timeout error, got {err:?}");
+		};
+		assert_eq!(partial.body, UnpackedResponseBody::new("data: one\n\n"));
+	}
+}
+
+//		assert_response_eq!														
+#[cfg(test)]
+mod macros {
+	use super::*;
+	
+	//		assert_cookie_eq													
+	#[test]
+	fn assert_cookie_eq__equal() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("set-cookie"), s!("foo=bar; Max-Age=60; HttpOnly"))], "");
+		assert_cookie_eq!(response.cookie("foo"), UnpackedCookie::new("foo", "bar").with_http_only(true));
+		assert_cookie_eq!(UnpackedCookie::new("foo", "bar").with_max_age(10), UnpackedCookie::new("foo", "bar"), "Cookies should match");
+	}
+	#[test]
+	fn assert_cookie_eq__both_missing() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![], "");
+		assert_cookie_eq!(response.cookie("foo"), None);
+		assert_cookie_eq!(response.cookie("foo"), None, "Cookies should both be missing");
+	}
+	#[test]
+	#[should_panic(expected = "assertion `left == right` failed: cookies differ\n  left: Some(UnpackedCookie { name: \"foo\", value: \"bar\"")]
+	fn assert_cookie_eq__different() {
+		assert_cookie_eq!(UnpackedCookie::new("foo", "bar"), UnpackedCookie::new("foo", "baz"));
+	}
+	#[test]
+	#[should_panic(expected = "assertion `left == right` failed: Custom message 42\n  left: None")]
+	fn assert_cookie_eq__missing_with_message() {
+		let response = UnpackedResponse::new(StatusCode::OK, vec![], "");
+		assert_cookie_eq!(response.cookie("foo"), UnpackedCookie::new("foo", "bar"), "Custom message {}", 42);
+	}
+	#[test]
+	#[should_panic(expected = "assertion `left == right` failed: cookies differ\n  left: Some(UnpackedCookie { name: \"foo\", value: \"bar\"")]
+	fn assert_cookie_eq__unexpected() {
+		assert_cookie_eq!(UnpackedCookie::new("foo", "bar"), None);
+	}
+	
+	//		assert_response_eq													
+	#[test]
+	fn assert_response_eq__equal() {
+		let left  = UnpackedResponse::new(StatusCode::OK, vec![(s!("Foo"), s!("Bar"))], "This is a test");
+		let right = UnpackedResponse::new(StatusCode::OK, vec![(s!("Foo"), s!("Bar"))], "This is a test");
+		assert_response_eq!(left, right);
+		assert_response_eq!(left, right, "Responses should match");
+	}
+	#[test]
+	#[should_panic(expected = "assertion `left == right` failed: responses differ\nStatus: 200 OK != 404 Not Found\n")]
+	fn assert_response_eq__different() {
+		let left  = UnpackedResponse::new(StatusCode::OK,        vec![], "This is a test");
+		let right = UnpackedResponse::new(StatusCode::NOT_FOUND, vec![], "This is a test");
+		assert_response_eq!(left, right);
+	}
+	#[test]
+	#[should_panic(expected = "assertion `left == right` failed: Custom message 42\nBody (text):\n  - 1: Foo\n  + 1: Bar\n")]
+	fn assert_response_eq__different_with_message() {
+		let left  = UnpackedResponse::new(StatusCode::OK, vec![], "Foo");
+		let right = UnpackedResponse::new(StatusCode::OK, vec![], "Bar");
+		assert_response_eq!(left, right, "Custom message {}", 42);
+	}
+	
+	//		assert_response_snapshot											
+	#[test]
+	fn assert_response_snapshot__matched() {
+		let dir      = tempdir().unwrap();
+		let options  = SnapshotOptions::new().with_directory(dir.path()).with_update(false);
+		let response = UnpackedResponse::new(StatusCode::OK, vec![(s!("foo"), s!("bar"))], "This is a test");
+		assert_response_snapshot!(response, "foo", options);
+		assert!(dir.path().join("foo.json").exists());
+		assert_response_snapshot!(&response, "foo", options);
+	}
+	#[test]
+	#[should_panic(expected = "assertion failed: Response does not match snapshot ")]
+	fn assert_response_snapshot__different() {
+		let dir     = tempdir().unwrap();
+		let options = SnapshotOptions::new().with_directory(dir.path()).with_update(false);
+		assert_response_snapshot!(UnpackedResponse::new(StatusCode::OK,        vec![], ""), "foo", options);
+		assert_response_snapshot!(UnpackedResponse::new(StatusCode::NOT_FOUND, vec![], ""), "foo", options);
+	}
+}
+
+//		Functions																
+#[cfg(test)]
+mod functions {
+	use super::*;
+	
+	//		body_error															
+	#[test]
+	fn body_error__limit_exceeded() {
+		let err = Limited::new(Full::new(Bytes::from("This is a test")), 5).collect().now_or_never().unwrap().unwrap_err();
+		assert!(matches!(body_error(err, 5), ResponseError::LimitExceeded(5)));
+	}
+	#[test]
+	fn body_error__other() {
+		let err = body_error(Box::new(IoError::other("Test error")), 5);
+		assert!(matches!(err, ResponseError::BodyReadError(_)));
+		assert_eq!(err.source().unwrap().to_string(), "Test error");
+	}
+	
+	//		check_utf8															
+	#[test]
+	fn check_utf8__disabled() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain")));
+		assert_ok!(check_utf8(&headers, &[0xFF], &UnpackOptions::new()));
+	}
+	#[test]
+	fn check_utf8__enabled() {
+		let options     = UnpackOptions::new().with_strict_utf8(true);
+		let mut headers = HeaderMap::new();
+		assert_ok!(check_utf8(&headers, &[0xFF], &options));
+		drop(headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain")));
+		assert_ok!(check_utf8(&headers, b"Valid", &options));
+		assert!(matches!(check_utf8(&headers, &[0xFF], &options), Err(ResponseError::InvalidUtf8(_))));
+		drop(headers.insert(CONTENT_TYPE, HeaderValue::from_static("image/png")));
+		assert_ok!(check_utf8(&headers, &[0xFF], &options));
+	}
+	
+	//		collect_body														
+	#[tokio::test]
+	async fn collect_body__basic() {
+		let body = collect_body(Full::new(Bytes::from("This is a test")), usize::MAX).await;
+		assert_ok_eq!(body, Bytes::from("This is a test"));
+	}
+	#[tokio::test]
+	async fn collect_body__limit_exceeded() {
+		let err = collect_body(Full::new(Bytes::from("This is a test")), 5).await.unwrap_err();
+		assert!(matches!(err, ResponseError::LimitExceeded(5)));
+	}
+	
+	//		convert_headers														
+	#[test]
+	fn convert_headers__basic() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert("foo", HeaderValue::from_static("bar")));
+		let converted   = convert_headers(&headers, &UnpackOptions::default());
+		let crafted     = vec![
+			UnpackedResponseHeader {
+				name:     s!("foo"),
+				value:    s!("bar"),
+			},
+		];
+		assert_eq!(converted, crafted);
+	}
+	#[test]
+	fn convert_headers__textcase() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert("Foo", HeaderValue::from_static("Bar")));
+		let converted   = convert_headers(&headers, &UnpackOptions::default());
+		let crafted     = vec![
+			UnpackedResponseHeader {
+				name:     s!("foo"),
+				value:    s!("Bar"),
+			},
+		];
+		assert_eq!(converted, crafted);
+	}
+	#[test]
+	fn convert_headers__order() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert("foo", HeaderValue::from_static("bar")));
+		drop(headers.insert("bar", HeaderValue::from_static("baz")));
+		let converted   = convert_headers(&headers, &UnpackOptions::default());
+		let crafted1    = vec![
+			UnpackedResponseHeader {
+				name:     s!("foo"),
+				value:    s!("bar"),
+			},
+			UnpackedResponseHeader {
+				name:     s!("bar"),
+				value:    s!("baz"),
+			},
+		];
+		let crafted2    = vec![
+			UnpackedResponseHeader {
+				name:     s!("bar"),
+				value:    s!("baz"),
+			},
+			UnpackedResponseHeader {
+				name:     s!("foo"),
+				value:    s!("bar"),
+			},
+		];
+		assert_ne!(converted, crafted1);
+		assert_eq!(converted, crafted2);
+	}
+	#[test]
+	fn convert_headers__duplicates() {
+		let mut headers = HeaderMap::new();
+		_               = headers.append("foo", HeaderValue::from_static("bar"));
+		_               = headers.append("bar", HeaderValue::from_static("baz"));
+		_               = headers.append("foo", HeaderValue::from_static("baz"));
+		let converted   = convert_headers(&headers, &UnpackOptions::default());
+		let crafted     = vec![
+			UnpackedResponseHeader {
+				name:     s!("bar"),
+				value:    s!("baz"),
+			},
+			UnpackedResponseHeader {
+				name:     s!("foo"),
+				value:    s!("bar"),
+			},
+			UnpackedResponseHeader {
+				name:     s!("foo"),
+				value:    s!("baz"),
+			},
+		];
+		assert_eq!(converted, crafted);
+	}
+	#[test]
+	fn convert_headers__no_duplicates() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert("foo", HeaderValue::from_static("bar")));
+		drop(headers.insert("bar", HeaderValue::from_static("baz")));
+		drop(headers.insert("foo", HeaderValue::from_static("baz")));
+		let converted   = convert_headers(&headers, &UnpackOptions::default());
+		let crafted     = vec![
+			UnpackedResponseHeader {
+				name:     s!("bar"),
+				value:    s!("baz"),
+			},
+			UnpackedResponseHeader {
+				name:     s!("foo"),
+				value:    s!("baz"),
+			},
+		];
+		assert_eq!(converted, crafted);
+	}
+	
+	#[test]
+	fn convert_headers__preserved_order() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert("foo", HeaderValue::from_static("bar")));
+		_ = headers.append("set-cookie", HeaderValue::from_static("b=2"));
+		_ = headers.append("set-cookie", HeaderValue::from_static("a=1"));
+		let options     = UnpackOptions::new().with_preserved_header_order(true);
+		assert_eq!(convert_headers(&headers, &options), vec![
+			UnpackedResponseHeader::new(s!("foo"),        s!("bar")),
+			UnpackedResponseHeader::new(s!("set-cookie"), s!("b=2")),
+			UnpackedResponseHeader::new(s!("set-cookie"), s!("a=1")),
+		]);
+		assert_eq!(convert_headers(&headers, &UnpackOptions::default()), vec![
+			UnpackedResponseHeader::new(s!("foo"),        s!("bar")),
+			UnpackedResponseHeader::new(s!("set-cookie"), s!("a=1")),
+			UnpackedResponseHeader::new(s!("set-cookie"), s!("b=2")),
+		]);
+	}
+	#[test]
+	fn convert_headers__invalid_utf8() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert("foo", HeaderValue::from_bytes(&[b'a', 0xFF]).unwrap()));
+		assert_eq!(convert_headers(&headers, &UnpackOptions::default()), vec![
+			UnpackedResponseHeader::new(s!("foo"), s!("a\u{FFFD}")),
+		]);
+	}
+	
+	//		convert_raw_headers													
+	#[test]
+	fn convert_raw_headers__basic() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert("foo", HeaderValue::from_bytes(&[b'a', 0xFF]).unwrap()));
+		_ = headers.append("bar", HeaderValue::from_static("2"));
+		_ = headers.append("bar", HeaderValue::from_static("1"));
+		let options     = UnpackOptions::new().with_raw_header_values(true);
+		assert_eq!(convert_raw_headers(&headers, &options), vec![
+			UnpackedRawHeader::new(s!("bar"), b"1".to_vec()),
+			UnpackedRawHeader::new(s!("bar"), b"2".to_vec()),
+			UnpackedRawHeader::new(s!("foo"), vec![b'a', 0xFF]),
+		]);
+		assert_eq!(convert_raw_headers(&headers, &options.with_preserved_header_order(true)), vec![
+			UnpackedRawHeader::new(s!("foo"), vec![b'a', 0xFF]),
+			UnpackedRawHeader::new(s!("bar"), b"2".to_vec()),
+			UnpackedRawHeader::new(s!("bar"), b"1".to_vec()),
+		]);
+		assert_eq!(convert_raw_headers(&headers, &UnpackOptions::default()), vec![]);
+	}
+	
+	//		convert_read_body													
+	#[test]
+	fn convert_read_body__complete() {
+		let read      = ReadBody { bytes: Bytes::from("This is a test"), ..Default::default() };
+		let converted = convert_read_body(StatusCode::OK, &HeaderMap::new(), read, &UnpackOptions::new());
+		assert_ok_eq!(converted, UnpackedResponse::new(StatusCode::OK, vec![], "This is a test"));
+	}
+	#[test]
+	fn convert_read_body__timed_out() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert("content-type",     HeaderValue::from_static("text/plain")));
+		drop(headers.insert("content-encoding", HeaderValue::from_static("gzip")));
+		let read        = ReadBody { bytes: Bytes::from(vec![b'a', 0xFF]), timed_out: Some(Duration::from_secs(1)), ..Default::default() };
+		let options     = UnpackOptions::new().with_decompression(true).with_strict_utf8(true);
+		let err         = convert_read_body(StatusCode::OK, &headers, read, &options).unwrap_err();
+		let ResponseError::Timeout { duration, partial } = err else {
+			panic!("Expected a timeout error, got {err:?}");
+		};
+		assert_eq!(duration,                      Duration::from_secs(1));
+		assert_eq!(partial.body.as_bytes(),       &[b'a', 0xFF]);
+		assert_eq!(partial.body.content_type(),   ContentType::Binary);
+		assert_eq!(partial.headers.len(),         2);
+	}
+	
+	//		convert_request														
+	#[test]
+	fn convert_request__basic() {
+		let mut headers  = HeaderMap::new();
+		drop(headers.insert("foo", HeaderValue::from_static("bar")));
+		let converted    = convert_request(
+			&Method::POST,
+			&Uri::from_static("/foo"),
+			Version::HTTP_11,
+			&headers,
+			&Bytes::from("This is a test"),
+		);
+		let crafted      = UnpackedRequest {
+			method:        Method::POST,
+			uri:           Uri::from_static("/foo"),
+			version:       Version::HTTP_11,
+			headers:       vec![
+				UnpackedResponseHeader {
+					name:  s!("foo"),
+					value: s!("bar"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+		};
+		assert_eq!(converted, crafted);
+	}
+	
+	//		convert_response													
+	#[test]
+	fn convert_response__basic() {
+		let mut headers  = HeaderMap::new();
+		drop(headers.insert("foo", HeaderValue::from_static("bar")));
+		let converted    = convert_response(StatusCode::OK, &headers, &Bytes::from("This is a test"), vec![], &UnpackOptions::default()).unwrap();
+		let crafted      = UnpackedResponse {
+			status:        StatusCode::OK,
+			headers:       vec![
+				UnpackedResponseHeader {
+					name:  s!("foo"),
+					value: s!("bar"),
+				},
+			],
+			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
+			frames:        vec![],
+			raw_headers:   vec![],
+		};
+		assert_eq!(converted, crafted);
+	}
+	
+	//		collect_frames														
+	#[tokio::test]
+	async fn collect_frames__basic() {
+		let read = collect_frames(create_stream_body_for_testing(), &UnpackOptions::new().with_frame_recording(true)).await.unwrap();
+		assert_eq!(read.bytes,        Bytes::from("data: one\n\ndata: two\n\n"));
+		assert_eq!(read.frames.len(), 3);
+		assert!(matches!(read.frames[2], UnpackedFrame::Trailers(_)));
+		assert_eq!(read.timed_out,    None);
+	}
+	#[tokio::test]
+	async fn collect_frames__not_recorded() {
+		let read = collect_frames(create_stream_body_for_testing(), &UnpackOptions::new()).await.unwrap();
+		assert_eq!(read.bytes, Bytes::from("data: one\n\ndata: two\n\n"));
+		assert!(read.frames.is_empty());
+	}
+	#[tokio::test]
+	async fn collect_frames__timeout() {
+		let options = UnpackOptions::new().with_frame_recording(true).with_timeout(Duration::from_millis(50));
+		let read    = collect_frames(create_stalled_body_for_testing(), &options).await.unwrap();
+		assert_eq!(read.bytes,        Bytes::from("data: one\n\n"));
+		assert_eq!(read.frames.len(), 1);
+		assert_eq!(read.timed_out,    Some(Duration::from_millis(50)));
+	}
+	#[test]
+	fn collect_frames__timeout_blocking() {
+		let options = UnpackOptions::new().with_timeout(Duration::from_millis(50));
+		let read    = executor::block_on(collect_frames(create_stalled_body_for_testing(), &options)).unwrap();
+		assert_eq!(read.bytes,     Bytes::from("data: one\n\n"));
+		assert_eq!(read.timed_out, Some(Duration::from_millis(50)));
+	}
+	
+	//		convert_frames														
+	#[test]
+	fn convert_frames__binary() {
+		let frames = vec![
+			UnpackedFrame::Data(UnpackedResponseBody::new("foo")),
+			UnpackedFrame::Trailers(vec![]),
+		];
+		let converted = convert_frames(frames, ContentType::Binary);
+		assert!(matches!(converted[0], UnpackedFrame::Data(ref data) if data.is_binary()));
+		assert!(matches!(converted[1], UnpackedFrame::Trailers(_)));
+	}
+	#[test]
+	fn convert_frames__json() {
+		let converted = convert_frames(vec![UnpackedFrame::Data(UnpackedResponseBody::new("{\"foo\":"))], ContentType::Json);
+		assert!(matches!(converted[0], UnpackedFrame::Data(ref data) if data.is_text()));
+	}
+	
+	//		compress_bytes														
+	#[test]
+	fn compress_bytes__gzip() {
+		let compressed = compress_bytes(b"This is a test", ContentEncoding::Gzip).unwrap();
+		assert_eq!(compressed.get(..2), Some(&[0x1F_u8, 0x8B][..]));
+		assert_ok_eq!(decompress_bytes(&compressed, ContentEncoding::Gzip, usize::MAX), b"This is a test".to_vec());
 	}
+	
+	//		decode_chunked														
 	#[test]
-	fn add__string() {
-		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		assert_eq!(body + s!(" a test"), UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn decode_chunked__valid() {
+		assert_eq!(decode_chunked(b"3\r\nfoo\r\nA\r\n0123456789\r\n0\r\nx-foo: 1\r\n\r\n"), Some(b"foo0123456789".to_vec()));
+		assert_eq!(decode_chunked(b"3\nfoo\n0\n"),                                         Some(b"foo".to_vec()));
 	}
 	#[test]
-	fn add__string_ref() {
-		let body   = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let string = s!(" a test");
-		assert_eq!(body + &string, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn decode_chunked__invalid() {
+		assert_eq!(decode_chunked(b"foo"),            None);
+		assert_eq!(decode_chunked(b"5\r\nfoo\r\n"),   None);
+		assert_eq!(decode_chunked(b"3\r\nfoo"),       None);
+		assert_eq!(decode_chunked(b"z\r\nfoo\r\n0\r\n"), None);
 	}
+	
+	//		decode_utf16														
 	#[test]
-	fn add__box_str() {
-		let body   = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		assert_eq!(body + s!(" a test").into_boxed_str(), UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn decode_utf16__bom() {
+		assert_eq!(decode_utf16(b"\xFE\xFF\0f\0o\0o", None),        Some(s!("foo")));
+		assert_eq!(decode_utf16(b"\xFF\xFEf\0o\0o\0", None),        Some(s!("foo")));
+		assert_eq!(decode_utf16(b"\xFF\xFEf\0o\0o\0", Some(false)), Some(s!("foo")));
 	}
 	#[test]
-	fn add__cow_borrowed() {
-		let body              = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let cow: Cow<'_, str> = Cow::Borrowed(" a test");
-		assert_eq!(body + cow, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn decode_utf16__no_bom() {
+		assert_eq!(decode_utf16(b"\0f\0o\0o", None),        Some(s!("foo")));
+		assert_eq!(decode_utf16(b"f\0o\0o\0", Some(false)), Some(s!("foo")));
+		assert_eq!(decode_utf16(b"", None),                 Some(s!("")));
 	}
 	#[test]
-	fn add__cow_owned() {
-		let body              = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let cow: Cow<'_, str> = Cow::Owned(s!(" a test"));
-		assert_eq!(body + cow, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn decode_utf16__invalid() {
+		assert_eq!(decode_utf16(b"\0f\0", None),          None);
+		assert_eq!(decode_utf16(b"\xDC\x00", Some(true)), None);
 	}
+	
+	//		decompress_bytes													
 	#[test]
-	fn add__u8() {
-		let body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		assert_eq!(body + 33, UnpackedResponseBody { body: b"This is a test!".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn decompress_bytes__raw_deflate() {
+		let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+		encoder.write_all(b"This is a test").unwrap();
+		let compressed  = encoder.finish().unwrap();
+		assert_ok_eq!(decompress_bytes(&compressed, ContentEncoding::Deflate, usize::MAX), b"This is a test".to_vec());
 	}
 	#[test]
-	fn add__vec_u8() {
-		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		assert_eq!(body + b" a test".to_vec(), UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn decompress_bytes__limit() {
+		let compressed = compress_bytes(&[b'a'; 1_000], ContentEncoding::Brotli).unwrap();
+		assert_eq!(decompress_bytes(&compressed, ContentEncoding::Brotli, 10).unwrap().len(),    11);
+		assert_eq!(decompress_bytes(&compressed, ContentEncoding::Brotli, 1_000).unwrap().len(), 1_000);
 	}
+	
+	//		decompress_content													
 	#[test]
-	fn add__vec_u8_ref() {
-		let body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let vec  = b" a test".to_vec();
-		assert_eq!(body + &vec, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn decompress_content__basic() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert("content-encoding", HeaderValue::from_static("gzip")));
+		drop(headers.insert("content-length",   HeaderValue::from_static("34")));
+		drop(headers.insert("content-type",     HeaderValue::from_static("text/plain")));
+		let mut body    = compress_bytes(b"This is a test", ContentEncoding::Gzip).unwrap();
+		assert_ok!(decompress_content(&mut headers, &mut body, usize::MAX));
+		assert_eq!(body,                           b"This is a test".to_vec());
+		assert_eq!(headers.get("content-encoding"), None);
+		assert_eq!(headers.get("content-length"),   Some(&HeaderValue::from_static("14")));
+		assert_eq!(headers.get("content-type"),     Some(&HeaderValue::from_static("text/plain")));
 	}
 	#[test]
-	fn add__unpacked_response_body() {
-		let body1 = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let body2 = UnpackedResponseBody { body: b" a test".to_vec(), ..Default::default() };
-		assert_eq!(body1 + body2, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body1, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn decompress_content__multiple_headers() {
+		let mut headers = HeaderMap::new();
+		_ = headers.append("content-encoding", HeaderValue::from_static("gzip"));
+		_ = headers.append("content-encoding", HeaderValue::from_static("identity, br"));
+		let mut body    = compress_bytes(&compress_bytes(b"This is a test", ContentEncoding::Gzip).unwrap(), ContentEncoding::Brotli).unwrap();
+		assert_ok!(decompress_content(&mut headers, &mut body, usize::MAX));
+		assert_eq!(body, b"This is a test".to_vec());
+		assert!(headers.is_empty());
 	}
+	
+	//		default_content_type												
 	#[test]
-	fn add__unpacked_response_body_ref() {
-		let body1 = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let body2 = UnpackedResponseBody { body: b" a test".to_vec(), ..Default::default() };
-		assert_eq!(body1 + &body2, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original response body after using the +
-		//	operator, because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(body1, UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() });
+	fn default_content_type__basic() {
+		assert_eq!(default_content_type("application/json"),         Some(ContentType::Json));
+		assert_eq!(default_content_type("application/hal+json"),     Some(ContentType::Json));
+		assert_eq!(default_content_type("text/csv"),                 Some(ContentType::Text));
+		assert_eq!(default_content_type("application/atom+xml"),     Some(ContentType::Text));
+		assert_eq!(default_content_type("application/javascript"),   Some(ContentType::Text));
+		assert_eq!(default_content_type("video/mp4"),                Some(ContentType::Binary));
+		assert_eq!(default_content_type("font/woff2"),               Some(ContentType::Binary));
+		assert_eq!(default_content_type("application/zip"),          Some(ContentType::Binary));
+		assert_eq!(default_content_type("application/x-unknown"),    None);
+		assert_eq!(default_content_type("json"),                     None);
 	}
 	
-	//		add_assign															
+	//		detect_content_type													
 	#[test]
-	fn add_assign__byte_array() {
-		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		body         += b" a test";
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn detect_content_type__header() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert("content-type", HeaderValue::from_static("application/json; charset=utf-8")));
+		assert_eq!(detect_content_type(&headers, br#"{"foo":"bar"}"#, &UnpackOptions::new()), ContentType::Json);
+		drop(headers.insert("content-type", HeaderValue::from_static("application/octet-stream")));
+		assert_eq!(detect_content_type(&headers, b"This is a test",   &UnpackOptions::new()), ContentType::Binary);
 	}
 	#[test]
-	fn add_assign__byte_slice() {
-		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		body         += &b" a test"[..];
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn detect_content_type__sniffing() {
+		let mut headers = HeaderMap::new();
+		assert_eq!(detect_content_type(&headers, b"This is a test",   &UnpackOptions::new()), ContentType::Text);
+		assert_eq!(detect_content_type(&headers, b"\x80\x81",         &UnpackOptions::new()), ContentType::Binary);
+		drop(headers.insert("content-type", HeaderValue::from_static("text/plain")));
+		assert_eq!(detect_content_type(&headers, b"\x80\x81",         &UnpackOptions::new()), ContentType::Binary);
+		assert_eq!(detect_content_type(&headers, b"\x80\x81",         &UnpackOptions::new().with_content_sniffing(false)), ContentType::Text);
+		drop(headers.insert("content-type", HeaderValue::from_static("application/x-unknown")));
+		assert_eq!(detect_content_type(&headers, b"\x80\x81",         &UnpackOptions::new()), ContentType::Binary);
+		assert_eq!(detect_content_type(&headers, b"\x80\x81",         &UnpackOptions::new().with_content_sniffing(false)), ContentType::Text);
 	}
-	#[expect(clippy::string_lit_as_bytes, reason = "Needed for the test")]
 	#[test]
-	fn add_assign__char_one_byte() {
-		let mut body  = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
-		body         += 'A';
-		assert_eq!(body, UnpackedResponseBody { body: s!("This is A").into_bytes(), ..Default::default() });
+	fn detect_content_type__overrides() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert("content-type", HeaderValue::from_static("text/plain")));
+		let options     = UnpackOptions::new().with_content_type("text/plain", ContentType::Text);
+		assert_eq!(detect_content_type(&headers, b"\x80\x81", &options), ContentType::Text);
+		assert_eq!(detect_content_type(&headers, b"\x80\x81", &UnpackOptions::new().with_content_detection(false)), ContentType::Text);
 	}
+	
+	//		detect_mime_content_type											
 	#[test]
-	fn add_assign__char_two_byte() {
-		let mut body  = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
-		body         += 'ñ';
-		assert_eq!(body, UnpackedResponseBody { body: s!("This is ñ").into_bytes(), ..Default::default() });
+	fn detect_mime_content_type__basic() {
+		let options = UnpackOptions::default();
+		assert_eq!(detect_mime_content_type(Some("application/json"), b"{}",        &options), ContentType::Json);
+		assert_eq!(detect_mime_content_type(None,                     b"foo",       &options), ContentType::Text);
+		assert_eq!(detect_mime_content_type(None,                     &[0xFF, 0xFE], &options), ContentType::Binary);
 	}
+	
+	//		diff_headers														
 	#[test]
-	fn add_assign__char_three_byte() {
-		let mut body  = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
-		body         += 'Ḁ';
-		assert_eq!(body, UnpackedResponseBody { body: s!("This is Ḁ").into_bytes(), ..Default::default() });
+	fn diff_headers__repeated() {
+		let left  = vec![
+			UnpackedResponseHeader::new(s!("Foo"), s!("Bar")),
+			UnpackedResponseHeader::new(s!("Foo"), s!("Baz")),
+		];
+		let right = vec![
+			UnpackedResponseHeader::new(s!("Foo"), s!("Baz")),
+			UnpackedResponseHeader::new(s!("Foo"), s!("Bar")),
+		];
+		assert_eq!(diff_headers(&left, &left), vec![]);
+		assert_eq!(diff_headers(&left, &right), vec![
+			HeaderDiff::Changed {
+				name:  s!("Foo"),
+				left:  vec![s!("Bar"), s!("Baz")],
+				right: vec![s!("Baz"), s!("Bar")],
+			},
+		]);
 	}
 	#[test]
-	fn add_assign__char_four_byte() {
-		let mut body  = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
-		body         += '𐍈';
-		assert_eq!(body, UnpackedResponseBody { body: s!("This is 𐍈").into_bytes(), ..Default::default() });
+	fn diff_headers__case_sensitive() {
+		let left  = vec![UnpackedResponseHeader::new(s!("foo"), s!("Bar"))];
+		let right = vec![UnpackedResponseHeader::new(s!("Foo"), s!("Bar"))];
+		assert_eq!(diff_headers(&left, &right), vec![
+			HeaderDiff::Added(UnpackedResponseHeader::new(s!("Foo"), s!("Bar"))),
+			HeaderDiff::Removed(UnpackedResponseHeader::new(s!("foo"), s!("Bar"))),
+		]);
 	}
-	#[expect(clippy::string_lit_as_bytes, reason = "Needed for the test")]
+	
+	//		diff_json															
 	#[test]
-	fn add_assign__char_ref() {
-		let mut body  = UnpackedResponseBody { body: b"This is ".to_vec(), ..Default::default() };
-		let char      = 'A';
-		body         += &char;
-		assert_eq!(body, UnpackedResponseBody { body: s!("This is A").into_bytes(), ..Default::default() });
+	fn diff_json__nested() {
+		let mut diffs = vec![];
+		diff_json("$", &json!({ "a": { "b": [ 1, { "c": 2 } ] } }), &json!({ "a": { "b": [ 1, { "c": 3 }, 4 ] } }), &mut diffs);
+		assert_eq!(diffs, vec![
+			JsonPathDiff::Changed { path: s!("$.a.b[1].c"), left: json!(2), right: json!(3) },
+			JsonPathDiff::Added   { path: s!("$.a.b[2]"),   value: json!(4) },
+		]);
 	}
 	#[test]
-	fn add_assign__str() {
-		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		body         += " a test";
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn diff_json__type_change() {
+		let mut diffs = vec![];
+		diff_json("$", &json!({ "a": [ 1 ] }), &json!({ "a": { "0": 1 } }), &mut diffs);
+		assert_eq!(diffs, vec![
+			JsonPathDiff::Changed { path: s!("$.a"), left: json!([ 1 ]), right: json!({ "0": 1 }) },
+		]);
 	}
 	#[test]
-	fn add_assign__str_ref() {
-		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let str       = " a test";
-		body         += str;
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn diff_json__identical() {
+		let mut diffs = vec![];
+		diff_json("$", &json!({ "a": [ 1, 2 ] }), &json!({ "a": [ 1, 2 ] }), &mut diffs);
+		assert_eq!(diffs, vec![]);
 	}
+	
+	//		diff_lines															
 	#[test]
-	fn add_assign__string() {
-		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		body         += s!(" a test");
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn diff_lines__basic() {
+		assert_eq!(diff_lines("a\nb\nc", "a\nb\nc"), vec![]);
+		assert_eq!(diff_lines("a\nb\nc", "a\nc"), vec![
+			TextLineDiff::Removed { line: 2, text: s!("b") },
+		]);
+		assert_eq!(diff_lines("a\nc", "b\na\nc\nd"), vec![
+			TextLineDiff::Added   { line: 1, text: s!("b") },
+			TextLineDiff::Added   { line: 4, text: s!("d") },
+		]);
 	}
 	#[test]
-	fn add_assign__string_ref() {
-		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let string    = s!(" a test");
-		body         += &string;
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn diff_lines__empty() {
+		assert_eq!(diff_lines("", "a"), vec![
+			TextLineDiff::Added   { line: 1, text: s!("a") },
+		]);
+		assert_eq!(diff_lines("a", ""), vec![
+			TextLineDiff::Removed { line: 1, text: s!("a") },
+		]);
 	}
 	#[test]
-	fn add_assign__box_str() {
-		let mut body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let box_str  = s!(" a test").into_boxed_str();
-		body        += box_str;
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn diff_lines__large() {
+		let left  = (0..1_500).map(|n| n.to_string()).collect::<Vec<_>>().join("\n");
+		let right = (1..1_501).map(|n| n.to_string()).collect::<Vec<_>>().join("\n");
+		let diffs = diff_lines(&left, &right);
+		assert_eq!(diffs.len(), 3_000);
+		assert_eq!(diffs[0],     TextLineDiff::Removed { line: 1,     text: s!("0") });
+		assert_eq!(diffs[1_500], TextLineDiff::Added   { line: 1,     text: s!("1") });
+		assert_eq!(diffs[2_999], TextLineDiff::Added   { line: 1_500, text: s!("1500") });
 	}
 	#[test]
-	fn add_assign__cow_borrowed() {
-		let mut body           = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let cow: Cow<'_, str>  = Cow::Borrowed(" a test");
-		body                  += cow;
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn diff_lines__prefix_and_suffix() {
+		let left  = format!("{}x{}", "a\n".repeat(2_000), "\nb".repeat(2_000));
+		let right = format!("{}y{}", "a\n".repeat(2_000), "\nb".repeat(2_000));
+		assert_eq!(diff_lines(&left, &right), vec![
+			TextLineDiff::Removed { line: 2_001, text: s!("x") },
+			TextLineDiff::Added   { line: 2_001, text: s!("y") },
+		]);
 	}
+	
+	//		find_delimiter														
 	#[test]
-	fn add_assign__cow_owned() {
-		let mut body           = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let cow: Cow<'_, str>  = Cow::Owned(s!(" a test"));
-		body                  += cow;
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn find_delimiter__basic() {
+		assert_eq!(find_delimiter(b"--abc\r\n",              b"--abc", 0), Some(0));
+		assert_eq!(find_delimiter(b"x--abc\n--abc--",         b"--abc", 0), Some(7));
+		assert_eq!(find_delimiter(b"--abcd\n--abc",           b"--abc", 0), Some(7));
+		assert_eq!(find_delimiter(b"--abc \n",                b"--abc", 1), None);
+		assert_eq!(find_delimiter(b"foo",                      b"--abc", 0), None);
 	}
+	
+	//		har_content															
 	#[test]
-	fn add_assign__u8() {
-		let mut body  = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		body         += 33;
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test!".to_vec(), ..Default::default() });
+	fn har_content__text() {
+		let content = har_content(&UnpackedResponseBody::new("foo"), Some("text/plain"));
+		assert_eq!(Json::Object(content), json!({ "mimeType": "text/plain", "text": "foo" }));
 	}
 	#[test]
-	fn add_assign__vec_u8() {
-		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		body         += b" a test".to_vec();
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn har_content__binary() {
+		let mut body = UnpackedResponseBody::new("foo");
+		_ = body.set_content_type(ContentType::Binary);
+		assert_eq!(Json::Object(har_content(&body, None)), json!({ "mimeType": "", "text": "Zm9v", "encoding": "base64" }));
 	}
+	
+	//		har_cookie															
 	#[test]
-	fn add_assign__vec_u8_ref() {
-		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let vec       = b" a test".to_vec();
-		body         += &vec;
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn har_cookie__basic() {
+		let cookie = UnpackedCookie::new("id", "1").with_domain("example.com").with_expires("Thu, 01 Jan 1970 00:00:00 GMT").with_secure(true);
+		assert_eq!(har_cookie(&cookie), json!({
+			"name":     "id",
+			"value":    "1",
+			"domain":   "example.com",
+			"expires":  "Thu, 01 Jan 1970 00:00:00 GMT",
+			"httpOnly": false,
+			"secure":   true,
+		}));
 	}
+	
+	//		har_field															
 	#[test]
-	fn add_assign__unpacked_response_body() {
-		let mut body  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		body         += UnpackedResponseBody { body: b" a test".to_vec(), ..Default::default() };
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn har_field__basic() {
+		assert_ok_eq!(har_field(&json!({ "foo": 1 }), "foo"), &json!(1));
+		assert!(matches!(har_field(&json!({}), "foo"), Err(HarError::InvalidField(ref field)) if field == "foo"));
 	}
+	
+	//		har_headers															
 	#[test]
-	fn add_assign__unpacked_response_body_ref() {
-		let mut body1  = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		let body2      = UnpackedResponseBody { body: b" a test".to_vec(), ..Default::default() };
-		body1          += &body2;
-		assert_eq!(body1, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn har_headers__basic() {
+		let headers = vec![UnpackedResponseHeader::new(s!("x-foo"), s!("1")), UnpackedResponseHeader::new(s!("x-foo"), s!("2"))];
+		assert_eq!(har_headers(&headers), json!([{ "name": "x-foo", "value": "1" }, { "name": "x-foo", "value": "2" }]));
 	}
 	
-	//		as_ref																
+	//		har_str																
 	#[test]
-	fn as_ref() {
-		//	Same tests as for as_bytes().
-		let body       = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		let byte_slice = body.as_ref();
-		assert_eq!(byte_slice, b"This is a test".to_vec());
-		assert_eq!(body,       UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn har_str__basic() {
+		assert_ok_eq!(har_str(&json!({ "foo": "bar" }), "foo"), "bar");
+		assert!(matches!(har_str(&json!({ "foo": 1 }), "foo"), Err(HarError::InvalidField(ref field)) if field == "foo"));
 	}
 	
-	//		as_mut																
+	//		is_token_char														
 	#[test]
-	fn as_mut() {
-		//	Same tests as for as_mut_bytes().
-		let mut body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		let byte_vec = body.as_mut();
-		assert_eq!(*byte_vec, b"This is a test".to_vec());
-		
-		byte_vec[10] = 84;
-		assert_eq!(*byte_vec, b"This is a Test".to_vec());
-		assert_eq!(body,      UnpackedResponseBody { body: b"This is a Test".to_vec(), ..Default::default() });
+	fn is_token_char__basic() {
+		assert!( is_token_char('a'));
+		assert!( is_token_char('-'));
+		assert!(!is_token_char(' '));
+		assert!(!is_token_char('"'));
+		assert!(!is_token_char('é'));
 	}
 	
-	//		clone																
+	//		is_yaml_path														
 	#[test]
-	fn clone() {
-		let mut body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		let clone    = body.clone();
-		assert_eq!(clone, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		
-		body.clear();
-		body.push_str("This is a different test");
-		assert_eq!(body,  UnpackedResponseBody { body: b"This is a different test".to_vec(), ..Default::default() });
-		assert_eq!(clone, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn is_yaml_path__basic() {
+		assert!( is_yaml_path(Path::new("test.yaml")));
+		assert!( is_yaml_path(Path::new("dir/test.YML")));
+		assert!(!is_yaml_path(Path::new("test.json")));
+		assert!(!is_yaml_path(Path::new("yaml")));
 	}
 	
-	//		clone_from															
+	//		json_path_matches													
 	#[test]
-	fn clone_from() {
-		let mut body  = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		let mut clone = UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() };
-		clone.clone_from(&body);
-		assert_eq!(body,  UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		assert_eq!(clone, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		
-		body.clear();
-		body.push_str("This is a different test");
-		assert_eq!(body,  UnpackedResponseBody { body: b"This is a different test".to_vec(), ..Default::default() });
-		assert_eq!(clone, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn json_path_matches__exact() {
+		assert!( json_path_matches("$.foo",        "$.foo"));
+		assert!( json_path_matches("$.foo",        "$.foo.bar[1]"));
+		assert!( json_path_matches("$.foo[1]",     "$.foo[1].bar"));
+		assert!(!json_path_matches("$.foo",        "$.foobar"));
+		assert!(!json_path_matches("$.foo.bar",    "$.foo"));
+		assert!(!json_path_matches("$.foo[1]",     "$.foo[10]"));
+	}
+	#[test]
+	fn json_path_matches__wildcards() {
+		assert!( json_path_matches("$.*.id",       "$.foo.id"));
+		assert!( json_path_matches("$.foo[*].id",  "$.foo[3].id"));
+		assert!(!json_path_matches("$.foo[*].id",  "$.foo.bar.id"));
+		assert!(!json_path_matches("$.*.id",       "$[0].id"));
 	}
 	
-	//		debug																
+	//		split_json_path														
 	#[test]
-	fn debug__binary() {
-		let body        = UnpackedResponseBody {
-			body:         b"This is a test".to_vec(),
-			content_type: ContentType::Binary,
-		};
-		assert_eq!(format!("{body:?}"), r#"UnpackedResponseBody { body: "VGhpcyBpcyBhIHRlc3Q=", content_type: Binary }"#);
+	fn split_json_path() {
+		assert_eq!(super::split_json_path("$"),            vec![s!("$")]);
+		assert_eq!(super::split_json_path("$.foo[2].bar"), vec![s!("$"), s!(".foo"), s!("[2]"), s!(".bar")]);
+		assert_eq!(super::split_json_path(""),             Vec::<String>::new());
 	}
+	
+	//		mime_essence														
 	#[test]
-	fn debug__text() {
-		let body        = UnpackedResponseBody {
-			body:         b"This is a test".to_vec(),
-			content_type: ContentType::Text,
-		};
-		assert_eq!(format!("{body:?}"), r#"UnpackedResponseBody { body: "This is a test", content_type: Text }"#);
+	fn mime_essence__basic() {
+		assert_eq!(mime_essence("text/plain"),                     "text/plain");
+		assert_eq!(mime_essence(" Text/HTML ; charset=UTF-8"),     "text/html");
+		assert_eq!(mime_essence(""),                               "");
+	}
+	
+	//		mime_parameter														
+	#[test]
+	fn mime_parameter__basic() {
+		assert_eq!(mime_parameter("multipart/mixed; boundary=abc",           "boundary"), Some(s!("abc")));
+		assert_eq!(mime_parameter("multipart/mixed; BOUNDARY=\"a;b\"",      "boundary"), Some(s!("a;b")));
+		assert_eq!(mime_parameter(r#"form-data; name="a\"b\\c""#,            "name"),     Some(s!(r#"a"b\c"#)));
+		assert_eq!(mime_parameter("form-data; filename=a.txt",                "name"),     None);
+		assert_eq!(mime_parameter("boundary=abc",                             "boundary"), None);
 	}
 	
-	//		default																
+	//		parse_har_body														
 	#[test]
-	fn default() {
-		let body = UnpackedResponseBody::default();
-		assert_eq!(body, UnpackedResponseBody { body: b"".to_vec(), ..Default::default() });
+	fn parse_har_body__missing() {
+		assert_ok_eq!(parse_har_body(None,                       &HeaderMap::new()), UnpackedResponseBody::default());
+		assert_ok_eq!(parse_har_body(Some(&json!({"size": 0})), &HeaderMap::new()), UnpackedResponseBody::default());
+	}
+	#[test]
+	fn parse_har_body__header_precedence() {
+		let mut headers = HeaderMap::new();
+		drop(headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain")));
+		let body        = parse_har_body(Some(&json!({ "mimeType": "application/json", "text": "{}" })), &headers).unwrap();
+		assert!(body.is_text());
 	}
 	
-	//		display																
+	//		parse_har_headers													
 	#[test]
-	fn display__binary() {
-		let body        = UnpackedResponseBody {
-			body:         b"This is a test".to_vec(),
-			content_type: ContentType::Binary,
-		};
-		assert_eq!(format!("{body}"), r"VGhpcyBpcyBhIHRlc3Q=");
+	fn parse_har_headers__basic() {
+		let headers = parse_har_headers(&json!({ "headers": [
+			{ "name": "X-Foo",   "value": "1" },
+			{ "name": "x-foo",   "value": "2" },
+			{ "name": ":method", "value": "GET" },
+		]})).unwrap();
+		assert_eq!(headers.len(), 2);
+		assert_eq!(headers.get_all("x-foo").iter().collect::<Vec<_>>(), vec!["1", "2"]);
+		assert!(parse_har_headers(&json!({})).unwrap().is_empty());
 	}
 	#[test]
-	fn display__text() {
-		let body        = UnpackedResponseBody {
-			body:         b"This is a test".to_vec(),
-			content_type: ContentType::Text,
-		};
-		assert_eq!(format!("{body}"), r"This is a test");
+	fn parse_har_headers__invalid() {
+		let err1 = parse_har_headers(&json!({ "headers": [{ "value": "1" }] })).unwrap_err();
+		let err2 = parse_har_headers(&json!({ "headers": [{ "name": "x-foo", "value": "a\nb" }] })).unwrap_err();
+		assert!(matches!(err1, HarError::InvalidField(ref field) if field == "name"));
+		assert!(matches!(err2, HarError::InvalidField(ref field) if field == "headers.x-foo"));
 	}
 	
-	//		from																
+	//		parse_header_lines													
 	#[test]
-	fn from__axum_body() {
-		let body1 = UnpackedResponseBody::from(AxumBody::from("This is a test"));
-		assert_eq!(body1, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		
-		let axum  = AxumBody::from("This is another test");
-		let body2 = UnpackedResponseBody::from(axum);
-		assert_eq!(body2, UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original AxumBody after calling from(),
-		//	because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(axum, "This is another test");
+	fn parse_header_lines__basic() {
+		assert_ok_eq!(parse_header_lines("X-Foo:  1 \r\n\r\nX-Bar: a\n\tb\n"), vec![
+			UnpackedResponseHeader::new(s!("x-foo"), s!("1")),
+			UnpackedResponseHeader::new(s!("x-bar"), s!("a b")),
+		]);
+		assert_ok_eq!(parse_header_lines(""), vec![]);
 	}
 	#[test]
-	fn from__byte_array() {
-		let body1      = UnpackedResponseBody::from(b"This is a test");
-		assert_eq!(body1,      UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		
-		let byte_array = b"This is another test";
-		let body2      = UnpackedResponseBody::from(byte_array);
-		assert_eq!(body2,      UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() });
-		assert_eq!(byte_array, b"This is another test");
+	fn parse_header_lines__invalid() {
+		assert_err_eq!(parse_header_lines("x-foo: 1\nfoo"), s!("foo"));
+		assert_err_eq!(parse_header_lines(" foo"),          s!(" foo"));
 	}
+	
+	//		parse_http1_response												
 	#[test]
-	fn from__byte_slice() {
-		let body1       = UnpackedResponseBody::from(&b"This is a test"[..]);
-		assert_eq!(body1,       UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		
-		let byte_slice = &b"This is another test"[..];
-		let body2      = UnpackedResponseBody::from(byte_slice);
-		assert_eq!(body2,      UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() });
-		assert_eq!(byte_slice, b"This is another test");
+	fn parse_http1_response__content_detection() {
+		let unpacked = parse_http1_response(b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\r\n{}", &UnpackOptions::new().with_content_detection(false)).unwrap();
+		assert!(unpacked.body.is_text());
 	}
+	
+	//		parse_http_version													
 	#[test]
-	fn from__char() {
-		let body1 = UnpackedResponseBody::from('A');
-		assert_eq!(body1, UnpackedResponseBody { body: b"A".to_vec(), ..Default::default() });
-		
-		let char  = 'B';
-		let body2 = UnpackedResponseBody::from(char);
-		assert_eq!(body2, UnpackedResponseBody { body: b"B".to_vec(), ..Default::default() });
-		assert_eq!(char,  'B');
+	fn parse_http_version__basic() {
+		assert_eq!(parse_http_version("HTTP/1.0"), Some(Version::HTTP_10));
+		assert_eq!(parse_http_version("http/1.1"), Some(Version::HTTP_11));
+		assert_eq!(parse_http_version(""),         Some(Version::HTTP_11));
+		assert_eq!(parse_http_version("HTTP/2"),   Some(Version::HTTP_2));
+		assert_eq!(parse_http_version("http/2.0"), Some(Version::HTTP_2));
+		assert_eq!(parse_http_version("h3"),       Some(Version::HTTP_3));
+		assert_eq!(parse_http_version("spdy/3"),   None);
 	}
+	
+	//		parse_multipart														
 	#[test]
-	fn from__char_ref() {
-		let char = 'A';
-		let body = UnpackedResponseBody::from(&char);
-		assert_eq!(body, UnpackedResponseBody { body: b"A".to_vec(), ..Default::default() });
-		assert_eq!(char, 'A');
+	fn parse_multipart__preamble() {
+		let parts = parse_multipart(b"ignored\r\n--abc\r\n\r\nfoo\r\n--abc--\r\nignored", "abc", &UnpackOptions::default()).unwrap();
+		assert_eq!(parts, vec![MultipartPart::new("foo")]);
 	}
-	#[expect(clippy::string_lit_as_bytes, reason = "Needed for the test")]
 	#[test]
-	fn from__char_one_byte() {
-		let body = UnpackedResponseBody::from('A');
-		assert_eq!(body, UnpackedResponseBody { body: vec![65], ..Default::default() });
-		assert_eq!(body, UnpackedResponseBody::from(s!("A")));
-		assert_eq!(body, UnpackedResponseBody { body: s!("A").into_bytes(), ..Default::default() });
+	fn parse_multipart__content_detection() {
+		let data  = b"--abc\r\ncontent-type: application/json\r\n\r\n{}\r\n--abc--";
+		let parts = parse_multipart(data, "abc", &UnpackOptions::new().with_content_detection(false)).unwrap();
+		assert!(parts[0].body.is_text());
 	}
+	
+	//		parse_multipart_part												
 	#[test]
-	fn from__char_two_byte() {
-		let body = UnpackedResponseBody::from('ñ');
-		assert_eq!(body, UnpackedResponseBody { body: vec![195, 177], ..Default::default() });
-		assert_eq!(body, UnpackedResponseBody::from(s!("ñ")));
-		assert_eq!(body, UnpackedResponseBody { body: s!("ñ").into_bytes(), ..Default::default() });
+	fn parse_multipart_part__no_body() {
+		let part = parse_multipart_part(b"x-foo: 1", &UnpackOptions::default()).unwrap();
+		assert_eq!(part, MultipartPart::new("").with_header("x-foo", "1"));
 	}
 	#[test]
-	fn from__char_three_byte() {
-		let three_byte_single_width = UnpackedResponseBody::from('Ḁ');
-		assert_eq!(three_byte_single_width, UnpackedResponseBody { body: vec![225, 184, 128], ..Default::default() });
-		assert_eq!(three_byte_single_width, UnpackedResponseBody::from(s!("Ḁ")));
-		assert_eq!(three_byte_single_width, UnpackedResponseBody { body: s!("Ḁ").into_bytes(), ..Default::default() });
-		
-		let three_byte_double_width = UnpackedResponseBody::from('你');
-		assert_eq!(three_byte_double_width, UnpackedResponseBody { body: vec![228, 189, 160], ..Default::default() });
-		assert_eq!(three_byte_double_width, UnpackedResponseBody::from(s!("你")));
-		assert_eq!(three_byte_double_width, UnpackedResponseBody { body: s!("你").into_bytes(), ..Default::default() });
+	fn parse_multipart_part__invalid_continuation() {
+		let err = parse_multipart_part(b" foo\r\n\r\nbar", &UnpackOptions::default()).unwrap_err();
+		assert!(matches!(err, MultipartError::InvalidHeader(ref line) if line == " foo"));
 	}
+	
+	//		redact_json															
 	#[test]
-	fn from__char_four_byte() {
-		let body = UnpackedResponseBody::from('𐍈');
-		assert_eq!(body, UnpackedResponseBody { body: vec![240, 144, 141, 136], ..Default::default() });
-		assert_eq!(body, UnpackedResponseBody::from(s!("𐍈")));
-		assert_eq!(body, UnpackedResponseBody { body: s!("𐍈").into_bytes(), ..Default::default() });
+	fn redact_json__nested() {
+		let mut json = json!({"foo": {"bar": 1, "baz": [1, 2]}, "qux": 3});
+		redact_json("$", &mut json, &[s!("$.foo.bar"), s!("$.foo.baz[1]")], "[redacted]");
+		assert_eq!(json, json!({"foo": {"bar": "[redacted]", "baz": [1, "[redacted]"]}, "qux": 3}));
 	}
 	#[test]
-	fn from__full() {
-		let body1 = UnpackedResponseBody::from(Full::new(Bytes::from("This is a test")));
-		assert_eq!(body1, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		
-		let full  = Full::new(Bytes::from("This is another test"));
-		let body2 = UnpackedResponseBody::from(full);
-		assert_eq!(body2, UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original Full after calling from(),
-		//	because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(full, "This is another test");
+	fn redact_json__root() {
+		let mut json = json!({"foo": 1});
+		redact_json("$", &mut json, &[s!("$")], "[redacted]");
+		assert_eq!(json, json!("[redacted]"));
 	}
-	#[tokio::test]
-	async fn from__incoming() {
-		let body1    = UnpackedResponseBody::from(create_incoming_for_testing(Bytes::from("This is a test")).await);
-		assert_eq!(body1, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		
-		let incoming = create_incoming_for_testing(Bytes::from("This is another test")).await;
-		let body2    = UnpackedResponseBody::from(incoming);
-		assert_eq!(body2, UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original incoming after calling from(),
-		//	because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(incoming, "This is another test");
+	#[test]
+	fn redact_json__placeholder() {
+		let mut json = json!({"foo": 1, "bar": 2});
+		redact_json("$", &mut json, &[s!("$.foo")], "***");
+		assert_eq!(json, json!({"foo": "***", "bar": 2}));
 	}
+	
+	//		parse_status_line													
 	#[test]
-	fn from__json() {
-		let body1 = UnpackedResponseBody::from(json!({
-			"foo": "bar",
-			"baz": 2,
-		}));
-		assert_json_eq!(json!(body1), r#"{"foo":"bar","baz":2}"#);
-		
-		let json = json!({
-			"str":   "foo",
-			"int":   99,
-			"float": 1.234,
-			"bool":  true,
-		});
-		let body2 = UnpackedResponseBody::from(json);
-		assert_json_eq!(json!(body2), r#"{"str":"foo","int":99,"float":1.234,"bool":true}"#);
-		//	We cannot compare to the original JSON after calling from(),
-		//	because it has been consumed.
-		//	Uncommenting the lines below would cause a compilation error:
-		// assert_json_eq!(json, json!({
-		// 	"str":   "foo",
-		// 	"int":   99,
-		// 	"float": 1.234,
-		// 	"bool":  true,
-		// }));
+	fn parse_status_line__basic() {
+		assert_eq!(parse_status_line("HTTP/1.1 200 OK"),            Some(StatusCode::OK));
+		assert_eq!(parse_status_line("HTTP/1.0 404 Not Found"),     Some(StatusCode::NOT_FOUND));
+		assert_eq!(parse_status_line("HTTP/2 204"),                 Some(StatusCode::NO_CONTENT));
+		assert_eq!(parse_status_line("HTTP/1.1 2000 OK"),           None);
+		assert_eq!(parse_status_line("HTTP/1.1"),                   None);
+		assert_eq!(parse_status_line("GET / HTTP/1.1"),             None);
 	}
+	
+	//		parse_sse_events													
 	#[test]
-	fn from__json_ref() {
-		let json = json!({
-			"foo": "bar",
-			"baz": 2,
-		});
-		let body = UnpackedResponseBody::from(&json);
-		assert_json_eq!(json!(body), r#"{"foo":"bar","baz":2}"#);
-		assert_json_eq!(json, json!({
-			"foo": "bar",
-			"baz": 2,
-		}));
+	fn parse_sse_events__id_not_carried_over() {
+		assert_eq!(parse_sse_events("id: 1\ndata: foo\n\ndata: bar\n\nid: a\0b\ndata: baz\n\n"), vec![
+			ServerSentEvent::new("foo").with_id("1"),
+			ServerSentEvent::new("bar"),
+			ServerSentEvent::new("baz"),
+		]);
 	}
 	#[test]
-	fn from__str() {
-		let body = UnpackedResponseBody::from("This is a test");
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn parse_sse_events__empty_data() {
+		assert_eq!(parse_sse_events("event: ping\n\ndata:\n\ndata\ndata\n\ndata: foo\n\n"), vec![
+			ServerSentEvent::new(""),
+			ServerSentEvent::new("\n"),
+			ServerSentEvent::new("foo"),
+		]);
 	}
+	
+	//		quote_parameter														
 	#[test]
-	fn from__str_ref() {
-		let str  = "This is a test";
-		let body = UnpackedResponseBody::from(str);
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		assert_eq!(str,  "This is a test");
+	fn quote_parameter__basic() {
+		assert_eq!(quote_parameter("foo"),        r#""foo""#);
+		assert_eq!(quote_parameter(r#"a"b\c"#),   r#""a\"b\\c""#);
+	}
+	
+	//		read_body															
+	#[tokio::test]
+	async fn read_body__frames() {
+		let read = read_body(create_stream_body_for_testing(), &UnpackOptions::new().with_frame_recording(true)).await.unwrap();
+		assert_eq!(read.bytes,        Bytes::from("data: one\n\ndata: two\n\n"));
+		assert_eq!(read.frames.len(), 3);
+	}
+	#[tokio::test]
+	async fn read_body__no_frames() {
+		let read = read_body(create_stream_body_for_testing(), &UnpackOptions::new()).await.unwrap();
+		assert_eq!(read.bytes, Bytes::from("data: one\n\ndata: two\n\n"));
+		assert!(read.frames.is_empty());
+	}
+	#[tokio::test]
+	async fn read_body__timeout() {
+		let read = read_body(create_stalled_body_for_testing(), &UnpackOptions::new().with_timeout(Duration::from_millis(50))).await.unwrap();
+		assert_eq!(read.bytes,     Bytes::from("data: one\n\n"));
+		assert!(read.frames.is_empty());
+		assert_eq!(read.timed_out, Some(Duration::from_millis(50)));
 	}
+	
+	//		request_path														
 	#[test]
-	fn from__mut_str_ref() {
-		let mut string = s!("This is a test");
-		let mut_str    = string.as_mut_str();
-		let body       = UnpackedResponseBody::from(mut_str);
-		assert_eq!(body,   UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		assert_eq!(string, "This is a test");
+	fn request_path__basic() {
+		assert_eq!(request_path(&Uri::from_static("/foo?bar=baz")),           "/foo?bar=baz");
+		assert_eq!(request_path(&Uri::from_static("https://example.com")),    "/");
+		assert_eq!(request_path(&Uri::from_static("https://example.com/foo")), "/foo");
+		assert_eq!(request_path(&Uri::from_static("example.com:80")),         "/");
 	}
+	
+	//		split_head															
 	#[test]
-	fn from__string() {
-		let string = s!("This is a test");
-		let body   = UnpackedResponseBody::from(string);
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original string after calling from(),
-		//	because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(string, "This is a test");
+	fn split_head__basic() {
+		assert_eq!(split_head(b"foo\r\n\r\nbar"),     (&b"foo"[..],          &b"bar"[..]));
+		assert_eq!(split_head(b"foo\nbaz\n\nbar"),     (&b"foo\nbaz"[..],     &b"bar"[..]));
+		assert_eq!(split_head(b"foo\n\nbar\r\n\r\n"), (&b"foo"[..],          &b"bar\r\n\r\n"[..]));
+		assert_eq!(split_head(b"foo"),                (&b"foo"[..],          &b""[..]));
 	}
+	
+	//		write_snapshot														
 	#[test]
-	fn from__string_ref() {
-		let string = s!("This is a test");
-		let body   = UnpackedResponseBody::from(&string);
-		assert_eq!(body,   UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		assert_eq!(string, "This is a test");
+	fn write_snapshot__creates_directories() {
+		let dir  = tempdir().unwrap();
+		let path = dir.path().join("foo/bar/baz.json");
+		assert_ok!(write_snapshot(&path, "{}\n"));
+		assert_eq!(fs::read_to_string(&path).unwrap(), "{}\n");
 	}
+	
+	//		serialize_status_code												
 	#[test]
-	fn from__box_str() {
-		let box_str = s!("This is a test").into_boxed_str();
-		let body    = UnpackedResponseBody::from(box_str);
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original box_str after calling from(),
-		//	because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(box_str, s!("This is a test").into_boxed_str());
+	fn serialize_status_code__basic() {
+		let status_code = StatusCode::OK;
+		let serializer  = TestSerializer::builder().build();
+		let result      = serialize_status_code(&status_code, &serializer);
+		assert_ok_eq!(result, vec![Token::U16(200)]);
 	}
+	
+	//		deserialize_status_code												
 	#[test]
-	fn from__cow_borrowed() {
-		let cow: Cow<'_, str> = Cow::Borrowed("This is a test");
-		let body              = UnpackedResponseBody::from(cow);
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original cow after calling from(),
-		//	because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(cow, "This is a test");
+	fn deserialize_status_code__basic() {
+		let mut deserializer = TestDeserializer::builder(vec![Token::U16(200)]).build();
+		let result           = deserialize_status_code(&mut deserializer);
+		assert_ok_eq!(result, StatusCode::OK);
 	}
+	
+	//		serialize_method													
 	#[test]
-	fn from__cow_owned() {
-		let cow: Cow<'_, str> = Cow::Owned(s!("This is a test"));
-		let body              = UnpackedResponseBody::from(cow);
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original cow after calling from(),
-		//	because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(cow, "This is a test");
+	fn serialize_method__basic() {
+		let serializer = TestSerializer::builder().build();
+		let result     = serialize_method(&Method::PATCH, &serializer);
+		assert_ok_eq!(result, vec![Token::Str(s!("PATCH"))]);
 	}
+	
+	//		deserialize_method													
 	#[test]
-	fn from__u8() {
-		let body = UnpackedResponseBody::from(65);
-		assert_eq!(body, UnpackedResponseBody { body: vec![65], ..Default::default() });
-		assert_eq!(body, UnpackedResponseBody { body: b"A".to_vec(), ..Default::default() });
+	fn deserialize_method__basic() {
+		let mut deserializer = TestDeserializer::builder(vec![Token::Str(s!("PATCH"))]).build();
+		let result           = deserialize_method(&mut deserializer);
+		assert_ok_eq!(result, Method::PATCH);
 	}
 	#[test]
-	fn from__vec_u8() {
-		let body1 = UnpackedResponseBody::from(b"This is a test".to_vec());
-		assert_eq!(body1, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		
-		let vec   = b"This is another test".to_vec();
-		let body2 = UnpackedResponseBody::from(vec);
-		assert_eq!(body2, UnpackedResponseBody { body: b"This is another test".to_vec(), ..Default::default() });
-		//	We cannot compare to the original vec after calling from(),
-		//	because it has been consumed.
-		//	Uncommenting the line below would cause a compilation error:
-		//assert_eq!(vec,  b"This is another test".to_vec());
-		
+	fn deserialize_method__invalid() {
+		let mut deserializer = TestDeserializer::builder(vec![Token::Str(s!("NOT VALID"))]).build();
+		let result           = deserialize_method(&mut deserializer);
+		assert_err!(result);
 	}
+	
+	//		serialize_uri														
 	#[test]
-	fn from__vec_u8_ref() {
-		let vec  = b"This is a test".to_vec();
-		let body = UnpackedResponseBody::from(&vec);
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		assert_eq!(vec,  b"This is a test".to_vec());
+	fn serialize_uri__basic() {
+		let serializer = TestSerializer::builder().build();
+		let result     = serialize_uri(&Uri::from_static("https://example.com/foo?bar=baz"), &serializer);
+		assert_ok_eq!(result, vec![Token::Str(s!("https://example.com/foo?bar=baz"))]);
 	}
 	
-	//		from_str															
+	//		deserialize_uri														
 	#[test]
-	fn from_str() {
-		//	Basic ASCII string
-		assert_ok_eq!(UnpackedResponseBody::from_str("Test"), UnpackedResponseBody { body: b"Test".to_vec(), ..Default::default() });
-		//	Strings containing different sizes of UTF8 characters
-		assert_ok_eq!(UnpackedResponseBody::from_str("ñ"),    UnpackedResponseBody { body: s!("ñ").into_bytes(), ..Default::default() });
-		assert_ok_eq!(UnpackedResponseBody::from_str("Ḁ"),    UnpackedResponseBody { body: s!("Ḁ").into_bytes(), ..Default::default() });
-		assert_ok_eq!(UnpackedResponseBody::from_str("𐍈"),    UnpackedResponseBody { body: s!("𐍈").into_bytes(), ..Default::default() });
+	fn deserialize_uri__basic() {
+		let mut deserializer = TestDeserializer::builder(vec![Token::Str(s!("/foo?bar=baz"))]).build();
+		let result           = deserialize_uri(&mut deserializer);
+		assert_ok_eq!(result, Uri::from_static("/foo?bar=baz"));
+	}
+	#[test]
+	fn deserialize_uri__invalid() {
+		let mut deserializer = TestDeserializer::builder(vec![Token::Str(s!("not a valid uri"))]).build();
+		let result           = deserialize_uri(&mut deserializer);
+		assert_err!(result);
 	}
 	
-	//		partial_eq															
+	//		serialize_version													
 	#[test]
-	fn partial_eq() {
-		let body = UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() };
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
-		assert_ne!(body, UnpackedResponseBody { body: b"This is different".to_vec(), ..Default::default() });
+	fn serialize_version__basic() {
+		let serializer = TestSerializer::builder().build();
+		let result     = serialize_version(&Version::HTTP_2, &serializer);
+		assert_ok_eq!(result, vec![Token::Str(s!("HTTP/2.0"))]);
 	}
 	
-	//		serialize															
+	//		deserialize_version													
 	#[test]
-	fn serialize__binary() {
-		let body        = UnpackedResponseBody {
-			body:         b"This is a test".to_vec(),
-			content_type: ContentType::Binary,
-		};
-		let json        = json!("VGhpcyBpcyBhIHRlc3Q=");
-		assert_json_eq!(json!(body), json);
+	fn deserialize_version__basic() {
+		let mut deserializer = TestDeserializer::builder(vec![Token::Str(s!("HTTP/1.0"))]).build();
+		let result           = deserialize_version(&mut deserializer);
+		assert_ok_eq!(result, Version::HTTP_10);
 	}
 	#[test]
-	fn serialize__text() {
-		let body        = UnpackedResponseBody {
-			body:         b"This is a test".to_vec(),
-			content_type: ContentType::Text,
-		};
-		let json        = json!("This is a test");
-		assert_json_eq!(json!(body), json);
+	fn deserialize_version__invalid() {
+		let mut deserializer = TestDeserializer::builder(vec![Token::Str(s!("HTTP/4.0"))]).build();
+		let result           = deserialize_version(&mut deserializer);
+		assert_err!(result);
 	}
 	
-	//		deserialize															
+	//		serialize_raw_header_value											
 	#[test]
-	fn deserialize__binary() {
-		let json        = r#""VGhpcyBpcyBhIHRlc3Q=""#;
-		let body        = UnpackedResponseBody {
-			body:         b"This is a test".to_vec(),
-			content_type: ContentType::Text,
-		};
-		assert_ok_eq!(serde_json::from_str::<UnpackedResponseBody>(json), body);
+	fn serialize_raw_header_value__basic() {
+		let serializer = TestSerializer::builder().build();
+		let result     = serialize_raw_header_value(&[b'a', 0xFF], &serializer);
+		assert_ok_eq!(result, vec![Token::Str(s!("Yf8="))]);
 	}
+	
+	//		deserialize_raw_header_value										
 	#[test]
-	fn deserialize__text() {
-		let json        = r#""This is a test""#;
-		let body        = UnpackedResponseBody {
-			body:         b"This is a test".to_vec(),
-			content_type: ContentType::Text,
-		};
-		assert_ok_eq!(serde_json::from_str::<UnpackedResponseBody>(json), body);
+	fn deserialize_raw_header_value__valid() {
+		let mut deserializer = TestDeserializer::builder([Token::Str(s!("Yf8="))]).build();
+		let result           = deserialize_raw_header_value(&mut deserializer);
+		assert_ok_eq!(result, vec![b'a', 0xFF]);
 	}
-	
-	//		write_str															
 	#[test]
-	fn write_str() {
-		let mut body = UnpackedResponseBody { body: b"This is".to_vec(), ..Default::default() };
-		assert_ok!(body.write_str(" a test"));
-		assert_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() });
+	fn deserialize_raw_header_value__invalid() {
+		let mut deserializer = TestDeserializer::builder([Token::Str(s!("!"))]).build();
+		let result           = deserialize_raw_header_value(&mut deserializer);
+		assert_err!(result);
 	}
-}
-
-//§		ResponseExt																
-#[cfg(test)]
-mod response_ext {
-	use super::*;
 	
-	//		unpack																
+	//		serialize_tagged_body												
 	#[test]
-	fn unpack__basic() {
-		let mut response = Response::builder()
-			.status(StatusCode::OK)
-			.header("foo", "bar")
-			.body(())
-			.unwrap()
-		;
-		let unpacked     = response.unpack();
-		let crafted      = UnpackedResponse {
-			status:        StatusCode::OK,
-			headers:       vec![
-				UnpackedResponseHeader {
-					name:  s!("foo"),
-					value: s!("bar"),
-				},
-			],
-			body:          UnpackedResponseBody { body: b"".to_vec(), ..Default::default() },
-		};
-		assert_ok_eq!(unpacked, crafted);
+	fn serialize_tagged_body__text() {
+		let body = UnpackedResponseBody::new("test");
+		assert_ok_eq!(serialize_tagged_body(&body, JsonSerializer), json!({
+			"content_type": "text",
+			"data":         "test",
+		}));
 	}
 	#[test]
-	fn unpack__string() {
-		let mut response = Response::builder()
-			.status(StatusCode::OK)
-			.body(s!("This is a test"))
-			.unwrap()
-		;
-		let unpacked     = response.unpack();
-		let crafted      = UnpackedResponse {
-			status:        StatusCode::OK,
-			headers:       vec![],
-			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		};
-		assert_ok_eq!(unpacked, crafted);
+	fn serialize_tagged_body__binary() {
+		let body = UnpackedResponseBody { body: b"This is a test".to_vec(), content_type: ContentType::Binary };
+		assert_ok_eq!(serialize_tagged_body(&body, JsonSerializer), json!({
+			"content_type": "binary",
+			"data":         "VGhpcyBpcyBhIHRlc3Q=",
+		}));
 	}
 	#[test]
-	fn unpack__hyper_body() {
-		let mut response = Response::builder()
-			.status(StatusCode::OK)
-			.body(Full::new(Bytes::from("This is a test")))
-			.unwrap()
-		;
-		let unpacked     = response.unpack();
-		let crafted      = UnpackedResponse {
-			status:        StatusCode::OK,
-			headers:       vec![],
-			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		};
-		assert_ok_eq!(unpacked, crafted);
+	fn serialize_tagged_body__json() {
+		let body = UnpackedResponseBody { body: br#"{"foo":[1,2]}"#.to_vec(), content_type: ContentType::Json };
+		assert_ok_eq!(serialize_tagged_body(&body, JsonSerializer), json!({
+			"content_type": "json",
+			"data":         r#"{"foo":[1,2]}"#,
+		}));
 	}
 	#[test]
-	fn unpack__axum_body() {
-		let mut response = (
-			StatusCode::OK,
-			"This is a test",
-		).into_response();
-		let unpacked     = response.unpack();
-		let crafted      = UnpackedResponse {
-			status:        StatusCode::OK,
-			headers:       vec![
-				//  Axum automatically adds a content-type header.
-				UnpackedResponseHeader {
-					name:  s!("content-type"),
-					value: s!("text/plain; charset=utf-8"),
-				},
-			],
-			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		};
-		assert_ok_eq!(unpacked, crafted);
+	fn serialize_tagged_body__invalid_utf8() {
+		let body = UnpackedResponseBody { body: vec![b'a', 0xFF], content_type: ContentType::Text };
+		assert_ok_eq!(serialize_tagged_body(&body, JsonSerializer), json!({
+			"content_type": "text",
+			"data":         "Yf8=",
+			"encoding":     "base64",
+		}));
 	}
-}
-
-//		Functions																
-#[cfg(test)]
-mod functions {
-	use super::*;
 	
-	//		convert_headers														
+	//		deserialize_tagged_body												
 	#[test]
-	fn convert_headers__basic() {
-		let mut headers = HeaderMap::new();
-		drop(headers.insert("foo", HeaderValue::from_static("bar")));
-		let converted   = convert_headers(&headers);
-		let crafted     = vec![
-			UnpackedResponseHeader {
-				name:     s!("foo"),
-				value:    s!("bar"),
-			},
-		];
-		assert_eq!(converted, crafted);
+	fn deserialize_tagged_body__text() {
+		let body = deserialize_tagged_body(json!({ "content_type": "text", "data": "abcd" })).unwrap();
+		assert_eq!(body, UnpackedResponseBody::new("abcd"));
+		assert_eq!(body.content_type(), ContentType::Text);
 	}
 	#[test]
-	fn convert_headers__textcase() {
-		let mut headers = HeaderMap::new();
-		drop(headers.insert("Foo", HeaderValue::from_static("Bar")));
-		let converted   = convert_headers(&headers);
-		let crafted     = vec![
-			UnpackedResponseHeader {
-				name:     s!("foo"),
-				value:    s!("Bar"),
-			},
-		];
-		assert_eq!(converted, crafted);
+	fn deserialize_tagged_body__binary() {
+		let body = deserialize_tagged_body(json!({ "content_type": "binary", "data": "VGhpcyBpcyBhIHRlc3Q=" }));
+		assert_ok_eq!(body, UnpackedResponseBody { body: b"This is a test".to_vec(), content_type: ContentType::Binary });
 	}
 	#[test]
-	fn convert_headers__order() {
-		let mut headers = HeaderMap::new();
-		drop(headers.insert("foo", HeaderValue::from_static("bar")));
-		drop(headers.insert("bar", HeaderValue::from_static("baz")));
-		let converted   = convert_headers(&headers);
-		let crafted1    = vec![
-			UnpackedResponseHeader {
-				name:     s!("foo"),
-				value:    s!("bar"),
-			},
-			UnpackedResponseHeader {
-				name:     s!("bar"),
-				value:    s!("baz"),
-			},
-		];
-		let crafted2    = vec![
-			UnpackedResponseHeader {
-				name:     s!("bar"),
-				value:    s!("baz"),
-			},
-			UnpackedResponseHeader {
-				name:     s!("foo"),
-				value:    s!("bar"),
-			},
-		];
-		assert_ne!(converted, crafted1);
-		assert_eq!(converted, crafted2);
+	fn deserialize_tagged_body__base64_text() {
+		let body = deserialize_tagged_body(json!({ "content_type": "text", "data": "Yf8=", "encoding": "base64" }));
+		assert_ok_eq!(body, UnpackedResponseBody { body: vec![b'a', 0xFF], content_type: ContentType::Text });
 	}
 	#[test]
-	fn convert_headers__duplicates() {
-		let mut headers = HeaderMap::new();
-		_               = headers.append("foo", HeaderValue::from_static("bar"));
-		_               = headers.append("bar", HeaderValue::from_static("baz"));
-		_               = headers.append("foo", HeaderValue::from_static("baz"));
-		let converted   = convert_headers(&headers);
-		let crafted     = vec![
-			UnpackedResponseHeader {
-				name:     s!("bar"),
-				value:    s!("baz"),
-			},
-			UnpackedResponseHeader {
-				name:     s!("foo"),
-				value:    s!("bar"),
-			},
-			UnpackedResponseHeader {
-				name:     s!("foo"),
-				value:    s!("baz"),
-			},
-		];
-		assert_eq!(converted, crafted);
+	fn deserialize_tagged_body__invalid_base64() {
+		assert_err!(deserialize_tagged_body(json!({ "content_type": "binary", "data": "!" })));
 	}
 	#[test]
-	fn convert_headers__no_duplicates() {
-		let mut headers = HeaderMap::new();
-		drop(headers.insert("foo", HeaderValue::from_static("bar")));
-		drop(headers.insert("bar", HeaderValue::from_static("baz")));
-		drop(headers.insert("foo", HeaderValue::from_static("baz")));
-		let converted   = convert_headers(&headers);
-		let crafted     = vec![
-			UnpackedResponseHeader {
-				name:     s!("bar"),
-				value:    s!("baz"),
-			},
-			UnpackedResponseHeader {
-				name:     s!("foo"),
-				value:    s!("baz"),
-			},
-		];
-		assert_eq!(converted, crafted);
+	fn deserialize_tagged_body__unknown_encoding() {
+		let err = deserialize_tagged_body(json!({ "content_type": "text", "data": "test", "encoding": "hex" })).unwrap_err();
+		assert_eq!(err.to_string(), "Unsupported body encoding: hex");
 	}
-	
-	//		convert_response													
 	#[test]
-	fn convert_response__basic() {
-		let mut headers  = HeaderMap::new();
-		drop(headers.insert("foo", HeaderValue::from_static("bar")));
-		let converted    = convert_response(StatusCode::OK, &headers, &Bytes::from("This is a test"));
-		let crafted      = UnpackedResponse {
-			status:        StatusCode::OK,
-			headers:       vec![
-				UnpackedResponseHeader {
-					name:  s!("foo"),
-					value: s!("bar"),
-				},
-			],
-			body:          UnpackedResponseBody { body: b"This is a test".to_vec(), ..Default::default() },
-		};
-		assert_eq!(converted, crafted);
+	fn deserialize_tagged_body__unknown_content_type() {
+		assert_err!(deserialize_tagged_body(json!({ "content_type": "image", "data": "test" })));
+	}
+	#[test]
+	fn deserialize_tagged_body__round_trip() {
+		#[derive(Debug, Deserialize, PartialEq, Serialize)]
+		struct Wrapper {
+			#[serde(serialize_with = "serialize_tagged_body", deserialize_with = "deserialize_tagged_body")]
+			body: UnpackedResponseBody,
+		}
+		for body in [
+			UnpackedResponseBody::new("test"),
+			UnpackedResponseBody::new("abcd"),
+			UnpackedResponseBody { body: br#"{"foo":[1,2]}"#.to_vec(), content_type: ContentType::Json },
+			UnpackedResponseBody { body: vec![0x89, b'P', b'N', b'G'], content_type: ContentType::Binary },
+			UnpackedResponseBody { body: vec![b'a', 0xFF], content_type: ContentType::Text },
+		] {
+			let wrapper = Wrapper { body };
+			let json    = serde_json::to_string(&wrapper).unwrap();
+			let result  = serde_json::from_str::<Wrapper>(&json).unwrap();
+			assert_eq!(result, wrapper);
+			assert_eq!(result.body.content_type(), wrapper.body.content_type());
+		}
 	}
 	
-	//		serialize_status_code												
+	//		serialize_tagged_response											
 	#[test]
-	fn serialize_status_code__basic() {
-		let status_code = StatusCode::OK;
-		let serializer  = TestSerializer::builder().build();
-		let result      = serialize_status_code(&status_code, &serializer);
-		assert_ok_eq!(result, vec![Token::U16(200)]);
+	fn serialize_tagged_response__basic() {
+		let mut response = UnpackedResponse::new(StatusCode::OK, vec![(s!("x-foo"), s!("bar"))], "pong");
+		response.frames  = vec![UnpackedFrame::Data(UnpackedResponseBody::new("pong"))];
+		assert_ok_eq!(serialize_tagged_response(&response, JsonSerializer), json!({
+			"status":  200,
+			"headers": [{ "name": "x-foo", "value": "bar" }],
+			"body":    { "content_type": "text", "data": "pong" },
+			"frames":  [{ "Data": { "content_type": "text", "data": "pong" } }],
+		}));
 	}
 	
-	//		deserialize_status_code												
+	//		deserialize_tagged_response											
 	#[test]
-	fn deserialize_status_code__basic() {
-		let mut deserializer = TestDeserializer::builder(vec![Token::U16(200)]).build();
-		let result           = deserialize_status_code(&mut deserializer);
-		assert_ok_eq!(result, StatusCode::OK);
+	fn deserialize_tagged_response__basic() {
+		let response = deserialize_tagged_response(json!({
+			"status":  404,
+			"headers": [],
+			"body":    { "content_type": "text", "data": "test" },
+		})).unwrap();
+		assert_eq!(response,                     UnpackedResponse::new(StatusCode::NOT_FOUND, vec![], "test"));
+		assert_eq!(response.body.content_type(), ContentType::Text);
+		assert!(response.frames.is_empty());
+	}
+	#[test]
+	fn deserialize_tagged_response__invalid_body() {
+		assert_err!(deserialize_tagged_response(json!({ "status": 200, "headers": [], "body": "test" })));
+	}
+	#[test]
+	fn deserialize_tagged_response__round_trip() {
+		#[derive(Debug, Deserialize, Serialize)]
+		struct Wrapper {
+			#[serde(serialize_with = "serialize_tagged_response", deserialize_with = "deserialize_tagged_response")]
+			response: UnpackedResponse,
+		}
+		let mut response = UnpackedResponse::new(StatusCode::OK, vec![], "pong");
+		response.frames  = vec![
+			UnpackedFrame::Data(UnpackedResponseBody::new("pong")),
+			UnpackedFrame::Trailers(vec![UnpackedResponseHeader::new(s!("x-checksum"), s!("abc123"))]),
+		];
+		let json         = serde_json::to_string(&Wrapper { response: response.clone() }).unwrap();
+		let result       = serde_json::from_str::<Wrapper>(&json).unwrap().response;
+		assert_eq!(result,                     response);
+		assert_eq!(result.body.content_type(), ContentType::Text);
+		assert_eq!(result.frames,              response.frames);
 	}
 }
-
-